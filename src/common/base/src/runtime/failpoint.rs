@@ -0,0 +1,259 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Failpoints inject failures at named sites of the query execution, so that the
+//! error propagation and cleanup of the rarely hit paths can be covered by tests.
+//!
+//! Failpoints are activated per query with the `failpoints` setting, which is a list of
+//! `<name>=<action>[*<times>|%<probability>]` separated by `;`, for example:
+//!
+//! ```text
+//! set failpoints = 'spiller_write=error*1;udf_server_call=timeout%0.5';
+//! ```
+//!
+//! The sites are declared with [`fail_point!`](crate::fail_point), which is a single atomic load
+//! if no query has activated failpoints.
+//!
+//! Failpoints are only compiled in with the `failpoints` feature, which is enabled for the debug
+//! builds that run the tests. In other builds `fail_point!` expands to nothing and the `failpoints`
+//! setting does not exist.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parking_lot::RwLock;
+
+use crate::runtime::ThreadTracker;
+
+/// Names of all the failpoints, a failpoint must be listed here before it can be activated.
+pub const FAIL_POINT_NAMES: &[&str] = &[
+    "spiller_write",
+    "spiller_read",
+    "exchange_send",
+    "exchange_recv",
+    "fuse_commit_snapshot",
+    "udf_server_call",
+];
+
+/// The failure injected when a failpoint is hit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailPointAction {
+    /// Return a `FailPointTriggered` error.
+    Error,
+    /// Return a `Timeout` error.
+    Timeout,
+    /// Return an `AbortedQuery` error, as if the query is cancelled.
+    Abort,
+    /// Panic at the failpoint.
+    Panic,
+}
+
+/// When the action of a failpoint is taken.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FailPointTrigger {
+    Always,
+    /// Only the first n hits fail.
+    Times(u64),
+    /// Every hit fails with the probability.
+    Probability(f64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FailPointConfig {
+    pub name: String,
+    pub action: FailPointAction,
+    pub trigger: FailPointTrigger,
+}
+
+impl FailPointConfig {
+    pub fn parse(config: &str) -> Result<Vec<FailPointConfig>> {
+        config
+            .split(';')
+            .map(|item| item.trim())
+            .filter(|item| !item.is_empty())
+            .map(Self::parse_item)
+            .collect()
+    }
+
+    fn parse_item(item: &str) -> Result<FailPointConfig> {
+        let bad_config =
+            |reason: &str| ErrorCode::BadArguments(format!("Invalid failpoint '{item}': {reason}"));
+
+        let (name, action) = item
+            .split_once('=')
+            .ok_or_else(|| bad_config("expect <name>=<action>[*<times>|%<probability>]"))?;
+        let name = name.trim();
+        if !FAIL_POINT_NAMES.contains(&name) {
+            return Err(bad_config(&format!(
+                "unknown failpoint, available failpoints are: {}",
+                FAIL_POINT_NAMES.join(", ")
+            )));
+        }
+
+        let action = action.trim();
+        let (action, trigger) = if let Some((action, times)) = action.split_once('*') {
+            let times = times
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| bad_config("times must be an unsigned integer"))?;
+            (action, FailPointTrigger::Times(times))
+        } else if let Some((action, probability)) = action.split_once('%') {
+            let probability = probability
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|p| (0.0..=1.0).contains(p))
+                .ok_or_else(|| bad_config("probability must be between 0 and 1"))?;
+            (action, FailPointTrigger::Probability(probability))
+        } else {
+            (action, FailPointTrigger::Always)
+        };
+
+        let action = match action.trim().to_lowercase().as_str() {
+            "error" => FailPointAction::Error,
+            "timeout" => FailPointAction::Timeout,
+            "abort" => FailPointAction::Abort,
+            "panic" => FailPointAction::Panic,
+            _ => {
+                return Err(bad_config(
+                    "action must be one of error, timeout, abort and panic",
+                ));
+            }
+        };
+
+        Ok(FailPointConfig {
+            name: name.to_string(),
+            action,
+            trigger,
+        })
+    }
+}
+
+struct FailPoint {
+    action: FailPointAction,
+    trigger: FailPointTrigger,
+    hits: AtomicU64,
+}
+
+impl FailPoint {
+    fn should_fail(&self) -> bool {
+        let hits = self.hits.fetch_add(1, Ordering::Relaxed);
+        match self.trigger {
+            FailPointTrigger::Always => true,
+            FailPointTrigger::Times(times) => hits < times,
+            FailPointTrigger::Probability(probability) => {
+                // RandomState is seeded randomly for each instance, which is enough for testing.
+                let random = RandomState::new().build_hasher().finish();
+                (random as f64 / u64::MAX as f64) < probability
+            }
+        }
+    }
+
+    fn fail(&self, name: &str) -> Result<()> {
+        let message = format!("Failpoint '{name}' is triggered");
+        match self.action {
+            FailPointAction::Error => Err(ErrorCode::FailPointTriggered(message)),
+            FailPointAction::Timeout => Err(ErrorCode::Timeout(message)),
+            FailPointAction::Abort => Err(ErrorCode::AbortedQuery(message)),
+            FailPointAction::Panic => panic!("{message}"),
+        }
+    }
+}
+
+type QueryFailPoints = Arc<HashMap<String, FailPoint>>;
+
+static FAIL_POINTS: LazyLock<RwLock<HashMap<String, QueryFailPoints>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+// The number of queries with failpoints, used to skip the lookup in the common case.
+static ACTIVE_QUERIES: AtomicUsize = AtomicUsize::new(0);
+
+pub struct FailPoints;
+
+impl FailPoints {
+    /// Activate the failpoints of the query, replacing the previously activated ones.
+    pub fn enable(query_id: &str, config: &str) -> Result<()> {
+        let configs = FailPointConfig::parse(config)?;
+        if configs.is_empty() {
+            Self::disable(query_id);
+            return Ok(());
+        }
+
+        let fail_points = configs
+            .into_iter()
+            .map(|config| {
+                (config.name, FailPoint {
+                    action: config.action,
+                    trigger: config.trigger,
+                    hits: AtomicU64::new(0),
+                })
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut queries = FAIL_POINTS.write();
+        queries.insert(query_id.to_string(), Arc::new(fail_points));
+        ACTIVE_QUERIES.store(queries.len(), Ordering::Release);
+        Ok(())
+    }
+
+    pub fn disable(query_id: &str) {
+        if ACTIVE_QUERIES.load(Ordering::Acquire) == 0 {
+            return;
+        }
+
+        let mut queries = FAIL_POINTS.write();
+        queries.remove(query_id);
+        ACTIVE_QUERIES.store(queries.len(), Ordering::Release);
+    }
+
+    /// Check the failpoint for the query tracked by the current thread.
+    pub fn check(name: &str) -> Result<()> {
+        if ACTIVE_QUERIES.load(Ordering::Acquire) == 0 {
+            return Ok(());
+        }
+
+        let Some(query_id) = ThreadTracker::query_id() else {
+            return Ok(());
+        };
+        let Some(fail_points) = FAIL_POINTS.read().get(query_id).cloned() else {
+            return Ok(());
+        };
+        match fail_points.get(name) {
+            Some(fail_point) if fail_point.should_fail() => fail_point.fail(name),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Declare a failpoint, which returns the injected error from the enclosing function
+/// if it's activated for the current query.
+///
+/// ```ignore
+/// fail_point!("spiller_write");
+/// ```
+#[macro_export]
+macro_rules! fail_point {
+    ($name:expr) => {
+        $crate::runtime::FailPoints::check($name)?;
+    };
+}
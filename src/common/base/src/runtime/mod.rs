@@ -16,6 +16,8 @@ mod backtrace;
 mod catch_unwind;
 mod defer;
 pub mod error_info;
+#[cfg(feature = "failpoints")]
+mod failpoint;
 mod global_runtime;
 mod memory;
 pub mod metrics;
@@ -32,6 +34,16 @@ pub use catch_unwind::catch_unwind;
 pub use catch_unwind::drop_guard;
 pub use catch_unwind::CatchUnwindFuture;
 pub use defer::defer;
+#[cfg(feature = "failpoints")]
+pub use failpoint::FailPointAction;
+#[cfg(feature = "failpoints")]
+pub use failpoint::FailPointConfig;
+#[cfg(feature = "failpoints")]
+pub use failpoint::FailPointTrigger;
+#[cfg(feature = "failpoints")]
+pub use failpoint::FailPoints;
+#[cfg(feature = "failpoints")]
+pub use failpoint::FAIL_POINT_NAMES;
 pub use global_runtime::GlobalIORuntime;
 pub use global_runtime::GlobalQueryRuntime;
 pub use memory::set_alloc_error_hook;
@@ -56,3 +68,10 @@ pub use runtime_tracker::TrackingPayload;
 pub use runtime_tracker::UnlimitedFuture;
 pub use thread::Thread;
 pub use thread::ThreadJoinHandle;
+
+/// Failpoints are not compiled in without the `failpoints` feature.
+#[cfg(not(feature = "failpoints"))]
+#[macro_export]
+macro_rules! fail_point {
+    ($name:expr) => {};
+}
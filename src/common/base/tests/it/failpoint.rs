@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::fail_point;
+use databend_common_base::runtime::FailPointAction;
+use databend_common_base::runtime::FailPointConfig;
+use databend_common_base::runtime::FailPointTrigger;
+use databend_common_base::runtime::FailPoints;
+use databend_common_base::runtime::ThreadTracker;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+fn spill() -> Result<()> {
+    fail_point!("spiller_write");
+    Ok(())
+}
+
+#[test]
+fn test_parse_failpoints() -> Result<()> {
+    let configs = FailPointConfig::parse(
+        "spiller_write=error; exchange_send=abort*2;udf_server_call=timeout%0.5;",
+    )?;
+    assert_eq!(configs, vec![
+        FailPointConfig {
+            name: "spiller_write".to_string(),
+            action: FailPointAction::Error,
+            trigger: FailPointTrigger::Always,
+        },
+        FailPointConfig {
+            name: "exchange_send".to_string(),
+            action: FailPointAction::Abort,
+            trigger: FailPointTrigger::Times(2),
+        },
+        FailPointConfig {
+            name: "udf_server_call".to_string(),
+            action: FailPointAction::Timeout,
+            trigger: FailPointTrigger::Probability(0.5),
+        },
+    ]);
+
+    assert!(FailPointConfig::parse("")?.is_empty());
+    for config in [
+        "spiller_write",
+        "unknown=error",
+        "spiller_write=oops",
+        "spiller_write=error*-1",
+        "spiller_write=error%2",
+    ] {
+        let err = FailPointConfig::parse(config).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS, "{config}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_check_failpoints() -> Result<()> {
+    let query_id = "test_check_failpoints";
+    let mut tracking_payload = ThreadTracker::new_tracking_payload();
+    tracking_payload.query_id = Some(query_id.to_string());
+    let _guard = ThreadTracker::tracking(tracking_payload);
+
+    assert!(spill().is_ok());
+
+    FailPoints::enable(query_id, "spiller_write=error*2")?;
+    for _ in 0..2 {
+        let err = spill().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::FAIL_POINT_TRIGGERED);
+    }
+    assert!(spill().is_ok());
+
+    // Failpoints of other queries are not affected.
+    FailPoints::enable("other_query", "spiller_write=error")?;
+    assert!(spill().is_ok());
+    FailPoints::disable("other_query");
+
+    FailPoints::enable(query_id, "spiller_write=timeout")?;
+    assert_eq!(spill().unwrap_err().code(), ErrorCode::TIMEOUT);
+    FailPoints::disable(query_id);
+    assert!(spill().is_ok());
+
+    Ok(())
+}
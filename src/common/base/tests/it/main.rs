@@ -16,6 +16,8 @@
 
 use databend_common_base::mem_allocator::GlobalAllocator;
 
+#[cfg(feature = "failpoints")]
+mod failpoint;
 mod fixed_heap;
 mod memory;
 mod metrics;
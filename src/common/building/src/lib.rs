@@ -64,6 +64,7 @@ pub fn set_env_config() {
         .fail_on_error()
         .build_timestamp()
         .cargo_features()
+        .cargo_target_triple()
         .rustc_semver()
         .git_sha(true)
         .emit()
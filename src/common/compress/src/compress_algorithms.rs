@@ -87,4 +87,63 @@ impl CompressAlgorithm {
 
         CompressAlgorithm::from_extension(&ext)
     }
+
+    /// Create CompressAlgorithm from the magic bytes at the beginning of the data.
+    ///
+    /// Only gzip, zstd, bzip2 and xz have a reliable signature, `None` will be return
+    /// for other algorithms and uncompressed data.
+    pub fn from_magic_bytes(data: &[u8]) -> Option<CompressAlgorithm> {
+        match data {
+            [0x1f, 0x8b, ..] => Some(CompressAlgorithm::Gzip),
+            [0x28, 0xb5, 0x2f, 0xfd, ..] => Some(CompressAlgorithm::Zstd),
+            [0xfd, b'7', b'z', b'X', b'Z', 0x00, ..] => Some(CompressAlgorithm::Xz),
+            // `BZh` is plain text, so the block size and the magic of the first block
+            // are also checked to avoid misjudging text files.
+            [
+                b'B',
+                b'Z',
+                b'h',
+                b'1'..=b'9',
+                b'1',
+                b'A',
+                b'Y',
+                b'&',
+                b'S',
+                b'Y',
+                ..,
+            ] => Some(CompressAlgorithm::Bz2),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_compress_algorithm_from_magic_bytes() -> std::io::Result<()> {
+        let cases = [
+            ("ontime_200.csv", None),
+            ("ontime_200.csv.gz", Some(CompressAlgorithm::Gzip)),
+            ("ontime_200.csv.zst", Some(CompressAlgorithm::Zstd)),
+            ("ontime_200.csv.bz2", Some(CompressAlgorithm::Bz2)),
+            ("ontime_200.csv.xz", Some(CompressAlgorithm::Xz)),
+        ];
+        for (file, expected) in cases {
+            let content = fs::read(format!(
+                "{}/tests/data/{}",
+                env::current_dir()?.to_string_lossy(),
+                file
+            ))?;
+            assert_eq!(CompressAlgorithm::from_magic_bytes(&content), expected);
+        }
+        assert_eq!(CompressAlgorithm::from_magic_bytes(&[0x1f]), None);
+        assert_eq!(CompressAlgorithm::from_magic_bytes(b"BZh,1,2\n"), None);
+
+        Ok(())
+    }
 }
@@ -157,6 +157,12 @@ build_exceptions! {
     OutofSequenceRange(1124),
     WrongSequenceCount(1125),
     UnknownSequence(1126),
+    // The persisted definition of an object (view, udf, computed column) is broken.
+    InvalidObjectDefinition(1127),
+    // The estimated or observed number of group by keys exceeds the limit.
+    TooManyGroupByKeys(1128),
+    // Injected by an activated failpoint, only used in tests.
+    FailPointTriggered(1129),
 
     // Data Related Errors
 
@@ -226,6 +232,8 @@ build_exceptions! {
     InvalidArgument(2004),
     // Meta service replied with invalid data
     InvalidReply(2005),
+    // Meta service is considered unhealthy, requests fail fast without being sent
+    MetaServiceUnavailable(2006),
 
     TableVersionMismatched(2009),
     OCCRetryFailure(2011),
@@ -317,6 +325,8 @@ build_exceptions! {
     UDFSchemaMismatch(2605),
     UnsupportedDataType(2606),
     UDFDataError(2607),
+    // The request to UDF server exceeds the message size limit.
+    UDFRequestTooLarge(2608),
 
     // Database error codes.
     UnknownDatabaseEngine(2701),
@@ -33,3 +33,4 @@ pub use crate::metrics::openai;
 pub use crate::metrics::session;
 pub use crate::metrics::storage;
 pub use crate::metrics::system;
+pub use crate::metrics::udf;
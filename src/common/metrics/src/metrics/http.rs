@@ -17,10 +17,12 @@ use std::time::Duration;
 
 use databend_common_base::runtime::metrics::register_counter;
 use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::register_gauge;
 use databend_common_base::runtime::metrics::register_histogram_family_in_seconds;
 use databend_common_base::runtime::metrics::Counter;
 use databend_common_base::runtime::metrics::FamilyCounter;
 use databend_common_base::runtime::metrics::FamilyHistogram;
+use databend_common_base::runtime::metrics::Gauge;
 
 use crate::VecLabels;
 
@@ -34,6 +36,10 @@ static QUERY_HTTP_RESPONSE_ERRORS_COUNT: LazyLock<FamilyCounter<VecLabels>> =
     LazyLock::new(|| register_counter_family("query_http_response_errors_count"));
 static QUERY_HTTP_RESPONSE_PANICS_COUNT: LazyLock<Counter> =
     LazyLock::new(|| register_counter("query_http_response_panics_count"));
+static QUERY_HTTP_RESULT_BUFFERED_BYTES: LazyLock<Gauge> =
+    LazyLock::new(|| register_gauge("query_http_result_buffered_bytes"));
+static QUERY_HTTP_RESULT_EVICTED_BYTES: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("query_http_result_evicted_bytes"));
 
 pub fn metrics_incr_http_request_count(method: String, api: String, status: String) {
     let labels = vec![("method", method), ("api", api), ("status", status)];
@@ -62,3 +68,11 @@ pub fn metrics_observe_http_response_duration(method: String, api: String, durat
 pub fn metrics_incr_http_response_panics_count() {
     QUERY_HTTP_RESPONSE_PANICS_COUNT.inc();
 }
+
+pub fn metrics_set_http_result_buffered_bytes(bytes: usize) {
+    QUERY_HTTP_RESULT_BUFFERED_BYTES.set(bytes as i64);
+}
+
+pub fn metrics_incr_http_result_evicted_bytes(bytes: usize) {
+    QUERY_HTTP_RESULT_EVICTED_BYTES.inc_by(bytes as u64);
+}
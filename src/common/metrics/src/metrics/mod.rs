@@ -22,3 +22,4 @@ pub mod openai;
 pub mod session;
 pub mod storage;
 pub mod system;
+pub mod udf;
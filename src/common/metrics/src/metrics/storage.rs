@@ -16,12 +16,16 @@ use std::sync::LazyLock;
 use std::time::Duration;
 
 use databend_common_base::runtime::metrics::register_counter;
+use databend_common_base::runtime::metrics::register_counter_family;
 use databend_common_base::runtime::metrics::register_gauge;
 use databend_common_base::runtime::metrics::register_histogram_in_milliseconds;
 use databend_common_base::runtime::metrics::Counter;
+use databend_common_base::runtime::metrics::FamilyCounter;
 use databend_common_base::runtime::metrics::Gauge;
 use databend_common_base::runtime::metrics::Histogram;
 
+use crate::VecLabels;
+
 // Common metrics.
 static OMIT_FILTER_ROWGROUPS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("omit_filter_rowgroups"));
@@ -119,6 +123,10 @@ static COMMIT_COPIED_FILES: LazyLock<Counter> =
 static COMMIT_MILLISECONDS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_commit_milliseconds"));
 static COMMIT_ABORTS: LazyLock<Counter> = LazyLock::new(|| register_counter("fuse_commit_aborts"));
+static COMMIT_TABLE_RETRIES: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("fuse_commit_table_retries"));
+static COMMIT_TABLE_CONFLICTS: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("fuse_commit_table_conflicts"));
 static REMOTE_IO_SEEKS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_remote_io_seeks"));
 static REMOTE_IO_SEEKS_AFTER_MERGED: LazyLock<Counter> =
@@ -465,6 +473,16 @@ pub fn metrics_inc_commit_aborts() {
     COMMIT_ABORTS.inc();
 }
 
+pub fn metrics_inc_commit_table_retries() {
+    COMMIT_TABLE_RETRIES.inc();
+}
+
+/// `conflict_type` is one of `append`, `mutation` or `unresolvable`.
+pub fn metrics_inc_commit_table_conflicts(conflict_type: &str) {
+    let labels = &vec![("type", conflict_type.to_string())];
+    COMMIT_TABLE_CONFLICTS.get_or_create(labels).inc();
+}
+
 pub fn metrics_inc_remote_io_seeks(c: u64) {
     REMOTE_IO_SEEKS.inc_by(c);
 }
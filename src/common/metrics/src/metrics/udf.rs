@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::LazyLock;
+
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::FamilyCounter;
+
+use crate::VecLabels;
+
+// The counters are incremented inside the UDF transform, whose tracking payload carries
+// the scoped registry of its plan, so the samples are also dumped into the `metrics`
+// of the plan profile: the profile of a query shows which addresses were requested
+// and which ones failed over.
+//
+// Only the address is used as label, the addresses are normalized by `udf_server_addresses`
+// and must be in `udf_server_allow_list`, so the number of label values is bounded.
+static UDF_SERVER_REQUEST_COUNT: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("udf_server_request_count"));
+static UDF_SERVER_FAILOVER_COUNT: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("udf_server_failover_count"));
+
+const LABEL_ADDRESS: &str = "address";
+
+pub fn metrics_inc_udf_server_request_count(address: &str) {
+    let labels = &vec![(LABEL_ADDRESS, address.to_string())];
+    UDF_SERVER_REQUEST_COUNT.get_or_create(labels).inc();
+}
+
+pub fn metrics_inc_udf_server_failover_count(address: &str) {
+    let labels = &vec![(LABEL_ADDRESS, address.to_string())];
+    UDF_SERVER_FAILOVER_COUNT.get_or_create(labels).inc();
+}
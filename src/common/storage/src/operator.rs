@@ -300,6 +300,10 @@ fn init_s3_operator(cfg: &StorageS3Config) -> Result<impl Builder> {
         builder.enable_virtual_host_style();
     }
 
+    // Server-side encryption
+    builder.server_side_encryption(&cfg.server_side_encryption);
+    builder.server_side_encryption_aws_kms_key_id(&cfg.server_side_encryption_aws_kms_key_id);
+
     builder.http_client(new_storage_http_client()?);
 
     Ok(builder)
@@ -33,6 +33,22 @@ pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
     Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
 }
 
+pub fn l1_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    if from.len() != to.len() {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "Vector length not equal: {:} != {:}",
+            from.len(),
+            to.len(),
+        )));
+    }
+
+    Ok(from
+        .iter()
+        .zip(to.iter())
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f32>())
+}
+
 pub fn l2_distance(from: &[f32], to: &[f32]) -> Result<f32> {
     if from.len() != to.len() {
         return Err(ErrorCode::InvalidArgument(format!(
@@ -67,6 +83,22 @@ pub fn cosine_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
     Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
 }
 
+pub fn l1_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
+    if from.len() != to.len() {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "Vector length not equal: {:} != {:}",
+            from.len(),
+            to.len(),
+        )));
+    }
+
+    Ok(from
+        .iter()
+        .zip(to.iter())
+        .map(|(a, b)| (a - b).abs())
+        .sum::<f64>())
+}
+
 pub fn l2_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
     if from.len() != to.len() {
         return Err(ErrorCode::InvalidArgument(format!(
@@ -83,3 +115,7 @@ pub fn l2_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
         .sum::<f64>()
         .sqrt())
 }
+
+pub fn vector_norm_64(vector: &[f64]) -> f64 {
+    vector.iter().map(|v| v.powi(2)).sum::<f64>().sqrt()
+}
@@ -41,6 +41,7 @@ use databend_common_meta_app::app_error::GetIndexWithDropTime;
 use databend_common_meta_app::app_error::IndexAlreadyExists;
 use databend_common_meta_app::app_error::IndexColumnIdNotFound;
 use databend_common_meta_app::app_error::MultiStmtTxnCommitFailed;
+use databend_common_meta_app::app_error::RenameDependentChanged;
 use databend_common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use databend_common_meta_app::app_error::StreamAlreadyExists;
 use databend_common_meta_app::app_error::StreamVersionMismatched;
@@ -63,6 +64,8 @@ use databend_common_meta_app::app_error::VirtualColumnAlreadyExists;
 use databend_common_meta_app::data_mask::MaskPolicyTableIdListIdent;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
 use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::principal::RoleIdent;
+use databend_common_meta_app::principal::TenantUserIdent;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdentRaw;
 use databend_common_meta_app::schema::CatalogIdIdent;
@@ -144,6 +147,7 @@ use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameDependents;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetLVTReply;
@@ -208,6 +212,7 @@ use databend_common_meta_types::TxnGetRequest;
 use databend_common_meta_types::TxnGetResponse;
 use databend_common_meta_types::TxnOp;
 use databend_common_meta_types::TxnRequest;
+use databend_common_proto_conv::FromToProto;
 use futures::TryStreamExt;
 use log::debug;
 use log::error;
@@ -633,7 +638,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 db_id_list.pop();
                 new_db_id_list.append(old_db_id);
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition: vec![
                         // Prevent renaming or deleting in other threads.
                         txn_cond_seq(tenant_dbname, Eq, old_db_id_seq),
@@ -655,6 +660,14 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     ],
                     else_then: vec![],
                 };
+                construct_rename_dependents_txn_operations(
+                    self,
+                    tenant_dbname.tenant(),
+                    &req.dependents,
+                    func_name!(),
+                    &mut txn_req,
+                )
+                .await?;
 
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
@@ -2015,11 +2028,19 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                     );
                 }
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition,
                     if_then: then_ops,
                     else_then: vec![],
                 };
+                construct_rename_dependents_txn_operations(
+                    self,
+                    &tenant_dbname_tbname.tenant,
+                    &req.dependents,
+                    func_name!(),
+                    &mut txn_req,
+                )
+                .await?;
 
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
@@ -4262,6 +4283,68 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
     }
 }
 
+/// Append the conditions and operations updating the dependents of a rename to `txn`.
+///
+/// Returns `RenameDependentChanged` if any dependent is changed after it's read by the caller.
+async fn construct_rename_dependents_txn_operations(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    tenant: &Tenant,
+    dependents: &RenameDependents,
+    ctx: &str,
+    txn: &mut TxnRequest,
+) -> Result<(), KVAppError> {
+    for (table_id, old, new) in &dependents.views {
+        let key = TableId {
+            table_id: *table_id,
+        };
+        let object = format!("view {}", table_id);
+        construct_rename_dependent_txn_operations(kv_api, &key, old, new, object, ctx, txn).await?;
+    }
+    for (index_id, old, new) in &dependents.indexes {
+        let key = IndexId {
+            index_id: *index_id,
+        };
+        let object = format!("index {}", index_id);
+        construct_rename_dependent_txn_operations(kv_api, &key, old, new, object, ctx, txn).await?;
+    }
+    for (old, new) in &dependents.users {
+        let key = TenantUserIdent::new(tenant, old.identity());
+        let object = format!("user {}", old.identity().display());
+        construct_rename_dependent_txn_operations(kv_api, &key, old, new, object, ctx, txn).await?;
+    }
+    for (old, new) in &dependents.roles {
+        let key = RoleIdent::new(tenant, &old.name);
+        let object = format!("role '{}'", old.name);
+        construct_rename_dependent_txn_operations(kv_api, &key, old, new, object, ctx, txn).await?;
+    }
+    Ok(())
+}
+
+/// Update the value of `key` from `old` to `new` in `txn`, fails if it's not `old` now.
+async fn construct_rename_dependent_txn_operations<K>(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    key: &K,
+    old: &K::ValueType,
+    new: &K::ValueType,
+    object: String,
+    ctx: &str,
+    txn: &mut TxnRequest,
+) -> Result<(), KVAppError>
+where
+    K: kvapi::Key,
+    K::ValueType: FromToProto + PartialEq,
+{
+    let (seq, value) = get_pb_value(kv_api, key).await?;
+    if value.as_ref() != Some(old) {
+        return Err(KVAppError::AppError(AppError::from(
+            RenameDependentChanged::new(object, ctx),
+        )));
+    }
+    txn.condition.push(txn_cond_seq(key, Eq, seq));
+    txn.if_then.push(txn_op_put(key, serialize_struct(new)?));
+    Ok(())
+}
+
 async fn construct_drop_virtual_column_txn_operations(
     kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
     name_ident: &VirtualColumnIdent,
@@ -96,6 +96,7 @@ use databend_common_meta_app::schema::ListTableReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockKey;
 use databend_common_meta_app::schema::RenameDatabaseReq;
+use databend_common_meta_app::schema::RenameDependents;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SetLVTReq;
@@ -512,6 +513,7 @@ impl SchemaApiTestSuite {
                 if_exists: false,
                 name_ident: db_name_ident.clone(),
                 new_db_name: db2_name.to_string(),
+                dependents: Default::default(),
             };
             let res = mt.rename_database(req).await;
             info!("rename database res: {:?}", res);
@@ -546,6 +548,7 @@ impl SchemaApiTestSuite {
                     },
                     new_db_name: db2_name.to_string(),
                     new_table_name: table2_name.to_string(),
+                    dependents: Default::default(),
                 })
                 .await;
             debug!("--- rename table on unknown database got: {:?}", got);
@@ -571,6 +574,7 @@ impl SchemaApiTestSuite {
                     },
                     new_db_name: db3_name.to_string(),
                     new_table_name: table3_name.to_string(),
+                    dependents: Default::default(),
                 })
                 .await;
             debug!("--- rename table on unknown database got: {:?}", got);
@@ -1071,6 +1075,7 @@ impl SchemaApiTestSuite {
                 if_exists: false,
                 name_ident: DatabaseNameIdent::new(&tenant, db_name),
                 new_db_name: new_db_name.to_string(),
+                dependents: Default::default(),
             };
 
             let res = mt.rename_database(req).await;
@@ -1094,6 +1099,7 @@ impl SchemaApiTestSuite {
                     if_exists: false,
                     name_ident: DatabaseNameIdent::new(&tenant, "db4"),
                     new_db_name: db_name.to_string(),
+                    dependents: Default::default(),
                 };
 
                 let res = mt.rename_database(req).await;
@@ -1116,6 +1122,7 @@ impl SchemaApiTestSuite {
                 if_exists: false,
                 name_ident: DatabaseNameIdent::new(&tenant, db_name),
                 new_db_name: db2_name.to_string(),
+                dependents: Default::default(),
             };
 
             let res = mt.rename_database(req).await;
@@ -1134,6 +1141,7 @@ impl SchemaApiTestSuite {
                 name_ident: DatabaseNameIdent::new(&tenant, db_name),
 
                 new_db_name: new_db_name.to_string(),
+                dependents: Default::default(),
             };
             let res = mt.rename_database(req).await;
             info!("rename database res: {:?}", res);
@@ -1353,6 +1361,7 @@ impl SchemaApiTestSuite {
                 if_exists: false,
                 name_ident: DatabaseNameIdent::new(&tenant, db_name),
                 new_db_name: new_db_name.to_string(),
+                dependents: Default::default(),
             })
             .await?;
             let res = mt
@@ -2252,6 +2261,7 @@ impl SchemaApiTestSuite {
             },
             new_db_name: db1_name.to_string(),
             new_table_name: tb3_name.to_string(),
+            dependents: Default::default(),
         };
 
         let table_meta = |created_on| TableMeta {
@@ -2413,6 +2423,7 @@ impl SchemaApiTestSuite {
                 },
                 new_db_name: db2_name.to_string(),
                 new_table_name: tb3_name.to_string(),
+                dependents: Default::default(),
             };
             let res = mt.rename_table(req.clone()).await;
             debug!("--- rename table to other db got: {:?}", res);
@@ -2449,6 +2460,7 @@ impl SchemaApiTestSuite {
                 },
                 new_db_name: db2_name.to_string(),
                 new_table_name: tb3_name.to_string(),
+                dependents: Default::default(),
             };
             let old_db1 = mt.get_database(Self::req_get_db(&tenant, db1_name)).await?;
             let old_db2 = mt.get_database(Self::req_get_db(&tenant, db2_name)).await?;
@@ -2472,6 +2484,53 @@ impl SchemaApiTestSuite {
             assert_meta_eq_without_updated!(want, got.as_ref().clone(), "get renamed table");
         }
 
+        info!("--- rename table with dependents, they are updated in the same txn");
+        {
+            // Use the renamed table itself as the dependent.
+            let got = mt
+                .get_table((tenant_name, db2_name, tb3_name).into())
+                .await?;
+            let table_id = got.ident.table_id;
+            let old = got.meta.clone();
+            let mut new = old.clone();
+            new.options
+                .insert("query".to_string(), "SELECT 1".to_string());
+            let mut stale = old.clone();
+            stale.comment = "stale".to_string();
+
+            let req = |old: &TableMeta| RenameTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: db2_name.to_string(),
+                    table_name: tb3_name.to_string(),
+                },
+                new_db_name: db2_name.to_string(),
+                new_table_name: tb2_name.to_string(),
+                dependents: RenameDependents {
+                    views: vec![(table_id, old.clone(), new.clone())],
+                    ..Default::default()
+                },
+            };
+
+            // The dependent is changed after it's read, nothing is changed.
+            let err = mt.rename_table(req(&stale)).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::UNRESOLVABLE_CONFLICT,
+                ErrorCode::from(err).code()
+            );
+            let got = mt
+                .get_table((tenant_name, db2_name, tb3_name).into())
+                .await?;
+            assert_eq!(old, got.meta);
+
+            mt.rename_table(req(&old)).await?;
+            let got = mt
+                .get_table((tenant_name, db2_name, tb2_name).into())
+                .await?;
+            assert_eq!(new, got.meta);
+        }
+
         Ok(())
     }
 
@@ -4885,6 +4944,7 @@ impl SchemaApiTestSuite {
                 name_ident: tbl_name_ident.clone(),
                 new_db_name: db_name.to_string(),
                 new_table_name: new_tbl_name.to_string(),
+                dependents: Default::default(),
             };
 
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
@@ -384,6 +384,22 @@ impl MultiStmtTxnCommitFailed {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("RenameDependentChanged: {object} while {context}")]
+pub struct RenameDependentChanged {
+    object: String,
+    context: String,
+}
+
+impl RenameDependentChanged {
+    pub fn new(object: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            object: object.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("DuplicatedUpsertFiles: {table_id} , in operation `{context}`")]
 pub struct DuplicatedUpsertFiles {
@@ -1227,6 +1243,9 @@ pub enum AppError {
     #[error(transparent)]
     MultiStatementTxnCommitFailed(#[from] MultiStmtTxnCommitFailed),
 
+    #[error(transparent)]
+    RenameDependentChanged(#[from] RenameDependentChanged),
+
     // sequence
     #[error(transparent)]
     SequenceError(#[from] SequenceError),
@@ -1328,6 +1347,15 @@ impl AppErrorMessage for UnknownStreamId {}
 
 impl AppErrorMessage for MultiStmtTxnCommitFailed {}
 
+impl AppErrorMessage for RenameDependentChanged {
+    fn message(&self) -> String {
+        format!(
+            "{} is changed concurrently while {}, please retry",
+            self.object, self.context
+        )
+    }
+}
+
 impl AppErrorMessage for DuplicatedUpsertFiles {}
 
 impl AppErrorMessage for CommitTableMetaError {
@@ -1772,6 +1800,7 @@ impl From<AppError> for ErrorCode {
             AppError::MultiStatementTxnCommitFailed(err) => {
                 ErrorCode::UnresolvableConflict(err.message())
             }
+            AppError::RenameDependentChanged(err) => ErrorCode::UnresolvableConflict(err.message()),
             AppError::SequenceError(err) => ErrorCode::SequenceError(err.message()),
         }
     }
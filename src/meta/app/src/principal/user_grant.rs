@@ -246,6 +246,25 @@ impl UserGrantSet {
             .collect::<Vec<_>>();
         self.entries = new_entries;
     }
+
+    /// Move the privileges of every entry whose object is mapped by `rename` to the new object,
+    /// returns true if any entry is changed.
+    pub fn rename_objects(&mut self, rename: impl Fn(&GrantObject) -> Option<GrantObject>) -> bool {
+        let mut renamed = vec![];
+        self.entries.retain(|e| match rename(&e.object) {
+            Some(object) => {
+                renamed.push((object, e.privileges));
+                false
+            }
+            None => true,
+        });
+
+        let changed = !renamed.is_empty();
+        for (object, privileges) in renamed {
+            self.grant_privileges(&object, privileges.into());
+        }
+        changed
+    }
 }
 
 impl ops::BitOrAssign for UserGrantSet {
@@ -23,6 +23,7 @@ use chrono::DateTime;
 use chrono::Utc;
 
 use super::CreateOption;
+use super::RenameDependents;
 use crate::schema::database_name_ident::DatabaseNameIdent;
 use crate::share::share_name_ident::ShareNameIdentRaw;
 use crate::share::ShareSpec;
@@ -220,6 +221,7 @@ pub struct RenameDatabaseReq {
     pub if_exists: bool,
     pub name_ident: DatabaseNameIdent,
     pub new_db_name: String,
+    pub dependents: RenameDependents,
 }
 
 impl Display for RenameDatabaseReq {
@@ -100,6 +100,7 @@ pub use table::GetTableReq;
 pub use table::ListDroppedTableReq;
 pub use table::ListDroppedTableResp;
 pub use table::ListTableReq;
+pub use table::RenameDependents;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
@@ -34,6 +34,9 @@ use maplit::hashmap;
 
 use super::CatalogInfo;
 use super::CreateOption;
+use super::IndexMeta;
+use crate::principal::RoleInfo;
+use crate::principal::UserInfo;
 use crate::schema::database_name_ident::DatabaseNameIdent;
 use crate::share::share_name_ident::ShareNameIdentRaw;
 use crate::share::ShareSpec;
@@ -654,6 +657,7 @@ pub struct RenameTableReq {
     pub name_ident: TableNameIdent,
     pub new_db_name: String,
     pub new_table_name: String,
+    pub dependents: RenameDependents,
 }
 
 impl RenameTableReq {
@@ -682,6 +686,32 @@ impl Display for RenameTableReq {
     }
 }
 
+/// The objects referencing a renamed database or table by name, updated in the same
+/// transaction as the rename.
+///
+/// Every entry is `(old, new)`, the rename fails with `RenameDependentChanged` if the stored
+/// object is no longer `old`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RenameDependents {
+    /// `(table_id, old, new)` of the views whose query references the renamed object.
+    pub views: Vec<(u64, TableMeta, TableMeta)>,
+    /// `(index_id, old, new)` of the aggregating indexes whose query references the renamed object.
+    pub indexes: Vec<(u64, IndexMeta, IndexMeta)>,
+    /// The users granted privileges on the renamed object.
+    pub users: Vec<(UserInfo, UserInfo)>,
+    /// The roles granted privileges on the renamed object.
+    pub roles: Vec<(RoleInfo, RoleInfo)>,
+}
+
+impl RenameDependents {
+    pub fn is_empty(&self) -> bool {
+        self.views.is_empty()
+            && self.indexes.is_empty()
+            && self.users.is_empty()
+            && self.roles.is_empty()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RenameTableReply {
     pub table_id: u64,
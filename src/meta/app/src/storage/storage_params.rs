@@ -376,6 +376,15 @@ pub struct StorageS3Config {
     pub role_arn: String,
     /// The ExternalId that used for AssumeRole.
     pub external_id: String,
+    /// Server-side encryption for S3, available values are `AES256` and `aws:kms`.
+    ///
+    /// Objects are encrypted by S3 itself, this is independent of the
+    /// client side encryption of fuse tables.
+    pub server_side_encryption: String,
+    /// The KMS key id used by server-side encryption.
+    ///
+    /// Only effective when `server_side_encryption` is `aws:kms`
+    pub server_side_encryption_aws_kms_key_id: String,
 }
 
 impl Default for StorageS3Config {
@@ -393,6 +402,8 @@ impl Default for StorageS3Config {
             enable_virtual_host_style: false,
             role_arn: "".to_string(),
             external_id: "".to_string(),
+            server_side_encryption: "".to_string(),
+            server_side_encryption_aws_kms_key_id: "".to_string(),
         }
     }
 }
@@ -408,6 +419,11 @@ impl Debug for StorageS3Config {
             .field("enable_virtual_host_style", &self.enable_virtual_host_style)
             .field("role_arn", &self.role_arn)
             .field("external_id", &self.external_id)
+            .field("server_side_encryption", &self.server_side_encryption)
+            .field(
+                "server_side_encryption_aws_kms_key_id",
+                &mask_string(&self.server_side_encryption_aws_kms_key_id, 3),
+            )
             .field("access_key_id", &mask_string(&self.access_key_id, 3))
             .field(
                 "secret_access_key",
@@ -132,6 +132,8 @@ impl FromToProto for StorageS3Config {
             enable_virtual_host_style: p.enable_virtual_host_style,
             role_arn: p.role_arn,
             external_id: p.external_id,
+            server_side_encryption: p.server_side_encryption,
+            server_side_encryption_aws_kms_key_id: p.server_side_encryption_aws_kms_key_id,
         })
     }
 
@@ -151,6 +153,10 @@ impl FromToProto for StorageS3Config {
             enable_virtual_host_style: self.enable_virtual_host_style,
             role_arn: self.role_arn.clone(),
             external_id: self.external_id.clone(),
+            server_side_encryption: self.server_side_encryption.clone(),
+            server_side_encryption_aws_kms_key_id: self
+                .server_side_encryption_aws_kms_key_id
+                .clone(),
         })
     }
 }
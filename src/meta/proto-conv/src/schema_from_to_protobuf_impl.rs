@@ -71,6 +71,7 @@ impl FromToProto for ex::TableField {
     fn from_pb(p: pb::DataField) -> Result<Self, Incompatible> {
         reader_check_msg(p.ver, p.min_reader_ver)?;
 
+        let checksum = p.computed_expr.as_ref().and_then(|expr| expr.checksum);
         let computed_expr = match p.computed_expr {
             Some(computed_expr) => Some(ex::ComputedExpr::from_pb(computed_expr)?),
             None => None,
@@ -85,12 +86,20 @@ impl FromToProto for ex::TableField {
         )
         .with_default_expr(p.default_expr)
         .with_computed_expr(computed_expr);
+        // The expressions written by old versions have no checksum.
+        let v = match checksum {
+            Some(checksum) => v.with_computed_expr_checksum(Some(checksum)),
+            None => v,
+        };
         Ok(v)
     }
 
     fn to_pb(&self) -> Result<pb::DataField, Incompatible> {
         let computed_expr = match self.computed_expr() {
-            Some(computed_expr) => Some(computed_expr.to_pb()?),
+            Some(computed_expr) => Some(pb::ComputedExpr {
+                checksum: self.computed_expr_checksum(),
+                ..computed_expr.to_pb()?
+            }),
             None => None,
         };
         let p = pb::DataField {
@@ -140,6 +149,8 @@ impl FromToProto for ex::ComputedExpr {
             min_reader_ver: MIN_READER_VER,
 
             computed_expr: Some(x),
+            // The checksum is kept by the owning `TableField`.
+            checksum: None,
         })
     }
 }
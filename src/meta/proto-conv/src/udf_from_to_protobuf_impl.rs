@@ -36,7 +36,9 @@ impl FromToProto for mt::LambdaUDF {
 
         Ok(mt::LambdaUDF {
             parameters: p.parameters,
+            defaults: p.defaults,
             definition: p.definition,
+            definition_checksum: p.definition_checksum,
         })
     }
 
@@ -46,6 +48,8 @@ impl FromToProto for mt::LambdaUDF {
             min_reader_ver: MIN_READER_VER,
             parameters: self.parameters.clone(),
             definition: self.definition.clone(),
+            defaults: self.defaults.clone(),
+            definition_checksum: self.definition_checksum,
         })
     }
 }
@@ -75,6 +79,7 @@ impl FromToProto for mt::UDFServer {
             return_type,
             handler: p.handler,
             language: p.language,
+            deterministic: p.deterministic,
         })
     }
 
@@ -102,6 +107,7 @@ impl FromToProto for mt::UDFServer {
             language: self.language.clone(),
             arg_types,
             return_type: Some(return_type),
+            deterministic: self.deterministic,
         })
     }
 }
@@ -124,6 +124,10 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (92, "2024-06-03: Add: user.proto/OrcFileFormatParams", ),
     (93, "2024-06-06: Add: null_if in user.proto/ParquetFileFormatParams"),
     (94, "2024-06-21: Remove: catalog in table meta"),
+    (95, "2024-06-26: Add: config.proto/S3StorageConfig add SSE options"),
+    (96, "2024-07-02: Add: udf.proto/LambdaUDF add defaults"),
+    (97, "2024-07-03: Add: udf.proto/UDFServer add deterministic"),
+    (98, "2024-07-08: Add: udf.proto/LambdaUDF add definition_checksum, metadata.proto/ComputedExpr add checksum"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -97,3 +97,7 @@ mod v091_role_user_create_time_info;
 mod v092_orc_format_params;
 mod v093_parquet_format_params;
 mod v094_table_meta;
+mod v095_s3_sse_options;
+mod v096_udf_lambda_defaults;
+mod v097_udf_server_deterministic;
+mod v098_definition_checksum;
@@ -57,6 +57,7 @@ fn test_decode_v57_udf() -> anyhow::Result<()> {
                 DataType::Number(NumberDataType::Int32),
             ],
             return_type: DataType::Number(NumberDataType::Int64),
+            deterministic: false,
         }),
         created_on: DateTime::<Utc>::default(),
     };
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use chrono::DateTime;
 use chrono::Utc;
 use databend_common_expression::types::DataType;
@@ -60,6 +62,7 @@ fn test_decode_v81_udf_python() -> anyhow::Result<()> {
                 DataType::Number(NumberDataType::Int32),
             ],
             return_type: DataType::Number(NumberDataType::Int64),
+            deterministic: false,
         }),
         created_on: DateTime::<Utc>::from_timestamp(1702603569, 0).unwrap(),
     };
@@ -82,7 +85,9 @@ fn test_decode_v81_udf_sql() -> anyhow::Result<()> {
         description: "This is a description".to_string(),
         definition: UDFDefinition::LambdaUDF(LambdaUDF {
             parameters: vec!["p".to_string()],
+            defaults: BTreeMap::new(),
             definition: "(p) -> (NOT is_null(p))".to_string(),
+            definition_checksum: None,
         }),
         created_on: DateTime::<Utc>::from_timestamp(170267984, 0).unwrap(),
     };
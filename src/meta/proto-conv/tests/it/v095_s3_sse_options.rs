@@ -0,0 +1,55 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::storage::StorageS3Config;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v95_s3_sse_options() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 9, 117, 115, 45, 101, 97, 115, 116, 45, 50, 18, 24, 104, 116, 116, 112, 115, 58, 47,
+        47, 115, 51, 46, 97, 109, 97, 122, 111, 110, 97, 119, 115, 46, 99, 111, 109, 26, 13, 97,
+        99, 99, 101, 115, 115, 95, 107, 101, 121, 95, 105, 100, 34, 17, 115, 101, 99, 114, 101,
+        116, 95, 97, 99, 99, 101, 115, 115, 95, 107, 101, 121, 42, 6, 98, 117, 99, 107, 101, 116,
+        50, 14, 47, 112, 97, 116, 104, 47, 116, 111, 47, 102, 105, 108, 101, 115, 114, 7, 97, 119,
+        115, 58, 107, 109, 115, 122, 10, 107, 109, 115, 95, 107, 101, 121, 95, 105, 100, 160, 6,
+        95, 168, 6, 24,
+    ];
+
+    let want = || StorageS3Config {
+        region: "us-east-2".to_string(),
+        endpoint_url: "https://s3.amazonaws.com".to_string(),
+        access_key_id: "access_key_id".to_string(),
+        secret_access_key: "secret_access_key".to_string(),
+        bucket: "bucket".to_string(),
+        root: "/path/to/files".to_string(),
+        server_side_encryption: "aws:kms".to_string(),
+        server_side_encryption_aws_kms_key_id: "kms_key_id".to_string(),
+        ..Default::default()
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 95, want())?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v96_udf_lambda_defaults() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 7, 97, 100, 100, 95, 116, 97, 120, 18, 20, 65, 100, 100, 32, 116, 97, 120, 32, 116,
+        111, 32, 116, 104, 101, 32, 112, 114, 105, 99, 101, 26, 52, 10, 5, 112, 114, 105, 99, 101,
+        10, 4, 114, 97, 116, 101, 18, 18, 112, 114, 105, 99, 101, 32, 42, 32, 40, 49, 32, 43, 32,
+        114, 97, 116, 101, 41, 26, 11, 10, 4, 114, 97, 116, 101, 18, 3, 48, 46, 49, 160, 6, 96,
+        168, 6, 24, 42, 23, 50, 48, 50, 52, 45, 48, 55, 45, 48, 49, 32, 48, 48, 58, 48, 48, 58, 48,
+        48, 32, 85, 84, 67, 160, 6, 96, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "add_tax".to_string(),
+        description: "Add tax to the price".to_string(),
+        definition: UDFDefinition::LambdaUDF(LambdaUDF {
+            parameters: vec!["price".to_string(), "rate".to_string()],
+            defaults: BTreeMap::from([("rate".to_string(), "0.1".to_string())]),
+            definition: "price * (1 + rate)".to_string(),
+            definition_checksum: None,
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1719792000, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 96, want())
+}
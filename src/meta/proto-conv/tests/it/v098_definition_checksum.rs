@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ComputedExpr;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v98_udf_definition_checksum() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 7, 97, 100, 100, 95, 116, 97, 120, 18, 20, 65, 100, 100, 32, 116, 97, 120, 32, 116,
+        111, 32, 116, 104, 101, 32, 112, 114, 105, 99, 101, 26, 32, 10, 5, 112, 114, 105, 99, 101,
+        18, 11, 112, 114, 105, 99, 101, 32, 42, 32, 49, 46, 49, 32, 195, 188, 177, 157, 4, 160, 6,
+        98, 168, 6, 24, 42, 23, 50, 48, 50, 52, 45, 48, 55, 45, 48, 56, 32, 48, 48, 58, 48, 48, 58,
+        48, 48, 32, 85, 84, 67, 160, 6, 98, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "add_tax".to_string(),
+        description: "Add tax to the price".to_string(),
+        definition: UDFDefinition::LambdaUDF(LambdaUDF {
+            parameters: vec!["price".to_string()],
+            defaults: BTreeMap::new(),
+            definition: "price * 1.1".to_string(),
+            definition_checksum: Some(1135369795),
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1720396800, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 98, want())
+}
+
+#[test]
+fn test_decode_v98_computed_expr_checksum() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 1, 98, 26, 17, 154, 2, 8, 58, 0, 160, 6, 98, 168, 6, 24, 160, 6, 98, 168, 6, 24, 32, 1,
+        42, 19, 10, 5, 97, 32, 43, 32, 49, 24, 199, 150, 130, 144, 3, 160, 6, 98, 168, 6, 24, 160,
+        6, 98, 168, 6, 24,
+    ];
+
+    let want = || {
+        TableField::new_from_column_id("b", TableDataType::Number(NumberDataType::Int32), 1)
+            .with_computed_expr(Some(ComputedExpr::Virtual("a + 1".to_string())))
+    };
+    assert_eq!(want().computed_expr_checksum(), Some(838896455));
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 98, want())
+}
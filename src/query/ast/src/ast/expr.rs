@@ -47,6 +47,19 @@ pub enum Expr {
         expr: Box<Expr>,
         not: bool,
     },
+    /// `IS [ NOT ] { TRUE | FALSE }` expression
+    IsBool {
+        span: Span,
+        expr: Box<Expr>,
+        value: bool,
+        not: bool,
+    },
+    /// `IS [ NOT ] JSON` expression
+    IsJson {
+        span: Span,
+        expr: Box<Expr>,
+        not: bool,
+    },
     /// `IS [NOT] DISTINCT` expression
     IsDistinctFrom {
         span: Span,
@@ -96,18 +109,20 @@ pub enum Expr {
         op: UnaryOperator,
         expr: Box<Expr>,
     },
-    /// `CAST` expression, like `CAST(expr AS target_type)`
+    /// `CAST` expression, like `CAST(expr AS target_type [FORMAT 'format'])`
     Cast {
         span: Span,
         expr: Box<Expr>,
         target_type: TypeName,
         pg_style: bool,
+        format: Option<String>,
     },
     /// `TRY_CAST` expression`
     TryCast {
         span: Span,
         expr: Box<Expr>,
         target_type: TypeName,
+        format: Option<String>,
     },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
@@ -121,11 +136,12 @@ pub enum Expr {
         kind: IntervalKind,
         expr: Box<Expr>,
     },
-    /// POSITION(<expr> IN <expr>)
+    /// POSITION(<expr> IN <expr> [FROM <expr>])
     Position {
         span: Span,
         substr_expr: Box<Expr>,
         str_expr: Box<Expr>,
+        position_from: Option<Box<Expr>>,
     },
     /// SUBSTRING(<expr> [FROM <expr>] [FOR <expr>])
     Substring {
@@ -152,6 +168,7 @@ pub enum Expr {
     CountAll {
         span: Span,
         window: Option<Window>,
+        filter: Option<Box<Expr>>,
     },
     /// `(foo, bar)`
     Tuple {
@@ -227,6 +244,11 @@ pub enum Expr {
         span: Span,
         name: String,
     },
+    /// MySQL-compatible system variable, e.g. `@@version_comment`
+    SystemVariable {
+        span: Span,
+        name: String,
+    },
 }
 
 impl Expr {
@@ -234,6 +256,8 @@ impl Expr {
         match self {
             Expr::ColumnRef { span, .. }
             | Expr::IsNull { span, .. }
+            | Expr::IsBool { span, .. }
+            | Expr::IsJson { span, .. }
             | Expr::IsDistinctFrom { span, .. }
             | Expr::InList { span, .. }
             | Expr::InSubquery { span, .. }
@@ -262,14 +286,17 @@ impl Expr {
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
             | Expr::DateTrunc { span, .. }
-            | Expr::Hole { span, .. } => *span,
+            | Expr::Hole { span, .. }
+            | Expr::SystemVariable { span, .. } => *span,
         }
     }
 
     pub fn whole_span(&self) -> Span {
         match self {
             Expr::ColumnRef { span, .. } => *span,
-            Expr::IsNull { span, expr, .. } => merge_span(*span, expr.whole_span()),
+            Expr::IsNull { span, expr, .. }
+            | Expr::IsBool { span, expr, .. }
+            | Expr::IsJson { span, expr, .. } => merge_span(*span, expr.whole_span()),
             Expr::IsDistinctFrom {
                 span, left, right, ..
             } => merge_span(merge_span(*span, left.whole_span()), right.whole_span()),
@@ -313,11 +340,17 @@ impl Expr {
                 span,
                 substr_expr,
                 str_expr,
-                ..
-            } => merge_span(
-                merge_span(*span, substr_expr.whole_span()),
-                str_expr.whole_span(),
-            ),
+                position_from,
+            } => {
+                let mut span = merge_span(
+                    merge_span(*span, substr_expr.whole_span()),
+                    str_expr.whole_span(),
+                );
+                if let Some(position_from) = position_from {
+                    span = merge_span(span, position_from.whole_span());
+                }
+                span
+            }
             Expr::Substring {
                 span,
                 expr,
@@ -396,6 +429,7 @@ impl Expr {
             } => merge_span(merge_span(*span, interval.whole_span()), date.whole_span()),
             Expr::DateTrunc { span, date, .. } => merge_span(*span, date.whole_span()),
             Expr::Hole { span, .. } => *span,
+            Expr::SystemVariable { span, .. } => *span,
         }
     }
 
@@ -479,6 +513,28 @@ impl Display for Expr {
                     }
                     write!(f, " NULL")?;
                 }
+                Expr::IsBool {
+                    expr, value, not, ..
+                } => {
+                    write_expr(expr, Some(affix), true, f)?;
+                    write!(f, " IS")?;
+                    if *not {
+                        write!(f, " NOT")?;
+                    }
+                    if *value {
+                        write!(f, " TRUE")?;
+                    } else {
+                        write!(f, " FALSE")?;
+                    }
+                }
+                Expr::IsJson { expr, not, .. } => {
+                    write_expr(expr, Some(affix), true, f)?;
+                    write!(f, " IS")?;
+                    if *not {
+                        write!(f, " NOT")?;
+                    }
+                    write!(f, " JSON")?;
+                }
                 Expr::IsDistinctFrom {
                     left, right, not, ..
                 } => {
@@ -558,19 +614,31 @@ impl Display for Expr {
                     expr,
                     target_type,
                     pg_style,
+                    format,
                     ..
                 } => {
                     if *pg_style {
                         write_expr(expr, Some(affix), true, f)?;
                         write!(f, "::{target_type}")?;
                     } else {
-                        write!(f, "CAST({expr} AS {target_type})")?;
+                        write!(f, "CAST({expr} AS {target_type}")?;
+                        if let Some(format) = format {
+                            write!(f, " FORMAT {}", QuotedString(format, '\''))?;
+                        }
+                        write!(f, ")")?;
                     }
                 }
                 Expr::TryCast {
-                    expr, target_type, ..
+                    expr,
+                    target_type,
+                    format,
+                    ..
                 } => {
-                    write!(f, "TRY_CAST({expr} AS {target_type})")?;
+                    write!(f, "TRY_CAST({expr} AS {target_type}")?;
+                    if let Some(format) = format {
+                        write!(f, " FORMAT {}", QuotedString(format, '\''))?;
+                    }
+                    write!(f, ")")?;
                 }
                 Expr::Extract {
                     kind: field, expr, ..
@@ -585,9 +653,14 @@ impl Display for Expr {
                 Expr::Position {
                     substr_expr,
                     str_expr,
+                    position_from,
                     ..
                 } => {
-                    write!(f, "POSITION({substr_expr} IN {str_expr})")?;
+                    write!(f, "POSITION({substr_expr} IN {str_expr}")?;
+                    if let Some(position_from) = position_from {
+                        write!(f, " FROM {position_from}")?;
+                    }
+                    write!(f, ")")?;
                 }
                 Expr::Substring {
                     expr,
@@ -613,8 +686,11 @@ impl Display for Expr {
                 Expr::Literal { value, .. } => {
                     write!(f, "{value}")?;
                 }
-                Expr::CountAll { window, .. } => {
+                Expr::CountAll { window, filter, .. } => {
                     write!(f, "COUNT(*)")?;
+                    if let Some(filter) = filter {
+                        write!(f, " FILTER (WHERE {filter})")?;
+                    }
                     if let Some(window) = window {
                         write!(f, " OVER {window}")?;
                     }
@@ -669,6 +745,7 @@ impl Display for Expr {
                         MapAccessor::Bracket { key } => write!(f, "[{key}]")?,
                         MapAccessor::DotNumber { key } => write!(f, ".{key}")?,
                         MapAccessor::Colon { key } => write!(f, ":{key}")?,
+                        MapAccessor::Wildcard => write!(f, "[*]")?,
                     }
                 }
                 Expr::Array { exprs, .. } => {
@@ -711,6 +788,9 @@ impl Display for Expr {
                 Expr::Hole { name, .. } => {
                     write!(f, ":{name}")?;
                 }
+                Expr::SystemVariable { name, .. } => {
+                    write!(f, "@@{name}")?;
+                }
             }
 
             if need_paren {
@@ -836,6 +916,10 @@ pub struct FunctionCall {
     pub params: Vec<Expr>,
     pub window: Option<Window>,
     pub lambda: Option<Lambda>,
+    /// The `ORDER BY` clause inside an aggregate function, like `STRING_AGG(a, ',' ORDER BY b)`
+    pub order_by: Vec<OrderByExpr>,
+    /// The `FILTER (WHERE ...)` clause of an aggregate function, like `COUNT(*) FILTER (WHERE a > 1)`
+    pub filter: Option<Box<Expr>>,
 }
 
 impl Display for FunctionCall {
@@ -847,6 +931,8 @@ impl Display for FunctionCall {
             params,
             window,
             lambda,
+            order_by,
+            filter,
         } = self;
         write!(f, "{name}")?;
         if !params.is_empty() {
@@ -862,8 +948,15 @@ impl Display for FunctionCall {
         if let Some(lambda) = lambda {
             write!(f, ", {lambda}")?;
         }
+        if !order_by.is_empty() {
+            write!(f, " ORDER BY ")?;
+            write_comma_separated_list(f, order_by)?;
+        }
         write!(f, ")")?;
 
+        if let Some(filter) = filter {
+            write!(f, " FILTER (WHERE {filter})")?;
+        }
         if let Some(window) = window {
             write!(f, " OVER {window}")?;
         }
@@ -880,6 +973,8 @@ pub enum MapAccessor {
     DotNumber { key: u64 },
     /// `:a:b`
     Colon { key: Identifier },
+    /// `[*]`, all elements of an array
+    Wildcard,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -1239,6 +1334,8 @@ pub enum BinaryOperator {
     BitwiseShiftLeft,
     BitwiseShiftRight,
     L2Distance,
+    L1Distance,
+    CosineDistance,
 }
 
 impl BinaryOperator {
@@ -1260,6 +1357,7 @@ impl BinaryOperator {
     pub fn to_func_name(&self) -> String {
         match self {
             BinaryOperator::StringConcat => "concat".to_string(),
+            BinaryOperator::NotEq => "noteq".to_string(),
             BinaryOperator::BitwiseOr => "bit_or".to_string(),
             BinaryOperator::BitwiseAnd => "bit_and".to_string(),
             BinaryOperator::BitwiseXor => "bit_xor".to_string(),
@@ -1267,6 +1365,8 @@ impl BinaryOperator {
             BinaryOperator::BitwiseShiftRight => "bit_shift_right".to_string(),
             BinaryOperator::Caret => "pow".to_string(),
             BinaryOperator::L2Distance => "l2_distance".to_string(),
+            BinaryOperator::L1Distance => "l1_distance".to_string(),
+            BinaryOperator::CosineDistance => "cosine_distance".to_string(),
             _ => {
                 let name = format!("{:?}", self);
                 name.to_lowercase()
@@ -1371,6 +1471,12 @@ impl Display for BinaryOperator {
             BinaryOperator::L2Distance => {
                 write!(f, "<->")
             }
+            BinaryOperator::L1Distance => {
+                write!(f, "<+>")
+            }
+            BinaryOperator::CosineDistance => {
+                write!(f, "<=>")
+            }
         }
     }
 }
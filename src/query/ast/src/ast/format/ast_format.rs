@@ -179,6 +179,33 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_is_bool(&mut self, _span: Span, expr: &'ast Expr, value: bool, not: bool) {
+        let name = match (value, not) {
+            (true, false) => "Function IsTrue",
+            (true, true) => "Function IsNotTrue",
+            (false, false) => "Function IsFalse",
+            (false, true) => "Function IsNotFalse",
+        };
+        self.visit_expr(expr);
+        let child = self.children.pop().unwrap();
+        let format_ctx = AstFormatContext::with_children(name.to_string(), 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_is_json(&mut self, _span: Span, expr: &'ast Expr, not: bool) {
+        let name = if not {
+            "Function IsNotJson".to_string()
+        } else {
+            "Function IsJson".to_string()
+        };
+        self.visit_expr(expr);
+        let child = self.children.pop().unwrap();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_is_distinct_from(
         &mut self,
         _span: Span,
@@ -361,16 +388,26 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
-    fn visit_position(&mut self, _span: Span, substr_expr: &'ast Expr, str_expr: &'ast Expr) {
+    fn visit_position(
+        &mut self,
+        _span: Span,
+        substr_expr: &'ast Expr,
+        str_expr: &'ast Expr,
+        position_from: &'ast Option<Box<Expr>>,
+    ) {
+        let mut children = Vec::with_capacity(3);
         self.visit_expr(substr_expr);
-        let substr_expr_child = self.children.pop().unwrap();
+        children.push(self.children.pop().unwrap());
         self.visit_expr(str_expr);
-        let str_expr_child = self.children.pop().unwrap();
+        children.push(self.children.pop().unwrap());
+        if let Some(position_from) = position_from {
+            self.visit_expr(position_from);
+            children.push(self.children.pop().unwrap());
+        }
 
         let name = "Function Position".to_string();
-        let format_ctx = AstFormatContext::with_children(name, 2);
-        let node =
-            FormatTreeNode::with_children(format_ctx, vec![substr_expr_child, str_expr_child]);
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
         self.children.push(node);
     }
 
@@ -422,11 +459,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
-    fn visit_count_all(&mut self, _span: Span, _window: &'ast Option<Window>) {
+    fn visit_count_all(
+        &mut self,
+        _span: Span,
+        _window: &'ast Option<Window>,
+        filter: &'ast Option<Box<Expr>>,
+    ) {
         let name = "Function CountAll".to_string();
-        let format_ctx = AstFormatContext::new(name);
-        let node = FormatTreeNode::new(format_ctx);
-        self.children.push(node);
+        if let Some(filter) = filter {
+            self.visit_expr(filter);
+            let child = self.children.pop().unwrap();
+            let format_ctx = AstFormatContext::with_children(name, 1);
+            let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+            self.children.push(node);
+        } else {
+            let format_ctx = AstFormatContext::new(name);
+            let node = FormatTreeNode::new(format_ctx);
+            self.children.push(node);
+        }
     }
 
     fn visit_tuple(&mut self, _span: Span, elements: &'ast [Expr]) {
@@ -450,12 +500,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         params: &'ast [Expr],
         _over: &'ast Option<Window>,
         _lambda: &'ast Option<Lambda>,
+        order_by: &'ast [OrderByExpr],
+        filter: &'ast Option<Box<Expr>>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + order_by.len() + 1);
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        for order in order_by.iter() {
+            self.visit_order_by(order);
+            children.push(self.children.pop().unwrap());
+        }
+        if let Some(filter) = filter {
+            self.visit_expr(filter);
+            children.push(self.children.pop().unwrap());
+        }
         for param in params.iter() {
             self.visit_expr(param);
         }
@@ -563,6 +623,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             MapAccessor::Bracket { key } => format!("accessor [{key}]"),
             MapAccessor::DotNumber { key } => format!("accessor .{key}"),
             MapAccessor::Colon { key } => format!("accessor :{key}"),
+            MapAccessor::Wildcard => "accessor [*]".to_string(),
         };
         let key_format_ctx = AstFormatContext::new(key_name);
         let key_child = FormatTreeNode::new(key_format_ctx);
@@ -722,6 +783,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 ExplainKind::Plan => "Plan",
                 ExplainKind::Memo(_) => "Memo",
                 ExplainKind::Join => "Join",
+                ExplainKind::Cte => "Cte",
                 ExplainKind::AnalyzePlan => "Analyze",
             },
             if options.is_empty() {
@@ -982,6 +1044,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_check_objects(&mut self) {
+        let name = "CheckObjects".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_show_options(&mut self, show_options: &'ast Option<ShowOptions>, name: String) {
         let mut children = Vec::new();
         if let Some(show_options) = show_options {
@@ -1076,6 +1145,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         }
         self.visit_insert_source(&insert.source);
         children.push(self.children.pop().unwrap());
+        if let Some(on_error) = &insert.on_error {
+            let on_error_name = format!("OnError {}", on_error);
+            let on_error_format_ctx = AstFormatContext::new(on_error_name);
+            children.push(FormatTreeNode::new(on_error_format_ctx));
+        }
 
         let name = if insert.overwrite {
             "InsertOverwrite".to_string()
@@ -1558,8 +1632,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let table_child = self.children.pop().unwrap();
 
         let action_child = match &stmt.action {
-            AlterTableAction::RenameTable { new_table } => {
-                let action_name = format!("Action RenameTo {}", new_table);
+            AlterTableAction::RenameTable {
+                new_database,
+                new_table,
+            } => {
+                let action_name = match new_database {
+                    Some(new_database) => {
+                        format!("Action RenameTo {}.{}", new_database, new_table)
+                    }
+                    None => format!("Action RenameTo {}", new_table),
+                };
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
@@ -2315,6 +2397,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         match &stmt.definition {
             UDFDefinition::LambdaUDF {
                 parameters,
+                defaults,
                 definition,
             } => {
                 if !parameters.is_empty() {
@@ -2331,6 +2414,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         parameters_children,
                     ));
                 }
+                if !defaults.is_empty() {
+                    let mut defaults_children = Vec::with_capacity(defaults.len());
+                    for default in defaults.iter() {
+                        self.visit_expr(default);
+                        defaults_children.push(self.children.pop().unwrap());
+                    }
+                    let defaults_name = "UdfParameterDefaults".to_string();
+                    let defaults_format_ctx =
+                        AstFormatContext::with_children(defaults_name, defaults_children.len());
+                    children.push(FormatTreeNode::with_children(
+                        defaults_format_ctx,
+                        defaults_children,
+                    ));
+                }
                 self.visit_expr(definition);
                 let definition_child = self.children.pop().unwrap();
                 let definition_name = "UdfDefinition".to_string();
@@ -2345,6 +2442,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 address,
                 handler,
                 language,
+                deterministic,
             } => {
                 if !arg_types.is_empty() {
                     let mut arg_types_children = Vec::with_capacity(arg_types.len());
@@ -2377,6 +2475,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let address_format_ctx =
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
+
+                if *deterministic {
+                    let deterministic_format_ctx =
+                        AstFormatContext::new("UdfServerDeterministic".to_string());
+                    children.push(FormatTreeNode::new(deterministic_format_ctx));
+                }
             }
             UDFDefinition::UDFScript {
                 arg_types,
@@ -2443,6 +2547,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_describe_udf(&mut self, udf_name: &'ast Identifier) {
+        let udf_name_format_ctx = AstFormatContext::new(format!("UdfIdentifier {}", udf_name));
+        let child = FormatTreeNode::new(udf_name_format_ctx);
+
+        let name = "DescribeUdf".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_alter_udf(&mut self, stmt: &'ast AlterUDFStmt) {
         let mut children = Vec::new();
         let udf_name_format_ctx =
@@ -2452,6 +2566,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         match &stmt.definition {
             UDFDefinition::LambdaUDF {
                 parameters,
+                defaults,
                 definition,
             } => {
                 if !parameters.is_empty() {
@@ -2468,6 +2583,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         parameters_children,
                     ));
                 }
+                if !defaults.is_empty() {
+                    let mut defaults_children = Vec::with_capacity(defaults.len());
+                    for default in defaults.iter() {
+                        self.visit_expr(default);
+                        defaults_children.push(self.children.pop().unwrap());
+                    }
+                    let defaults_name = "UdfParameterDefaults".to_string();
+                    let defaults_format_ctx =
+                        AstFormatContext::with_children(defaults_name, defaults_children.len());
+                    children.push(FormatTreeNode::with_children(
+                        defaults_format_ctx,
+                        defaults_children,
+                    ));
+                }
                 self.visit_expr(definition);
                 let definition_child = self.children.pop().unwrap();
                 let definition_name = "UdfDefinition".to_string();
@@ -2482,6 +2611,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 address,
                 handler,
                 language,
+                deterministic,
             } => {
                 if !arg_types.is_empty() {
                     let mut arg_types_children = Vec::with_capacity(arg_types.len());
@@ -2514,6 +2644,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let address_format_ctx =
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
+
+                if *deterministic {
+                    let deterministic_format_ctx =
+                        AstFormatContext::new("UdfServerDeterministic".to_string());
+                    children.push(FormatTreeNode::new(deterministic_format_ctx));
+                }
             }
 
             UDFDefinition::UDFScript {
@@ -3068,75 +3204,15 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(selection_node);
         }
         if let Some(group_by) = &stmt.group_by {
-            match group_by {
-                GroupBy::Normal(exprs) => {
-                    let mut group_by_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        group_by_list_children.push(self.children.pop().unwrap());
-                    }
-                    let group_by_list_name = "GroupByList".to_string();
-                    let group_by_list_format_ctx = AstFormatContext::with_children(
-                        group_by_list_name,
-                        group_by_list_children.len(),
-                    );
-                    let group_by_list_node = FormatTreeNode::with_children(
-                        group_by_list_format_ctx,
-                        group_by_list_children,
-                    );
-                    children.push(group_by_list_node);
-                }
-                GroupBy::All => {}
-                GroupBy::GroupingSets(sets) => {
-                    let mut grouping_sets = Vec::with_capacity(sets.len());
-                    for set in sets.iter() {
-                        let mut grouping_set = Vec::with_capacity(set.len());
-                        for expr in set.iter() {
-                            self.visit_expr(expr);
-                            grouping_set.push(self.children.pop().unwrap());
-                        }
-                        let name = "GroupingSet".to_string();
-                        let grouping_set_format_ctx =
-                            AstFormatContext::with_children(name, grouping_set.len());
-                        let grouping_set_node =
-                            FormatTreeNode::with_children(grouping_set_format_ctx, grouping_set);
-                        grouping_sets.push(grouping_set_node);
-                    }
-                    let group_by_list_name = "GroupByList".to_string();
-                    let group_by_list_format_ctx =
-                        AstFormatContext::with_children(group_by_list_name, grouping_sets.len());
-                    let group_by_list_node =
-                        FormatTreeNode::with_children(group_by_list_format_ctx, grouping_sets);
-                    children.push(group_by_list_node);
-                }
-                GroupBy::Rollup(exprs) => {
-                    let mut rollup_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        rollup_list_children.push(self.children.pop().unwrap());
-                    }
-                    let rollup_list_name = "GroupByRollUpList".to_string();
-                    let rollup_list_format_ctx = AstFormatContext::with_children(
-                        rollup_list_name,
-                        rollup_list_children.len(),
-                    );
-                    let rollup_list_node =
-                        FormatTreeNode::with_children(rollup_list_format_ctx, rollup_list_children);
-                    children.push(rollup_list_node);
-                }
-                GroupBy::Cube(exprs) => {
-                    let mut cube_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        cube_list_children.push(self.children.pop().unwrap());
-                    }
-                    let cube_list_name = "GroupByCubeList".to_string();
-                    let cube_list_format_ctx =
-                        AstFormatContext::with_children(cube_list_name, cube_list_children.len());
-                    let cube_list_node =
-                        FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children);
-                    children.push(cube_list_node);
-                }
+            let (group_by, with_totals) = match group_by {
+                GroupBy::WithTotals(group_by) => (group_by.as_ref(), true),
+                group_by => (group_by, false),
+            };
+            let group_by_nodes = self.visit_group_by(group_by);
+            children.extend(group_by_nodes);
+            if with_totals {
+                let with_totals_format_ctx = AstFormatContext::new("GroupByWithTotals".to_string());
+                children.push(FormatTreeNode::new(with_totals_format_ctx));
             }
         }
 
@@ -3521,4 +3597,82 @@ impl AstFormatVisitor {
         }
         into_nodes
     }
+
+    fn visit_group_by(&mut self, group_by: &GroupBy) -> Vec<FormatTreeNode<AstFormatContext>> {
+        let mut nodes = Vec::new();
+        match group_by {
+            GroupBy::Normal(exprs) => {
+                let mut group_by_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    group_by_list_children.push(self.children.pop().unwrap());
+                }
+                let group_by_list_name = "GroupByList".to_string();
+                let group_by_list_format_ctx = AstFormatContext::with_children(
+                    group_by_list_name,
+                    group_by_list_children.len(),
+                );
+                let group_by_list_node =
+                    FormatTreeNode::with_children(group_by_list_format_ctx, group_by_list_children);
+                nodes.push(group_by_list_node);
+            }
+            GroupBy::All => {}
+            GroupBy::GroupingSets(sets) => {
+                let mut grouping_sets = Vec::with_capacity(sets.len());
+                for set in sets.iter() {
+                    let mut grouping_set = Vec::with_capacity(set.len());
+                    for expr in set.iter() {
+                        self.visit_expr(expr);
+                        grouping_set.push(self.children.pop().unwrap());
+                    }
+                    let name = "GroupingSet".to_string();
+                    let grouping_set_format_ctx =
+                        AstFormatContext::with_children(name, grouping_set.len());
+                    let grouping_set_node =
+                        FormatTreeNode::with_children(grouping_set_format_ctx, grouping_set);
+                    grouping_sets.push(grouping_set_node);
+                }
+                let group_by_list_name = "GroupByList".to_string();
+                let group_by_list_format_ctx =
+                    AstFormatContext::with_children(group_by_list_name, grouping_sets.len());
+                let group_by_list_node =
+                    FormatTreeNode::with_children(group_by_list_format_ctx, grouping_sets);
+                nodes.push(group_by_list_node);
+            }
+            GroupBy::Rollup(exprs) => {
+                let mut rollup_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    rollup_list_children.push(self.children.pop().unwrap());
+                }
+                let rollup_list_name = "GroupByRollUpList".to_string();
+                let rollup_list_format_ctx =
+                    AstFormatContext::with_children(rollup_list_name, rollup_list_children.len());
+                let rollup_list_node =
+                    FormatTreeNode::with_children(rollup_list_format_ctx, rollup_list_children);
+                nodes.push(rollup_list_node);
+            }
+            GroupBy::Cube(exprs) => {
+                let mut cube_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    cube_list_children.push(self.children.pop().unwrap());
+                }
+                let cube_list_name = "GroupByCubeList".to_string();
+                let cube_list_format_ctx =
+                    AstFormatContext::with_children(cube_list_name, cube_list_children.len());
+                let cube_list_node =
+                    FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children);
+                nodes.push(cube_list_node);
+            }
+            GroupBy::Combined(group_bys) => {
+                for group_by in group_bys.iter() {
+                    let group_by_nodes = self.visit_group_by(group_by);
+                    nodes.extend(group_by_nodes);
+                }
+            }
+            GroupBy::WithTotals(_) => unreachable!("WITH TOTALS can not be nested"),
+        }
+        nodes
+    }
 }
@@ -172,8 +172,16 @@ pub(crate) fn pretty_alter_table(stmt: AlterTableStmt) -> RcDoc<'static> {
 
 pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'static> {
     match action {
-        AlterTableAction::RenameTable { new_table } => RcDoc::line()
+        AlterTableAction::RenameTable {
+            new_database,
+            new_table,
+        } => RcDoc::line()
             .append(RcDoc::text("RENAME TO "))
+            .append(if let Some(new_database) = new_database {
+                RcDoc::text(new_database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
             .append(RcDoc::text(new_table.to_string())),
         AlterTableAction::ModifyTableComment { new_comment } => RcDoc::line()
             .append(RcDoc::text("COMMENT='"))
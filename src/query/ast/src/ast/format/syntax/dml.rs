@@ -69,6 +69,11 @@ pub(crate) fn pretty_insert(insert_stmt: InsertStmt) -> RcDoc<'static> {
                 }),
         )
         .append(pretty_source(insert_stmt.source))
+        .append(if let Some(on_error) = insert_stmt.on_error {
+            RcDoc::line().append(RcDoc::text(on_error.to_string()))
+        } else {
+            RcDoc::nil()
+        })
 }
 
 fn pretty_source(source: InsertSource) -> RcDoc<'static> {
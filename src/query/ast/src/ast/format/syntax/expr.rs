@@ -19,6 +19,7 @@ use crate::ast::format::syntax::inline_comma;
 use crate::ast::format::syntax::interweave_comma;
 use crate::ast::format::syntax::parenthesized;
 use crate::ast::format::syntax::NEST_FACTOR;
+use crate::ast::quote::QuotedString;
 use crate::ast::BinaryOperator;
 use crate::ast::Expr;
 use crate::ast::FunctionCall;
@@ -47,6 +48,28 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             })
             .append(RcDoc::space())
             .append(RcDoc::text("NULL")),
+        Expr::IsBool {
+            expr, value, not, ..
+        } => pretty_expr(*expr)
+            .append(RcDoc::space())
+            .append(RcDoc::text("IS"))
+            .append(if not {
+                RcDoc::space().append(RcDoc::text("NOT"))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::space())
+            .append(RcDoc::text(if value { "TRUE" } else { "FALSE" })),
+        Expr::IsJson { expr, not, .. } => pretty_expr(*expr)
+            .append(RcDoc::space())
+            .append(RcDoc::text("IS"))
+            .append(if not {
+                RcDoc::space().append(RcDoc::text("NOT"))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::space())
+            .append(RcDoc::text("JSON")),
         Expr::IsDistinctFrom {
             left, right, not, ..
         } => pretty_expr(*left)
@@ -144,6 +167,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             expr,
             target_type,
             pg_style,
+            format,
             ..
         } => {
             if pg_style {
@@ -157,17 +181,22 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                     .append(RcDoc::text("AS"))
                     .append(RcDoc::space())
                     .append(RcDoc::text(target_type.to_string()))
+                    .append(pretty_cast_format(format))
                     .append(RcDoc::text(")"))
             }
         }
         Expr::TryCast {
-            expr, target_type, ..
+            expr,
+            target_type,
+            format,
+            ..
         } => RcDoc::text("TRY_CAST(")
             .append(pretty_expr(*expr))
             .append(RcDoc::space())
             .append(RcDoc::text("AS"))
             .append(RcDoc::space())
             .append(RcDoc::text(target_type.to_string()))
+            .append(pretty_cast_format(format))
             .append(RcDoc::text(")")),
         Expr::Extract {
             kind: field, expr, ..
@@ -190,6 +219,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
         Expr::Position {
             substr_expr,
             str_expr,
+            position_from,
             ..
         } => RcDoc::text("POSITION(")
             .append(pretty_expr(*substr_expr))
@@ -197,6 +227,14 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(RcDoc::text("IN"))
             .append(RcDoc::space())
             .append(pretty_expr(*str_expr))
+            .append(if let Some(position_from) = position_from {
+                RcDoc::space()
+                    .append(RcDoc::text("FROM"))
+                    .append(RcDoc::space())
+                    .append(pretty_expr(*position_from))
+            } else {
+                RcDoc::nil()
+            })
             .append(RcDoc::text(")")),
         Expr::Substring {
             expr,
@@ -234,15 +272,21 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*expr))
             .append(RcDoc::text(")")),
         Expr::Literal { value, .. } => RcDoc::text(value.to_string()),
-        Expr::CountAll { window, .. } => {
-            RcDoc::text("COUNT(*)").append(if let Some(window) = window {
+        Expr::CountAll { window, filter, .. } => RcDoc::text("COUNT(*)")
+            .append(if let Some(filter) = filter {
+                RcDoc::text(" FILTER (WHERE ")
+                    .append(pretty_expr(*filter))
+                    .append(RcDoc::text(")"))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(window) = window {
                 RcDoc::text(" OVER (")
                     .append(RcDoc::text(window.to_string()))
                     .append(")")
             } else {
                 RcDoc::nil()
-            })
-        }
+            }),
         Expr::Tuple { exprs, .. } => RcDoc::text("(")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
             .append(RcDoc::text(")")),
@@ -254,6 +298,8 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 params,
                 window,
                 lambda,
+                order_by,
+                filter,
             } = func;
 
             RcDoc::text(name.to_string())
@@ -293,7 +339,23 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 } else {
                     RcDoc::nil()
                 })
+                .append(if !order_by.is_empty() {
+                    RcDoc::text(" ORDER BY ").append(inline_comma(
+                        order_by
+                            .into_iter()
+                            .map(|order| RcDoc::text(order.to_string())),
+                    ))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(RcDoc::text(")"))
+                .append(if let Some(filter) = filter {
+                    RcDoc::text(" FILTER (WHERE ")
+                        .append(pretty_expr(*filter))
+                        .append(RcDoc::text(")"))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(if let Some(window) = window {
                     RcDoc::text(" OVER (")
                         .append(RcDoc::text(window.to_string()))
@@ -363,6 +425,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 .append(RcDoc::text("]")),
             MapAccessor::DotNumber { key } => RcDoc::text(".").append(RcDoc::text(key.to_string())),
             MapAccessor::Colon { key } => RcDoc::text(":").append(RcDoc::text(key.to_string())),
+            MapAccessor::Wildcard => RcDoc::text("[*]"),
         }),
         Expr::Array { exprs, .. } => RcDoc::text("[")
             .append(inline_comma(exprs.into_iter().map(pretty_expr)))
@@ -418,5 +481,18 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*date))
             .append(RcDoc::text(")")),
         Expr::Hole { name, .. } => RcDoc::text(":").append(RcDoc::text(name.to_string())),
+        Expr::SystemVariable { name, .. } => {
+            RcDoc::text("@@").append(RcDoc::text(name.to_string()))
+        }
+    }
+}
+
+fn pretty_cast_format(format: Option<String>) -> RcDoc<'static> {
+    match format {
+        Some(format) => RcDoc::space()
+            .append(RcDoc::text("FORMAT"))
+            .append(RcDoc::space())
+            .append(RcDoc::text(QuotedString(format, '\'').to_string())),
+        None => RcDoc::nil(),
     }
 }
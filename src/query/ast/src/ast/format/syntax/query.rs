@@ -66,11 +66,7 @@ fn pretty_with(with: Option<With>) -> RcDoc<'static> {
 fn pretty_cte(cte: CTE) -> RcDoc<'static> {
     RcDoc::text(format!("{} AS ", cte.alias))
         .append(RcDoc::softline())
-        .append(if cte.materialized {
-            RcDoc::text("MATERIALIZED ".to_string())
-        } else {
-            RcDoc::nil()
-        })
+        .append(RcDoc::text(cte.materialization.to_string()))
         .append(parenthesized(pretty_query(*cte.query)))
 }
 
@@ -272,6 +268,20 @@ fn pretty_group_by(group_by: Option<GroupBy>) -> RcDoc<'static> {
                 )
                 .append(RcDoc::line())
                 .append(RcDoc::text(")")),
+            GroupBy::Combined(group_bys) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY").append(RcDoc::line().nest(NEST_FACTOR)))
+                .append(
+                    interweave_comma(
+                        group_bys
+                            .into_iter()
+                            .map(|group_by| RcDoc::text(group_by.to_string())),
+                    )
+                    .nest(NEST_FACTOR)
+                    .group(),
+                ),
+            GroupBy::WithTotals(group_by) => {
+                pretty_group_by(Some(*group_by)).append(RcDoc::text(" WITH TOTALS"))
+            }
         }
     } else {
         RcDoc::nil()
@@ -107,21 +107,40 @@ impl Display for With {
 pub struct CTE {
     pub span: Span,
     pub alias: TableAlias,
-    pub materialized: bool,
+    pub materialization: CTEMaterialization,
     pub query: Box<Query>,
 }
 
 impl Display for CTE {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "{} AS ", self.alias)?;
-        if self.materialized {
-            write!(f, "MATERIALIZED ")?;
-        }
-        write!(f, "({})", self.query)?;
+        write!(
+            f,
+            "{} AS {}({})",
+            self.alias, self.materialization, self.query
+        )?;
         Ok(())
     }
 }
 
+/// The `[NOT] MATERIALIZED` hint of a common table expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum CTEMaterialization {
+    /// No hint, the planner decides whether to materialize the cte.
+    Auto,
+    Materialized,
+    NotMaterialized,
+}
+
+impl Display for CTEMaterialization {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CTEMaterialization::Auto => Ok(()),
+            CTEMaterialization::Materialized => write!(f, "MATERIALIZED "),
+            CTEMaterialization::NotMaterialized => write!(f, "NOT MATERIALIZED "),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct SetOperation {
     pub span: Span,
@@ -183,38 +202,8 @@ impl Display for SelectStmt {
         }
 
         // GROUP BY clause
-        if self.group_by.is_some() {
-            write!(f, " GROUP BY ")?;
-            match self.group_by.as_ref().unwrap() {
-                GroupBy::Normal(exprs) => {
-                    write_comma_separated_list(f, exprs)?;
-                }
-                GroupBy::All => {
-                    write!(f, "ALL")?;
-                }
-                GroupBy::GroupingSets(sets) => {
-                    write!(f, "GROUPING SETS (")?;
-                    for (i, set) in sets.iter().enumerate() {
-                        if i > 0 {
-                            write!(f, ", ")?;
-                        }
-                        write!(f, "(")?;
-                        write_comma_separated_list(f, set)?;
-                        write!(f, ")")?;
-                    }
-                    write!(f, ")")?;
-                }
-                GroupBy::Cube(exprs) => {
-                    write!(f, "CUBE (")?;
-                    write_comma_separated_list(f, exprs)?;
-                    write!(f, ")")?;
-                }
-                GroupBy::Rollup(exprs) => {
-                    write!(f, "ROLLUP (")?;
-                    write_comma_separated_list(f, exprs)?;
-                    write!(f, ")")?;
-                }
-            }
+        if let Some(group_by) = &self.group_by {
+            write!(f, " GROUP BY {group_by}")?;
         }
 
         // HAVING clause
@@ -250,6 +239,57 @@ pub enum GroupBy {
     Cube(Vec<Expr>),
     /// GROUP BY ROLLUP ( expr [, expr]* )
     Rollup(Vec<Expr>),
+    /// GROUP BY <group by item> [, <group by item>]*
+    ///
+    /// Mixes regular group items with CUBE, ROLLUP and GROUPING SETS, the grouping sets
+    /// are the cross product of the grouping sets of each item.
+    Combined(Vec<GroupBy>),
+    /// GROUP BY <group by> WITH TOTALS
+    ///
+    /// Emits an extra grand-total row after the grouped rows.
+    WithTotals(Box<GroupBy>),
+}
+
+impl Display for GroupBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupBy::Normal(exprs) => {
+                write_comma_separated_list(f, exprs)?;
+            }
+            GroupBy::All => {
+                write!(f, "ALL")?;
+            }
+            GroupBy::GroupingSets(sets) => {
+                write!(f, "GROUPING SETS (")?;
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "(")?;
+                    write_comma_separated_list(f, set)?;
+                    write!(f, ")")?;
+                }
+                write!(f, ")")?;
+            }
+            GroupBy::Cube(exprs) => {
+                write!(f, "CUBE (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")?;
+            }
+            GroupBy::Rollup(exprs) => {
+                write!(f, "ROLLUP (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")?;
+            }
+            GroupBy::Combined(group_bys) => {
+                write_comma_separated_list(f, group_bys)?;
+            }
+            GroupBy::WithTotals(group_by) => {
+                write!(f, "{group_by} WITH TOTALS")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
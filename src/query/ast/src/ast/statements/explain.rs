@@ -35,6 +35,9 @@ pub enum ExplainKind {
 
     Join,
 
+    // Explain whether the common table expressions are materialized
+    Cte,
+
     // Explain analyze plan
     AnalyzePlan,
 }
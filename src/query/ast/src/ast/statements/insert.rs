@@ -38,6 +38,7 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    pub on_error: Option<InsertOnError>,
 }
 
 impl Display for InsertStmt {
@@ -66,7 +67,46 @@ impl Display for InsertStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
-        write!(f, " {}", self.source)
+        write!(f, " {}", self.source)?;
+        if let Some(on_error) = &self.on_error {
+            write!(f, " {on_error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `ON ERROR SKIP [LOG INTO [catalog.][database.]table]`
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct InsertOnError {
+    pub log_into: Option<InsertErrorLogTable>,
+}
+
+impl Display for InsertOnError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ON ERROR SKIP")?;
+        if let Some(log_into) = &self.log_into {
+            write!(f, " LOG INTO {log_into}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct InsertErrorLogTable {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for InsertErrorLogTable {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
     }
 }
 
@@ -74,6 +74,7 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
     ShowLocks(ShowLocksStmt),
+    CheckObjects,
 
     KillStmt {
         kill_target: KillTarget,
@@ -205,6 +206,9 @@ pub enum Statement {
         udf_name: Identifier,
     },
     AlterUDF(AlterUDFStmt),
+    DescribeUDF {
+        udf_name: Identifier,
+    },
 
     // Stages
     CreateStage(CreateStageStmt),
@@ -396,6 +400,7 @@ impl Display for Statement {
                     ExplainKind::Plan => (),
                     ExplainKind::AnalyzePlan => write!(f, " ANALYZE")?,
                     ExplainKind::Join => write!(f, " JOIN")?,
+                    ExplainKind::Cte => write!(f, " CTE")?,
                     ExplainKind::Memo(_) => write!(f, " MEMO")?,
                 }
                 write!(f, " {query}")?;
@@ -461,6 +466,7 @@ impl Display for Statement {
                 }
             }
             Statement::ShowLocks(stmt) => write!(f, "{stmt}")?,
+            Statement::CheckObjects => write!(f, "CHECK OBJECTS")?,
             Statement::KillStmt {
                 kill_target,
                 object_id,
@@ -610,6 +616,7 @@ impl Display for Statement {
                 write!(f, " {udf_name}")?;
             }
             Statement::AlterUDF(stmt) => write!(f, "{stmt}")?,
+            Statement::DescribeUDF { udf_name } => write!(f, "DESCRIBE FUNCTION {udf_name}")?,
             Statement::ListStage { location, pattern } => {
                 write!(f, "LIST @{location}")?;
                 if let Some(pattern) = pattern {
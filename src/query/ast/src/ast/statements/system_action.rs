@@ -18,6 +18,9 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::write_dot_separated_list;
+use crate::ast::Identifier;
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct SystemStmt {
     pub action: SystemAction,
@@ -32,6 +35,13 @@ impl Display for SystemStmt {
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum SystemAction {
     Backtrace(bool),
+    ShowDataCache,
+    /// Drop the disk cache of the table data, or all the table data if `table` is `None`.
+    DropDataCache {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Option<Identifier>,
+    },
 }
 
 impl Display for SystemAction {
@@ -41,6 +51,19 @@ impl Display for SystemAction {
                 true => write!(f, "ENABLE EXCEPTION_BACKTRACE"),
                 false => write!(f, "DISABLE EXCEPTION_BACKTRACE"),
             },
+            SystemAction::ShowDataCache => write!(f, "SHOW DATA CACHE"),
+            SystemAction::DropDataCache {
+                catalog,
+                database,
+                table,
+            } => {
+                write!(f, "DROP DATA CACHE")?;
+                if let Some(table) = table {
+                    write!(f, " FOR TABLE ")?;
+                    write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -350,6 +350,7 @@ impl Display for AlterTableStmt {
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum AlterTableAction {
     RenameTable {
+        new_database: Option<Identifier>,
         new_table: Identifier,
     },
     AddColumn {
@@ -394,8 +395,12 @@ impl Display for AlterTableAction {
                 write_comma_separated_string_map(f, set_options)?;
                 write!(f, ")")?;
             }
-            AlterTableAction::RenameTable { new_table } => {
-                write!(f, "RENAME TO {new_table}")?;
+            AlterTableAction::RenameTable {
+                new_database,
+                new_table,
+            } => {
+                write!(f, "RENAME TO ")?;
+                write_dot_separated_list(f, new_database.iter().chain(Some(new_table)))?;
             }
             AlterTableAction::ModifyTableComment { new_comment } => {
                 write!(f, "COMMENT='{new_comment}'")?;
@@ -504,6 +509,9 @@ pub struct TruncateTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// Move the streams on the table to the truncated table version,
+    /// so that the truncation is not reported as changes.
+    pub restart_streams: bool,
 }
 
 impl Display for TruncateTableStmt {
@@ -516,6 +524,9 @@ impl Display for TruncateTableStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if self.restart_streams {
+            write!(f, " RESTART STREAMS")?;
+        }
         Ok(())
     }
 }
@@ -28,6 +28,8 @@ use crate::ast::TypeName;
 pub enum UDFDefinition {
     LambdaUDF {
         parameters: Vec<Identifier>,
+        /// The default values of the trailing parameters.
+        defaults: Vec<Expr>,
         definition: Box<Expr>,
     },
     UDFServer {
@@ -36,6 +38,7 @@ pub enum UDFDefinition {
         address: String,
         handler: String,
         language: String,
+        deterministic: bool,
     },
 
     UDFScript {
@@ -53,10 +56,20 @@ impl Display for UDFDefinition {
         match self {
             UDFDefinition::LambdaUDF {
                 parameters,
+                defaults,
                 definition,
             } => {
                 write!(f, "AS (")?;
-                write_comma_separated_list(f, parameters)?;
+                let first_default = parameters.len() - defaults.len();
+                for (i, parameter) in parameters.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{parameter}")?;
+                    if i >= first_default {
+                        write!(f, " DEFAULT {}", defaults[i - first_default])?;
+                    }
+                }
                 write!(f, ") -> {definition}")?;
             }
             UDFDefinition::UDFServer {
@@ -65,6 +78,7 @@ impl Display for UDFDefinition {
                 address,
                 handler,
                 language,
+                deterministic,
             } => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, arg_types)?;
@@ -72,6 +86,9 @@ impl Display for UDFDefinition {
                     f,
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' ADDRESS = '{address}'"
                 )?;
+                if *deterministic {
+                    write!(f, " DETERMINISTIC")?;
+                }
             }
             UDFDefinition::UDFScript {
                 arg_types,
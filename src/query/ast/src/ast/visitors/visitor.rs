@@ -91,6 +91,14 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, expr);
     }
 
+    fn visit_is_bool(&mut self, _span: Span, expr: &'ast Expr, _value: bool, _not: bool) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_is_json(&mut self, _span: Span, expr: &'ast Expr, _not: bool) {
+        walk_expr(self, expr);
+    }
+
     fn visit_is_distinct_from(
         &mut self,
         _span: Span,
@@ -177,9 +185,18 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, expr);
     }
 
-    fn visit_position(&mut self, _span: Span, substr_expr: &'ast Expr, str_expr: &'ast Expr) {
+    fn visit_position(
+        &mut self,
+        _span: Span,
+        substr_expr: &'ast Expr,
+        str_expr: &'ast Expr,
+        position_from: &'ast Option<Box<Expr>>,
+    ) {
         walk_expr(self, substr_expr);
         walk_expr(self, str_expr);
+        if let Some(position_from) = position_from {
+            walk_expr(self, position_from);
+        }
     }
 
     fn visit_substring(
@@ -207,10 +224,18 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &'ast Literal) {}
 
-    fn visit_count_all(&mut self, _span: Span, window: &'ast Option<Window>) {
+    fn visit_count_all(
+        &mut self,
+        _span: Span,
+        window: &'ast Option<Window>,
+        filter: &'ast Option<Box<Expr>>,
+    ) {
         if let Some(window) = window {
             self.visit_window(window);
         }
+        if let Some(filter) = filter {
+            walk_expr(self, filter);
+        }
     }
 
     fn visit_tuple(&mut self, _span: Span, elements: &'ast [Expr]) {
@@ -229,6 +254,8 @@ pub trait Visitor<'ast>: Sized {
         params: &'ast [Expr],
         over: &'ast Option<Window>,
         lambda: &'ast Option<Lambda>,
+        order_by: &'ast [OrderByExpr],
+        filter: &'ast Option<Box<Expr>>,
     ) {
         for arg in args {
             walk_expr(self, arg);
@@ -243,6 +270,12 @@ pub trait Visitor<'ast>: Sized {
         if let Some(lambda) = lambda {
             walk_expr(self, &lambda.expr)
         }
+        for order in order_by {
+            walk_expr(self, &order.expr);
+        }
+        if let Some(filter) = filter {
+            walk_expr(self, filter);
+        }
     }
 
     fn visit_window(&mut self, window: &'ast Window) {
@@ -409,6 +442,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_locks(&mut self, _stmt: &'ast ShowLocksStmt) {}
 
+    fn visit_check_objects(&mut self) {}
+
     fn visit_kill(&mut self, _kill_target: &'ast KillTarget, _object_id: &'ast str) {}
 
     fn visit_set_variable(
@@ -628,6 +663,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_udf(&mut self, _stmt: &'ast AlterUDFStmt) {}
 
+    fn visit_describe_udf(&mut self, _udf_name: &'ast Identifier) {}
+
     fn visit_create_stage(&mut self, _stmt: &'ast CreateStageStmt) {}
 
     fn visit_show_stages(&mut self) {}
@@ -771,6 +808,10 @@ pub trait Visitor<'ast>: Sized {
             walk_expr(self, selection);
         }
 
+        let group_by = match group_by {
+            Some(GroupBy::WithTotals(group_by)) => Some(group_by.as_ref()),
+            group_by => group_by.as_ref(),
+        };
         match group_by {
             Some(GroupBy::Normal(exprs)) => {
                 for expr in exprs {
@@ -784,6 +825,25 @@ pub trait Visitor<'ast>: Sized {
                     }
                 }
             }
+            Some(GroupBy::Combined(group_bys)) => {
+                for group_by in group_bys {
+                    match group_by {
+                        GroupBy::Normal(exprs) => {
+                            for expr in exprs {
+                                walk_expr(self, expr);
+                            }
+                        }
+                        GroupBy::GroupingSets(sets) => {
+                            for set in sets {
+                                for expr in set {
+                                    walk_expr(self, expr);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -83,6 +83,14 @@ pub trait VisitorMut: Sized {
         Self::visit_expr(self, expr);
     }
 
+    fn visit_is_bool(&mut self, _span: Span, expr: &mut Expr, _value: bool, _not: bool) {
+        Self::visit_expr(self, expr);
+    }
+
+    fn visit_is_json(&mut self, _span: Span, expr: &mut Expr, _not: bool) {
+        Self::visit_expr(self, expr);
+    }
+
     fn visit_is_distinct_from(
         &mut self,
         _span: Span,
@@ -169,9 +177,19 @@ pub trait VisitorMut: Sized {
         Self::visit_expr(self, expr);
     }
 
-    fn visit_position(&mut self, _span: Span, substr_expr: &mut Expr, str_expr: &mut Expr) {
+    fn visit_position(
+        &mut self,
+        _span: Span,
+        substr_expr: &mut Expr,
+        str_expr: &mut Expr,
+        position_from: &mut Option<Box<Expr>>,
+    ) {
         Self::visit_expr(self, substr_expr);
         Self::visit_expr(self, str_expr);
+
+        if let Some(position_from) = position_from {
+            Self::visit_expr(self, position_from);
+        }
     }
 
     fn visit_substring(
@@ -204,7 +222,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_literal(&mut self, _span: Span, _lit: &mut Literal) {}
 
-    fn visit_count_all(&mut self, _span: Span, window: &mut Option<Window>) {
+    fn visit_count_all(
+        &mut self,
+        _span: Span,
+        window: &mut Option<Window>,
+        filter: &mut Option<Box<Expr>>,
+    ) {
         if let Some(window) = window {
             match window {
                 Window::WindowReference(reference) => {
@@ -225,6 +248,9 @@ pub trait VisitorMut: Sized {
                 }
             }
         }
+        if let Some(filter) = filter {
+            Self::visit_expr(self, filter);
+        }
     }
 
     fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
@@ -243,6 +269,8 @@ pub trait VisitorMut: Sized {
         params: &mut Vec<Expr>,
         over: &mut Option<Window>,
         lambda: &mut Option<Lambda>,
+        order_by: &mut Vec<OrderByExpr>,
+        filter: &mut Option<Box<Expr>>,
     ) {
         for arg in args.iter_mut() {
             Self::visit_expr(self, arg);
@@ -250,6 +278,12 @@ pub trait VisitorMut: Sized {
         for param in params.iter_mut() {
             Self::visit_expr(self, param);
         }
+        for order in order_by.iter_mut() {
+            Self::visit_expr(self, &mut order.expr);
+        }
+        if let Some(filter) = filter {
+            Self::visit_expr(self, filter);
+        }
 
         if let Some(over) = over {
             match over {
@@ -415,6 +449,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_locks(&mut self, _show_locks: &mut ShowLocksStmt) {}
 
+    fn visit_check_objects(&mut self) {}
+
     fn visit_show_table_functions(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_show_limit(&mut self, _limit: &mut ShowLimit) {}
@@ -640,6 +676,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_udf(&mut self, _stmt: &mut AlterUDFStmt) {}
 
+    fn visit_describe_udf(&mut self, _udf_name: &mut Identifier) {}
+
     fn visit_create_stage(&mut self, _stmt: &mut CreateStageStmt) {}
 
     fn visit_show_stages(&mut self) {}
@@ -785,6 +823,10 @@ pub trait VisitorMut: Sized {
             Self::visit_expr(self, selection);
         }
 
+        let group_by = match group_by {
+            Some(GroupBy::WithTotals(group_by)) => Some(group_by.as_mut()),
+            group_by => group_by.as_mut(),
+        };
         match group_by {
             Some(GroupBy::Normal(exprs)) => {
                 for expr in exprs {
@@ -798,6 +840,25 @@ pub trait VisitorMut: Sized {
                     }
                 }
             }
+            Some(GroupBy::Combined(group_bys)) => {
+                for group_by in group_bys {
+                    match group_by {
+                        GroupBy::Normal(exprs) => {
+                            for expr in exprs {
+                                Self::visit_expr(self, expr);
+                            }
+                        }
+                        GroupBy::GroupingSets(sets) => {
+                            for set in sets {
+                                for expr in set {
+                                    Self::visit_expr(self, expr);
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -29,6 +29,13 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
                 },
         } => visitor.visit_column_ref(*span, database, table, column),
         Expr::IsNull { span, expr, not } => visitor.visit_is_null(*span, expr, *not),
+        Expr::IsBool {
+            span,
+            expr,
+            value,
+            not,
+        } => visitor.visit_is_bool(*span, expr, *value, *not),
+        Expr::IsJson { span, expr, not } => visitor.visit_is_json(*span, expr, *not),
         Expr::IsDistinctFrom {
             span,
             left,
@@ -72,11 +79,13 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
             expr,
             target_type,
             pg_style,
+            ..
         } => visitor.visit_cast(*span, expr, target_type, *pg_style),
         Expr::TryCast {
             span,
             expr,
             target_type,
+            ..
         } => visitor.visit_try_cast(*span, expr, target_type),
         Expr::Extract { span, kind, expr } => visitor.visit_extract(*span, kind, expr),
         Expr::DatePart { span, kind, expr } => visitor.visit_extract(*span, kind, expr),
@@ -84,7 +93,8 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
             span,
             substr_expr,
             str_expr,
-        } => visitor.visit_position(*span, substr_expr, str_expr),
+            position_from,
+        } => visitor.visit_position(*span, substr_expr, str_expr, position_from),
         Expr::Substring {
             span,
             expr,
@@ -97,7 +107,11 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
             trim_where,
         } => visitor.visit_trim(*span, expr, trim_where),
         Expr::Literal { span, value } => visitor.visit_literal(*span, value),
-        Expr::CountAll { span, window } => visitor.visit_count_all(*span, window),
+        Expr::CountAll {
+            span,
+            window,
+            filter,
+        } => visitor.visit_count_all(*span, window, filter),
         Expr::Tuple { span, exprs } => visitor.visit_tuple(*span, exprs),
         Expr::FunctionCall {
             span,
@@ -109,8 +123,12 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
                     params,
                     window,
                     lambda,
+                    order_by,
+                    filter,
                 },
-        } => visitor.visit_function_call(*span, *distinct, name, args, params, window, lambda),
+        } => visitor.visit_function_call(
+            *span, *distinct, name, args, params, window, lambda, order_by, filter,
+        ),
         Expr::Case {
             span,
             operand,
@@ -150,6 +168,7 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
         } => visitor.visit_date_sub(*span, unit, interval, date),
         Expr::DateTrunc { span, unit, date } => visitor.visit_date_trunc(*span, unit, date),
         Expr::Hole { .. } => {}
+        Expr::SystemVariable { .. } => {}
     }
 }
 
@@ -425,6 +444,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         }
         Statement::ShowIndexes { show_options } => visitor.visit_show_indexes(show_options),
         Statement::ShowLocks(stmt) => visitor.visit_show_locks(stmt),
+        Statement::CheckObjects => visitor.visit_check_objects(),
         Statement::KillStmt {
             kill_target,
             object_id,
@@ -515,6 +535,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             udf_name,
         } => visitor.visit_drop_udf(*if_exists, udf_name),
         Statement::AlterUDF(stmt) => visitor.visit_alter_udf(stmt),
+        Statement::DescribeUDF { udf_name } => visitor.visit_describe_udf(udf_name),
         Statement::ListStage { location, pattern } => visitor.visit_list_stage(location, pattern),
         Statement::ShowStages => visitor.visit_show_stages(),
         Statement::DropStage {
@@ -299,6 +299,12 @@ pub fn semicolon_terminated_list1<'a, T>(
     many1(terminated(item, match_text(";")))
 }
 
+pub fn comma_terminated_list1<'a, T>(
+    item: impl FnMut(Input<'a>) -> IResult<'a, T>,
+) -> impl FnMut(Input<'a>) -> IResult<'a, Vec<T>> {
+    many1(terminated(item, match_text(",")))
+}
+
 pub fn comma_separated_list1<'a, T>(
     item: impl FnMut(Input<'a>) -> IResult<'a, T>,
 ) -> impl FnMut(Input<'a>) -> IResult<'a, Vec<T>> {
@@ -150,6 +150,15 @@ pub enum ExprElement {
     IsNull {
         not: bool,
     },
+    /// `IS [NOT] { TRUE | FALSE }` expression
+    IsBool {
+        value: bool,
+        not: bool,
+    },
+    /// `IS [NOT] JSON` expression
+    IsJson {
+        not: bool,
+    },
     /// `IS [NOT] DISTINCT FROM` expression
     IsDistinctFrom {
         not: bool,
@@ -186,11 +195,13 @@ pub enum ExprElement {
     Cast {
         expr: Box<Expr>,
         target_type: TypeName,
+        format: Option<String>,
     },
     /// `TRY_CAST` expression`
     TryCast {
         expr: Box<Expr>,
         target_type: TypeName,
+        format: Option<String>,
     },
     /// `::<type_name>` expression
     PgCast {
@@ -206,10 +217,11 @@ pub enum ExprElement {
         field: IntervalKind,
         expr: Box<Expr>,
     },
-    /// POSITION(<expr> IN <expr>)
+    /// POSITION(<expr> IN <expr> [FROM <expr>])
     Position {
         substr_expr: Box<Expr>,
         str_expr: Box<Expr>,
+        position_from: Option<Box<Expr>>,
     },
     /// SUBSTRING(<expr> [FROM <expr>] [FOR <expr>])
     SubString {
@@ -232,6 +244,7 @@ pub enum ExprElement {
     /// `Count(*)` expression
     CountAll {
         window: Option<Window>,
+        filter: Option<Box<Expr>>,
     },
     /// `(foo, bar)`
     Tuple {
@@ -306,6 +319,9 @@ pub enum ExprElement {
     Hole {
         name: String,
     },
+    SystemVariable {
+        name: String,
+    },
 }
 
 pub const BETWEEN_PREC: u32 = 20;
@@ -355,6 +371,8 @@ const fn binary_affix(op: &BinaryOperator) -> Affix {
         BinaryOperator::BitwiseAnd => Affix::Infix(Precedence(22), Associativity::Left),
         BinaryOperator::BitwiseXor => Affix::Infix(Precedence(22), Associativity::Left),
         BinaryOperator::L2Distance => Affix::Infix(Precedence(22), Associativity::Left),
+        BinaryOperator::L1Distance => Affix::Infix(Precedence(22), Associativity::Left),
+        BinaryOperator::CosineDistance => Affix::Infix(Precedence(22), Associativity::Left),
         BinaryOperator::BitwiseShiftLeft => Affix::Infix(Precedence(23), Associativity::Left),
         BinaryOperator::BitwiseShiftRight => Affix::Infix(Precedence(23), Associativity::Left),
         BinaryOperator::Xor => Affix::Infix(Precedence(24), Associativity::Left),
@@ -377,6 +395,8 @@ impl ExprElement {
             ExprElement::DotAccess { .. } => DOT_ACCESS_AFFIX,
             ExprElement::MapAccess { .. } => MAP_ACCESS_AFFIX,
             ExprElement::IsNull { .. } => IS_NULL_AFFIX,
+            ExprElement::IsBool { .. } => IS_NULL_AFFIX,
+            ExprElement::IsJson { .. } => IS_NULL_AFFIX,
             ExprElement::Between { .. } => BETWEEN_AFFIX,
             ExprElement::IsDistinctFrom { .. } => IS_DISTINCT_FROM_AFFIX,
             ExprElement::InList { .. } => IN_LIST_AFFIX,
@@ -409,6 +429,7 @@ impl ExprElement {
             ExprElement::DateSub { .. } => Affix::Nilfix,
             ExprElement::DateTrunc { .. } => Affix::Nilfix,
             ExprElement::Hole { .. } => Affix::Nilfix,
+            ExprElement::SystemVariable { .. } => Affix::Nilfix,
         }
     }
 }
@@ -418,6 +439,8 @@ impl Expr {
         match self {
             Expr::MapAccess { .. } => MAP_ACCESS_AFFIX,
             Expr::IsNull { .. } => IS_NULL_AFFIX,
+            Expr::IsBool { .. } => IS_NULL_AFFIX,
+            Expr::IsJson { .. } => IS_NULL_AFFIX,
             Expr::Between { .. } => BETWEEN_AFFIX,
             Expr::IsDistinctFrom { .. } => Affix::Nilfix,
             Expr::InList { .. } => IN_LIST_AFFIX,
@@ -450,6 +473,7 @@ impl Expr {
             Expr::DateSub { .. } => Affix::Nilfix,
             Expr::DateTrunc { .. } => Affix::Nilfix,
             Expr::Hole { .. } => Affix::Nilfix,
+            Expr::SystemVariable { .. } => Affix::Nilfix,
         }
     }
 }
@@ -471,16 +495,26 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.tokens),
                 column,
             },
-            ExprElement::Cast { expr, target_type } => Expr::Cast {
+            ExprElement::Cast {
+                expr,
+                target_type,
+                format,
+            } => Expr::Cast {
                 span: transform_span(elem.span.tokens),
                 expr,
                 target_type,
                 pg_style: false,
+                format,
             },
-            ExprElement::TryCast { expr, target_type } => Expr::TryCast {
+            ExprElement::TryCast {
+                expr,
+                target_type,
+                format,
+            } => Expr::TryCast {
                 span: transform_span(elem.span.tokens),
                 expr,
                 target_type,
+                format,
             },
             ExprElement::Extract { field, expr } => Expr::Extract {
                 span: transform_span(elem.span.tokens),
@@ -495,10 +529,12 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
             ExprElement::Position {
                 substr_expr,
                 str_expr,
+                position_from,
             } => Expr::Position {
                 span: transform_span(elem.span.tokens),
                 substr_expr,
                 str_expr,
+                position_from,
             },
             ExprElement::SubString {
                 expr,
@@ -519,9 +555,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.tokens),
                 value,
             },
-            ExprElement::CountAll { window } => Expr::CountAll {
+            ExprElement::CountAll { window, filter } => Expr::CountAll {
                 span: transform_span(elem.span.tokens),
                 window,
+                filter,
             },
             ExprElement::Tuple { exprs } => Expr::Tuple {
                 span: transform_span(elem.span.tokens),
@@ -584,6 +621,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                                 params: vec![param.clone()],
                                 expr: Box::new(filter),
                             }),
+                            order_by: vec![],
+                            filter: None,
                         },
                     };
                 }
@@ -600,6 +639,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                             params: vec![param.clone()],
                             expr: Box::new(result),
                         }),
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -641,6 +682,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.tokens),
                 name,
             },
+            ExprElement::SystemVariable { name } => Expr::SystemVariable {
+                span: transform_span(elem.span.tokens),
+                name,
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -731,6 +776,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                     params: vec![],
                     window: None,
                     lambda,
+                    order_by: vec![],
+                    filter: None,
                 },
             },
             ExprElement::IsNull { not } => Expr::IsNull {
@@ -738,6 +785,17 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 expr: Box::new(lhs),
                 not,
             },
+            ExprElement::IsBool { value, not } => Expr::IsBool {
+                span: transform_span(elem.span.tokens),
+                expr: Box::new(lhs),
+                value,
+                not,
+            },
+            ExprElement::IsJson { not } => Expr::IsJson {
+                span: transform_span(elem.span.tokens),
+                expr: Box::new(lhs),
+                not,
+            },
             ExprElement::InList { list, not } => Expr::InList {
                 span: transform_span(elem.span.tokens),
                 expr: Box::new(lhs),
@@ -762,6 +820,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 expr: Box::new(lhs),
                 target_type,
                 pg_style: true,
+                format: None,
             },
             ExprElement::UnaryOp { op } => Expr::UnaryOp {
                 span: transform_span(elem.span.tokens),
@@ -790,6 +849,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             not: opt_not.is_some(),
         },
     );
+    let is_bool_or_json = map(
+        rule! {
+            IS ~ NOT? ~ ( TRUE | FALSE | JSON )
+        },
+        |(_, opt_not, token)| match token.kind {
+            TokenKind::TRUE => ExprElement::IsBool {
+                value: true,
+                not: opt_not.is_some(),
+            },
+            TokenKind::FALSE => ExprElement::IsBool {
+                value: false,
+                not: opt_not.is_some(),
+            },
+            TokenKind::JSON => ExprElement::IsJson {
+                not: opt_not.is_some(),
+            },
+            _ => unreachable!(),
+        },
+    );
     let in_list = map(
         rule! {
             NOT? ~ IN ~ "(" ~ #comma_separated_list1(subexpr(0)) ~ ^")"
@@ -825,18 +903,22 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             ~ ^#subexpr(0)
             ~ ^( AS | "," )
             ~ ^#type_name
+            ~ ( FORMAT ~ ^#literal_string )?
             ~ ^")"
         },
-        |(cast, _, expr, _, target_type, _)| {
+        |(cast, _, expr, _, target_type, format, _)| {
+            let format = format.map(|(_, format)| format);
             if cast.kind == CAST {
                 ExprElement::Cast {
                     expr: Box::new(expr),
                     target_type,
+                    format,
                 }
             } else {
                 ExprElement::TryCast {
                     expr: Box::new(expr),
                     target_type,
+                    format,
                 }
             }
         },
@@ -872,11 +954,13 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             ~ ^#subexpr(BETWEEN_PREC)
             ~ ^IN
             ~ ^#subexpr(0)
+            ~ ( FROM ~ ^#subexpr(0) )?
             ~ ^")"
         },
-        |(_, _, substr_expr, _, str_expr, _)| ExprElement::Position {
+        |(_, _, substr_expr, _, str_expr, opt_position_from, _)| ExprElement::Position {
             substr_expr: Box::new(substr_expr),
             str_expr: Box::new(str_expr),
+            position_from: opt_position_from.map(|(_, expr)| Box::new(expr)),
         },
     );
     let substring = map(
@@ -930,10 +1014,11 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
 
     let count_all_with_window = map(
         rule! {
-            COUNT ~ "(" ~ "*" ~ ")" ~ ( OVER ~ #window_spec_ident )?
+            COUNT ~ "(" ~ "*" ~ ")" ~ #aggregate_filter? ~ ( OVER ~ #window_spec_ident )?
         },
-        |(_, _, _, _, window)| ExprElement::CountAll {
+        |(_, _, _, _, filter, window)| ExprElement::CountAll {
             window: window.map(|w| w.1),
+            filter,
         },
     );
 
@@ -967,9 +1052,11 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     let function_call = map(
         rule! {
             #function_name
-            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))?
+            ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )? ~ ")"
+            ~ #aggregate_filter?
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, opt_order_by, _, filter)| ExprElement::FunctionCall {
             func: FunctionCall {
                 distinct: opt_distinct.is_some(),
                 name,
@@ -977,35 +1064,49 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 params: vec![],
                 window: None,
                 lambda: None,
+                order_by: opt_order_by
+                    .map(|(_, _, order_by)| order_by)
+                    .unwrap_or_default(),
+                filter,
             },
         },
     );
     let function_call_with_lambda = map(
         rule! {
             #function_name
-            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
-        },
-        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
-            func: FunctionCall {
-                distinct: false,
-                name,
-                args: vec![arg],
-                params: vec![],
-                window: None,
-                lambda: Some(Lambda {
-                    params,
-                    expr: Box::new(expr),
-                }),
-            },
+            ~ "(" ~ #comma_terminated_list1(subexpr(0)) ~ #lambda_params ~ "->" ~ #subexpr(0)
+            ~ ( "," ~ #comma_separated_list1(subexpr(0)) )? ~ ")"
+        },
+        |(name, _, mut args, params, _, expr, opt_trailing_args, _)| {
+            // The arguments after the lambda, e.g. the sorting order of
+            // `array_sort_by(arr, x -> x.score, 'desc')`, follow the leading ones.
+            if let Some((_, trailing_args)) = opt_trailing_args {
+                args.extend(trailing_args);
+            }
+            ExprElement::FunctionCall {
+                func: FunctionCall {
+                    distinct: false,
+                    name,
+                    args,
+                    params: vec![],
+                    window: None,
+                    lambda: Some(Lambda {
+                        params,
+                        expr: Box::new(expr),
+                    }),
+                    order_by: vec![],
+                    filter: None,
+                },
+            }
         },
     );
     let function_call_with_window = map(
         rule! {
             #function_name
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
-            ~ (OVER ~ #window_spec_ident)
+            ~ #aggregate_filter? ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, _, filter, window)| ExprElement::FunctionCall {
             func: FunctionCall {
                 distinct: opt_distinct.is_some(),
                 name,
@@ -1013,6 +1114,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 params: vec![],
                 window: Some(window.1),
                 lambda: None,
+                order_by: vec![],
+                filter,
             },
         },
     );
@@ -1021,8 +1124,9 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             #function_name
             ~ ("(" ~ #comma_separated_list1(subexpr(0)) ~ ")")?
             ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ #aggregate_filter?
         },
-        |(name, params, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, params, _, opt_distinct, opt_args, _, filter)| ExprElement::FunctionCall {
             func: FunctionCall {
                 distinct: opt_distinct.is_some(),
                 name,
@@ -1030,6 +1134,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 params: params.map(|(_, x, _)| x).unwrap_or_default(),
                 window: None,
                 lambda: None,
+                order_by: vec![],
+                filter,
             },
         },
     );
@@ -1227,14 +1333,23 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 params: vec![],
                 window: None,
                 lambda: None,
+                order_by: vec![],
+                filter: None,
             },
         }
     });
 
+    let system_variable = map(rule! { SystemVariable }, |token| {
+        ExprElement::SystemVariable {
+            name: token.text()[2..].to_string(),
+        }
+    });
+
     let (rest, (span, elem)) = consumed(alt((
         // Note: each `alt` call supports maximum of 21 parsers
         rule!(
             #is_null : "`... IS [NOT] NULL`"
+            | #is_bool_or_json : "`... IS [NOT] { TRUE | FALSE | JSON }`"
             | #in_list : "`[NOT] IN (<expr>, ...)`"
             | #in_subquery : "`[NOT] IN (SELECT ...)`"
             | #exists : "`[NOT] EXISTS (SELECT ...)`"
@@ -1253,6 +1368,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
             | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
             | #position : "`POSITION(... IN ...)`"
+            | #system_variable : "`@@<variable>`"
         ),
         rule!(
             #substring : "`SUBSTRING(... [FROM ...] [FOR ...])`"
@@ -1282,6 +1398,16 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     Ok((rest, WithSpan { span, elem }))
 }
 
+/// Parses the `FILTER (WHERE <expr>)` clause following an aggregate function call.
+pub fn aggregate_filter(i: Input) -> IResult<Box<Expr>> {
+    map(
+        rule! {
+            FILTER ~ "(" ~ ^WHERE ~ ^#subexpr(0) ~ ^")"
+        },
+        |(_, _, _, expr, _)| Box::new(expr),
+    )(i)
+}
+
 pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
     // Plus and Minus are parsed as binary op at first.
     alt((
@@ -1306,6 +1432,8 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
             value(BinaryOperator::Modulo, rule! { "%" }),
             value(BinaryOperator::StringConcat, rule! { "||" }),
             value(BinaryOperator::L2Distance, rule! { "<->" }),
+            value(BinaryOperator::L1Distance, rule! { "<+>" }),
+            value(BinaryOperator::CosineDistance, rule! { "<=>" }),
             value(BinaryOperator::Gt, rule! { ">" }),
             value(BinaryOperator::Lt, rule! { "<" }),
             value(BinaryOperator::Gte, rule! { ">=" }),
@@ -1570,15 +1698,15 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         |(_, item_type)| TypeName::Nullable(Box::new(item_type.1)),
     );
     let ty_tuple = map(
-        rule! { TUPLE ~ "(" ~ #comma_separated_list1(type_name) ~ ")" },
-        |(_, _, fields_type, _)| TypeName::Tuple {
+        rule! { TUPLE ~ "(" ~ #comma_separated_list1_ignore_trailing(type_name) ~ ","? ~ ")" },
+        |(_, _, fields_type, _, _)| TypeName::Tuple {
             fields_name: None,
             fields_type,
         },
     );
     let ty_named_tuple = map_res(
-        rule! { TUPLE ~ "(" ~ #comma_separated_list1(rule! { #ident ~ #type_name }) ~ ")" },
-        |(_, _, fields, _)| {
+        rule! { TUPLE ~ "(" ~ #comma_separated_list1_ignore_trailing(rule! { #ident ~ #type_name }) ~ ","? ~ ")" },
+        |(_, _, fields, _, _)| {
             let (fields_name, fields_type): (Vec<String>, Vec<TypeName>) =
                 fields.into_iter().map(|(name, ty)| (name.name, ty)).unzip();
             if fields_name
@@ -1714,6 +1842,12 @@ pub fn interval_kind(i: Input) -> IResult<IntervalKind> {
 }
 
 pub fn map_access(i: Input) -> IResult<MapAccessor> {
+    let wildcard = map(
+        rule! {
+           "[" ~ "*" ~ "]"
+        },
+        |_| MapAccessor::Wildcard,
+    );
     let bracket = map(
         rule! {
            "[" ~ #subexpr(0) ~ "]"
@@ -1741,7 +1875,8 @@ pub fn map_access(i: Input) -> IResult<MapAccessor> {
     );
 
     rule!(
-        #bracket
+        #wildcard
+        | #bracket
         | #dot_number
         | #colon
     )(i)
@@ -139,8 +139,7 @@ impl Dialect {
 
     pub fn substr_index_zero_literal_as_one(&self) -> bool {
         match self {
-            Dialect::MySQL => false,
-            Dialect::Hive => true,
+            Dialect::MySQL | Dialect::Hive => true,
             Dialect::Experimental | Dialect::PostgreSQL | Dialect::PRQL => false,
         }
     }
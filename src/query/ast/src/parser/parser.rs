@@ -110,6 +110,40 @@ pub fn parse_raw_replace_stmt(tokens: &[Token], dialect: Dialect) -> Result<Stat
     )
 }
 
+/// The maximum nesting depth of parentheses, brackets and braces in a SQL. The parser
+/// is recursive, so a deeper nesting is rejected before parsing it rather than
+/// overflowing the stack.
+///
+/// The parser has no access to the session settings, so this limit is fixed. It is
+/// applied before, and independently of, the `max_expression_depth` setting checked
+/// by the type checker, whose description documents both limits.
+pub const MAX_NESTING_DEPTH: usize = 1000;
+
+fn check_nesting_depth(tokens: &[Token]) -> Result<()> {
+    let mut depth = 0usize;
+    for token in tokens {
+        match token.kind {
+            TokenKind::LParen | TokenKind::LBracket | TokenKind::LBrace => {
+                depth += 1;
+                if depth > MAX_NESTING_DEPTH {
+                    return Err(ParseError(
+                        transform_span(std::slice::from_ref(token)),
+                        format!(
+                            "SQL is nested too deeply, exceeds the maximum depth {}",
+                            MAX_NESTING_DEPTH
+                        ),
+                    ));
+                }
+            }
+            TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace => {
+                depth = depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 pub fn run_parser<O>(
     tokens: &[Token],
     dialect: Dialect,
@@ -117,6 +151,7 @@ pub fn run_parser<O>(
     allow_partial: bool,
     mut parser: impl FnMut(Input) -> IResult<O>,
 ) -> Result<O> {
+    check_nesting_depth(tokens)?;
     let backtrace = Backtrace::new();
     let input = Input {
         tokens,
@@ -353,12 +353,16 @@ pub fn row_values(i: Input) -> IResult<Vec<Expr>> {
 pub fn with(i: Input) -> IResult<With> {
     let cte = map(
         consumed(rule! {
-            #table_alias_without_as ~ AS ~ MATERIALIZED? ~ "(" ~ #query ~ ")"
+            #table_alias_without_as ~ AS ~ ( NOT? ~ MATERIALIZED )? ~ "(" ~ #query ~ ")"
         }),
-        |(span, (table_alias, _, materialized, _, query, _))| CTE {
+        |(span, (table_alias, _, materialization, _, query, _))| CTE {
             span: transform_span(span.tokens),
             alias: table_alias,
-            materialized: materialized.is_some(),
+            materialization: match materialization {
+                None => CTEMaterialization::Auto,
+                Some((None, _)) => CTEMaterialization::Materialized,
+                Some((Some(_), _)) => CTEMaterialization::NotMaterialized,
+            },
             query: Box::new(query),
         },
     );
@@ -991,10 +995,6 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
 }
 
 pub fn group_by_items(i: Input) -> IResult<GroupBy> {
-    let normal = map(rule! { ^#comma_separated_list1(expr) }, |groups| {
-        GroupBy::Normal(groups)
-    });
-
     let all = map(rule! { ALL }, |_| GroupBy::All);
 
     let cube = map(
@@ -1017,7 +1017,42 @@ pub fn group_by_items(i: Input) -> IResult<GroupBy> {
         rule! { GROUPING ~ SETS ~ "(" ~ ^#comma_separated_list1(group_set) ~ ")"  },
         |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
     );
-    rule!(#all | #group_sets | #cube | #rollup | #normal)(i)
+    let group_by_item = alt((
+        group_sets,
+        cube,
+        rollup,
+        map(rule! { #expr }, |expr| GroupBy::Normal(vec![expr])),
+    ));
+    // GROUP BY a, b => Normal([a, b])
+    // GROUP BY a, ROLLUP (b, c) => Combined([Normal([a]), Rollup([b, c])])
+    let combined = map(
+        rule! { ^#comma_separated_list1(group_by_item) },
+        |mut items| {
+            if items.len() == 1 {
+                items.pop().unwrap()
+            } else if items.iter().all(|item| matches!(item, GroupBy::Normal(_))) {
+                let exprs = items
+                    .into_iter()
+                    .flat_map(|item| match item {
+                        GroupBy::Normal(exprs) => exprs,
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                GroupBy::Normal(exprs)
+            } else {
+                GroupBy::Combined(items)
+            }
+        },
+    );
+    map(
+        rule! {
+            ( #all | #combined ) ~ ( WITH ~ TOTALS )?
+        },
+        |(group_by, opt_with_totals)| match opt_with_totals {
+            Some(_) => GroupBy::WithTotals(Box::new(group_by)),
+            None => group_by,
+        },
+    )(i)
 }
 
 pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
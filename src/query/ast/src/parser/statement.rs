@@ -57,7 +57,7 @@ pub enum CreateDatabaseOption {
 pub fn statement_body(i: Input) -> IResult<Statement> {
     let explain = map_res(
         rule! {
-            EXPLAIN ~ ( "(" ~ #comma_separated_list1(explain_option) ~ ")" )? ~ ( AST | SYNTAX | PIPELINE | JOIN | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
+            EXPLAIN ~ ( "(" ~ #comma_separated_list1(explain_option) ~ ")" )? ~ ( AST | SYNTAX | PIPELINE | JOIN | CTE | GRAPH | FRAGMENTS | RAW | OPTIMIZED | MEMO )? ~ #statement
         },
         |(_, options, opt_kind, statement)| {
             Ok(Statement::Explain {
@@ -78,6 +78,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     }
                     Some(TokenKind::PIPELINE) => ExplainKind::Pipeline,
                     Some(TokenKind::JOIN) => ExplainKind::Join,
+                    Some(TokenKind::CTE) => ExplainKind::Cte,
                     Some(TokenKind::GRAPH) => ExplainKind::Graph,
                     Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
                     Some(TokenKind::RAW) => ExplainKind::Raw,
@@ -329,6 +330,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let check_objects = value(Statement::CheckObjects, rule! { CHECK ~ OBJECTS });
+
     // kill query 199;
     let kill_stmt = map(
         rule! {
@@ -772,13 +775,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let truncate_table = map(
         rule! {
-            TRUNCATE ~ TABLE ~ #dot_separated_idents_1_to_3
+            TRUNCATE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ ( RESTART ~ STREAMS )?
         },
-        |(_, _, (catalog, database, table))| {
+        |(_, _, (catalog, database, table), opt_restart_streams)| {
             Statement::TruncateTable(TruncateTableStmt {
                 catalog,
                 database,
                 table,
+                restart_streams: opt_restart_streams.is_some(),
             })
         },
     );
@@ -1341,6 +1345,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             udf_name,
         },
     );
+    let describe_udf = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ FUNCTION ~ #ident
+        },
+        |(_, _, udf_name)| Statement::DescribeUDF { udf_name },
+    );
     let alter_udf = map(
         rule! {
             ALTER ~ FUNCTION
@@ -2068,10 +2078,11 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
             | #show_indexes : "`SHOW INDEXES`"
             | #show_locks : "`SHOW LOCKS [IN ACCOUNT] [WHERE ...]`"
+            | #check_objects : "`CHECK OBJECTS`"
             | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
             | #vacuum_temp_files : "VACUUM TEMPORARY FILES [RETAIN number SECONDS|DAYS] [LIMIT number]"
             | #set_priority: "`SET PRIORITY (HIGH | MEDIUM | LOW) <object_id>`"
-            | #system_action: "`SYSTEM (ENABLE | DISABLE) EXCEPTION_BACKTRACE`"
+            | #system_action: "`SYSTEM (ENABLE | DISABLE) EXCEPTION_BACKTRACE | SHOW DATA CACHE | DROP DATA CACHE [FOR TABLE <table>]`"
         ),
         // database
         rule!(
@@ -2127,7 +2138,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
             | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
-            | #truncate_table : "`TRUNCATE TABLE [<database>.]<table>`"
+            | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [RESTART STREAMS]`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
@@ -2164,9 +2175,10 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
-            | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
+            | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter> [DEFAULT <expr>], ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address> [DETERMINISTIC]} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
+            | #describe_udf : "`(DESC | DESCRIBE) FUNCTION <udf_name>`"
             | #set_role: "`SET [DEFAULT] ROLE <role>`"
             | #set_secondary_roles: "`SET SECONDARY ROLES (ALL | NONE)`"
             | #show_user_functions : "`SHOW USER FUNCTIONS [<show_limit>]`"
@@ -2306,6 +2318,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 ~ #dot_separated_idents_1_to_3
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
+                ~ #insert_on_error?
             },
             |(
                 with,
@@ -2316,6 +2329,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 (catalog, database, table),
                 opt_columns,
                 source,
+                on_error,
             )| {
                 Statement::Insert(InsertStmt {
                     hints: opt_hints,
@@ -2328,6 +2342,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                         .unwrap_or_default(),
                     source,
                     overwrite: overwrite.kind == OVERWRITE,
+                    on_error,
                 })
             },
         )(i)
@@ -2532,12 +2547,38 @@ pub fn raw_insert_source(i: Input) -> IResult<InsertSource> {
             query: Box::new(query),
         },
     );
+    // The `ON ERROR` clause is parsed by `insert_stmt` after the source.
+    let query_on_error = map(
+        rule! {
+            #query ~ &(ON ~ ERROR)
+        },
+        |(query, _)| InsertSource::Select {
+            query: Box::new(query),
+        },
+    );
 
     rule!(
         #streaming
         | #streaming_v2
         | #values
         | #query
+        | #query_on_error
+    )(i)
+}
+
+// `ON ERROR SKIP [LOG INTO [catalog.][database.]table]`
+pub fn insert_on_error(i: Input) -> IResult<InsertOnError> {
+    map(
+        rule! {
+            ON ~ ERROR ~ ^SKIP ~ ( LOG ~ ^INTO ~ ^#dot_separated_idents_1_to_3 )?
+        },
+        |(_, _, _, opt_log_into)| InsertOnError {
+            log_into: opt_log_into.map(|(_, _, (catalog, database, table))| InsertErrorLogTable {
+                catalog,
+                database,
+                table,
+            }),
+        },
     )(i)
 }
 
@@ -3286,9 +3327,12 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
 pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     let rename_table = map(
         rule! {
-           RENAME ~ TO ~ #ident
+           RENAME ~ TO ~ #dot_separated_idents_1_to_2
+        },
+        |(_, _, (new_database, new_table))| AlterTableAction::RenameTable {
+            new_database,
+            new_table,
         },
-        |(_, _, new_table)| AlterTableAction::RenameTable { new_table },
     );
     let rename_column = map(
         rule! {
@@ -3759,9 +3803,28 @@ pub fn action(i: Input) -> IResult<SystemAction> {
         },
         |(switch, _)| SystemAction::Backtrace(switch),
     );
+    let show_data_cache = value(SystemAction::ShowDataCache, rule! { SHOW ~ DATA ~ CACHE });
+    let drop_data_cache = map(
+        rule! {
+            DROP ~ DATA ~ CACHE ~ ( FOR ~ TABLE ~ #dot_separated_idents_1_to_3 )?
+        },
+        |(_, _, _, opt_table)| {
+            let (catalog, database, table) = match opt_table {
+                Some((_, _, (catalog, database, table))) => (catalog, database, Some(table)),
+                None => (None, None, None),
+            };
+            SystemAction::DropDataCache {
+                catalog,
+                database,
+                table,
+            }
+        },
+    );
     // add other system action type here
     rule!(
         #backtrace
+        | #show_data_cache
+        | #drop_data_cache
     )(i)
 }
 
@@ -4066,14 +4129,37 @@ pub fn udf_arg_type(i: Input) -> IResult<TypeName> {
 }
 
 pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
-    let lambda_udf = map(
+    let lambda_udf_parameter = map(
+        rule! {
+            #ident ~ ( DEFAULT ~ ^#expr )?
+        },
+        |(name, opt_default)| (name, opt_default.map(|(_, default)| default)),
+    );
+    let lambda_udf = map_res(
         rule! {
-            AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
+            AS ~ "(" ~ #comma_separated_list0(lambda_udf_parameter) ~ ")"
             ~ "->" ~ #expr
         },
-        |(_, _, parameters, _, _, definition)| UDFDefinition::LambdaUDF {
-            parameters,
-            definition: Box::new(definition),
+        |(_, _, parameters_with_defaults, _, _, definition)| {
+            let mut parameters = Vec::with_capacity(parameters_with_defaults.len());
+            let mut defaults = Vec::new();
+            for (parameter, default) in parameters_with_defaults {
+                match default {
+                    Some(default) => defaults.push(default),
+                    None if !defaults.is_empty() => {
+                        return Err(nom::Err::Failure(ErrorKind::Other(
+                            "parameters without default value must precede parameters with default value",
+                        )));
+                    }
+                    None => {}
+                }
+                parameters.push(parameter);
+            }
+            Ok(UDFDefinition::LambdaUDF {
+                parameters,
+                defaults,
+                definition: Box::new(definition),
+            })
         },
     );
 
@@ -4084,14 +4170,31 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
             ~ LANGUAGE ~ #ident
             ~ HANDLER ~ ^"=" ~ ^#literal_string
             ~ ADDRESS ~ ^"=" ~ ^#literal_string
+            ~ DETERMINISTIC?
         },
-        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, _, address)| {
+        |(
+            _,
+            arg_types,
+            _,
+            _,
+            return_type,
+            _,
+            language,
+            _,
+            _,
+            handler,
+            _,
+            _,
+            address,
+            opt_deterministic,
+        )| {
             UDFDefinition::UDFServer {
                 arg_types,
                 return_type,
                 address,
                 handler,
                 language: language.to_string(),
+                deterministic: opt_deterministic.is_some(),
             }
         },
     );
@@ -4119,8 +4222,8 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
     );
 
     rule!(
-        #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
-        | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
+        #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address> [DETERMINISTIC]"
+        | #lambda_udf: "AS (<parameter> [DEFAULT <expr>], ...) -> <definition expr>"
         | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> AS <language_codes>"
     )(i)
 }
@@ -157,9 +157,13 @@ pub enum TokenKind {
     #[regex(r#"\$\$([^\$]|(\$[^\$]))*\$\$"#)]
     LiteralCodeString,
 
-    #[regex(r#"@([^\s`;'"()]|\\\s|\\'|\\"|\\\\)+"#)]
+    #[regex(r#"@([^\s`;'"()@]|\\\s|\\'|\\"|\\\\)([^\s`;'"()]|\\\s|\\'|\\"|\\\\)*"#)]
     LiteralAtString,
 
+    /// MySQL-compatible system variable, e.g. `@@version_comment` or `@@session.tx_isolation`
+    #[regex(r#"@@[_a-zA-Z][_$a-zA-Z0-9]*(\.[_a-zA-Z][_$a-zA-Z0-9]*)?"#)]
+    SystemVariable,
+
     #[regex(r"[xX]'[a-fA-F0-9]*'")]
     PGLiteralHex,
     #[regex(r"0[xX][a-fA-F0-9]+")]
@@ -402,6 +406,8 @@ pub enum TokenKind {
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -436,6 +442,8 @@ pub enum TokenKind {
     CONTINUE,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
     #[token("COLUMN", ignore(ascii_case))]
     COLUMN,
     #[token("COLUMNS", ignore(ascii_case))]
@@ -458,6 +466,8 @@ pub enum TokenKind {
     CROSS,
     #[token("CSV", ignore(ascii_case))]
     CSV,
+    #[token("CTE", ignore(ascii_case))]
+    CTE,
     #[token("CURRENT", ignore(ascii_case))]
     CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
@@ -498,6 +508,8 @@ pub enum TokenKind {
     DESC,
     #[token("DETAILED_OUTPUT", ignore(ascii_case))]
     DETAILED_OUTPUT,
+    #[token("DETERMINISTIC", ignore(ascii_case))]
+    DETERMINISTIC,
     #[token("DESCRIBE", ignore(ascii_case))]
     DESCRIBE,
     #[token("DISABLE", ignore(ascii_case))]
@@ -554,6 +566,8 @@ pub enum TokenKind {
     ENGINES,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
+    #[token("ERROR", ignore(ascii_case))]
+    ERROR,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
@@ -588,6 +602,8 @@ pub enum TokenKind {
     FILE,
     #[token("FILES", ignore(ascii_case))]
     FILES,
+    #[token("FILTER", ignore(ascii_case))]
+    FILTER,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
@@ -718,6 +734,8 @@ pub enum TokenKind {
     LOCATION_PREFIX,
     #[token("LOCKS", ignore(ascii_case))]
     LOCKS,
+    #[token("LOG", ignore(ascii_case))]
+    LOG,
     #[token("LOGICAL", ignore(ascii_case))]
     LOGICAL,
     #[token("LOOP", ignore(ascii_case))]
@@ -729,6 +747,9 @@ pub enum TokenKind {
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
     #[token("<->")]
     L2DISTANCE,
+    /// L1DISTANCE op, from https://github.com/pgvector/pgvector
+    #[token("<+>")]
+    L1DISTANCE,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -801,6 +822,8 @@ pub enum TokenKind {
     NULLABLE,
     #[token("OBJECT", ignore(ascii_case))]
     OBJECT,
+    #[token("OBJECTS", ignore(ascii_case))]
+    OBJECTS,
     #[token("OF", ignore(ascii_case))]
     OF,
     #[token("OFFSET", ignore(ascii_case))]
@@ -903,6 +926,8 @@ pub enum TokenKind {
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("RESTART", ignore(ascii_case))]
+    RESTART,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
     RETURN_FAILED_ONLY,
     #[token("REVERSE", ignore(ascii_case))]
@@ -1011,6 +1036,8 @@ pub enum TokenKind {
     SIZE_LIMIT,
     #[token("MAX_FILES", ignore(ascii_case))]
     MAX_FILES,
+    #[token("SKIP", ignore(ascii_case))]
+    SKIP,
     #[token("SKIP_HEADER", ignore(ascii_case))]
     SKIP_HEADER,
     #[token("SMALLINT", ignore(ascii_case))]
@@ -1099,6 +1126,8 @@ pub enum TokenKind {
     TO,
     #[token("TOKEN", ignore(ascii_case))]
     TOKEN,
+    #[token("TOTALS", ignore(ascii_case))]
+    TOTALS,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
     #[token("TRANSIENT", ignore(ascii_case))]
@@ -1305,6 +1334,7 @@ impl TokenKind {
         !matches!(
             self,
             Ident
+                | SystemVariable
                 | LiteralString
                 | LiteralCodeString
                 | PGLiteralHex
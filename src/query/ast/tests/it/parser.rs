@@ -18,8 +18,11 @@ use std::io::Write;
 
 use databend_common_ast::ast::quote::ident_needs_quote;
 use databend_common_ast::ast::quote::QuotedIdent;
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::parser::display_parser_error;
 use databend_common_ast::parser::expr::*;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::query::*;
 use databend_common_ast::parser::script::script_block;
@@ -141,6 +144,7 @@ fn test_statement() {
                 location_prefix = 'db';
         "#,
         r#"truncate table a;"#,
+        r#"truncate table a restart streams;"#,
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
@@ -604,6 +608,8 @@ fn test_statement() {
         r#"SELECT * FROM t GROUP BY GROUPING SETS ((a, b), (), (d, e))"#,
         r#"SELECT * FROM t GROUP BY CUBE (a, b, c)"#,
         r#"SELECT * FROM t GROUP BY ROLLUP (a, b, c)"#,
+        r#"SELECT * FROM t GROUP BY a WITH TOTALS"#,
+        r#"SELECT * FROM t GROUP BY a, ROLLUP (b, c)"#,
         r#"CREATE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"CREATE OR REPLACE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"DESC MASKING POLICY email_mask"#,
@@ -736,6 +742,7 @@ fn test_statement() {
         r#"DESC CONNECTION my_conn;"#,
         r#"SHOW CONNECTIONS;"#,
         r#"SHOW LOCKS IN ACCOUNT"#,
+        r#"CHECK OBJECTS"#,
         // pipes
         r#"CREATE PIPE IF NOT EXISTS MyPipe1 AUTO_INGEST = TRUE COMMENT = 'This is test pipe 1' AS COPY INTO MyTable1 FROM '@~/MyStage1' FILE_FORMAT = (TYPE = 'CSV')"#,
         r#"CREATE PIPE pipe1 AS COPY INTO db1.MyTable1 FROM @~/mybucket/data.csv"#,
@@ -766,8 +773,10 @@ fn test_statement() {
         r#"attach table t 's3://a' connection=(access_key_id ='x' secret_access_key ='y' endpoint_url='http://127.0.0.1:9900')"#,
         r#"CREATE FUNCTION IF NOT EXISTS isnotempty AS(p) -> not(is_null(p));"#,
         r#"CREATE OR REPLACE FUNCTION isnotempty_test_replace AS(p) -> not(is_null(p))  DESC = 'This is a description';"#,
+        r#"CREATE FUNCTION add_tax AS (price, rate DEFAULT 10) -> price + rate;"#,
         r#"CREATE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815';"#,
         r#"CREATE OR REPLACE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815';"#,
+        r#"CREATE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815' DETERMINISTIC;"#,
         r#"CREATE STAGE s file_format=(record_delimiter='\n' escape='\\');"#,
         r#"
             create or replace function addone(int)
@@ -811,6 +820,7 @@ fn test_statement() {
             )
             select * from abc;
         "#,
+        r#"SELECT @@a,@@b"#,
     ];
 
     for case in cases {
@@ -937,6 +947,7 @@ fn test_raw_insert_stmt() {
         r#"insert into t (c1, c2) values (1, 2);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert into table t select * from t2 on error skip log into db.err;"#,
     ];
 
     for case in cases {
@@ -1079,15 +1090,18 @@ fn test_expr() {
         r#"CAST(col1 AS BIGINT UNSIGNED)"#,
         r#"TRY_CAST(col1 AS BIGINT UNSIGNED)"#,
         r#"TRY_CAST(col1 AS TUPLE(BIGINT UNSIGNED NULL, BOOLEAN))"#,
+        r#"CAST(col1 AS DATE FORMAT 'DD/MM/YYYY')"#,
         r#"trim(leading 'abc' from 'def')"#,
         r#"extract(year from d)"#,
         r#"date_part(year, d)"#,
         r#"position('a' in str)"#,
+        r#"position('a' in str from 2)"#,
         r#"substring(a from b for c)"#,
         r#"substring(a, b, c)"#,
         r#"col1::UInt8"#,
         r#"(arr[0]:a).b"#,
         r#"arr[4]["k"]"#,
+        r#"obj:items[*].price"#,
         r#"a rlike '^11'"#,
         r#"'中文'::text not in ('a', 'b')"#,
         r#"G.E.B IS NOT NULL AND col1 not between col2 and (1 + col3) DIV sum(col4)"#,
@@ -1123,6 +1137,14 @@ fn test_expr() {
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
         r#"(current_timestamp, current_timestamp(), now())"#,
         r#"ARRAY_REDUCE([1,2,3], (acc,t) -> acc + t)"#,
+        r#"STRING_AGG(a, ',' ORDER BY b DESC)"#,
+        r#"SUM(a) FILTER (WHERE a > 1)"#,
+        r#"ARRAY_MAP(a, b, (x, y) -> x + y)"#,
+        r#"a <+> b"#,
+        r#"a <=> b"#,
+        r#"@@session.tx_isolation"#,
+        r#"a IS NOT TRUE"#,
+        r#"b is json"#,
     ];
 
     for case in cases {
@@ -1356,3 +1378,39 @@ fn test_quote() {
         };
     }
 }
+
+#[test]
+fn test_not_eq_func_name() {
+    let func_names = ["a != b", "a <> b"]
+        .iter()
+        .map(|sql| {
+            let tokens = tokenize_sql(sql).unwrap();
+            match parse_expr(&tokens, Dialect::PostgreSQL).unwrap() {
+                Expr::BinaryOp { op, .. } => {
+                    assert_eq!(op, BinaryOperator::NotEq);
+                    op.to_func_name()
+                }
+                expr => panic!("unexpected expr: {expr:?}"),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(func_names, vec!["noteq", "noteq"]);
+}
+
+#[test]
+fn test_nesting_depth() {
+    // Rejected before parsing instead of overflowing the stack.
+    let sql = format!("SELECT {}1{}", "(".repeat(20000), ")".repeat(20000));
+    let tokens = tokenize_sql(&sql).unwrap();
+    let err = parse_sql(&tokens, Dialect::PostgreSQL).unwrap_err();
+    assert!(
+        err.1.contains("exceeds the maximum depth 1000"),
+        "{}",
+        err.1
+    );
+
+    let sql = format!("SELECT {}1{}", "(".repeat(10), ")".repeat(10));
+    let tokens = tokenize_sql(&sql).unwrap();
+    parse_sql(&tokens, Dialect::PostgreSQL).unwrap();
+}
@@ -19,6 +19,7 @@ pub mod catalog_kind;
 pub mod cluster_info;
 pub mod database;
 pub mod lock;
+pub mod meta_call_policy;
 pub mod plan;
 pub mod query_kind;
 pub mod statistics;
@@ -0,0 +1,283 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::tokio::time::timeout_at;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CatalogType;
+use log::warn;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::catalog::Catalog;
+
+/// How many times a failed idempotent read is retried before giving up.
+const META_READ_MAX_RETRIES: u64 = 2;
+/// The backoff before the n-th retry is `n * META_READ_RETRY_BACKOFF`.
+const META_READ_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+/// Consecutive failed meta requests that open the circuit breaker.
+const META_BREAKER_FAILURE_THRESHOLD: u64 = 3;
+/// How long the circuit breaker stays open before a request is let through again.
+const META_BREAKER_COOL_DOWN: Duration = Duration::from_secs(10);
+
+static META_CIRCUIT_BREAKER: LazyLock<Arc<MetaCircuitBreaker>> = LazyLock::new(|| {
+    Arc::new(MetaCircuitBreaker::create(
+        META_BREAKER_FAILURE_THRESHOLD,
+        META_BREAKER_COOL_DOWN,
+    ))
+});
+
+/// Per query statistics of the meta service requests made while planning.
+#[derive(Default)]
+pub struct MetaCallMetrics {
+    calls: AtomicU64,
+    retries: AtomicU64,
+    duration_us: AtomicU64,
+}
+
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct MetaCallMetricValues {
+    pub calls: u64,
+    pub retries: u64,
+    pub duration_ms: u64,
+}
+
+impl MetaCallMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn as_values(&self) -> MetaCallMetricValues {
+        MetaCallMetricValues {
+            calls: self.calls.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            duration_ms: self.duration_us.load(Ordering::Relaxed) / 1000,
+        }
+    }
+
+    pub fn add_call(&self, duration: Duration, retries: u64) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.retries.fetch_add(retries, Ordering::Relaxed);
+        self.duration_us
+            .fetch_add(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+}
+
+struct BreakerState {
+    consecutive_failures: u64,
+    open_until: Option<Instant>,
+    /// Set while the probe of the half open breaker is in flight. A probe which never
+    /// reports back, e.g. its query is killed, is given up after the cool down.
+    probe_until: Option<Instant>,
+}
+
+/// Fails meta requests fast once the meta service looks unhealthy.
+///
+/// The breaker opens after `failure_threshold` consecutive meta service errors and rejects
+/// every request with `MetaServiceUnavailable` until `cool_down` has passed. Then it is half
+/// open: a single probe request is let through while the others are still rejected. A
+/// successful probe closes the breaker and a failed one opens it again.
+pub struct MetaCircuitBreaker {
+    failure_threshold: u64,
+    cool_down: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl MetaCircuitBreaker {
+    pub fn create(failure_threshold: u64, cool_down: Duration) -> Self {
+        MetaCircuitBreaker {
+            failure_threshold,
+            cool_down,
+            state: Mutex::new(BreakerState {
+                consecutive_failures: 0,
+                open_until: None,
+                probe_until: None,
+            }),
+        }
+    }
+
+    /// The breaker shared by all queries of this node.
+    pub fn instance() -> Arc<MetaCircuitBreaker> {
+        META_CIRCUIT_BREAKER.clone()
+    }
+
+    pub fn check(&self, name: &str) -> Result<()> {
+        let mut state = self.state.lock();
+        let now = Instant::now();
+        match state.open_until {
+            Some(open_until) if now < open_until => {
+                Err(ErrorCode::MetaServiceUnavailable(format!(
+                    "Meta service is unavailable, `{}` is rejected after {} consecutive failed meta requests, retry in {}s",
+                    name,
+                    state.consecutive_failures,
+                    (open_until - now).as_secs() + 1,
+                )))
+            }
+            Some(_)
+                if state
+                    .probe_until
+                    .is_some_and(|probe_until| now < probe_until) =>
+            {
+                Err(ErrorCode::MetaServiceUnavailable(format!(
+                    "Meta service is unavailable, `{}` is rejected while probing the meta service after {} consecutive failed meta requests",
+                    name, state.consecutive_failures,
+                )))
+            }
+            Some(_) => {
+                // Half open: let this request through as the probe, its result decides the next state.
+                state.probe_until = Some(now + self.cool_down);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    pub fn record_success(&self) {
+        let mut state = self.state.lock();
+        state.consecutive_failures = 0;
+        state.open_until = None;
+        state.probe_until = None;
+    }
+
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock();
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.failure_threshold {
+            state.open_until = Some(Instant::now() + self.cool_down);
+            state.probe_until = None;
+        }
+    }
+}
+
+/// Deadline, retry and circuit breaker policy of the meta service requests made by a query.
+pub struct MetaCallPolicy {
+    timeout: Duration,
+    max_retries: u64,
+    retry_backoff: Duration,
+    breaker: Arc<MetaCircuitBreaker>,
+    metrics: Arc<MetaCallMetrics>,
+}
+
+impl MetaCallPolicy {
+    /// A zero `timeout` means the requests have no deadline.
+    pub fn create(
+        timeout: Duration,
+        breaker: Arc<MetaCircuitBreaker>,
+        metrics: Arc<MetaCallMetrics>,
+    ) -> Self {
+        MetaCallPolicy {
+            timeout,
+            max_retries: META_READ_MAX_RETRIES,
+            retry_backoff: META_READ_RETRY_BACKOFF,
+            breaker,
+            metrics,
+        }
+    }
+
+    pub fn with_retries(mut self, max_retries: u64, retry_backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Runs an idempotent meta read.
+    ///
+    /// The whole call, retries included, must finish before the deadline. Only meta service
+    /// failures are retried, errors such as an unknown table are returned as is.
+    pub async fn read<T, F, Fut>(&self, name: &str, mut f: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.breaker.check(name)?;
+
+        let start = Instant::now();
+        let deadline = (!self.timeout.is_zero()).then(|| start + self.timeout);
+        let mut retries = 0;
+        let res = loop {
+            let res = match deadline {
+                Some(deadline) => match timeout_at(deadline.into(), f()).await {
+                    Ok(res) => res,
+                    Err(_) => Err(ErrorCode::Timeout(format!(
+                        "Meta request `{}` did not finish within {}s, see setting meta_request_timeout_secs",
+                        name,
+                        self.timeout.as_secs_f64(),
+                    ))),
+                },
+                None => f().await,
+            };
+
+            match res {
+                Err(cause) if Self::is_retryable(&cause) && retries < self.max_retries => {
+                    retries += 1;
+                    let backoff = self.retry_backoff * retries as u32;
+                    if deadline.is_some_and(|deadline| Instant::now() + backoff >= deadline) {
+                        break Err(cause);
+                    }
+                    warn!(
+                        "Meta request `{}` failed, retry {}/{} after {:?}: {}",
+                        name, retries, self.max_retries, backoff, cause
+                    );
+                    sleep(backoff).await;
+                }
+                res => break res,
+            }
+        };
+
+        self.metrics.add_call(start.elapsed(), retries);
+        match &res {
+            Err(cause) if Self::is_retryable(cause) => self.breaker.record_failure(),
+            // The deadline comes from the session setting `meta_request_timeout_secs`, a query
+            // with a short deadline must not open the breaker shared by all queries of this
+            // node, nor close it.
+            Err(cause) if cause.code() == ErrorCode::TIMEOUT => {}
+            _ => self.breaker.record_success(),
+        }
+        res
+    }
+
+    /// Runs an idempotent read of `catalog`.
+    ///
+    /// Only the catalogs stored in the meta service are read under the policy. The other
+    /// catalogs, such as Hive, do not reach the meta service and must not trip its breaker.
+    pub async fn read_catalog<T, F, Fut>(
+        &self,
+        catalog: &dyn Catalog,
+        name: &str,
+        mut f: F,
+    ) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        match catalog.info().catalog_type() {
+            CatalogType::Default => self.read(name, f).await,
+            _ => f().await,
+        }
+    }
+
+    fn is_retryable(cause: &ErrorCode) -> bool {
+        cause.code() == ErrorCode::META_SERVICE_ERROR
+    }
+}
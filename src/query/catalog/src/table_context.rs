@@ -27,6 +27,7 @@ use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::AbortChecker;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::CheckAbort;
@@ -62,6 +63,7 @@ use xorf::BinaryFuse16;
 use crate::catalog::Catalog;
 use crate::cluster_info::Cluster;
 use crate::merge_into_join::MergeIntoJoin;
+use crate::meta_call_policy::MetaCallPolicy;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
@@ -72,6 +74,9 @@ use crate::table::Table;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
+/// The argument types and the return type of a function exposed by a UDF server.
+pub type UDFServerSchema = (Vec<DataType>, DataType);
+
 #[derive(Debug)]
 pub struct ProcessInfo {
     pub id: String,
@@ -90,6 +95,8 @@ pub struct ProcessInfo {
     pub created_time: SystemTime,
     pub status_info: Option<String>,
     pub current_query_id: Option<String>,
+    /// bytes of the query results buffered and not fetched by the client yet.
+    pub result_buffered_bytes: u64,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -149,6 +156,9 @@ pub trait TableContext: Send + Sync {
     fn get_status_info(&self) -> String;
     fn set_status_info(&self, info: &str);
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics;
+    /// The deadline, retry and circuit breaker policy of the idempotent meta service reads
+    /// made while planning the query.
+    fn get_meta_call_policy(&self) -> Result<MetaCallPolicy>;
     fn get_partition(&self) -> Option<PartInfoPtr>;
     fn get_partitions(&self, num: usize) -> Vec<PartInfoPtr>;
     fn partition_num(&self) -> usize {
@@ -231,6 +241,8 @@ pub trait TableContext: Send + Sync {
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
     fn set_query_id_result_cache(&self, query_id: String, result_cache_key: String);
+    fn get_udf_server_schema(&self, address: &str, handler: &str) -> Option<UDFServerSchema>;
+    fn set_udf_server_schema(&self, address: String, handler: String, schema: UDFServerSchema);
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>>;
     fn set_on_error_map(&self, map: Arc<DashMap<String, HashMap<u16, InputError>>>);
     fn get_on_error_mode(&self) -> Option<OnErrorMode>;
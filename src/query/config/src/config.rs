@@ -782,6 +782,8 @@ impl TryInto<InnerStorageS3Config> for S3StorageConfig {
             enable_virtual_host_style: self.enable_virtual_host_style,
             role_arn: self.s3_role_arn,
             external_id: self.s3_external_id,
+            server_side_encryption: "".to_string(),
+            server_side_encryption_aws_kms_key_id: "".to_string(),
         })
     }
 }
@@ -1433,6 +1435,11 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "60")]
     pub http_handler_result_timeout_secs: u64,
 
+    /// The maximum bytes of result blocks buffered by all the http queries of this node,
+    /// a http query with buffered results waits for its client once the limit is reached.
+    #[clap(long, value_name = "VALUE", default_value = "2147483648")]
+    pub http_handler_result_buffer_global_max_bytes: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
     pub flight_sql_handler_host: String,
 
@@ -1520,6 +1527,12 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "auto")]
     pub default_compression: String,
 
+    /// Keys to encrypt the data blocks of fuse tables with, as `<key_id>:<hex encoded 32 bytes key>`.
+    ///
+    /// A table picks its key by the `encryption_key_id` table option.
+    #[clap(long, value_name = "VALUE")]
+    pub data_encryption_keys: Vec<String>,
+
     #[clap(skip)]
     users: Vec<UserConfig>,
 
@@ -1691,6 +1704,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             http_handler_host: self.http_handler_host,
             http_handler_port: self.http_handler_port,
             http_handler_result_timeout_secs: self.http_handler_result_timeout_secs,
+            http_handler_result_buffer_global_max_bytes: self
+                .http_handler_result_buffer_global_max_bytes,
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
@@ -1720,6 +1735,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             jwt_key_files: self.jwt_key_files,
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
+            data_encryption_keys: self.data_encryption_keys,
             idm: InnerIDMConfig {
                 users: users_to_inner(self.users)?,
             },
@@ -1776,6 +1792,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             http_handler_host: inner.http_handler_host,
             http_handler_port: inner.http_handler_port,
             http_handler_result_timeout_secs: inner.http_handler_result_timeout_secs,
+            http_handler_result_buffer_global_max_bytes: inner
+                .http_handler_result_buffer_global_max_bytes,
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
@@ -1805,6 +1823,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             jwt_key_files: inner.jwt_key_files,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
+            data_encryption_keys: inner.data_encryption_keys,
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
             share_endpoint_auth_token_file: inner.share_endpoint_auth_token_file,
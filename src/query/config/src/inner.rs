@@ -169,6 +169,7 @@ pub struct QueryConfig {
     pub http_handler_host: String,
     pub http_handler_port: u16,
     pub http_handler_result_timeout_secs: u64,
+    pub http_handler_result_buffer_global_max_bytes: u64,
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
@@ -206,6 +207,7 @@ pub struct QueryConfig {
     pub jwt_key_files: Vec<String>,
     pub default_storage_format: String,
     pub default_compression: String,
+    pub data_encryption_keys: Vec<String>,
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
@@ -255,6 +257,7 @@ impl Default for QueryConfig {
             http_handler_host: "127.0.0.1".to_string(),
             http_handler_port: 8000,
             http_handler_result_timeout_secs: 60,
+            http_handler_result_buffer_global_max_bytes: 2 * 1024 * 1024 * 1024,
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
@@ -283,6 +286,7 @@ impl Default for QueryConfig {
             jwt_key_files: Vec::new(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
+            data_encryption_keys: Vec::new(),
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
@@ -322,6 +326,11 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.data_encryption_keys = self
+            .data_encryption_keys
+            .iter()
+            .map(|key| mask_string(key, 3))
+            .collect();
         sanitized
     }
 }
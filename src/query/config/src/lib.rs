@@ -50,6 +50,9 @@ pub use inner::DiskCacheKeyReloadPolicy;
 pub use inner::InnerConfig;
 pub use inner::ThriftProtocol;
 pub use version::DATABEND_COMMIT_VERSION;
+pub use version::QUERY_BUILD_TIMESTAMP;
 pub use version::QUERY_GIT_SEMVER;
 pub use version::QUERY_GIT_SHA;
+pub use version::QUERY_RUSTC_SEMVER;
 pub use version::QUERY_SEMVER;
+pub use version::QUERY_TARGET_TRIPLE;
@@ -70,6 +70,13 @@ impl QueryConfig {
         // Mask OpenAI API key
         masked_config.openai_api_key = mask_sensitive_field(&self.openai_api_key);
 
+        // Mask data encryption keys
+        masked_config.data_encryption_keys = self
+            .data_encryption_keys
+            .iter()
+            .map(|key| mask_sensitive_field(key))
+            .collect();
+
         masked_config
     }
 }
@@ -47,6 +47,24 @@ pub static QUERY_GIT_SHA: LazyLock<String> =
         None => "unknown".to_string(),
     });
 
+pub static QUERY_BUILD_TIMESTAMP: LazyLock<String> =
+    LazyLock::new(|| match option_env!("VERGEN_BUILD_TIMESTAMP") {
+        Some(timestamp) => timestamp.to_string(),
+        None => "unknown".to_string(),
+    });
+
+pub static QUERY_RUSTC_SEMVER: LazyLock<String> =
+    LazyLock::new(|| match option_env!("VERGEN_RUSTC_SEMVER") {
+        Some(semver) => semver.to_string(),
+        None => "unknown".to_string(),
+    });
+
+pub static QUERY_TARGET_TRIPLE: LazyLock<String> =
+    LazyLock::new(|| match option_env!("VERGEN_CARGO_TARGET_TRIPLE") {
+        Some(triple) => triple.to_string(),
+        None => "unknown".to_string(),
+    });
+
 pub static QUERY_SEMVER: LazyLock<Version> = LazyLock::new(|| {
     //
     let build_semver = option_env!("DATABEND_GIT_SEMVER");
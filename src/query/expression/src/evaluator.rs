@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Not;
 
 use databend_common_arrow::arrow::bitmap;
@@ -42,6 +43,7 @@ use crate::types::NumberScalar;
 use crate::values::Column;
 use crate::values::ColumnBuilder;
 use crate::values::Scalar;
+use crate::values::ScalarRef;
 use crate::values::Value;
 use crate::BlockEntry;
 use crate::ColumnIndex;
@@ -50,6 +52,7 @@ use crate::FunctionDomain;
 use crate::FunctionEval;
 use crate::FunctionRegistry;
 use crate::RemoteExpr;
+use crate::SortColumnDescription;
 
 #[derive(Default)]
 pub struct EvaluateOptions<'a> {
@@ -1097,7 +1100,24 @@ impl<'a> Evaluator<'a> {
                 }
             }
         }
-        // TODO: Support multi args
+        if MAP_LAMBDA_FUNCTIONS.contains(&func_name) {
+            return self.run_map_lambda(func_name, &args[0], &expr);
+        }
+        if let Some((_, (asc, nulls_first))) = ARRAY_SORT_BY_FUNCTIONS
+            .iter()
+            .find(|(name, _)| *name == func_name)
+        {
+            return self.run_array_sort_by(&args[0], &expr, *asc, *nulls_first);
+        }
+        if func_name == ARRAY_SORT_BY_COMPARATOR {
+            return self.run_array_sort_by_comparator(&args[0], &expr);
+        }
+        if func_name == "array_distinct_by" {
+            return self.run_array_distinct_by(&args[0], &expr);
+        }
+        if args.len() > 1 {
+            return self.run_zipped_arrays_lambda(func_name, &args, &expr, return_type);
+        }
         match &args[0] {
             Value::Scalar(s) => match s {
                 Scalar::Array(c) => {
@@ -1150,21 +1170,9 @@ impl<'a> Evaluator<'a> {
                     let result_col = result_col.remove_nullable();
                     let bitmap = result_col.as_boolean().unwrap();
                     let filtered_inner_col = inner_col.filter(bitmap);
-                    // generate new offsets after filter.
-                    let mut new_offset = 0;
-                    let mut filtered_offsets = Vec::with_capacity(offsets.len());
-                    filtered_offsets.push(0);
-                    for offset in offsets.windows(2) {
-                        let off = offset[0] as usize;
-                        let len = (offset[1] - offset[0]) as usize;
-                        let unset_count = bitmap.null_count_range(off, len);
-                        new_offset += (len - unset_count) as u64;
-                        filtered_offsets.push(new_offset);
-                    }
-
                     Column::Array(Box::new(ArrayColumn {
                         values: filtered_inner_col,
-                        offsets: filtered_offsets.into(),
+                        offsets: filter_offsets(&offsets, bitmap).into(),
                     }))
                 } else {
                     Column::Array(Box::new(ArrayColumn {
@@ -1184,6 +1192,414 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Sort the elements of each array by the keys computed by the lambda function.
+    fn run_array_sort_by(
+        &self,
+        arg: &Value<AnyType>,
+        expr: &Expr,
+        asc: bool,
+        nulls_first: bool,
+    ) -> Result<Value<AnyType>> {
+        match arg {
+            Value::Scalar(Scalar::Array(c)) => {
+                let keys = self.run_array_keys(c, expr)?;
+                let sorted = sort_by_keys(c.clone(), keys, asc, nulls_first)?;
+                Ok(Value::Scalar(Scalar::Array(sorted)))
+            }
+            Value::Column(c) => {
+                let (array_col, validity) = match c {
+                    Column::Array(box array_col) => (array_col, None),
+                    Column::Nullable(box nullable_col) => match &nullable_col.column {
+                        Column::Array(box array_col) => {
+                            (array_col, Some(nullable_col.validity.clone()))
+                        }
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                };
+                let keys = self.run_array_keys(&array_col.values, expr)?;
+                let mut sorted_values = Vec::with_capacity(array_col.len());
+                for window in array_col.offsets.windows(2) {
+                    let (start, end) = (window[0] as usize, window[1] as usize);
+                    if start == end {
+                        continue;
+                    }
+                    sorted_values.push(sort_by_keys(
+                        array_col.values.slice(start..end),
+                        keys.slice(start..end),
+                        asc,
+                        nulls_first,
+                    )?);
+                }
+                let values = if sorted_values.is_empty() {
+                    array_col.values.clone()
+                } else {
+                    Column::concat_columns(sorted_values.into_iter())?
+                };
+                let array_col = Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: array_col.offsets.clone(),
+                }));
+                let col = match validity {
+                    Some(validity) => Column::Nullable(Box::new(NullableColumn {
+                        column: array_col,
+                        validity,
+                    })),
+                    None => array_col,
+                };
+                Ok(Value::Column(col))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Sort the elements of each array by the comparator lambda function.
+    fn run_array_sort_by_comparator(
+        &self,
+        arg: &Value<AnyType>,
+        expr: &Expr,
+    ) -> Result<Value<AnyType>> {
+        match arg {
+            Value::Scalar(Scalar::Array(c)) => {
+                let sorted = self.sort_by_comparator(c, &[0, c.len() as u64], expr)?;
+                Ok(Value::Scalar(Scalar::Array(sorted)))
+            }
+            Value::Column(c) => {
+                let (array_col, validity) = match c {
+                    Column::Array(box array_col) => (array_col, None),
+                    Column::Nullable(box nullable_col) => match &nullable_col.column {
+                        Column::Array(box array_col) => {
+                            (array_col, Some(nullable_col.validity.clone()))
+                        }
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                };
+                let values =
+                    self.sort_by_comparator(&array_col.values, &array_col.offsets, expr)?;
+                let array_col = Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: array_col.offsets.clone(),
+                }));
+                let col = match validity {
+                    Some(validity) => Column::Nullable(Box::new(NullableColumn {
+                        column: array_col,
+                        validity,
+                    })),
+                    None => array_col,
+                };
+                Ok(Value::Column(col))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Sort the values of each array delimited by `offsets` with the comparator.
+    ///
+    /// This is a bottom-up merge sort, the merges of all the arrays run in lockstep so that
+    /// each round evaluates the comparator once on the pending pair of every merge. Taking
+    /// the left element of equal elements keeps the sort stable. A `NULL` result of the
+    /// comparator means the elements are equal.
+    fn sort_by_comparator(&self, values: &Column, offsets: &[u64], expr: &Expr) -> Result<Column> {
+        let base = offsets[0] as usize;
+        let ranges = offsets
+            .windows(2)
+            .map(|window| (window[0] as usize - base, window[1] as usize - base))
+            .collect::<Vec<_>>();
+        let max_len = ranges
+            .iter()
+            .map(|(start, end)| end - start)
+            .max()
+            .unwrap_or(0);
+
+        let mut src = (offsets[0] as u32..offsets[offsets.len() - 1] as u32).collect::<Vec<_>>();
+        let mut dst = src.clone();
+        let mut width = 1;
+        while width < max_len {
+            // Runs without a partner to merge with are already in place.
+            dst.copy_from_slice(&src);
+            let mut merges = Vec::new();
+            for (start, end) in ranges.iter() {
+                for left in (*start..*end).step_by(width * 2) {
+                    let right = (left + width).min(*end);
+                    let right_end = (left + width * 2).min(*end);
+                    if right < right_end {
+                        merges.push(MergeRuns {
+                            left,
+                            left_end: right,
+                            right,
+                            right_end,
+                            out: left,
+                        });
+                    }
+                }
+            }
+
+            while !merges.is_empty() {
+                let lhs = merges.iter().map(|m| src[m.left]).collect::<Vec<_>>();
+                let rhs = merges.iter().map(|m| src[m.right]).collect::<Vec<_>>();
+                let orderings = self.run_comparator(values, &lhs, &rhs, expr)?;
+                for (m, ordering) in merges.iter_mut().zip(orderings) {
+                    if ordering == std::cmp::Ordering::Greater {
+                        dst[m.out] = src[m.right];
+                        m.right += 1;
+                    } else {
+                        dst[m.out] = src[m.left];
+                        m.left += 1;
+                    }
+                    m.out += 1;
+                }
+                merges.retain(|m| {
+                    if m.left < m.left_end && m.right < m.right_end {
+                        return true;
+                    }
+                    // One run is exhausted, the rest of the other run is already in order.
+                    let (from, to) = if m.left < m.left_end {
+                        (m.left, m.left_end)
+                    } else {
+                        (m.right, m.right_end)
+                    };
+                    dst[m.out..m.out + to - from].copy_from_slice(&src[from..to]);
+                    false
+                });
+            }
+
+            std::mem::swap(&mut src, &mut dst);
+            width *= 2;
+        }
+        Ok(values.take(&src, &mut None))
+    }
+
+    /// Evaluate the comparator on the pairs of values, a `NULL` result is treated as equal.
+    fn run_comparator(
+        &self,
+        values: &Column,
+        lhs: &[u32],
+        rhs: &[u32],
+        expr: &Expr,
+    ) -> Result<Vec<std::cmp::Ordering>> {
+        let num_pairs = lhs.len();
+        let data_type = values.data_type();
+        let block = DataBlock::new(
+            vec![
+                BlockEntry::new(
+                    data_type.clone(),
+                    Value::Column(values.take(lhs, &mut None)),
+                ),
+                BlockEntry::new(data_type, Value::Column(values.take(rhs, &mut None))),
+            ],
+            num_pairs,
+        );
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(expr)?;
+        let (result, validity) = match result.convert_to_full_column(expr.data_type(), num_pairs) {
+            Column::Nullable(box nullable_col) => {
+                (nullable_col.column, Some(nullable_col.validity))
+            }
+            col => (col, None),
+        };
+        let orderings = result.as_number().unwrap().as_int64().unwrap();
+        Ok((0..num_pairs)
+            .map(|index| match &validity {
+                Some(validity) if !validity.get_bit(index) => std::cmp::Ordering::Equal,
+                _ => orderings[index].cmp(&0),
+            })
+            .collect())
+    }
+
+    /// Keep the first element of each group of the elements with the same key
+    /// computed by the lambda function.
+    fn run_array_distinct_by(&self, arg: &Value<AnyType>, expr: &Expr) -> Result<Value<AnyType>> {
+        match arg {
+            Value::Scalar(Scalar::Array(c)) => {
+                let keys = self.run_array_keys(c, expr)?;
+                let bitmap = distinct_keys_bitmap(&keys, &[0, c.len() as u64]);
+                Ok(Value::Scalar(Scalar::Array(c.filter(&bitmap))))
+            }
+            Value::Column(c) => {
+                let (array_col, validity) = match c {
+                    Column::Array(box array_col) => (array_col, None),
+                    Column::Nullable(box nullable_col) => match &nullable_col.column {
+                        Column::Array(box array_col) => {
+                            (array_col, Some(nullable_col.validity.clone()))
+                        }
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                };
+                let keys = self.run_array_keys(&array_col.values, expr)?;
+                let bitmap = distinct_keys_bitmap(&keys, &array_col.offsets);
+                let array_col = Column::Array(Box::new(ArrayColumn {
+                    values: array_col.values.filter(&bitmap),
+                    offsets: filter_offsets(&array_col.offsets, &bitmap).into(),
+                }));
+                let col = match validity {
+                    Some(validity) => Column::Nullable(Box::new(NullableColumn {
+                        column: array_col,
+                        validity,
+                    })),
+                    None => array_col,
+                };
+                Ok(Value::Column(col))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn run_array_keys(&self, values: &Column, expr: &Expr) -> Result<Column> {
+        let entry = BlockEntry::new(values.data_type(), Value::Column(values.clone()));
+        let block = DataBlock::new(vec![entry], values.len());
+
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(expr)?;
+        Ok(result.convert_to_full_column(expr.data_type(), values.len()))
+    }
+
+    /// Run a lambda function over the entries of a map, the lambda parameters bind
+    /// the key and the value of each entry.
+    fn run_map_lambda(
+        &self,
+        func_name: &str,
+        arg: &Value<AnyType>,
+        expr: &Expr,
+    ) -> Result<Value<AnyType>> {
+        match arg {
+            Value::Scalar(Scalar::Map(entries)) => {
+                let result = self.run_map_entries(entries, expr)?;
+                let entries = if func_name == "map_filter" {
+                    let result = result.remove_nullable();
+                    entries.filter(result.as_boolean().unwrap())
+                } else {
+                    transform_map_values(entries, result)
+                };
+                Ok(Value::Scalar(Scalar::Map(entries)))
+            }
+            // A NULL map of a nullable map type.
+            Value::Scalar(Scalar::Null) => Ok(Value::Scalar(Scalar::Null)),
+            Value::Column(c) => {
+                let (map_col, validity) = match c {
+                    Column::Map(box map_col) => (map_col, None),
+                    Column::Nullable(box nullable_col) => match &nullable_col.column {
+                        Column::Map(box map_col) => (map_col, Some(nullable_col.validity.clone())),
+                        _ => unreachable!(),
+                    },
+                    _ => unreachable!(),
+                };
+                let result = self.run_map_entries(&map_col.values, expr)?;
+                let map_col = if func_name == "map_filter" {
+                    let result = result.remove_nullable();
+                    let bitmap = result.as_boolean().unwrap();
+                    ArrayColumn {
+                        values: map_col.values.filter(bitmap),
+                        offsets: filter_offsets(&map_col.offsets, bitmap).into(),
+                    }
+                } else {
+                    ArrayColumn {
+                        values: transform_map_values(&map_col.values, result),
+                        offsets: map_col.offsets.clone(),
+                    }
+                };
+                let col = match validity {
+                    Some(validity) => Column::Nullable(Box::new(NullableColumn {
+                        column: Column::Map(Box::new(map_col)),
+                        validity,
+                    })),
+                    None => Column::Map(Box::new(map_col)),
+                };
+                Ok(Value::Column(col))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn run_map_entries(&self, entries: &Column, expr: &Expr) -> Result<Column> {
+        let Column::Tuple(fields) = entries else {
+            unreachable!()
+        };
+        let block_entries = fields
+            .iter()
+            .map(|field| BlockEntry::new(field.data_type(), Value::Column(field.clone())))
+            .collect();
+        let block = DataBlock::new(block_entries, entries.len());
+
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(expr)?;
+        Ok(result.convert_to_full_column(expr.data_type(), entries.len()))
+    }
+
+    /// Run a lambda function over several arrays, the i-th lambda parameter binds
+    /// the elements of the i-th array. Arrays of the same row must have the same length.
+    fn run_zipped_arrays_lambda(
+        &self,
+        func_name: &str,
+        args: &[Value<AnyType>],
+        expr: &Expr,
+        return_type: &DataType,
+    ) -> Result<Value<AnyType>> {
+        let num_rows = args.iter().find_map(|arg| match arg {
+            Value::Column(col) => Some(col.len()),
+            Value::Scalar(_) => None,
+        });
+        let Some(num_rows) = num_rows else {
+            let mut arrays = Vec::with_capacity(args.len());
+            for arg in args {
+                match arg {
+                    Value::Scalar(Scalar::Array(c)) => arrays.push(c.clone()),
+                    Value::Scalar(Scalar::Null) => return Ok(Value::Scalar(Scalar::Null)),
+                    _ => unreachable!(),
+                }
+            }
+            let result = self.run_zipped_arrays(func_name, &arrays, expr)?;
+            return Ok(Value::Scalar(Scalar::Array(result)));
+        };
+
+        let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+        for row in 0..num_rows {
+            let mut arrays = Vec::with_capacity(args.len());
+            for arg in args {
+                match arg.index(row).unwrap() {
+                    ScalarRef::Array(c) => arrays.push(c),
+                    ScalarRef::Null => break,
+                    _ => unreachable!(),
+                }
+            }
+            if arrays.len() < args.len() {
+                builder.push_default();
+                continue;
+            }
+            let result = self.run_zipped_arrays(func_name, &arrays, expr)?;
+            builder.push(ScalarRef::Array(result));
+        }
+        Ok(Value::Column(builder.build()))
+    }
+
+    fn run_zipped_arrays(&self, func_name: &str, arrays: &[Column], expr: &Expr) -> Result<Column> {
+        let len = arrays[0].len();
+        if let Some(array) = arrays.iter().find(|array| array.len() != len) {
+            return Err(ErrorCode::BadArguments(format!(
+                "arrays passed to lambda function `{func_name}` must have the same length, but got {len} and {}",
+                array.len()
+            )));
+        }
+        let entries = arrays
+            .iter()
+            .map(|array| BlockEntry::new(array.data_type(), Value::Column(array.clone())))
+            .collect();
+        let block = DataBlock::new(entries, len);
+
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let result = evaluator.run(expr)?;
+        let result_col = result.convert_to_full_column(expr.data_type(), len);
+        if func_name == "array_filter" {
+            // keep the elements of the first array
+            let result_col = result_col.remove_nullable();
+            let bitmap = result_col.as_boolean().unwrap();
+            Ok(arrays[0].filter(bitmap))
+        } else {
+            Ok(result_col)
+        }
+    }
+
     pub fn get_children(
         &self,
         args: &[Expr],
@@ -1397,6 +1813,51 @@ impl<'a> Evaluator<'a> {
 
 const MAX_FUNCTION_ARGS_TO_FOLD: usize = 4096;
 
+/// The lambda functions which sort an array by the keys computed by the lambda,
+/// with the sort order `(asc, nulls_first)`.
+pub const ARRAY_SORT_BY_FUNCTIONS: &[(&str, (bool, bool)); 4] = &[
+    ("array_sort_by_asc_null_first", (true, true)),
+    ("array_sort_by_desc_null_first", (false, true)),
+    ("array_sort_by_asc_null_last", (true, false)),
+    ("array_sort_by_desc_null_last", (false, false)),
+];
+
+/// The lambda functions which take a map and a lambda of the key and the value.
+pub const MAP_LAMBDA_FUNCTIONS: &[&str; 2] = &["map_filter", "map_transform_values"];
+
+/// The lambda function which sorts an array by a comparator lambda of two elements,
+/// the comparator returns a negative, zero or positive integer like `a - b`.
+pub const ARRAY_SORT_BY_COMPARATOR: &str = "array_sort_by_comparator";
+
+/// Sort the values by the keys, both columns have the same length.
+/// A pending merge of two adjacent sorted runs of `sort_by_comparator`.
+struct MergeRuns {
+    left: usize,
+    left_end: usize,
+    right: usize,
+    right_end: usize,
+    out: usize,
+}
+
+fn sort_by_keys(values: Column, keys: Column, asc: bool, nulls_first: bool) -> Result<Column> {
+    let num_rows = values.len();
+    let sort_desc = vec![SortColumnDescription {
+        offset: 0,
+        asc,
+        nulls_first,
+        is_nullable: keys.data_type().is_nullable(),
+    }];
+    let block = DataBlock::new(
+        vec![
+            BlockEntry::new(keys.data_type(), Value::Column(keys)),
+            BlockEntry::new(values.data_type(), Value::Column(values)),
+        ],
+        num_rows,
+    );
+    let block = DataBlock::sort(&block, &sort_desc, None)?;
+    Ok(block.columns()[1].value.clone().into_column().unwrap())
+}
+
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
     input_domains: &'a HashMap<Index, Domain>,
     func_ctx: &'a FunctionContext,
@@ -2008,3 +2469,42 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
         Some(output_domain)
     }
 }
+
+/// Generate the offsets of an array column after its values are filtered.
+fn filter_offsets(offsets: &[u64], bitmap: &Bitmap) -> Vec<u64> {
+    let mut new_offset = 0;
+    let mut filtered_offsets = Vec::with_capacity(offsets.len());
+    filtered_offsets.push(0);
+    for offset in offsets.windows(2) {
+        let off = offset[0] as usize;
+        let len = (offset[1] - offset[0]) as usize;
+        let unset_count = bitmap.null_count_range(off, len);
+        new_offset += (len - unset_count) as u64;
+        filtered_offsets.push(new_offset);
+    }
+    filtered_offsets
+}
+
+/// Mark the first occurrence of each key in each array, the arrays are delimited by `offsets`.
+fn distinct_keys_bitmap(keys: &Column, offsets: &[u64]) -> Bitmap {
+    let mut bitmap = MutableBitmap::from_len_zeroed(keys.len());
+    let mut seen = HashSet::new();
+    for window in offsets.windows(2) {
+        seen.clear();
+        for i in window[0] as usize..window[1] as usize {
+            let key = keys.index(i).unwrap();
+            if seen.insert(key) {
+                bitmap.set(i, true);
+            }
+        }
+    }
+    bitmap.into()
+}
+
+/// Replace the values of map entries, keeping the keys.
+fn transform_map_values(entries: &Column, values: Column) -> Column {
+    let Column::Tuple(fields) = entries else {
+        unreachable!()
+    };
+    Column::Tuple(vec![fields[0].clone(), values])
+}
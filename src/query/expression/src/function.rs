@@ -99,6 +99,8 @@ pub struct FunctionContext {
     pub tz: TzLUT,
     pub now: DateTime<Utc>,
     pub rounding_mode: bool,
+    /// Numeric casts raise errors on precision loss instead of rounding or truncating.
+    pub numeric_cast_error: bool,
     pub disable_variant_check: bool,
 
     pub openai_api_chat_base_url: String,
@@ -111,6 +113,7 @@ pub struct FunctionContext {
     pub external_server_connect_timeout_secs: u64,
     pub external_server_request_timeout_secs: u64,
     pub external_server_request_batch_rows: u64,
+    pub external_server_request_retry_times: u64,
 
     pub geometry_output_format: GeometryDataType,
     pub parse_datetime_ignore_remainder: bool,
@@ -142,6 +142,12 @@ impl ComputedExpr {
             ComputedExpr::Stored(expr) => expr,
         }
     }
+
+    /// Checksum of the expression, persisted with it to detect corrupted definitions.
+    #[inline]
+    pub fn checksum(&self) -> u32 {
+        crc32fast::hash(self.expr().as_bytes())
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -173,6 +179,9 @@ pub struct TableField {
     #[serde(default = "uninit_column_id")]
     pub column_id: ColumnId,
     pub computed_expr: Option<ComputedExpr>,
+    /// Checksum of `computed_expr`, persisted with it to detect a corrupted expression.
+    #[serde(default)]
+    pub computed_expr_checksum: Option<u32>,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -1027,6 +1036,7 @@ impl TableField {
             data_type,
             column_id: 0,
             computed_expr: None,
+            computed_expr_checksum: None,
         }
     }
 
@@ -1037,6 +1047,7 @@ impl TableField {
             data_type,
             column_id,
             computed_expr: None,
+            computed_expr_checksum: None,
         }
     }
 
@@ -1083,6 +1094,7 @@ impl TableField {
             data_type: self.data_type.clone(),
             column_id,
             computed_expr: self.computed_expr.clone(),
+            computed_expr_checksum: self.computed_expr_checksum,
         }
     }
 
@@ -1124,10 +1136,17 @@ impl TableField {
     }
 
     pub fn with_computed_expr(mut self, computed_expr: Option<ComputedExpr>) -> Self {
+        self.computed_expr_checksum = computed_expr.as_ref().map(|expr| expr.checksum());
         self.computed_expr = computed_expr;
         self
     }
 
+    /// Keep the checksum stored with the computed expression, instead of the one of the expression.
+    pub fn with_computed_expr_checksum(mut self, checksum: Option<u32>) -> Self {
+        self.computed_expr_checksum = checksum;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1144,6 +1163,10 @@ impl TableField {
         self.computed_expr.as_ref()
     }
 
+    pub fn computed_expr_checksum(&self) -> Option<u32> {
+        self.computed_expr_checksum
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -175,6 +175,27 @@ pub fn check_cast<Index: ColumnIndex>(
             dest_type: wrapped_dest_type,
         })
     } else {
+        // arrays, maps and tuples are formatted as JSON when they are cast to string
+        if dest_type.remove_nullable() == DataType::String
+            && matches!(
+                expr.data_type().remove_nullable(),
+                DataType::EmptyArray
+                    | DataType::EmptyMap
+                    | DataType::Array(_)
+                    | DataType::Map(_)
+                    | DataType::Tuple(_)
+            )
+        {
+            let variant_fn = if is_try {
+                "try_to_variant"
+            } else {
+                "to_variant"
+            };
+            let variant = check_function(span, variant_fn, &[], &[expr], fn_registry)?;
+            let string_expr = check_function(span, "to_string", &[], &[variant], fn_registry)?;
+            return check_cast(span, false, string_expr, &wrapped_dest_type, fn_registry);
+        }
+
         // fast path to eval function for cast
         if let Some(cast_fn) = get_simple_cast_function(is_try, expr.data_type(), dest_type) {
             let params = if let DataType::Decimal(ty) = dest_type {
@@ -17,6 +17,7 @@ use std::time::Duration;
 use arrow_array::RecordBatch;
 use arrow_flight::decode::FlightRecordBatchStream;
 use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
 use arrow_flight::flight_service_client::FlightServiceClient;
 use arrow_flight::FlightDescriptor;
 use arrow_select::concat::concat_batches;
@@ -27,7 +28,9 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 use tonic::transport::channel::Channel;
 use tonic::transport::Endpoint;
+use tonic::Code;
 use tonic::Request;
+use tonic::Status;
 
 use crate::types::DataType;
 use crate::DataSchema;
@@ -39,6 +42,23 @@ const UDF_KEEP_ALIVE_TIMEOUT_SEC: u64 = 20;
 // max_encoding_message_size is usize::max by default
 const MAX_DECODING_MESSAGE_SIZE: usize = 16 * 1024 * 1024 * 1024;
 
+/// Split the comma-separated addresses of a UDF server, the requests fail over to
+/// the next address if the current one is unreachable.
+///
+/// The trailing '/' is trimmed, so the same server is always reported with the same address.
+pub fn udf_server_addresses(address: &str) -> Vec<&str> {
+    address
+        .split(',')
+        .map(|addr| addr.trim().trim_end_matches('/'))
+        .filter(|addr| !addr.is_empty())
+        .collect()
+}
+
+/// Whether the request should be sent to the next address of the UDF server.
+pub fn is_udf_server_failover_error(err: &ErrorCode) -> bool {
+    err.code() == ErrorCode::UDF_SERVER_CONNECT_ERROR || err.code() == ErrorCode::TIMEOUT
+}
+
 #[derive(Debug, Clone)]
 pub struct UDFFlightClient {
     inner: FlightServiceClient<Channel>,
@@ -81,16 +101,17 @@ impl UDFFlightClient {
         Request::new(t)
     }
 
+    /// Get the argument types and the return type of the function exposed by the UDF server.
     #[async_backtrace::framed]
-    pub async fn check_schema(
-        &mut self,
-        func_name: &str,
-        arg_types: &[DataType],
-        return_type: &DataType,
-    ) -> Result<()> {
+    pub async fn get_schema(&mut self, func_name: &str) -> Result<(Vec<DataType>, DataType)> {
         let descriptor = FlightDescriptor::new_path(vec![func_name.to_string()]);
         let request = self.make_request(descriptor);
-        let flight_info = self.inner.get_flight_info(request).await?.into_inner();
+        let flight_info = self
+            .inner
+            .get_flight_info(request)
+            .await
+            .map_err(Self::map_status)?
+            .into_inner();
         let schema = flight_info
             .try_decode_schema()
             .map_err(|err| ErrorCode::UDFDataError(format!("Decode UDF schema error: {err}")))
@@ -104,14 +125,22 @@ impl UDFFlightClient {
         }
 
         let (input_fields, output_fields) = schema.fields().split_at(fields_num - 1);
-        let expect_arg_types = input_fields
-            .iter()
-            .map(|f| f.data_type().clone())
-            .collect::<Vec<_>>();
-        let expect_return_type = output_fields
+        let arg_types = input_fields
             .iter()
             .map(|f| f.data_type().clone())
             .collect::<Vec<_>>();
+        let return_type = output_fields[0].data_type().clone();
+        Ok((arg_types, return_type))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn check_schema(
+        &mut self,
+        func_name: &str,
+        arg_types: &[DataType],
+        return_type: &DataType,
+    ) -> Result<()> {
+        let (expect_arg_types, expect_return_type) = self.get_schema(func_name).await?;
         if expect_arg_types != arg_types {
             return Err(ErrorCode::UDFSchemaMismatch(format!(
                 "UDF arg types mismatch, actual arg types: ({:?})",
@@ -123,10 +152,10 @@ impl UDFFlightClient {
             )));
         }
 
-        if &expect_return_type[0] != return_type {
+        if &expect_return_type != return_type {
             return Err(ErrorCode::UDFSchemaMismatch(format!(
                 "UDF return type mismatch, actual return type: {}",
-                expect_return_type[0]
+                expect_return_type
             )));
         }
 
@@ -152,11 +181,19 @@ impl UDFFlightClient {
             .build(stream::iter(batches))
             .map(|data| data.unwrap());
         let request = self.make_request(flight_data_stream);
-        let flight_data_stream = self.inner.do_exchange(request).await?.into_inner();
+        let flight_data_stream = self
+            .inner
+            .do_exchange(request)
+            .await
+            .map_err(Self::map_status)?
+            .into_inner();
         let record_batch_stream = FlightRecordBatchStream::new_from_flight_data(
             flight_data_stream.map_err(|err| err.into()),
         )
-        .map_err(|err| ErrorCode::UDFDataError(format!("Decode record batch error: {err}")));
+        .map_err(|err| match err {
+            FlightError::Tonic(status) => Self::map_status(status),
+            err => ErrorCode::UDFDataError(format!("Decode record batch error: {err}")),
+        });
 
         let batches: Vec<RecordBatch> = record_batch_stream.try_collect().await?;
         if batches.is_empty() {
@@ -169,4 +206,22 @@ impl UDFFlightClient {
         concat_batches(&schema, batches.iter())
             .map_err(|err| ErrorCode::UDFDataError(err.to_string()))
     }
+
+    fn map_status(status: Status) -> ErrorCode {
+        match status.code() {
+            // The message size exceeds the limit of the client or server.
+            Code::ResourceExhausted | Code::OutOfRange => {
+                ErrorCode::UDFRequestTooLarge(status.message().to_string())
+            }
+            Code::Unavailable => ErrorCode::UDFServerConnectError(format!(
+                "UDF server is unavailable: {}",
+                status.message()
+            )),
+            Code::DeadlineExceeded => ErrorCode::Timeout(format!(
+                "Request to UDF server timed out: {}",
+                status.message()
+            )),
+            _ => status.into(),
+        }
+    }
 }
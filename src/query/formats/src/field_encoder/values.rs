@@ -46,6 +46,7 @@ use micromarshal::Marshal;
 use micromarshal::Unmarshal;
 use ordered_float::OrderedFloat;
 
+use crate::binary::encode_binary;
 use crate::field_encoder::helpers::write_quoted_string;
 use crate::field_encoder::helpers::PrimitiveWithFormat;
 use crate::FileFormatOptionsExt;
@@ -234,7 +235,7 @@ impl FieldEncoderValues {
 
     fn write_binary(&self, column: &BinaryColumn, row_index: usize, out_buf: &mut Vec<u8>) {
         let v = unsafe { column.index_unchecked(row_index) };
-        out_buf.extend_from_slice(hex::encode_upper(v).as_bytes());
+        out_buf.extend_from_slice(&encode_binary(v, self.common_settings.binary_format));
     }
 
     fn write_string(
@@ -0,0 +1,232 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::SortColumnDescription;
+
+use crate::aggregates::borsh_deserialize_state;
+use crate::aggregates::borsh_serialize_state;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+use crate::aggregates::StateAddr;
+
+/// The ordering of one `ORDER BY` item inside an aggregate function call,
+/// e.g. `string_agg(name, ',' ORDER BY id DESC)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct AggregateFunctionSortDesc {
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+struct SortAggregateState {
+    rows: Vec<Vec<Scalar>>,
+}
+
+/// SortAdaptor buffers all the input rows of a group, including the sort keys which
+/// are appended after the arguments of the nested function. When the result is required,
+/// the buffered rows are sorted by the sort keys and then fed to the nested function in order.
+pub struct AggregateFunctionSortAdaptor {
+    inner: AggregateFunctionRef,
+    arguments: Vec<DataType>,
+    sort_descs: Vec<AggregateFunctionSortDesc>,
+}
+
+impl AggregateFunctionSortAdaptor {
+    pub fn create(
+        inner: AggregateFunctionRef,
+        arguments: Vec<DataType>,
+        sort_descs: Vec<AggregateFunctionSortDesc>,
+    ) -> Result<AggregateFunctionRef> {
+        if sort_descs.is_empty() {
+            return Ok(inner);
+        }
+        Ok(Arc::new(AggregateFunctionSortAdaptor {
+            inner,
+            arguments,
+            sort_descs,
+        }))
+    }
+
+    #[inline]
+    fn num_nested_args(&self) -> usize {
+        self.arguments.len() - self.sort_descs.len()
+    }
+
+    #[inline]
+    fn nested_offset(&self) -> usize {
+        let layout = Layout::new::<SortAggregateState>();
+        let nested = self.inner.state_layout();
+        layout.size().next_multiple_of(nested.align())
+    }
+
+    #[inline]
+    fn nested_place(&self, place: StateAddr) -> StateAddr {
+        place.next(self.nested_offset())
+    }
+
+    fn add_row(state: &mut SortAggregateState, columns: &[Column], row: usize) {
+        let values = columns
+            .iter()
+            .map(|col| unsafe { AnyType::index_column_unchecked(col, row).to_owned() })
+            .collect::<Vec<_>>();
+        state.rows.push(values);
+    }
+}
+
+impl AggregateFunction for AggregateFunctionSortAdaptor {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.inner.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(SortAggregateState::default);
+        self.inner.init_state(self.nested_place(place));
+    }
+
+    fn state_layout(&self) -> Layout {
+        let layout = Layout::new::<SortAggregateState>();
+        let nested = self.inner.state_layout();
+        Layout::from_size_align(
+            self.nested_offset() + nested.size(),
+            layout.align().max(nested.align()),
+        )
+        .unwrap()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<SortAggregateState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                Self::add_row(state, columns, row);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<SortAggregateState>();
+        Self::add_row(state, columns, row);
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<SortAggregateState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<SortAggregateState>();
+        let rhs: SortAggregateState = borsh_deserialize_state(reader)?;
+        state.rows.extend(rhs.rows);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<SortAggregateState>();
+        let other = rhs.get::<SortAggregateState>();
+        state.rows.extend(other.rows.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<SortAggregateState>();
+        let nested_place = self.nested_place(place);
+        let num_rows = state.rows.len();
+        if num_rows == 0 {
+            return self.inner.merge_result(nested_place, builder);
+        }
+
+        let mut builders = self
+            .arguments
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, num_rows))
+            .collect::<Vec<_>>();
+        for row in state.rows.iter() {
+            for (builder, value) in builders.iter_mut().zip(row.iter()) {
+                builder.push(value.as_ref());
+            }
+        }
+        let block = DataBlock::new_from_columns(builders.into_iter().map(|b| b.build()).collect());
+
+        let num_nested_args = self.num_nested_args();
+        let descriptions = self
+            .sort_descs
+            .iter()
+            .enumerate()
+            .map(|(i, desc)| SortColumnDescription {
+                offset: num_nested_args + i,
+                asc: desc.asc,
+                nulls_first: desc.nulls_first,
+                is_nullable: self.arguments[num_nested_args + i].is_nullable_or_null(),
+            })
+            .collect::<Vec<_>>();
+        let block = DataBlock::sort(&block, &descriptions, None)?;
+
+        let columns = block.columns()[..num_nested_args]
+            .iter()
+            .map(|entry| entry.to_column(num_rows))
+            .collect::<Vec<_>>();
+        self.inner
+            .accumulate(nested_place, &columns, None, num_rows)?;
+        self.inner.merge_result(nested_place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<SortAggregateState>();
+        std::ptr::drop_in_place(state);
+
+        if self.inner.need_manual_drop_state() {
+            self.inner.drop_state(self.nested_place(place));
+        }
+    }
+
+    fn convert_const_to_full(&self) -> bool {
+        self.inner.convert_const_to_full()
+    }
+}
+
+impl fmt::Display for AggregateFunctionSortAdaptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
@@ -16,8 +16,10 @@ mod aggregate_null_adaptor;
 mod aggregate_null_unary_adaptor;
 mod aggregate_null_variadic_adaptor;
 mod aggregate_ornull_adaptor;
+mod aggregate_sort_adaptor;
 
 pub use aggregate_null_adaptor::*;
 pub use aggregate_null_unary_adaptor::*;
 pub use aggregate_null_variadic_adaptor::*;
 pub use aggregate_ornull_adaptor::*;
+pub use aggregate_sort_adaptor::*;
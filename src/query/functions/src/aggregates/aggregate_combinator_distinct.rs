@@ -37,6 +37,7 @@ use super::aggregate_function_factory::AggregateFunctionCreator;
 use super::aggregate_function_factory::AggregateFunctionDescription;
 use super::aggregate_function_factory::CombinatorDescription;
 use super::aggregator_common::assert_variadic_arguments;
+use super::try_create_aggregate_array_agg_function;
 use super::AggregateCountFunction;
 use super::StateAddr;
 
@@ -188,6 +189,19 @@ pub fn try_create_uniq(
     try_create(nested_name, params, arguments, &creator)
 }
 
+pub fn aggregate_combinator_group_uniq_array_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_group_uniq_array))
+}
+
+pub fn try_create_group_uniq_array(
+    nested_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    let creator: AggregateFunctionCreator = Box::new(try_create_aggregate_array_agg_function);
+    try_create(nested_name, params, arguments, &creator)
+}
+
 pub fn try_create(
     nested_name: &str,
     params: Vec<Scalar>,
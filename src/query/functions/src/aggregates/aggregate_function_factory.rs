@@ -23,6 +23,8 @@ use databend_common_expression::Scalar;
 
 use super::AggregateFunctionCombinatorNull;
 use super::AggregateFunctionOrNullAdaptor;
+use super::AggregateFunctionSortAdaptor;
+use super::AggregateFunctionSortDesc;
 use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
@@ -163,6 +165,31 @@ impl AggregateFunctionFactory {
         self.get_or_null(name, params, arguments, true)
     }
 
+    /// Get the aggregate function with an `ORDER BY` clause inside the call, the types of
+    /// the sort keys are appended after the types of the arguments.
+    pub fn get_with_sort_descs(
+        &self,
+        name: impl AsRef<str>,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        sort_descs: Vec<AggregateFunctionSortDesc>,
+    ) -> Result<AggregateFunctionRef> {
+        if sort_descs.is_empty() {
+            return self.get(name, params, arguments);
+        }
+        if sort_descs.len() > arguments.len() {
+            return Err(ErrorCode::Internal(format!(
+                "Aggregate function {} has {} sort keys but only {} arguments",
+                name.as_ref(),
+                sort_descs.len(),
+                arguments.len()
+            )));
+        }
+        let nested_arguments = arguments[..arguments.len() - sort_descs.len()].to_vec();
+        let nested = self.get(name, params, nested_arguments)?;
+        AggregateFunctionSortAdaptor::create(nested, arguments, sort_descs)
+    }
+
     pub fn get_or_null(
         &self,
         name: impl AsRef<str>,
@@ -248,3 +248,15 @@ pub fn aggregate_stddev_samp_function_desc() -> AggregateFunctionDescription {
         try_create_aggregate_stddev_pop_function::<STD_SAMP>,
     ))
 }
+
+pub fn aggregate_var_pop_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_stddev_pop_function::<VAR_POP>,
+    ))
+}
+
+pub fn aggregate_var_samp_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_stddev_pop_function::<VAR_SAMP>,
+    ))
+}
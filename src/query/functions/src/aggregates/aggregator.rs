@@ -24,6 +24,7 @@ use super::aggregate_bitmap::aggregate_bitmap_or_count_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_union_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
+use super::aggregate_combinator_distinct::aggregate_combinator_group_uniq_array_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_combinator_state::AggregateStateCombinator;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
@@ -33,6 +34,8 @@ use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
 use super::aggregate_stddev::aggregate_stddev_pop_function_desc;
 use super::aggregate_stddev::aggregate_stddev_samp_function_desc;
+use super::aggregate_stddev::aggregate_var_pop_function_desc;
+use super::aggregate_stddev::aggregate_var_samp_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
@@ -76,6 +79,11 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("stddev", aggregate_stddev_samp_function_desc());
         factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("var_samp", aggregate_var_samp_function_desc());
+        factory.register("var_pop", aggregate_var_pop_function_desc());
+        factory.register("variance_samp", aggregate_var_samp_function_desc());
+        factory.register("variance_pop", aggregate_var_pop_function_desc());
+        factory.register("variance", aggregate_var_samp_function_desc());
         factory.register("quantile", aggregate_quantile_disc_function_desc());
         factory.register("quantile_disc", aggregate_quantile_disc_function_desc());
         factory.register("quantile_cont", aggregate_quantile_cont_function_desc());
@@ -101,6 +109,10 @@ impl Aggregators {
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
+        factory.register(
+            "group_uniq_array",
+            aggregate_combinator_group_uniq_array_desc(),
+        );
         factory.register(
             "group_array_moving_avg",
             aggregate_array_moving_avg_function_desc(),
@@ -112,6 +124,7 @@ impl Aggregators {
         factory.register("kurtosis", aggregate_kurtosis_function_desc());
         factory.register("skewness", aggregate_skewness_function_desc());
         factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("group_concat", aggregate_string_agg_function_desc());
 
         factory.register(
             "bitmap_and_count",
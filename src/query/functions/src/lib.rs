@@ -47,7 +47,7 @@ pub static BUILTIN_FUNCTIONS: FunctionRegistry = builtin_functions();
 
 pub const ASYNC_FUNCTIONS: [&str; 1] = ["nextval"];
 
-pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
+pub const GENERAL_WINDOW_FUNCTIONS: [&str; 14] = [
     "row_number",
     "rank",
     "dense_rank",
@@ -61,18 +61,27 @@ pub const GENERAL_WINDOW_FUNCTIONS: [&str; 13] = [
     "nth_value",
     "ntile",
     "cume_dist",
+    "interpolate",
 ];
 
-pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 5] = [
+pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 9] = [
     "array_transform",
     "array_apply",
     "array_map",
     "array_filter",
     "array_reduce",
+    "array_sort_by",
+    "array_distinct_by",
+    "map_filter",
+    "map_transform_values",
 ];
 
 pub const GENERAL_SEARCH_FUNCTIONS: [&str; 3] = ["match", "query", "score"];
 
+/// Aggregate functions that accept `ORDER BY` in the call, e.g. `array_agg(x ORDER BY y)`.
+pub const ORDERED_AGGREGATE_FUNCTIONS: [&str; 4] =
+    ["array_agg", "list", "string_agg", "group_concat"];
+
 fn builtin_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
 
@@ -659,7 +659,7 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                     } else {
                                         domain.overflow_cast()
                                     };
-                                    if overflowing {
+                                    if overflowing || func_ctx.numeric_cast_error {
                                         FunctionDomain::MayThrow
                                     } else {
                                         FunctionDomain::Domain(domain)
@@ -667,6 +667,11 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                 },
                                 vectorize_with_builder_1_arg::<NumberType<SRC_TYPE>, NumberType<DEST_TYPE>>(
                                     move |val, output, ctx| {
+                                        if ctx.func_ctx.numeric_cast_error && AsPrimitive::<f64>::as_(val).fract() != 0.0 {
+                                            ctx.set_error(output.len(), "number lost precision");
+                                            output.push(DEST_TYPE::default());
+                                            return;
+                                        }
                                         let val = if ctx.func_ctx.rounding_mode {
                                             let val = AsPrimitive::<f64>::as_(val);
                                             num_traits::cast::cast(val.round())
@@ -742,7 +747,7 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                         domain.overflow_cast()
                                     };
                                     FunctionDomain::Domain(NullableDomain {
-                                        has_null: overflowing,
+                                        has_null: overflowing || func_ctx.numeric_cast_error,
                                         value: Some(Box::new(
                                             domain,
                                         )),
@@ -750,6 +755,10 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                                 },
                                 vectorize_with_builder_1_arg::<NumberType<SRC_TYPE>, NullableType<NumberType<DEST_TYPE>>>(
                                     |val, output, ctx| {
+                                        if ctx.func_ctx.numeric_cast_error && AsPrimitive::<f64>::as_(val).fract() != 0.0 {
+                                            output.push_null();
+                                            return;
+                                        }
                                         let val = if ctx.func_ctx.rounding_mode {
                                             let val = AsPrimitive::<f64>::as_(val);
                                             num_traits::cast::cast(val.round())
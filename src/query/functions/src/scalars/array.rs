@@ -19,6 +19,7 @@ use std::sync::Arc;
 use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::boolean::BooleanDomain;
 use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::number::UInt64Type;
@@ -96,6 +97,7 @@ const ARRAY_SORT_FUNCTIONS: &[(&str, (bool, bool)); 4] = &[
 ];
 
 pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_aliases("array_compact", &["array_remove_nulls"]);
     registry.register_aliases("contains", &["array_contains"]);
     registry.register_aliases("get", &["array_get"]);
     registry.register_aliases("length", &["array_length"]);
@@ -159,6 +161,40 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    registry.register_function_factory("array_zip", |_, args_type| {
+        if args_type.is_empty() {
+            return None;
+        }
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let args_type = args_type
+            .iter()
+            .map(|t| t.remove_nullable())
+            .collect::<Vec<_>>();
+        let inner_types = args_type
+            .iter()
+            .map(|t| t.as_array().map(|inner| *inner.clone()))
+            .collect::<Option<Vec<_>>>()?;
+        let return_type = DataType::Array(Box::new(DataType::Tuple(inner_types)));
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: "array_zip".to_string(),
+                args_type,
+                return_type: return_type.clone(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| eval_array_zip(args, &return_type, ctx)),
+            },
+        };
+
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+
     registry.register_1_arg::<EmptyArrayType, NumberType<u8>, _, _>(
         "length",
         |_, _| FunctionDomain::Domain(SimpleDomain { min: 0, max: 0 }),
@@ -224,6 +260,40 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_2_arg_core::<NullableType<EmptyArrayType>, NullableType<Int64Type>, NullType, _, _>(
+        "get",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    registry.register_2_arg_core::<NullableType<ArrayType<NullType>>, NullableType<Int64Type>, NullType, _, _>(
+        "get",
+        |_, _, _| FunctionDomain::Full,
+        |_, _, _| Value::Scalar(()),
+    );
+
+    // Negative index counts from the end of the array, e.g. `-1` is the last element.
+    registry.register_combine_nullable_2_arg::<ArrayType<NullableType<GenericType<0>>>, Int64Type, GenericType<0>, _, _>(
+        "get",
+        |_, domain, _| FunctionDomain::Domain(NullableDomain {
+            has_null: true,
+            value: domain.as_ref().and_then(|domain| domain.value.clone()),
+        }),
+        vectorize_with_builder_2_arg::<ArrayType<NullableType<GenericType<0>>>, Int64Type, NullableType<GenericType<0>>>(
+            |arr, idx, output, _| {
+                let pos = if idx > 0 {
+                    Some(idx as usize - 1)
+                } else {
+                    arr.len().checked_sub(idx.unsigned_abs() as usize)
+                };
+                match pos.filter(|_| idx != 0).and_then(|pos| arr.index(pos)) {
+                    Some(Some(item)) => output.push(item),
+                    _ => output.push_null(),
+                }
+            }
+        ),
+    );
+
     registry.register_2_arg_core::<NullType, NullType, NullType, _, _>(
         "array_indexof",
         |_, _, _| FunctionDomain::Full,
@@ -428,6 +498,25 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_reverse",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>, _, _>(
+        "array_reverse",
+        |_, domain| FunctionDomain::Domain(domain.clone()),
+        vectorize_with_builder_1_arg::<ArrayType<GenericType<0>>, ArrayType<GenericType<0>>>(
+            |arr, output, _| {
+                for i in (0..arr.len()).rev() {
+                    output.put_item(unsafe { arr.index_unchecked(i) });
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
     registry.register_2_arg_core::<GenericType<0>, NullableType<ArrayType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
         "array_prepend",
         |_, item_domain, array_domain| {
@@ -700,6 +789,36 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<EmptyArrayType, EmptyArrayType>(|arr, _| arr),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullType>, EmptyArrayType, _, _>(
+        "array_compact",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<NullType>, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    // Removing the NULLs makes the elements of the result array not nullable.
+    registry.register_passthrough_nullable_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>, _, _>(
+        "array_compact",
+        |_, domain| FunctionDomain::Domain(
+            domain.as_ref().and_then(|domain| domain.value.as_ref().map(|value| (**value).clone()))
+        ),
+        vectorize_with_builder_1_arg::<ArrayType<NullableType<GenericType<0>>>, ArrayType<GenericType<0>>>(
+            |arr, output, _| {
+                for item in arr.iter().flatten() {
+                    output.put_item(item);
+                }
+                output.commit_row()
+            }
+        ),
+    );
+
     registry.register_passthrough_nullable_1_arg::<EmptyArrayType, EmptyArrayType, _, _>(
         "array_distinct",
         |_, _| FunctionDomain::Full,
@@ -735,6 +854,50 @@ pub fn register(registry: &mut FunctionRegistry) {
     );
 }
 
+/// Zip the arrays into an array of tuples, the arrays of a row must have the same length.
+fn eval_array_zip(
+    args: &[ValueRef<AnyType>],
+    return_type: &DataType,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let mut builder = ColumnBuilder::with_capacity(return_type, len.unwrap_or(1));
+    for idx in 0..len.unwrap_or(1) {
+        let arrays = args
+            .iter()
+            .map(|arg| {
+                let item = match arg {
+                    ValueRef::Scalar(scalar) => scalar.clone(),
+                    ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+                };
+                item.as_array().unwrap().clone()
+            })
+            .collect::<Vec<_>>();
+        let array_len = arrays[0].len();
+        if arrays.iter().any(|array| array.len() != array_len) {
+            ctx.set_error(
+                builder.len(),
+                format!(
+                    "array_zip expects the arrays to have the same length, but got {}",
+                    arrays.iter().map(|array| array.len()).join(", ")
+                ),
+            );
+            builder.push_default();
+        } else {
+            builder.push(ScalarRef::Array(Column::Tuple(arrays)));
+        }
+    }
+
+    match len {
+        Some(_) => Value::Column(builder.build()),
+        None => Value::Scalar(builder.build_scalar()),
+    }
+}
+
 fn register_array_aggr(registry: &mut FunctionRegistry) {
     fn eval_array_aggr(
         name: &str,
@@ -208,6 +208,8 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_aliases("bitmap_subset_in_range", &["bitmap_range"]);
+
     registry.register_passthrough_nullable_3_arg::<BitmapType, UInt64Type, UInt64Type, BitmapType, _, _>(
         "sub_bitmap",
         |_, _, _, _| FunctionDomain::MayThrow,
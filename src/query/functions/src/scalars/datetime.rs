@@ -46,6 +46,8 @@ use databend_common_expression::types::timestamp::string_to_timestamp;
 use databend_common_expression::types::timestamp::timestamp_to_string;
 use databend_common_expression::types::timestamp::MICROS_IN_A_MILLI;
 use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
+use databend_common_expression::types::timestamp::TIMESTAMP_MAX;
+use databend_common_expression::types::timestamp::TIMESTAMP_MIN;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
@@ -119,6 +121,35 @@ fn int64_to_timestamp(n: i64) -> Result<i64, String> {
     }
 }
 
+/// Convert an epoch in units of `10^-scale` seconds to micros, e.g. scale 3 means milliseconds.
+fn int64_to_timestamp_with_scale(n: i64, scale: u64) -> Result<i64, String> {
+    let micros = match scale {
+        0..=6 => n.checked_mul(10_i64.pow(6 - scale as u32)),
+        7..=9 => Some(n / 10_i64.pow(scale as u32 - 6)),
+        _ => return Err(format!("scale must be between 0 and 9, but got {scale}")),
+    };
+    micros
+        .ok_or_else(|| "timestamp is out of range".to_string())
+        .and_then(check_timestamp)
+}
+
+/// Convert a fractional epoch in units of `10^-scale` seconds to micros, the fraction of
+/// micros is truncated.
+fn float64_to_timestamp_with_scale(n: f64, scale: u64) -> Result<i64, String> {
+    let micros = match scale {
+        0..=6 => n * 10_f64.powi(6 - scale as i32),
+        7..=9 => n / 10_f64.powi(scale as i32 - 6),
+        _ => return Err(format!("scale must be between 0 and 9, but got {scale}")),
+    }
+    .trunc();
+    // NaN and infinities are out of range too.
+    if (TIMESTAMP_MIN as f64..=TIMESTAMP_MAX as f64).contains(&micros) {
+        Ok(micros as i64)
+    } else {
+        Err("timestamp is out of range".to_string())
+    }
+}
+
 fn int64_domain_to_timestamp_domain<T: AsPrimitive<i64>>(
     domain: &SimpleDomain<T>,
 ) -> Option<SimpleDomain<i64>> {
@@ -433,6 +464,64 @@ fn register_number_to_timestamp(registry: &mut FunctionRegistry) {
             }
         })(val, ctx)
     }
+
+    // to_timestamp(epoch, scale)
+    registry.register_passthrough_nullable_2_arg::<Int64Type, UInt64Type, TimestampType, _, _>(
+        "to_timestamp",
+        |_, _, _| FunctionDomain::MayThrow,
+        eval_number_to_timestamp_with_scale,
+    );
+    registry.register_combine_nullable_2_arg::<Int64Type, UInt64Type, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<Int64Type, UInt64Type, NullableType<TimestampType>>(
+            |val, scale, output, _| match int64_to_timestamp_with_scale(val, scale) {
+                Ok(ts) => output.push(ts),
+                Err(_) => output.push_null(),
+            },
+        ),
+    );
+
+    fn eval_number_to_timestamp_with_scale(
+        val: ValueRef<Int64Type>,
+        scale: ValueRef<UInt64Type>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampType> {
+        vectorize_with_builder_2_arg::<Int64Type, UInt64Type, TimestampType>(
+            |val, scale, output, ctx| match int64_to_timestamp_with_scale(val, scale) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        )(val, scale, ctx)
+    }
+
+    // to_timestamp(fractional epoch, scale)
+    registry.register_passthrough_nullable_2_arg::<Float64Type, UInt64Type, TimestampType, _, _>(
+        "to_timestamp",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Float64Type, UInt64Type, TimestampType>(
+            |val, scale, output, ctx| match float64_to_timestamp_with_scale(val.0, scale) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_combine_nullable_2_arg::<Float64Type, UInt64Type, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<Float64Type, UInt64Type, NullableType<TimestampType>>(
+            |val, scale, output, _| match float64_to_timestamp_with_scale(val.0, scale) {
+                Ok(ts) => output.push(ts),
+                Err(_) => output.push_null(),
+            },
+        ),
+    );
 }
 
 fn register_string_to_date(registry: &mut FunctionRegistry) {
@@ -39,6 +39,7 @@ use databend_common_expression::Value;
 use databend_common_expression::ValueRef;
 use ethnum::i256;
 use num_traits::AsPrimitive;
+use num_traits::NumCast;
 use ordered_float::OrderedFloat;
 
 // int float to decimal
@@ -225,6 +226,9 @@ pub(crate) fn register_decimal_to_int<T: Number>(registry: &mut FunctionRegistry
             },
             eval: FunctionEval::Scalar {
                 calc_domain: Box::new(|ctx, d| {
+                    if ctx.numeric_cast_error {
+                        return FunctionDomain::MayThrow;
+                    }
                     let res_fn = move || match d[0].as_decimal().unwrap() {
                         DecimalDomain::Decimal128(d, size) => Some(SimpleDomain::<T> {
                             min: d.min.to_int(size.scale, ctx.rounding_mode)?,
@@ -536,9 +540,22 @@ where
     let min_for_precision = T::min_for_precision(size.precision);
     let max_for_precision = T::max_for_precision(size.precision);
 
-    let f = |x: S::ScalarRef<'_>, builder: &mut Vec<T>, ctx: &mut EvalContext| {
-        let mut x = x.as_() * multiplier;
-        if ctx.func_ctx.rounding_mode {
+    let f = |value: S::ScalarRef<'_>, builder: &mut Vec<T>, ctx: &mut EvalContext| {
+        let mut x = value.as_() * multiplier;
+        if ctx.func_ctx.numeric_cast_error {
+            // The float itself is inexact, e.g. 0.07 * 100 is 7.000000000000001, so round to
+            // the target scale first, and the cast is lossless if the rounded value converts
+            // back to the same float of the source type.
+            x = x.round();
+            if <S::ScalarRef<'_> as NumCast>::from(x / multiplier) != Some(value) {
+                ctx.set_error(
+                    builder.len(),
+                    concat!("Decimal lost precision at line : ", line!()),
+                );
+                builder.push(T::one());
+                return;
+            }
+        } else if ctx.func_ctx.rounding_mode {
             x = x.round();
         }
         let x = T::from_float(x);
@@ -574,6 +591,15 @@ fn get_round_val<T: Decimal>(x: T, scale: u32, ctx: &mut EvalContext) -> Option<
     round_val
 }
 
+/// Whether dropping the last `scale` digits of `x` loses precision when casts are required
+/// to raise errors instead of rounding or truncating.
+#[inline]
+fn lose_precision<T: Decimal>(x: T, scale: u32, ctx: &EvalContext) -> bool {
+    ctx.func_ctx.numeric_cast_error
+        && scale > 0
+        && x.checked_rem(T::e(scale)).map_or(true, |r| r != T::zero())
+}
+
 fn decimal_256_to_128(
     buffer: &ValueRef<AnyType>,
     from_size: DecimalSize,
@@ -606,6 +632,14 @@ fn decimal_256_to_128(
 
         vectorize_with_builder_1_arg::<DecimalType<i256>, DecimalType<i128>>(
             |x: i256, builder: &mut Vec<i128>, ctx: &mut EvalContext| {
+                if lose_precision::<i256>(x, scale_diff, ctx) {
+                    ctx.set_error(
+                        builder.len(),
+                        concat!("Decimal lost precision at line : ", line!()),
+                    );
+                    builder.push(i128::one());
+                    return;
+                }
                 let round_val = get_round_val::<i256>(x, scale_diff, ctx);
                 let y = match (x.checked_div(factor), round_val) {
                     (Some(x), Some(round_val)) => x.checked_add(round_val),
@@ -661,6 +695,14 @@ macro_rules! m_decimal_to_decimal {
             vectorize_with_builder_1_arg::<DecimalType<F>, DecimalType<T>>(
                 |x: F, builder: &mut Vec<T>, ctx: &mut EvalContext| {
                     let x = T::from(x);
+                    if lose_precision::<T>(x, scale_diff, ctx) {
+                        ctx.set_error(
+                            builder.len(),
+                            concat!("Decimal lost precision at line : ", line!()),
+                        );
+                        builder.push(T::one());
+                        return;
+                    }
                     let round_val = get_round_val::<T>(x, scale_diff, ctx);
                     let y = match (x.checked_div(factor), round_val) {
                         (Some(x), Some(round_val)) => x.checked_add(round_val),
@@ -803,13 +845,18 @@ fn decimal_to_int<T: Number>(
         DecimalDataType::DECIMAL_TYPE(from_size) => {
             let value = arg.try_downcast().unwrap();
             vectorize_with_builder_1_arg::<DecimalType<DECIMAL_TYPE>, NumberType<T>>(
-                |x, builder: &mut Vec<T>, ctx: &mut EvalContext| match x
-                    .to_int(from_size.scale, ctx.func_ctx.rounding_mode)
-                {
-                    Some(x) => builder.push(x),
-                    None => {
-                        ctx.set_error(builder.len(), "decimal cast to int overflow");
-                        builder.push(T::default())
+                |x, builder: &mut Vec<T>, ctx: &mut EvalContext| {
+                    if lose_precision(x, from_size.scale as u32, ctx) {
+                        ctx.set_error(builder.len(), "decimal cast to int lost precision");
+                        builder.push(T::default());
+                        return;
+                    }
+                    match x.to_int(from_size.scale, ctx.func_ctx.rounding_mode) {
+                        Some(x) => builder.push(x),
+                        None => {
+                            ctx.set_error(builder.len(), "decimal cast to int overflow");
+                            builder.push(T::default())
+                        }
                     }
                 },
             )(value, ctx)
@@ -39,8 +39,8 @@ use stringslice::StringSlice;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("to_string", &["to_varchar", "to_text"]);
-    registry.register_aliases("upper", &["ucase"]);
-    registry.register_aliases("lower", &["lcase"]);
+    registry.register_aliases("upper", &["ucase", "upper_utf8"]);
+    registry.register_aliases("lower", &["lcase", "lower_utf8"]);
     registry.register_aliases("length", &[
         "char_length",
         "character_length",
@@ -52,6 +52,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "substr_utf8",
         "substring_utf8",
     ]);
+    registry.register_aliases("substring_index", &["substr_index"]);
 
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "upper",
@@ -93,6 +94,36 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    // The `_binary` variants only convert ascii characters, they are used
+    // under binary collation.
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "upper_binary",
+        |_, _| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data().len(),
+            |val, output, _| {
+                for ch in val.chars() {
+                    output.put_char(ch.to_ascii_uppercase());
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "lower_binary",
+        |_, _| FunctionDomain::Full,
+        vectorize_string_to_string(
+            |col| col.data().len(),
+            |val, output, _| {
+                for ch in val.chars() {
+                    output.put_char(ch.to_ascii_lowercase());
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
     registry.register_1_arg::<StringType, NumberType<u64>, _, _>(
         "bit_length",
         |_, _| FunctionDomain::Full,
@@ -786,7 +817,35 @@ pub fn register(registry: &mut FunctionRegistry) {
                     output.commit_row();
                 },
             ),
-        )
+        );
+
+    // Returns the substring before the `count`-th occurrence of `delim` when `count` is positive,
+    // or the substring after the `count`-th occurrence counting from the right when `count` is negative.
+    // The whole string is returned if there are fewer occurrences, and an empty string if `count` is 0.
+    registry
+        .register_passthrough_nullable_3_arg::<StringType, StringType, NumberType<i64>, StringType, _, _>(
+            "substring_index",
+            |_, _, _, _| FunctionDomain::Full,
+            vectorize_with_builder_3_arg::<StringType, StringType, NumberType<i64>, StringType>(
+                |s, delim, count, output, _| {
+                    if !delim.is_empty() && count != 0 {
+                        let nth = (count.unsigned_abs() - 1) as usize;
+                        if count > 0 {
+                            match s.match_indices(delim).nth(nth) {
+                                Some((idx, _)) => output.put_str(&s[..idx]),
+                                None => output.put_str(s),
+                            }
+                        } else {
+                            match s.rmatch_indices(delim).nth(nth) {
+                                Some((idx, _)) => output.put_str(&s[idx + delim.len()..]),
+                                None => output.put_str(s),
+                            }
+                        }
+                    }
+                    output.commit_row();
+                },
+            ),
+        );
 }
 
 pub(crate) mod soundex {
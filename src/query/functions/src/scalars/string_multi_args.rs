@@ -417,6 +417,19 @@ pub fn register(registry: &mut FunctionRegistry) {
             Some(Arc::new(f))
         }
     });
+
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, StringType, ArrayType<StringType>, _, _>(
+            "regexp_extract_all",
+            |_, _, _| FunctionDomain::MayThrow,
+            |source, pat, ctx| regexp_extract_all_fn(source, pat, ValueRef::Scalar(0), ctx),
+        );
+
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, UInt32Type, ArrayType<StringType>, _, _>(
+        "regexp_extract_all",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        regexp_extract_all_fn,
+    );
 }
 
 fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
@@ -848,6 +861,71 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
     }
 }
 
+/// Extract all the matches of the pattern, or of the capture group of the pattern if
+/// the group index is not 0, the pattern is case sensitive.
+fn regexp_extract_all_fn(
+    source: ValueRef<StringType>,
+    pat: ValueRef<StringType>,
+    group: ValueRef<UInt32Type>,
+    ctx: &mut EvalContext,
+) -> Value<ArrayType<StringType>> {
+    let len = [source.len(), pat.len(), group.len()]
+        .into_iter()
+        .max()
+        .unwrap();
+    let cached_re = match &pat {
+        ValueRef::Scalar(pat) => Some(regex::Regex::new(pat)),
+        ValueRef::Column(_) => None,
+    };
+
+    let mut builder = ArrayType::<StringType>::create_builder(len, ctx.generics);
+    for idx in 0..len {
+        let source = unsafe { source.index_unchecked(idx) };
+        let group = unsafe { group.index_unchecked(idx) } as usize;
+        let local_re;
+        let re = match &cached_re {
+            Some(re) => re,
+            None => {
+                local_re = regex::Regex::new(unsafe { pat.index_unchecked(idx) });
+                &local_re
+            }
+        };
+        let re = match re {
+            Ok(re) => re,
+            Err(err) => {
+                ctx.set_error(builder.len(), format!("invalid regular expression: {err}"));
+                builder.commit_row();
+                continue;
+            }
+        };
+        if group >= re.captures_len() {
+            ctx.set_error(
+                builder.len(),
+                format!(
+                    "group index {group} of regexp_extract_all is out of range, the pattern has {} groups",
+                    re.captures_len() - 1
+                ),
+            );
+            builder.commit_row();
+            continue;
+        }
+        for caps in re.captures_iter(source) {
+            builder
+                .builder
+                .put_str(caps.get(group).map(|m| m.as_str()).unwrap_or_default());
+            builder.builder.commit_row();
+        }
+        builder.commit_row();
+    }
+
+    match (&source, &pat, &group) {
+        (ValueRef::Scalar(_), ValueRef::Scalar(_), ValueRef::Scalar(_)) => {
+            Value::Scalar(builder.build_scalar())
+        }
+        _ => Value::Column(builder.build()),
+    }
+}
+
 pub mod regexp {
     use databend_common_expression::types::string::StringColumnBuilder;
     use regex::Regex;
@@ -985,21 +1063,49 @@ pub mod regexp {
             }
         };
 
-        let (before_trimmed, trimmed) = (&s[..char_pos], &s[char_pos..]);
-        builder.put_str(before_trimmed);
-
-        // means we should replace all matched strings
-        if occur == 0 {
-            builder.put_str(&re.replace_all(trimmed, repl));
-        } else if let Some(capature) = re.captures_iter(trimmed).nth((occur - 1) as _) {
+        // Search the whole string from `pos` instead of the trimmed string, so that anchors
+        // like `^` and `\b` are matched against the whole string.
+        let mut last_end = 0;
+        let mut last_match_end = None;
+        let mut start = char_pos;
+        let mut matched = 0;
+        let mut expanded = String::new();
+        while start <= s.len() {
+            let Some(captures) = re.captures_at(s, start) else {
+                break;
+            };
             // unwrap on 0 is OK because captures only reports matches.
-            let m = capature.get(0).unwrap();
-            builder.put_str(&trimmed[0..m.start()]);
-            builder.put_str(repl);
-            builder.put_str(&trimmed[m.end()..]);
-        } else {
-            builder.put_str(trimmed);
+            let m = captures.get(0).unwrap();
+            if m.is_empty() {
+                // Skip to the next character after an empty match, like `Regex::find_iter`.
+                start = s[m.end()..]
+                    .chars()
+                    .next()
+                    .map_or(s.len() + 1, |c| m.end() + c.len_utf8());
+                if last_match_end == Some(m.end()) {
+                    continue;
+                }
+            } else {
+                start = m.end();
+            }
+            last_match_end = Some(m.end());
+            matched += 1;
+
+            // means we should replace all matched strings
+            if occur == 0 {
+                expanded.clear();
+                captures.expand(repl, &mut expanded);
+                builder.put_str(&s[last_end..m.start()]);
+                builder.put_str(&expanded);
+                last_end = m.end();
+            } else if matched == occur {
+                builder.put_str(&s[last_end..m.start()]);
+                builder.put_str(repl);
+                last_end = m.end();
+                break;
+            }
         }
+        builder.put_str(&s[last_end..]);
     }
 
     #[inline]
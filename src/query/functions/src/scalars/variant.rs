@@ -109,6 +109,7 @@ use jsonb::type_of;
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("json_object_keys", &["object_keys"]);
     registry.register_aliases("to_string", &["json_to_string"]);
+    registry.register_aliases("json_strip_nulls", &["json_remove_nulls"]);
 
     registry.register_passthrough_nullable_1_arg::<VariantType, VariantType, _, _>(
         "parse_json",
@@ -244,6 +245,36 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<VariantType, BooleanType, _, _>(
+        "is_parsable_json",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<VariantType, BooleanType>(|s, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.push(false);
+                    return;
+                }
+            }
+            // Variant value may be an invalid JSON, convert them to string and then check.
+            let val = to_string(s);
+            output.push(parse_value(val.as_bytes()).is_ok());
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BooleanType, _, _>(
+        "is_parsable_json",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BooleanType>(|s, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.push(false);
+                    return;
+                }
+            }
+            output.push(parse_value(s.as_bytes()).is_ok());
+        }),
+    );
+
     registry.register_1_arg_core::<NullableType<VariantType>, NullableType<UInt32Type>, _, _>(
         "length",
         |_, _| FunctionDomain::Full,
@@ -1193,6 +1224,30 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_2_arg::<VariantType, BooleanType, VariantType, _, _>(
+        "json_strip_nulls",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<VariantType, BooleanType, VariantType>(
+            |val, recursive, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.commit_row();
+                        return;
+                    }
+                }
+                let res = if recursive {
+                    strip_nulls(val, &mut output.data)
+                } else {
+                    strip_top_level_nulls(val, &mut output.data)
+                };
+                if let Err(err) = res {
+                    ctx.set_error(output.len(), err.to_string());
+                };
+                output.commit_row();
+            },
+        ),
+    );
+
     registry.register_passthrough_nullable_2_arg(
         "concat",
         |_, _, _| FunctionDomain::MayThrow,
@@ -1547,6 +1602,18 @@ fn json_object_impl_fn(
     }
 }
 
+/// Remove the null members of an object, nested objects and arrays are kept unchanged.
+fn strip_top_level_nulls(val: &[u8], buf: &mut Vec<u8>) -> Result<(), jsonb::Error> {
+    match jsonb::from_slice(val)? {
+        jsonb::Value::Object(mut obj) => {
+            obj.retain(|_, v| !matches!(v, jsonb::Value::Null));
+            jsonb::Value::Object(obj).write_to_vec(buf);
+        }
+        _ => buf.extend_from_slice(val),
+    }
+    Ok(())
+}
+
 fn prepare_args_columns(
     args: &[ValueRef<AnyType>],
     ctx: &EvalContext,
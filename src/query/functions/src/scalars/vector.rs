@@ -26,8 +26,11 @@ use databend_common_expression::FunctionRegistry;
 use databend_common_openai::OpenAI;
 use databend_common_vector::cosine_distance;
 use databend_common_vector::cosine_distance_64;
+use databend_common_vector::l1_distance;
+use databend_common_vector::l1_distance_64;
 use databend_common_vector::l2_distance;
 use databend_common_vector::l2_distance_64;
+use databend_common_vector::vector_norm_64;
 
 pub fn register(registry: &mut FunctionRegistry) {
     // cosine_distance
@@ -81,6 +84,31 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    // L1 distance
+    // This function takes two Float32 arrays as input and computes the l1 distance between them.
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
+        "l1_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>,  Float32Type>(
+            |lhs, rhs, output, ctx| {
+                let l =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(lhs) };
+                let r =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(rhs) };
+
+                match l1_distance(l.as_slice(), r .as_slice()) {
+                    Ok(dist) => {
+                        output.push(F32::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F32::from(0.0));
+                    }
+                }
+            }
+        ),
+    );
+
     registry.register_passthrough_nullable_2_arg::<ArrayType<Float64Type>, ArrayType<Float64Type>, Float64Type, _, _>(
         "cosine_distance",
         |_, _, _| FunctionDomain::MayThrow,
@@ -127,6 +155,40 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_2_arg::<ArrayType<Float64Type>, ArrayType<Float64Type>, Float64Type, _, _>(
+        "l1_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<ArrayType<Float64Type>, ArrayType<Float64Type>,  Float64Type>(
+            |lhs, rhs, output, ctx| {
+                let l =
+                    unsafe { std::mem::transmute::<Buffer<F64>, Buffer<f64>>(lhs) };
+                let r =
+                    unsafe { std::mem::transmute::<Buffer<F64>, Buffer<f64>>(rhs) };
+
+                match l1_distance_64(l.as_slice(), r .as_slice()) {
+                    Ok(dist) => {
+                        output.push(F64::from(dist));
+                    }
+                    Err(err) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(F64::from(0.0));
+                    }
+                }
+            }
+        ),
+    );
+
+    // vector_norm
+    // This function takes a Float64 array as input and computes the l2 norm of it.
+    registry.register_passthrough_nullable_1_arg::<ArrayType<Float64Type>, Float64Type, _, _>(
+        "vector_norm",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<ArrayType<Float64Type>, Float64Type>(|val, output, _| {
+            let v = unsafe { std::mem::transmute::<Buffer<F64>, Buffer<f64>>(val) };
+            output.push(F64::from(vector_norm_64(v.as_slice())));
+        }),
+    );
+
     // embedding_vector
     // This function takes two strings as input, sends an API request to OpenAI, and returns the Float32 array of embeddings.
     // The OpenAI API key is pre-configured during the binder phase, so we rewrite this function and set the API key.
@@ -42,11 +42,16 @@ use jsonb::array_values;
 use jsonb::as_str;
 use jsonb::get_by_index;
 use jsonb::get_by_name;
+use jsonb::is_array;
+use jsonb::is_null;
+use jsonb::is_object;
 use jsonb::jsonpath::parse_json_path;
 use jsonb::jsonpath::Mode as SelectorMode;
 use jsonb::jsonpath::Selector;
 use jsonb::object_each;
 use jsonb::object_keys;
+use jsonb::to_str;
+use jsonb::to_string;
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.properties.insert(
@@ -189,6 +194,42 @@ pub fn register(registry: &mut FunctionRegistry) {
         }))
     });
 
+    registry.properties.insert(
+        "json_array_elements_text".to_string(),
+        FunctionProperty::default().kind(FunctionKind::SRF),
+    );
+    registry.register_function_factory("json_array_elements_text", |_, args_type| {
+        if args_type.len() != 1 {
+            return None;
+        }
+        if args_type[0].remove_nullable() != DataType::Variant && args_type[0] != DataType::Null {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_array_elements_text".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::Tuple(vec![DataType::Nullable(Box::new(DataType::String))]),
+            },
+            eval: FunctionEval::SRF {
+                eval: Box::new(|args, ctx, max_nums_per_row| {
+                    let arg = args[0].clone().to_owned();
+                    (0..ctx.num_rows)
+                        .map(|row| match arg.index(row).unwrap() {
+                            ScalarRef::Null => {
+                                (Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0)
+                            }
+                            ScalarRef::Variant(val) => {
+                                unnest_variant_array_text(val, row, max_nums_per_row)
+                            }
+                            _ => unreachable!(),
+                        })
+                        .collect()
+                }),
+            },
+        }))
+    });
+
     registry.properties.insert(
         "json_each".to_string(),
         FunctionProperty::default().kind(FunctionKind::SRF),
@@ -219,7 +260,23 @@ pub fn register(registry: &mut FunctionRegistry) {
                                 0,
                             ),
                             ScalarRef::Variant(val) => {
-                                unnest_variant_obj(val, row, max_nums_per_row)
+                                if is_object(val) {
+                                    unnest_variant_obj(val, row, max_nums_per_row)
+                                } else if is_array(val) {
+                                    unnest_variant_array_with_index(val, row, max_nums_per_row)
+                                } else {
+                                    // JSON null is treated as SQL NULL, other scalars can't be expanded.
+                                    if !is_null(val) {
+                                        ctx.set_error(
+                                            row,
+                                            "json_each expects an object or an array, but got a scalar",
+                                        );
+                                    }
+                                    (
+                                        Value::Scalar(Scalar::Tuple(vec![Scalar::Null, Scalar::Null])),
+                                        0,
+                                    )
+                                }
                             }
                             _ => unreachable!(),
                         })
@@ -454,12 +511,66 @@ pub(crate) fn unnest_variant_array(
     }
 }
 
+/// Expands the elements of an array into their text representation,
+/// strings are unquoted and JSON nulls become SQL NULLs.
+fn unnest_variant_array_text(
+    val: &[u8],
+    row: usize,
+    max_nums_per_row: &mut [usize],
+) -> (Value<AnyType>, usize) {
+    match array_values(val) {
+        Some(vals) if !vals.is_empty() => {
+            let len = vals.len();
+            let mut builder = NullableColumnBuilder::<StringType>::with_capacity(len, &[]);
+
+            max_nums_per_row[row] = std::cmp::max(max_nums_per_row[row], len);
+
+            for val in vals {
+                if is_null(&val) {
+                    builder.push_null();
+                } else {
+                    let text = to_str(&val).unwrap_or_else(|_| to_string(&val));
+                    builder.push(&text);
+                }
+            }
+
+            let col = NullableType::<StringType>::upcast_column(builder.build());
+            (Value::Column(Column::Tuple(vec![col])), len)
+        }
+        _ => (Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0),
+    }
+}
+
 fn unnest_variant_obj(
     val: &[u8],
     row: usize,
     max_nums_per_row: &mut [usize],
 ) -> (Value<AnyType>, usize) {
-    match object_each(val) {
+    unnest_variant_pairs(object_each(val), row, max_nums_per_row)
+}
+
+/// Expands the elements of an array into `(index, value)` pairs, the index is a string
+/// so that arrays share the same `(key, value)` schema with objects.
+fn unnest_variant_array_with_index(
+    val: &[u8],
+    row: usize,
+    max_nums_per_row: &mut [usize],
+) -> (Value<AnyType>, usize) {
+    let pairs = array_values(val).map(|vals| {
+        vals.into_iter()
+            .enumerate()
+            .map(|(idx, val)| (idx.to_string().into_bytes(), val))
+            .collect()
+    });
+    unnest_variant_pairs(pairs, row, max_nums_per_row)
+}
+
+fn unnest_variant_pairs(
+    pairs: Option<Vec<(Vec<u8>, Vec<u8>)>>,
+    row: usize,
+    max_nums_per_row: &mut [usize],
+) -> (Value<AnyType>, usize) {
+    match pairs {
         Some(vals) if !vals.is_empty() => {
             let len = vals.len();
             let mut val_builder = BinaryColumnBuilder::with_capacity(0, 0);
@@ -33,12 +33,14 @@ fn test_array() {
     test_contains(file);
     test_array_remove_first(file);
     test_array_remove_last(file);
+    test_array_reverse(file);
     test_array_concat(file);
     test_array_prepend(file);
     test_array_append(file);
     test_array_indexof(file);
     test_array_unique(file);
     test_array_distinct(file);
+    test_array_compact(file);
     test_array_sum(file);
     test_array_avg(file);
     test_array_count(file);
@@ -148,6 +150,12 @@ fn test_array_remove_last(file: &mut impl Write) {
     ]);
 }
 
+fn test_array_reverse(file: &mut impl Write) {
+    run_ast(file, "array_reverse([])", &[]);
+    run_ast(file, "array_reverse([1, 2, 3])", &[]);
+    run_ast(file, "array_reverse(['a', 'b', 'c', 'd'])", &[]);
+}
+
 fn test_contains(file: &mut impl Write) {
     run_ast(file, "false in (false, true)", &[]);
     run_ast(file, "'33' in ('1', '33', '23', '33')", &[]);
@@ -330,6 +338,13 @@ fn test_array_distinct(file: &mut impl Write) {
     ]);
 }
 
+fn test_array_compact(file: &mut impl Write) {
+    run_ast(file, "array_compact([])", &[]);
+    run_ast(file, "array_compact([NULL, NULL])", &[]);
+    run_ast(file, "array_compact([1, NULL, 2, NULL])", &[]);
+    run_ast(file, "array_remove_nulls(['a', NULL, 'b'])", &[]);
+}
+
 fn test_array_sum(file: &mut impl Write) {
     run_ast(file, "array_sum([])", &[]);
     run_ast(file, "array_sum([1, 2, 3, 4, 5, 6, 7])", &[]);
@@ -23,6 +23,7 @@ use databend_common_ast::ast::UnaryOperator;
 use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
+use databend_common_ast::Span;
 use databend_common_expression::shrink_scalar;
 use databend_common_expression::type_check;
 use databend_common_expression::types::decimal::DecimalDataType;
@@ -227,14 +228,27 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
             span,
             substr_expr,
             str_expr,
-        } => RawExpr::FunctionCall {
-            span,
-            name: "position".to_string(),
-            params: vec![],
-            args: vec![
-                transform_expr(*substr_expr, columns),
-                transform_expr(*str_expr, columns),
-            ],
+            position_from,
+        } => match position_from {
+            Some(position_from) => RawExpr::FunctionCall {
+                span,
+                name: "locate".to_string(),
+                params: vec![],
+                args: vec![
+                    transform_expr(*substr_expr, columns),
+                    transform_expr(*str_expr, columns),
+                    transform_expr(*position_from, columns),
+                ],
+            },
+            None => RawExpr::FunctionCall {
+                span,
+                name: "position".to_string(),
+                params: vec![],
+                args: vec![
+                    transform_expr(*substr_expr, columns),
+                    transform_expr(*str_expr, columns),
+                ],
+            },
         },
         AExpr::Trim {
             span,
@@ -350,6 +364,9 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
             expr,
             accessor,
         } => {
+            if has_wildcard_accessor(&expr, &accessor) {
+                return transform_wildcard_access(span, *expr, accessor, columns);
+            }
             let (params, args) = match accessor {
                 MapAccessor::Bracket { key } => (vec![], vec![
                     transform_expr(*expr, columns),
@@ -365,6 +382,7 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
                 MapAccessor::DotNumber { key } => {
                     (vec![key as i64], vec![transform_expr(*expr, columns)])
                 }
+                MapAccessor::Wildcard => unreachable!(),
             };
             let params = params
                 .into_iter()
@@ -521,6 +539,71 @@ pub fn transform_expr(ast: AExpr, columns: &[(&str, DataType)]) -> RawExpr {
     }
 }
 
+fn has_wildcard_accessor(expr: &AExpr, accessor: &MapAccessor) -> bool {
+    match (expr, accessor) {
+        (_, MapAccessor::Wildcard) => true,
+        (AExpr::MapAccess { expr, accessor, .. }, _) => has_wildcard_accessor(expr, accessor),
+        _ => false,
+    }
+}
+
+/// Rewrite the chain of accessors with a wildcard as `json_path_query_array`, as the
+/// type checker does for the variant map access.
+fn transform_wildcard_access(
+    span: Span,
+    expr: AExpr,
+    accessor: MapAccessor,
+    columns: &[(&str, DataType)],
+) -> RawExpr {
+    let mut accessors = vec![accessor];
+    let mut expr = expr;
+    while let AExpr::MapAccess {
+        expr: inner,
+        accessor,
+        ..
+    } = expr
+    {
+        accessors.push(accessor);
+        expr = *inner;
+    }
+
+    let mut json_path = "$".to_string();
+    for accessor in accessors.into_iter().rev() {
+        match accessor {
+            MapAccessor::Bracket { key } => match *key {
+                AExpr::Literal {
+                    value: ASTLiteral::UInt64(idx),
+                    ..
+                } => json_path.push_str(&format!("[{idx}]")),
+                AExpr::Literal {
+                    value: ASTLiteral::String(name),
+                    ..
+                } => json_path.push_str(&format!("[\"{}\"]", escape_path_name(&name))),
+                key => unimplemented!("{key:?} is not supported as a key of wildcard access"),
+            },
+            MapAccessor::DotNumber { key } => json_path.push_str(&format!("[{key}]")),
+            MapAccessor::Colon { key } => {
+                json_path.push_str(&format!("[\"{}\"]", escape_path_name(&key.name)))
+            }
+            MapAccessor::Wildcard => json_path.push_str("[*]"),
+        }
+    }
+
+    RawExpr::FunctionCall {
+        span,
+        name: "json_path_query_array".to_string(),
+        params: vec![],
+        args: vec![transform_expr(expr, columns), RawExpr::Constant {
+            span,
+            scalar: Scalar::String(json_path),
+        }],
+    }
+}
+
+fn escape_path_name(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
 fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataType {
     match target_type {
         databend_common_ast::ast::TypeName::Boolean => DataType::Boolean,
@@ -376,6 +376,8 @@ fn test_regexp_replace(file: &mut impl Write) {
         "regexp_replace('abc def ghi', '[a-z]+', 'X', 1, 3)",
         &[],
     );
+    run_ast(file, "regexp_replace('abc abc', '^a|c$', 'X')", &[]);
+    run_ast(file, "regexp_replace('abc abc', '^a', 'X', 5)", &[]);
 
     let three_columns = &[
         (
@@ -71,6 +71,8 @@ fn test_upper(file: &mut impl Write) {
         "a",
         StringType::from_data(vec!["Abc", "Dobrý den", "ß😀山"]),
     )]);
+    run_ast(file, "upper_binary('Dobrý den')", &[]);
+    run_ast(file, "upper_binary('ß😀山')", &[]);
 }
 
 fn test_lower(file: &mut impl Write) {
@@ -82,6 +84,8 @@ fn test_lower(file: &mut impl Write) {
         "a",
         StringType::from_data(vec!["Abc", "DOBRÝ DEN", "İ😀山"]),
     )]);
+    run_ast(file, "lower_binary('DOBRÝ DEN')", &[]);
+    run_ast(file, "lower_binary('İ😀山')", &[]);
 }
 
 fn test_bit_length(file: &mut impl Write) {
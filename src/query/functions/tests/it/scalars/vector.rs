@@ -26,6 +26,7 @@ fn test_vector() {
     let file = &mut mint.new_goldenfile("vector.txt").unwrap();
 
     test_vector_cosine_distance(file);
+    test_vector_norm(file);
 }
 
 fn test_vector_cosine_distance(file: &mut impl Write) {
@@ -34,3 +35,10 @@ fn test_vector_cosine_distance(file: &mut impl Write) {
         ("b", Float32Type::from_data(vec![3f32, 4.0, 5.0])),
     ]);
 }
+
+fn test_vector_norm(file: &mut impl Write) {
+    run_ast(file, "vector_norm([a, b])", &[
+        ("a", Float64Type::from_data(vec![3f64, 0.0, 6.0])),
+        ("b", Float64Type::from_data(vec![4f64, 0.0, 8.0])),
+    ]);
+}
@@ -1149,6 +1149,8 @@ fn wrap_is_true(expr: Expr) -> Expr {
             params: vec![],
             window: None,
             lambda: None,
+            order_by: vec![],
+            filter: None,
         },
     }
 }
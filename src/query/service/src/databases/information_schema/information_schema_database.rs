@@ -22,6 +22,8 @@ use databend_common_meta_app::tenant::Tenant;
 use databend_common_storages_information_schema::ColumnsTable;
 use databend_common_storages_information_schema::KeyColumnUsageTable;
 use databend_common_storages_information_schema::KeywordsTable;
+use databend_common_storages_information_schema::ParametersTable;
+use databend_common_storages_information_schema::RoutinesTable;
 use databend_common_storages_information_schema::SchemataTable;
 use databend_common_storages_information_schema::StatisticsTable;
 use databend_common_storages_information_schema::TablesTable;
@@ -46,6 +48,8 @@ impl InformationSchemaDatabase {
             SchemataTable::create(sys_db_meta.next_table_id()),
             StatisticsTable::create(sys_db_meta.next_table_id()),
             KeyColumnUsageTable::create(sys_db_meta.next_table_id()),
+            RoutinesTable::create(sys_db_meta.next_table_id()),
+            ParametersTable::create(sys_db_meta.next_table_id()),
         ];
 
         let db = "information_schema";
@@ -28,6 +28,7 @@ use databend_common_meta_app::schema::CatalogType;
 use databend_common_sharing::ShareEndpointManager;
 use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
+use databend_common_storages_fuse::io::DataEncryption;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
 use databend_common_tracing::GlobalLogger;
@@ -128,6 +129,7 @@ impl GlobalServices {
             &config.query.max_server_memory_usage,
             config.query.tenant_id.tenant_name().to_string(),
         )?;
+        DataEncryption::init(&config.query.data_encryption_keys)?;
 
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr, config.query.cloud_control_grpc_timeout).await?;
@@ -61,6 +61,7 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::Call
                             | RewriteKind::ShowRoles
                             | RewriteKind::ShowLocks
+                            | RewriteKind::ShowDataCache
                             | RewriteKind::ShowStreams(_)),
                             _ => false
                         }
@@ -118,6 +119,7 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+                | Plan::DescribeUDF(_)
                 | Plan::UseDatabase(_) => true,
                 Plan::DescribeTable(plan) => {
                     let catalog = &plan.catalog;
@@ -56,7 +56,7 @@ enum ObjectId {
 // some statements like `SELECT 1`, `SHOW USERS`, `SHOW ROLES`, `SHOW TABLES` will be
 // rewritten to the queries on the system tables, we need to skip the privilege check on
 // these tables.
-const SYSTEM_TABLES_ALLOW_LIST: [&str; 19] = [
+const SYSTEM_TABLES_ALLOW_LIST: [&str; 20] = [
     "catalogs",
     "columns",
     "databases",
@@ -74,6 +74,7 @@ const SYSTEM_TABLES_ALLOW_LIST: [&str; 19] = [
     "one",
     "processes",
     "user_functions",
+    "user_function_parameters",
     "functions",
     "indexes",
 ];
@@ -738,6 +739,12 @@ impl AccessChecker for PrivilegeAccess {
                                         .await?;
                 }
             }
+            Plan::DescribeUDF(plan) => {
+                if enable_experimental_rbac_check {
+                    let udf = HashSet::from([&plan.udf]);
+                    self.validate_udf_access(udf).await?;
+                }
+            }
             Plan::DropStage(plan) => {
                 match UserApiProvider::instance().get_stage(&tenant, &plan.name).await {
                     Ok(stage) => {
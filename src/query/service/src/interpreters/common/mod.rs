@@ -16,6 +16,7 @@ mod grant;
 mod metrics;
 mod notification;
 mod query_log;
+mod rename;
 mod stream;
 mod table;
 mod task;
@@ -26,9 +27,12 @@ mod shared_table;
 pub use grant::validate_grant_object_exists;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
+pub use rename::collect_rename_dependents;
+pub use rename::RenamedObject;
 pub use shared_table::save_share_table_info;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
+pub use stream::truncate_build_restart_stream_req;
 pub use stream::StreamTableUpdates;
 pub use table::check_referenced_computed_columns;
 pub use task::get_task_client_config;
@@ -18,6 +18,7 @@ use std::time::SystemTime;
 
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_config::QUERY_GIT_SHA;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_storages_system::LogType;
@@ -129,6 +130,9 @@ impl InterpreterQueryLog {
         let bytes_from_disk_cache = 0;
         let bytes_from_mem_cache = 0;
 
+        // Meta requests made while planning.
+        let meta_call_metrics = ctx.get_meta_call_metrics();
+
         // Client.
         let client_address = match ctx.get_client_address() {
             Some(addr) => addr,
@@ -204,6 +208,9 @@ impl InterpreterQueryLog {
             bytes_from_remote_disk: bytes_from_storage,
             bytes_from_local_disk: bytes_from_disk_cache,
             bytes_from_memory: bytes_from_mem_cache,
+            meta_call_count: meta_call_metrics.calls,
+            meta_call_retries: meta_call_metrics.retries,
+            meta_call_duration_ms: meta_call_metrics.duration_ms,
 
             client_info: "".to_string(),
             client_address,
@@ -213,6 +220,7 @@ impl InterpreterQueryLog {
             exception_text,
             stack_trace,
             server_version: DATABEND_COMMIT_VERSION.to_string(),
+            server_git_sha: QUERY_GIT_SHA.to_string(),
             session_settings,
             extra: "".to_string(),
             has_profiles: false,
@@ -290,6 +298,9 @@ impl InterpreterQueryLog {
         let bytes_from_local_disk = data_cache_metrics.bytes_from_local_disk as u64;
         let bytes_from_memory = data_cache_metrics.bytes_from_memory as u64;
 
+        // Meta requests made while planning.
+        let meta_call_metrics = ctx.get_meta_call_metrics();
+
         // Client.
         let client_address = match ctx.get_client_address() {
             Some(addr) => addr,
@@ -370,6 +381,9 @@ impl InterpreterQueryLog {
             bytes_from_remote_disk,
             bytes_from_local_disk,
             bytes_from_memory,
+            meta_call_count: meta_call_metrics.calls,
+            meta_call_retries: meta_call_metrics.retries,
+            meta_call_duration_ms: meta_call_metrics.duration_ms,
 
             client_info: "".to_string(),
             client_address,
@@ -380,6 +394,7 @@ impl InterpreterQueryLog {
             exception_text,
             stack_trace,
             server_version: DATABEND_COMMIT_VERSION.to_string(),
+            server_git_sha: QUERY_GIT_SHA.to_string(),
             session_settings,
             extra: "".to_string(),
             has_profiles,
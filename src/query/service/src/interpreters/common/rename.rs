@@ -0,0 +1,348 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::CTE;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_ast::Span;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::schema::ListIndexesReq;
+use databend_common_meta_app::schema::RenameDependents;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::persisted_definition::definition_checksum;
+use databend_common_sql::NameResolutionContext;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::QUERY_CHECKSUM;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use log::info;
+
+use crate::sessions::QueryContext;
+
+/// A database or table that is renamed in `catalog`.
+#[derive(Debug, Clone)]
+pub enum RenamedObject {
+    Database {
+        catalog: String,
+        database: String,
+        new_database: String,
+    },
+    Table {
+        catalog: String,
+        database: String,
+        table: String,
+        new_database: String,
+        new_table: String,
+    },
+}
+
+impl RenamedObject {
+    fn catalog(&self) -> &str {
+        match self {
+            RenamedObject::Database { catalog, .. } => catalog,
+            RenamedObject::Table { catalog, .. } => catalog,
+        }
+    }
+
+    fn rename_grant_object(&self, object: &GrantObject) -> Option<GrantObject> {
+        match (self, object) {
+            (
+                RenamedObject::Database {
+                    catalog,
+                    database,
+                    new_database,
+                },
+                GrantObject::Database(c, d),
+            ) if c == catalog && d == database => {
+                Some(GrantObject::Database(c.clone(), new_database.clone()))
+            }
+            (
+                RenamedObject::Database {
+                    catalog,
+                    database,
+                    new_database,
+                },
+                GrantObject::Table(c, d, t),
+            ) if c == catalog && d == database => Some(GrantObject::Table(
+                c.clone(),
+                new_database.clone(),
+                t.clone(),
+            )),
+            (
+                RenamedObject::Table {
+                    catalog,
+                    database,
+                    table,
+                    new_database,
+                    new_table,
+                },
+                GrantObject::Table(c, d, t),
+            ) if c == catalog && d == database && t == table => Some(GrantObject::Table(
+                c.clone(),
+                new_database.clone(),
+                new_table.clone(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Rewrite the table references of a persisted query that point to the renamed object.
+#[derive(VisitorMut)]
+#[visitor(CTE(enter), TableReference(enter))]
+struct RenameRewriter {
+    object: RenamedObject,
+    name_resolution_ctx: NameResolutionContext,
+    // The database of the unqualified table references, None if they are not rewritten.
+    default_database: Option<String>,
+    cte_names: HashSet<String>,
+    changed: bool,
+}
+
+impl RenameRewriter {
+    fn normalize(&self, ident: &Identifier) -> String {
+        normalize_identifier(ident, &self.name_resolution_ctx).name
+    }
+
+    fn enter_cte(&mut self, cte: &mut CTE) {
+        self.cte_names.insert(self.normalize(&cte.alias.name));
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        let TableReference::Table {
+            span,
+            catalog,
+            database,
+            table,
+            ..
+        } = table_ref
+        else {
+            return;
+        };
+        if let Some(catalog) = catalog {
+            if self.normalize(catalog) != self.object.catalog() {
+                return;
+            }
+        }
+
+        let db_name = match database {
+            Some(database) => self.normalize(database),
+            None => {
+                if catalog.is_some() || self.cte_names.contains(&self.normalize(table)) {
+                    return;
+                }
+                match &self.default_database {
+                    Some(default_database) => default_database.clone(),
+                    None => return,
+                }
+            }
+        };
+        match &self.object {
+            RenamedObject::Database {
+                database: old_database,
+                new_database,
+                ..
+            } => {
+                // The unqualified references follow the database of the query.
+                if let Some(database) = database {
+                    if &db_name == old_database {
+                        *database = quoted_identifier(database.span, new_database);
+                        self.changed = true;
+                    }
+                }
+            }
+            RenamedObject::Table {
+                database: old_database,
+                table: old_table,
+                new_database,
+                new_table,
+                ..
+            } => {
+                if &db_name == old_database && &self.normalize(table) == old_table {
+                    // Qualify the unqualified reference if the table is moved to another
+                    // database.
+                    if database.is_some() || new_database != old_database {
+                        *database = Some(quoted_identifier(*span, new_database));
+                    }
+                    *table = quoted_identifier(table.span, new_table);
+                    self.changed = true;
+                }
+            }
+        }
+    }
+
+    /// Returns the rewritten query, or None if the query does not reference the renamed object.
+    ///
+    /// The unqualified table references are resolved in `default_database`.
+    fn rewrite(
+        &mut self,
+        query: &str,
+        default_database: Option<&str>,
+        dialect: Dialect,
+    ) -> Option<String> {
+        let tokens = tokenize_sql(query).ok()?;
+        let (Statement::Query(mut query), _) = parse_sql(&tokens, dialect).ok()? else {
+            return None;
+        };
+        self.default_database = default_database.map(|db| db.to_string());
+        self.cte_names.clear();
+        self.changed = false;
+        query.drive_mut(self);
+        self.changed.then(|| query.to_string())
+    }
+}
+
+fn quoted_identifier(span: Span, name: &str) -> Identifier {
+    Identifier::from_name_with_quoted(span, name, Some('`'))
+}
+
+/// Collect the updates of the views, aggregating indexes and grants referencing the renamed
+/// object by name. They are committed together with the rename in one meta transaction, so
+/// queries never see a half-renamed state.
+///
+/// Moving a table that has streams to another database is blocked until the streams are
+/// dropped, because streams are bound to the database of their base table.
+///
+/// No reverse index from a table to the views and streams referencing it exists, so all the
+/// tables in the catalog are scanned once.
+#[async_backtrace::framed]
+pub async fn collect_rename_dependents(
+    ctx: &Arc<QueryContext>,
+    catalog: &Arc<dyn Catalog>,
+    object: &RenamedObject,
+) -> Result<RenameDependents> {
+    let tenant = ctx.get_tenant();
+    let settings = ctx.get_settings();
+    let dialect = settings.get_sql_dialect()?;
+    let mut rewriter = RenameRewriter {
+        object: object.clone(),
+        name_resolution_ctx: NameResolutionContext::try_from(settings.as_ref())?,
+        default_database: None,
+        cte_names: HashSet::new(),
+        changed: false,
+    };
+
+    let moved_table_id = match object {
+        RenamedObject::Table {
+            database,
+            table,
+            new_database,
+            ..
+        } if database != new_database => match catalog.get_table(&tenant, database, table).await {
+            Ok(table) => Some(table.get_id().to_string()),
+            // Let the rename report the unknown table.
+            Err(_) => return Ok(RenameDependents::default()),
+        },
+        _ => None,
+    };
+
+    let mut dependents = RenameDependents::default();
+    let mut streams = vec![];
+    for db in catalog.list_databases(&tenant).await? {
+        for table in db.list_tables().await? {
+            if table.engine() == STREAM_ENGINE {
+                if moved_table_id.is_some()
+                    && table.options().get(OPT_KEY_SOURCE_TABLE_ID) == moved_table_id.as_ref()
+                {
+                    streams.push(format!("'{}'.'{}'", db.name(), table.name()));
+                }
+                continue;
+            }
+            if table.engine() != VIEW_ENGINE {
+                continue;
+            }
+            let Some(query) = table.options().get(QUERY) else {
+                continue;
+            };
+            if let Some(new_query) = rewriter.rewrite(query, Some(db.name()), dialect) {
+                info!(
+                    "Update view '{}'.'{}' after rename {:?}",
+                    db.name(),
+                    table.name(),
+                    object
+                );
+                let meta = &table.get_table_info().meta;
+                let mut new_meta = meta.clone();
+                new_meta.options.insert(
+                    QUERY_CHECKSUM.to_string(),
+                    definition_checksum(&new_query).to_string(),
+                );
+                new_meta.options.insert(QUERY.to_string(), new_query);
+                dependents
+                    .views
+                    .push((table.get_id(), meta.clone(), new_meta));
+            }
+        }
+    }
+
+    if !streams.is_empty() {
+        let RenamedObject::Table {
+            database, table, ..
+        } = object
+        else {
+            unreachable!("only tables are moved");
+        };
+        return Err(ErrorCode::IllegalStream(format!(
+            "Cannot move table '{}'.'{}' to another database, it is depended on by streams: {}",
+            database,
+            table,
+            streams.join(", ")
+        )));
+    }
+
+    for (index_id, index_name, index_meta) in catalog
+        .list_indexes(ListIndexesReq::new(&tenant, None))
+        .await?
+    {
+        // The queries of aggregating indexes are always qualified.
+        let Some(query) = rewriter.rewrite(&index_meta.query, None, dialect) else {
+            continue;
+        };
+        let mut new_index_meta = index_meta.clone();
+        if let Some(original_query) = rewriter.rewrite(&index_meta.original_query, None, dialect) {
+            new_index_meta.original_query = original_query;
+        }
+        new_index_meta.query = query;
+        info!("Update index '{}' after rename {:?}", index_name, object);
+        dependents
+            .indexes
+            .push((index_id, index_meta, new_index_meta));
+    }
+
+    let rename = |grant_object: &GrantObject| object.rename_grant_object(grant_object);
+    let user_api = UserApiProvider::instance();
+    dependents.users = user_api
+        .rename_grant_objects_of_users(&tenant, &rename)
+        .await?;
+    dependents.roles = user_api
+        .rename_grant_objects_of_roles(&tenant, &rename)
+        .await?;
+
+    Ok(dependents)
+}
@@ -98,6 +98,53 @@ pub async fn dml_build_update_stream_req(
     Ok(reqs)
 }
 
+/// Build the requests to restart the streams on the source table from its truncated version,
+/// the requests are committed together with the truncation.
+pub async fn truncate_build_restart_stream_req(
+    ctx: Arc<QueryContext>,
+    catalog_name: &str,
+    source_table: &FuseTable,
+) -> Result<Vec<UpdateStreamMetaReq>> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let source_table_id = source_table.get_id();
+    // The streams are committed with the truncation, so the streams start from the
+    // table version before the truncation, and without snapshot location all the
+    // blocks of the truncated table are treated as appended.
+    let table_version = source_table.get_table_info().ident.seq;
+
+    let mut reqs = vec![];
+    for db in catalog.list_databases(&tenant).await? {
+        for table in catalog.list_tables(&tenant, db.name()).await? {
+            if table.engine() != STREAM_ENGINE {
+                continue;
+            }
+            let stream = StreamTable::try_from_table(table.as_ref())?;
+            if !matches!(stream.source_table_id(), Ok(id) if id == source_table_id) {
+                continue;
+            }
+
+            let stream_info = stream.get_table_info();
+            let mut options = stream.options().clone();
+            options.insert(OPT_KEY_TABLE_VER.to_string(), table_version.to_string());
+            options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+            reqs.push(UpdateStreamMetaReq {
+                stream_id: stream_info.ident.table_id,
+                seq: MatchSeq::Exact(stream_info.ident.seq),
+                options,
+            });
+        }
+    }
+
+    if !reqs.is_empty() {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(ctx.get_license_key(), Feature::Stream)?;
+    }
+    Ok(reqs)
+}
+
 fn get_stream_table<F>(metadata: &MetadataRef, pred: F) -> Result<Vec<Arc<dyn Table>>>
 where F: Fn(&TableEntry) -> bool {
     let r_lock = metadata.read();
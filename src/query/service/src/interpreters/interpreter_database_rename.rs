@@ -18,7 +18,10 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_sql::plans::RenameDatabasePlan;
+use databend_common_users::RoleCacheManager;
 
+use crate::interpreters::common::collect_rename_dependents;
+use crate::interpreters::common::RenamedObject;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -50,13 +53,30 @@ impl Interpreter for RenameDatabaseInterpreter {
         for entity in &self.plan.entities {
             let catalog = self.ctx.get_catalog(&entity.catalog).await?;
             let tenant = self.plan.tenant.clone();
+            if !catalog.exists_database(&tenant, &entity.database).await? && entity.if_exists {
+                continue;
+            }
+            let dependents =
+                collect_rename_dependents(&self.ctx, &catalog, &RenamedObject::Database {
+                    catalog: entity.catalog.clone(),
+                    database: entity.database.clone(),
+                    new_database: entity.new_database.clone(),
+                })
+                .await?;
+            let update_grants = !dependents.users.is_empty() || !dependents.roles.is_empty();
+
             catalog
                 .rename_database(RenameDatabaseReq {
                     if_exists: entity.if_exists,
-                    name_ident: DatabaseNameIdent::new(tenant, &entity.database),
+                    name_ident: DatabaseNameIdent::new(&tenant, &entity.database),
                     new_db_name: entity.new_database.clone(),
+                    dependents,
                 })
                 .await?;
+
+            if update_grants {
+                RoleCacheManager::instance().invalidate_cache(&tenant);
+            }
         }
 
         Ok(PipelineBuildResult::create())
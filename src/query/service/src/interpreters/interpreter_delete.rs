@@ -238,6 +238,7 @@ impl Interpreter for DeleteInterpreter {
                     self.ctx.clone(),
                     &mut build_res.main_pipeline,
                     TruncateMode::Delete,
+                    vec![],
                 )
                 .await?;
             return Ok(build_res);
@@ -266,6 +267,7 @@ impl Interpreter for DeleteInterpreter {
                         self.ctx.clone(),
                         &mut build_res.main_pipeline,
                         TruncateMode::Delete,
+                        vec![],
                     )
                     .await?;
                 return Ok(build_res);
@@ -217,6 +217,8 @@ impl Client for ScriptClient {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             },
             Scalar::Array(v) => Expr::FunctionCall {
@@ -231,6 +233,8 @@ impl Client for ScriptClient {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             },
             Scalar::Decimal(DecimalScalar::Decimal128(v, size)) => Expr::Literal {
@@ -274,6 +278,8 @@ impl Client for ScriptClient {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             },
                             Expr::FunctionCall {
@@ -285,12 +291,16 @@ impl Client for ScriptClient {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             },
                         ],
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -309,6 +319,8 @@ impl Client for ScriptClient {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -321,6 +333,8 @@ impl Client for ScriptClient {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             },
             Scalar::EmptyMap => Expr::FunctionCall {
@@ -332,6 +346,8 @@ impl Client for ScriptClient {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             },
             Scalar::Date(v) => Expr::Cast {
@@ -346,6 +362,7 @@ impl Client for ScriptClient {
                 }),
                 target_type: TypeName::Date,
                 pg_style: false,
+                format: None,
             },
             Scalar::Timestamp(v) => Expr::Cast {
                 span: None,
@@ -359,6 +376,7 @@ impl Client for ScriptClient {
                 }),
                 target_type: TypeName::Timestamp,
                 pg_style: false,
+                format: None,
             },
             Scalar::Null => Expr::Literal {
                 span: None,
@@ -132,6 +132,8 @@ impl Interpreter for ExplainInterpreter {
                         .await?
                 }
                 Plan::MergeInto(plan) => {
+                    // The source is not executed here, so the target is not pruned by the
+                    // source join keys, which is only done by real execution and EXPLAIN ANALYZE.
                     let mut res = self.explain_plan(&self.plan)?;
                     let input = self
                         .explain_query(&plan.input, &plan.meta_data, &plan.bind_context, &None)
@@ -160,6 +162,13 @@ impl Interpreter for ExplainInterpreter {
                 ))?,
             },
 
+            ExplainKind::Cte => match &self.plan {
+                Plan::Query { metadata, .. } => self.explain_cte(metadata)?,
+                _ => Err(ErrorCode::Unimplemented(
+                    "Unsupported EXPLAIN CTE statement",
+                ))?,
+            },
+
             ExplainKind::AnalyzePlan => match &self.plan {
                 Plan::Query {
                     s_expr,
@@ -177,13 +186,11 @@ impl Interpreter for ExplainInterpreter {
                     .await?
                 }
                 Plan::MergeInto(plan) => {
-                    self.explain_analyze(
-                        &plan.input,
-                        &plan.meta_data,
-                        *plan.columns_set.clone(),
-                        true,
-                    )
-                    .await?
+                    let input = MergeIntoInterpreter::try_create(self.ctx.clone(), *plan.clone())?
+                        .prune_target_by_source_keys(&plan.input)
+                        .await?;
+                    self.explain_analyze(&input, &plan.meta_data, *plan.columns_set.clone(), true)
+                        .await?
                 }
                 _ => Err(ErrorCode::Unimplemented(
                     "Unsupported EXPLAIN ANALYZE statement",
@@ -321,6 +328,28 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    pub fn explain_cte(&self, metadata: &MetadataRef) -> Result<Vec<DataBlock>> {
+        let result = metadata
+            .read()
+            .cte_materializations()
+            .iter()
+            .map(|info| {
+                format!(
+                    "{}: {}, {}",
+                    info.cte_name,
+                    if info.materialized {
+                        "materialized"
+                    } else {
+                        "inlined"
+                    },
+                    info.reason
+                )
+            })
+            .collect::<Vec<_>>();
+        let formatted_plan = StringType::from_data(result);
+        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+    }
+
     fn format_pipeline(build_res: &PipelineBuildResult) -> Vec<DataBlock> {
         let mut blocks = Vec::with_capacity(1 + build_res.sources_pipelines.len());
         // Format root pipeline
@@ -457,8 +486,31 @@ impl ExplainInterpreter {
         // It's because we need to get the same partitions as the original selecting plan.
         let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, formatted_ast.is_none());
         let plan = builder.build(s_expr, bind_context.column_set()).await?;
-        self.explain_physical_plan(&plan, metadata, formatted_ast)
-            .await
+        let mut res = self
+            .explain_physical_plan(&plan, metadata, formatted_ast)
+            .await?;
+
+        // Display the `TRY_CAST`s which may turn the values failed to be converted into NULLs.
+        if !bind_context.try_cast_diagnostics.is_empty() {
+            let children = bind_context
+                .try_cast_diagnostics
+                .iter()
+                .map(|diagnostic| {
+                    FormatTreeNode::new(format!(
+                        "{} -> {}",
+                        diagnostic.source_type, diagnostic.target_type
+                    ))
+                })
+                .collect();
+            let result = FormatTreeNode::with_children("TryCastDiagnostics".to_string(), children)
+                .format_pretty()?;
+            let line_split_result: Vec<&str> = result.lines().collect();
+            res.push(DataBlock::new_from_columns(vec![StringType::from_data(
+                line_split_result,
+            )]));
+            res = vec![DataBlock::concat(&res)?];
+        }
+        Ok(res)
     }
 
     #[async_backtrace::framed]
@@ -425,6 +425,9 @@ impl InterpreterFactory {
                 ctx,
                 *drop_udf.clone(),
             )?)),
+            Plan::DescribeUDF(describe_udf) => Ok(Arc::new(
+                DescribeUserUDFInterpreter::try_create(ctx, *describe_udf.clone())?,
+            )),
 
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
@@ -15,12 +15,24 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::FromData;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_expression::Value;
 use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::physical_plans::MutationKind;
@@ -38,6 +50,8 @@ use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
@@ -46,6 +60,7 @@ use crate::pipelines::ValueSource;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
 
 pub struct InsertInterpreter {
     ctx: Arc<QueryContext>,
@@ -217,9 +232,10 @@ impl Interpreter for InsertInterpreter {
                 let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
                 let catalog_info = catalog.info();
 
-                // here we remove the last exchange merge plan to trigger distribute insert
+                // here we remove the last exchange merge plan to trigger distribute insert,
+                // the skipped rows of `ON ERROR SKIP` are only collected in the local node.
                 let insert_select_plan = match select_plan {
-                    PhysicalPlan::Exchange(ref mut exchange) => {
+                    PhysicalPlan::Exchange(ref mut exchange) if self.plan.on_error.is_none() => {
                         // insert can be dispatched to different nodes
                         let input = exchange.input.clone();
                         exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(Box::new(
@@ -234,6 +250,7 @@ impl Interpreter for InsertInterpreter {
                                 select_column_bindings,
                                 insert_schema: self.plan.dest_schema(),
                                 cast_needed: self.check_schema_cast(plan)?,
+                                on_error: None,
                             },
                         )));
                         select_plan
@@ -251,6 +268,7 @@ impl Interpreter for InsertInterpreter {
                             select_column_bindings,
                             insert_schema: self.plan.dest_schema(),
                             cast_needed: self.check_schema_cast(plan)?,
+                            on_error: self.plan.on_error.clone(),
                         }))
                     }
                 };
@@ -269,6 +287,18 @@ impl Interpreter for InsertInterpreter {
                     unsafe { self.ctx.get_settings().get_deduplicate_label()? },
                 )?;
 
+                if let Some(on_error) = &self.plan.on_error {
+                    let ctx = self.ctx.clone();
+                    let log_table = on_error.log_table.clone();
+                    build_res.main_pipeline.set_on_finished(
+                        move |info: &ExecutionInfo| match &info.res {
+                            Ok(_) => GlobalIORuntime::instance()
+                                .block_on(log_insert_errors(ctx, log_table)),
+                            Err(_) => Ok(()),
+                        },
+                    );
+                }
+
                 //  Execute the hook operator.
                 {
                     let hook_operator = HookOperator::create(
@@ -319,4 +349,84 @@ impl Interpreter for InsertInterpreter {
 
         Ok(build_res)
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        if self.plan.on_error.is_none() {
+            return Ok(Box::pin(DataBlockStream::create(None, vec![])));
+        }
+        let error_log = self.ctx.get_insert_error_log();
+        let blocks = vec![DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![error_log.inserted_rows()]),
+            UInt64Type::from_data(vec![error_log.skipped_rows()]),
+        ])];
+        Ok(Box::pin(DataBlockStream::create(None, blocks)))
+    }
+}
+
+/// Reports the rows skipped by `INSERT INTO ... SELECT ... ON ERROR SKIP`,
+/// and writes them into the error table if there is one.
+async fn log_insert_errors(
+    ctx: Arc<QueryContext>,
+    log_table: Option<(String, String, String)>,
+) -> Result<()> {
+    let error_log = ctx.get_insert_error_log();
+    // Taken before the error table is written, which also counts into the write progress.
+    error_log.set_inserted_rows(ctx.get_write_progress_value().rows as u64);
+    let skipped_rows = error_log.skipped_rows();
+    if skipped_rows == 0 {
+        return Ok(());
+    }
+    ctx.push_warning(format!("{skipped_rows} rows are skipped because of errors"));
+
+    let Some((catalog, database, table)) = log_table else {
+        return Ok(());
+    };
+    let errors = error_log.take_errors();
+    if (errors.len() as u64) < skipped_rows {
+        ctx.push_warning(format!(
+            "only {} of the skipped rows are logged into {database}.{table}, \
+            the limit is set by max_insert_logged_errors",
+            errors.len()
+        ));
+    }
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let table = ctx.get_table(&catalog, &database, &table).await?;
+    let table_schema = table.schema();
+    let num_rows = errors.len();
+    let (messages, source_rows): (Vec<_>, Vec<_>) = errors
+        .into_iter()
+        .map(|error| (error.error, error.source_row))
+        .unzip();
+    let mut fields = Vec::with_capacity(2);
+    let mut entries = Vec::with_capacity(2);
+    for (name, values) in [("error", messages), ("source_row", source_rows)] {
+        let data_type = DataType::from(table_schema.field_with_name(name)?.data_type());
+        let mut column = StringType::from_data(values);
+        if data_type.is_nullable() {
+            column = column.wrap_nullable(None);
+        }
+        fields.push(DataField::new(name, data_type.clone()));
+        entries.push(BlockEntry::new(data_type, Value::Column(column)));
+    }
+
+    let mut build_res = PipelineBuildResult::from_blocks(vec![DataBlock::new(entries, num_rows)])?;
+    PipelineBuilder::build_append2table_with_commit_pipeline(
+        ctx.clone(),
+        &mut build_res.main_pipeline,
+        table,
+        DataSchemaRefExt::create(fields),
+        None,
+        vec![],
+        false,
+        AppendMode::Normal,
+        None,
+    )?;
+    let settings = ExecutorSettings::try_create(ctx.clone())?;
+    let executor =
+        PipelineCompleteExecutor::from_pipelines(vec![build_res.main_pipeline], settings)?;
+    ctx.set_executor(executor.get_inner())?;
+    executor.execute()
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::u64::MAX;
@@ -31,11 +32,14 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::FromData;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_expression::ROW_NUMBER_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::LockGuard;
+use databend_common_sql::binder::contain_subquery;
+use databend_common_sql::binder::ColumnBindingBuilder;
 use databend_common_sql::binder::MergeIntoType;
 use databend_common_sql::executor::physical_plans::CommitSink;
 use databend_common_sql::executor::physical_plans::Exchange;
@@ -45,27 +49,41 @@ use databend_common_sql::executor::physical_plans::MergeIntoAppendNotMatched;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::optimizer::RelExpr;
+use databend_common_sql::optimizer::SExpr;
 use databend_common_sql::plans;
+use databend_common_sql::plans::ConstantExpr;
+use databend_common_sql::plans::EvalScalar;
+use databend_common_sql::plans::FunctionCall;
 use databend_common_sql::plans::LockTableOption;
 use databend_common_sql::plans::MergeInto as MergePlan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::plans::ScalarItem;
+use databend_common_sql::BindContext;
 use databend_common_sql::IndexType;
 use databend_common_sql::ScalarExpr;
 use databend_common_sql::TypeCheck;
+use databend_common_sql::Visibility;
 use databend_common_sql::DUMMY_COLUMN_INDEX;
 use databend_common_sql::DUMMY_TABLE_INDEX;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use futures_util::TryStreamExt;
 use itertools::Itertools;
 
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::stream::DataBlockStream;
+use crate::stream::PullingExecutorStream;
 
 // predicate_index should not be conflict with update expr's column_binding's index.
 pub const PREDICATE_COLUMN_INDEX: IndexType = MAX as usize;
@@ -221,7 +239,7 @@ impl MergeIntoInterpreter {
         let update_stream_meta = dml_build_update_stream_req(self.ctx.clone(), meta_data).await?;
 
         let table_name = table_name.clone();
-        let input = input.clone();
+        let input = self.prune_target_by_source_keys(input).await?;
 
         let mut builder = PhysicalPlanBuilder::new(meta_data.clone(), self.ctx.clone(), false);
         let join_input = builder.build(&input, *columns_set.clone()).await?;
@@ -472,6 +490,137 @@ impl MergeIntoInterpreter {
         Ok((physical_plan, lock_guard))
     }
 
+    /// Prunes the target table by the join keys of a small source.
+    ///
+    /// Only the target rows whose keys appear in the source can be matched, so the keys
+    /// collected from the source are pushed down to the target scan as predicates, then
+    /// the blocks without any of them are pruned by the range index and the bloom index.
+    /// All the matched rows are still read, so a target row matched by multiple source
+    /// rows is reported as before.
+    pub async fn prune_target_by_source_keys(&self, input: &SExpr) -> Result<SExpr> {
+        let max_rows = self
+            .ctx
+            .get_settings()
+            .get_merge_into_source_pruning_max_rows()?;
+        let RelOperator::Join(join) = input.plan() else {
+            return Ok(input.clone());
+        };
+        if max_rows == 0 || input.arity() != 2 {
+            return Ok(input.clone());
+        }
+
+        let target_table_idx = self.plan.target_table_idx;
+        let (target_side, target_conditions, source_conditions) =
+            if contains_table_scan(input.child(0)?, target_table_idx) {
+                (0, &join.left_conditions, &join.right_conditions)
+            } else {
+                (1, &join.right_conditions, &join.left_conditions)
+            };
+        let source = input.child(1 - target_side)?;
+        // The source is executed once more to collect the keys, so it must be cheap and
+        // produce the same rows in both runs.
+        if !is_deterministic_source(source)
+            || RelExpr::with_s_expr(source)
+                .derive_cardinality()?
+                .cardinality
+                > max_rows as f64
+        {
+            return Ok(input.clone());
+        }
+
+        // Only the keys which are columns of the target table can be pushed down.
+        let (target_keys, source_keys): (Vec<_>, Vec<_>) = target_conditions
+            .iter()
+            .zip(source_conditions.iter())
+            .enumerate()
+            .filter(|(idx, (target, _))| {
+                !join.is_null_equal.contains(idx)
+                    && matches!(target, ScalarExpr::BoundColumnRef(column)
+                        if column.column.table_index == Some(target_table_idx))
+            })
+            .map(|(_, (target, source))| (target.clone(), source.clone()))
+            .unzip();
+        if target_keys.is_empty() {
+            return Ok(input.clone());
+        }
+
+        let source_keys = self.collect_source_keys(source, source_keys).await?;
+        let predicates = target_keys
+            .into_iter()
+            .zip(source_keys)
+            .map(|(target_key, values)| build_key_predicate(target_key, values))
+            .collect::<Vec<_>>();
+        log::info!(
+            "prune the target table of merge into by {} source keys",
+            predicates.len()
+        );
+
+        let target =
+            push_down_target_predicates(input.child(target_side)?, target_table_idx, &predicates);
+        let mut children = vec![
+            Arc::new(input.child(0)?.clone()),
+            Arc::new(input.child(1)?.clone()),
+        ];
+        children[target_side] = Arc::new(target);
+        Ok(input.replace_children(children))
+    }
+
+    // Execute the source and collect the distinct non-null values of each key.
+    async fn collect_source_keys(
+        &self,
+        source: &SExpr,
+        source_keys: Vec<ScalarExpr>,
+    ) -> Result<Vec<BTreeSet<Scalar>>> {
+        let mut bind_context = BindContext::new();
+        let mut items = Vec::with_capacity(source_keys.len());
+        for (idx, key) in source_keys.into_iter().enumerate() {
+            let name = format!("_source_key_{idx}");
+            let data_type = key.data_type()?;
+            let index = self.plan.meta_data.write().add_derived_column(
+                name.clone(),
+                data_type.clone(),
+                Some(key.clone()),
+            );
+            bind_context.add_column_binding(
+                ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+                    .build(),
+            );
+            items.push(ScalarItem { scalar: key, index });
+        }
+        let s_expr = SExpr::create_unary(
+            Arc::new(RelOperator::EvalScalar(EvalScalar { items })),
+            Arc::new(source.clone()),
+        );
+
+        let mut builder =
+            PhysicalPlanBuilder::new(self.plan.meta_data.clone(), self.ctx.clone(), false);
+        let physical_plan = builder.build(&s_expr, bind_context.column_set()).await?;
+        let pipeline =
+            build_query_pipeline(&self.ctx, &bind_context.columns, &physical_plan, false).await?;
+
+        let settings = ExecutorSettings::try_create(self.ctx.clone())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(pipeline, settings)?;
+        self.ctx.set_executor(pulling_executor.get_inner())?;
+        let blocks = PullingExecutorStream::create(pulling_executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        let mut keys = vec![BTreeSet::new(); bind_context.columns.len()];
+        for block in blocks {
+            for (values, entry) in keys.iter_mut().zip(block.columns()) {
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                for value in column.iter() {
+                    if !value.is_null() {
+                        values.insert(value.to_owned());
+                    }
+                }
+            }
+        }
+        Ok(keys)
+    }
+
     fn transform_scalar_expr2expr(
         &self,
         scalar_expr: &ScalarExpr,
@@ -510,3 +659,104 @@ impl MergeIntoInterpreter {
         Ok(vec![DataBlock::new_from_columns(columns)])
     }
 }
+
+fn contains_table_scan(s_expr: &SExpr, table_index: IndexType) -> bool {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        return scan.table_index == table_index;
+    }
+    s_expr
+        .children()
+        .any(|child| contains_table_scan(child, table_index))
+}
+
+// Only scans, filters and projections of deterministic expressions are allowed, so the
+// source produces the same rows when it's executed again.
+fn is_deterministic_source(s_expr: &SExpr) -> bool {
+    let is_deterministic = |scalar: &ScalarExpr| {
+        !contain_subquery(scalar)
+            && scalar
+                .as_expr()
+                .map_or(false, |expr| expr.is_deterministic(&BUILTIN_FUNCTIONS))
+    };
+    let deterministic = match s_expr.plan() {
+        RelOperator::Scan(scan) => scan
+            .push_down_predicates
+            .iter()
+            .flatten()
+            .all(is_deterministic),
+        RelOperator::ConstantTableScan(_) => true,
+        RelOperator::Filter(filter) => filter.predicates.iter().all(is_deterministic),
+        RelOperator::EvalScalar(eval_scalar) => eval_scalar
+            .items
+            .iter()
+            .all(|item| is_deterministic(&item.scalar)),
+        _ => false,
+    };
+    deterministic && s_expr.children().all(is_deterministic_source)
+}
+
+// Build `key = v1 OR key = v2 ...`, which can be used by both the range index and the
+// bloom index. The disjunctions are combined pairwise to keep the expression shallow.
+fn build_key_predicate(key: ScalarExpr, values: BTreeSet<Scalar>) -> ScalarExpr {
+    let mut predicates = values
+        .into_iter()
+        .map(|value| {
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![
+                    key.clone(),
+                    ScalarExpr::ConstantExpr(ConstantExpr { span: None, value }),
+                ],
+            })
+        })
+        .collect::<Vec<_>>();
+    if predicates.is_empty() {
+        // No target row can be matched.
+        return ScalarExpr::ConstantExpr(ConstantExpr {
+            span: None,
+            value: Scalar::Boolean(false),
+        });
+    }
+    while predicates.len() > 1 {
+        predicates = predicates
+            .into_iter()
+            .chunks(2)
+            .into_iter()
+            .map(|pair| {
+                pair.reduce(|left, right| {
+                    ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "or".to_string(),
+                        params: vec![],
+                        arguments: vec![left, right],
+                    })
+                })
+                .unwrap()
+            })
+            .collect();
+    }
+    predicates.pop().unwrap()
+}
+
+fn push_down_target_predicates(
+    s_expr: &SExpr,
+    table_index: IndexType,
+    predicates: &[ScalarExpr],
+) -> SExpr {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        if scan.table_index == table_index {
+            let mut scan = scan.clone();
+            scan.push_down_predicates
+                .get_or_insert_with(Vec::new)
+                .extend(predicates.iter().cloned());
+            return s_expr.replace_plan(Arc::new(RelOperator::Scan(scan)));
+        }
+    }
+    let children = s_expr
+        .children()
+        .map(|child| Arc::new(push_down_target_predicates(child, table_index, predicates)))
+        .collect::<Vec<_>>();
+    s_expr.replace_children(children)
+}
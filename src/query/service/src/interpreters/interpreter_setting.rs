@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+#[cfg(feature = "failpoints")]
+use databend_common_base::runtime::FailPointConfig;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -83,6 +85,13 @@ impl Interpreter for SettingInterpreter {
                     self.set_setting_by_var(&var, tz.to_string()).await?;
                     true
                 }
+                #[cfg(feature = "failpoints")]
+                "failpoints" => {
+                    // check if the failpoints are valid
+                    FailPointConfig::parse(&var.value)?;
+                    self.set_setting_by_var(&var, var.value.clone()).await?;
+                    true
+                }
                 // TODO: if account_admin is built-in meta in future, we need process set sandbox_tenant in there.
                 // Like: https://github.com/datafuselabs/databend/pull/14451/files#diff-a26c9dfc9c0a37f5efa19e2b16006732b9023f42ee47cbe37fe461fb46b9dfc0R82-R85
                 "sandbox_tenant" => {
@@ -20,6 +20,9 @@ use databend_common_exception::set_backtrace;
 use databend_common_exception::Result;
 use databend_common_sql::plans::SystemAction;
 use databend_common_sql::plans::SystemPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_cache_manager::CacheManager;
+use log::info;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -66,6 +69,18 @@ impl Interpreter for SystemActionInterpreter {
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // Resolve the table before broadcasting the action, so that no node drops
+        // anything if the table doesn't exist.
+        let table_prefix = match &self.plan.action {
+            SystemAction::DropDataCache(Some((catalog, database, table))) => {
+                let table = self.ctx.get_table(catalog, database, table).await?;
+                let table = FuseTable::try_from_table(table.as_ref())?;
+                Some(table.meta_location_generator().prefix().to_string())
+            }
+            _ => None,
+        };
+        // The table data cache is local to each node, the action is broadcast to
+        // all the nodes of the cluster.
         if self.proxy_to_cluster {
             let settings = self.ctx.get_settings();
             let timeout = settings.get_flight_client_timeout()?;
@@ -79,9 +94,18 @@ impl Interpreter for SystemActionInterpreter {
                 }
             }
         }
-        match self.plan.action {
+        match &self.plan.action {
             SystemAction::Backtrace(switch) => {
-                set_backtrace(switch);
+                set_backtrace(*switch);
+            }
+            SystemAction::DropDataCache(_) => {
+                if let Some(table_data_cache) = CacheManager::instance().get_table_data_cache() {
+                    let removed = table_data_cache.remove_items(table_prefix.as_deref());
+                    info!(
+                        "{} items of table data are dropped from disk cache, table prefix: {:?}",
+                        removed, table_prefix
+                    );
+                }
             }
         }
         Ok(PipelineBuildResult::create())
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
@@ -45,9 +46,13 @@ use databend_common_sql::field_default_value;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::io::DataEncryption;
 use databend_common_storages_fuse::io::MetaReaders;
+use databend_common_storages_fuse::FuseStorageFormat;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use databend_common_storages_fuse::FUSE_OPT_KEY_CACHE_PIN;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENCRYPTION_KEY_ID;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -247,6 +252,7 @@ impl CreateTableInterpreter {
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
             table_info: Some(table_info),
+            on_error: None,
         };
 
         // update share spec if needed
@@ -426,6 +432,8 @@ impl CreateTableInterpreter {
         is_valid_change_tracking(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
+        is_valid_cache_pin(&table_meta.options)?;
+        is_valid_encryption_key_id(&table_meta.options, &table_meta.options)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -531,6 +539,8 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_CACHE_PIN);
+    r.insert(FUSE_OPT_KEY_ENCRYPTION_KEY_ID);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -617,3 +627,42 @@ pub fn is_valid_random_seed(options: &BTreeMap<String, String>) -> Result<()> {
     }
     Ok(())
 }
+
+pub fn is_valid_cache_pin(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_CACHE_PIN) {
+        value.parse::<u64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid cache_pin option {value}, it should be the number of bytes"
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+/// Check the encryption_key_id option, the storage format is looked up in
+/// `table_options`, which differ from `options` when altering a table.
+pub fn is_valid_encryption_key_id(
+    options: &BTreeMap<String, String>,
+    table_options: &BTreeMap<String, String>,
+) -> Result<()> {
+    if let Some(key_id) = options.get(FUSE_OPT_KEY_ENCRYPTION_KEY_ID) {
+        if let Some(storage_format) = table_options.get(OPT_KEY_STORAGE_FORMAT) {
+            if matches!(
+                FuseStorageFormat::from_str(storage_format)?,
+                FuseStorageFormat::Native
+            ) {
+                return Err(ErrorCode::TableOptionInvalid(
+                    "encryption_key_id is not supported by tables of native storage format",
+                ));
+            }
+        }
+        // new blocks are written with this key, make sure it can be resolved.
+        DataEncryption::instance().cipher(key_id).map_err(|e| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid encryption_key_id option {key_id}: {}",
+                e.message()
+            ))
+        })?;
+    }
+    Ok(())
+}
@@ -140,6 +140,7 @@ impl Interpreter for DropTableInterpreter {
                         self.ctx.clone(),
                         &mut build_res.main_pipeline,
                         TruncateMode::Purge,
+                        vec![],
                     )
                     .await?
             } else {
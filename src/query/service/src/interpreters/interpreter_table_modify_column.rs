@@ -303,6 +303,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: old_data_type,
                         column_id: old_column_id,
                         computed_expr: old_computed_expr,
+                        computed_expr_checksum: _,
                     } = old_field;
                     let TableField {
                         name: new_name,
@@ -310,6 +311,7 @@ impl ModifyTableColumnInterpreter {
                         data_type: new_data_type,
                         column_id: new_column_id,
                         computed_expr: new_computed_expr,
+                        computed_expr_checksum: _,
                     } = new_field;
                     old_name == new_name
                         && old_default_expr == new_default_expr
@@ -400,6 +402,7 @@ impl ModifyTableColumnInterpreter {
                 select_column_bindings,
                 insert_schema: Arc::new(Arc::new(new_schema).into()),
                 cast_needed: true,
+                on_error: None,
             }));
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &insert_plan).await?;
@@ -484,6 +487,7 @@ impl ModifyTableColumnInterpreter {
             }
             let mut new_field = field.clone();
             new_field.computed_expr = None;
+            new_field.computed_expr_checksum = None;
             let mut fields = schema.fields().clone();
             fields[i] = new_field;
             TableSchema::new_from(fields, schema.metadata.clone())
@@ -18,7 +18,10 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_sql::plans::RenameTablePlan;
+use databend_common_users::RoleCacheManager;
 
+use crate::interpreters::common::collect_rename_dependents;
+use crate::interpreters::common::RenamedObject;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -51,6 +54,16 @@ impl Interpreter for RenameTableInterpreter {
         // You must have ALTER and DROP privileges for the original table,
         // and CREATE and INSERT privileges for the new table.
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let dependents = collect_rename_dependents(&self.ctx, &catalog, &RenamedObject::Table {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+            new_database: self.plan.new_database.clone(),
+            new_table: self.plan.new_table.clone(),
+        })
+        .await?;
+        let update_grants = !dependents.users.is_empty() || !dependents.roles.is_empty();
+
         catalog
             .rename_table(RenameTableReq {
                 if_exists: self.plan.if_exists,
@@ -61,9 +74,14 @@ impl Interpreter for RenameTableInterpreter {
                 },
                 new_db_name: self.plan.new_database.clone(),
                 new_table_name: self.plan.new_table.clone(),
+                dependents,
             })
             .await?;
 
+        if update_grants {
+            RoleCacheManager::instance().invalidate_cache(&self.plan.tenant);
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
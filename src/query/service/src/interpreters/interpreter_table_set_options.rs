@@ -30,7 +30,9 @@ use log::error;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
+use super::interpreter_table_create::is_valid_cache_pin;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_encryption_key_id;
 use super::interpreter_table_create::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -65,6 +67,7 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        is_valid_cache_pin(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -116,6 +119,8 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check encryption_key_id, blocks written before keep their own key id.
+        is_valid_encryption_key_id(&self.plan.set_options, table.options())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
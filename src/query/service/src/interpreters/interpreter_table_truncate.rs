@@ -19,7 +19,10 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_sql::plans::LockTableOption;
 use databend_common_sql::plans::TruncateTablePlan;
+use databend_common_storages_fuse::operations::TruncateMode;
+use databend_common_storages_fuse::FuseTable;
 
+use crate::interpreters::common::truncate_build_restart_stream_req;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::servers::flight::v1::packets::Packet;
@@ -32,6 +35,7 @@ pub struct TruncateTableInterpreter {
     table_name: String,
     catalog_name: String,
     database_name: String,
+    restart_streams: bool,
 
     proxy_to_cluster: bool,
 }
@@ -43,6 +47,7 @@ impl TruncateTableInterpreter {
             table_name: plan.table,
             catalog_name: plan.catalog,
             database_name: plan.database,
+            restart_streams: plan.restart_streams,
             proxy_to_cluster: true,
         })
     }
@@ -53,6 +58,7 @@ impl TruncateTableInterpreter {
             table_name: packet.table_name,
             catalog_name: packet.catalog_name,
             database_name: packet.database_name,
+            restart_streams: false,
             proxy_to_cluster: false,
         })
     }
@@ -110,9 +116,24 @@ impl Interpreter for TruncateTableInterpreter {
 
         let mut build_res = PipelineBuildResult::create();
         build_res.main_pipeline.add_lock_guard(lock_guard);
-        table
-            .truncate(self.ctx.clone(), &mut build_res.main_pipeline)
-            .await?;
+        if self.restart_streams {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            let update_stream_meta =
+                truncate_build_restart_stream_req(self.ctx.clone(), &self.catalog_name, fuse_table)
+                    .await?;
+            fuse_table
+                .do_truncate(
+                    self.ctx.clone(),
+                    &mut build_res.main_pipeline,
+                    TruncateMode::Normal,
+                    update_stream_meta,
+                )
+                .await?;
+        } else {
+            table
+                .truncate(self.ctx.clone(), &mut build_res.main_pipeline)
+                .await?;
+        }
         Ok(build_res)
     }
 }
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::principal::LambdaUDF;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDFScript;
+use databend_common_meta_app::principal::UDFServer;
+use databend_common_sql::plans::DescribeUDFPlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescribeUserUDFInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescribeUDFPlan,
+}
+
+impl DescribeUserUDFInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescribeUDFPlan) -> Result<Self> {
+        Ok(DescribeUserUDFInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeUserUDFInterpreter {
+    fn name(&self) -> &str {
+        "DescribeUserUDFInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let udf = UserApiProvider::instance()
+            .get_udf(&tenant, &self.plan.udf)
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::UnknownFunction(format!("Unknown function {}", self.plan.udf))
+            })?;
+
+        let mut properties = vec![
+            ("name", udf.name.clone()),
+            ("description", udf.description.clone()),
+        ];
+        match &udf.definition {
+            UDFDefinition::LambdaUDF(LambdaUDF {
+                parameters,
+                defaults,
+                definition,
+                ..
+            }) => {
+                let parameters = parameters
+                    .iter()
+                    .map(|parameter| match defaults.get(parameter) {
+                        Some(default) => format!("{parameter} DEFAULT {default}"),
+                        None => parameter.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                properties.push(("language", "SQL".to_string()));
+                properties.push(("signature", format!("({})", parameters.join(", "))));
+                properties.push(("definition", definition.clone()));
+            }
+            UDFDefinition::UDFServer(
+                server @ UDFServer {
+                    handler,
+                    language,
+                    arg_types,
+                    return_type,
+                    deterministic,
+                    ..
+                },
+            ) => {
+                properties.push(("language", language.clone()));
+                properties.push(("signature", display_signature(arg_types, return_type)));
+                properties.push(("handler", handler.clone()));
+                // The address may contain credentials.
+                properties.push(("address", server.masked_address()));
+                properties.push(("deterministic", deterministic.to_string()));
+            }
+            UDFDefinition::UDFScript(UDFScript {
+                code,
+                handler,
+                language,
+                arg_types,
+                return_type,
+                runtime_version,
+            }) => {
+                properties.push(("language", language.clone()));
+                properties.push(("signature", display_signature(arg_types, return_type)));
+                properties.push(("handler", handler.clone()));
+                properties.push(("runtime_version", runtime_version.clone()));
+                properties.push(("definition", code.clone()));
+            }
+        }
+
+        let (names, values): (Vec<_>, Vec<_>) = properties
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .unzip();
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+        ])])
+    }
+}
+
+fn display_signature(arg_types: &[DataType], return_type: &DataType) -> String {
+    let arg_types = arg_types
+        .iter()
+        .map(|ty| ty.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("({arg_types}) RETURNS {return_type}")
+}
@@ -19,8 +19,11 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
+use databend_common_sql::persisted_definition::definition_checksum;
 use databend_common_sql::plans::AlterViewPlan;
 use databend_common_sql::Planner;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::QUERY_CHECKSUM;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -75,7 +78,11 @@ impl Interpreter for AlterViewInterpreter {
                     self.plan.column_names.join(", ")
                 )
             };
-            options.insert("query".to_string(), Some(subquery));
+            options.insert(
+                QUERY_CHECKSUM.to_string(),
+                Some(definition_checksum(&subquery).to_string()),
+            );
+            options.insert(QUERY.to_string(), Some(subquery));
 
             let req = UpsertTableOptionReq {
                 table_id: tbl.get_id(),
@@ -20,10 +20,12 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::persisted_definition::definition_checksum;
 use databend_common_sql::plans::CreateViewPlan;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
 use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::QUERY_CHECKSUM;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 
 use crate::interpreters::Interpreter;
@@ -104,6 +106,10 @@ impl Interpreter for CreateViewInterpreter {
                 self.plan.column_names.join(", ")
             )
         };
+        options.insert(
+            QUERY_CHECKSUM.to_string(),
+            definition_checksum(&subquery).to_string(),
+        );
         options.insert(QUERY.to_string(), subquery);
 
         let plan = CreateTableReq {
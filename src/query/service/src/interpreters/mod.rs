@@ -137,6 +137,7 @@ mod interpreter_user_stage_drop;
 mod interpreter_user_stage_remove;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
+mod interpreter_user_udf_describe;
 mod interpreter_user_udf_drop;
 mod interpreter_vacuum_drop_tables;
 mod interpreter_vacuum_temporary_files;
@@ -245,6 +246,7 @@ pub use interpreter_user_stage_drop::DropUserStageInterpreter;
 pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFScript;
 pub use interpreter_user_udf_create::CreateUserUDFScript;
+pub use interpreter_user_udf_describe::DescribeUserUDFInterpreter;
 pub use interpreter_user_udf_drop::DropUserUDFScript;
 pub use interpreter_vacuum_drop_tables::VacuumDropTablesInterpreter;
 pub use interpreter_vacuum_temporary_files::VacuumTemporaryFilesInterpreter;
@@ -38,6 +38,7 @@ use crate::pipelines::processors::transforms::aggregator::build_partition_bucket
 use crate::pipelines::processors::transforms::aggregator::AggregateInjector;
 use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
 use crate::pipelines::processors::transforms::aggregator::FinalSingleStateAggregator;
+use crate::pipelines::processors::transforms::aggregator::GroupByKeysLimit;
 use crate::pipelines::processors::transforms::aggregator::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::aggregator::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformExpandGroupingSets;
@@ -108,6 +109,11 @@ impl PipelineBuilder {
 
         let in_cluster = !self.ctx.get_cluster().is_empty();
 
+        let group_by_keys_limit = GroupByKeysLimit::create(
+            self.settings.get_max_group_by_keys()?,
+            &aggregate.group_by_display,
+        );
+
         let params = Self::build_aggregator_params(
             aggregate.input.output_schema()?,
             &aggregate.group_by,
@@ -116,6 +122,7 @@ impl PipelineBuilder {
             in_cluster,
             max_block_size as usize,
             None,
+            group_by_keys_limit,
         )?;
 
         if params.group_columns.is_empty() {
@@ -222,6 +229,10 @@ impl PipelineBuilder {
             .settings
             .get_enable_experimental_aggregate_hashtable()?;
         let in_cluster = !self.ctx.get_cluster().is_empty();
+        let group_by_keys_limit = GroupByKeysLimit::create(
+            self.settings.get_max_group_by_keys()?,
+            &aggregate.group_by_display,
+        );
         let params = Self::build_aggregator_params(
             aggregate.before_group_by_schema.clone(),
             &aggregate.group_by,
@@ -230,6 +241,7 @@ impl PipelineBuilder {
             in_cluster,
             max_block_size as usize,
             aggregate.limit,
+            group_by_keys_limit,
         )?;
 
         if params.group_columns.is_empty() {
@@ -296,6 +308,7 @@ impl PipelineBuilder {
         in_cluster: bool,
         max_block_size: usize,
         limit: Option<usize>,
+        group_by_keys_limit: Option<GroupByKeysLimit>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut agg_args = Vec::with_capacity(agg_funcs.len());
         let (group_by, group_data_types) = group_by
@@ -320,10 +333,11 @@ impl PipelineBuilder {
                     })
                     .collect::<Result<Vec<_>>>()?;
                 agg_args.push(args);
-                AggregateFunctionFactory::instance().get(
+                AggregateFunctionFactory::instance().get_with_sort_descs(
                     agg_func.sig.name.as_str(),
                     agg_func.sig.params.clone(),
                     agg_func.sig.args.clone(),
+                    agg_func.sig.sort_descs.clone(),
                 )
             })
             .collect::<Result<_>>()?;
@@ -338,6 +352,7 @@ impl PipelineBuilder {
             in_cluster,
             max_block_size,
             limit,
+            group_by_keys_limit,
         )?;
 
         Ok(params)
@@ -17,6 +17,7 @@ use databend_common_exception::Result;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformTryCastSchema;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -38,7 +39,27 @@ impl PipelineBuilder {
             false,
         )?;
 
-        if insert_select.cast_needed {
+        if insert_select.cast_needed && insert_select.on_error.is_some() {
+            let error_log = self.ctx.get_insert_error_log();
+            let max_logged_errors = match &insert_select.on_error {
+                Some(on_error) if on_error.log_table.is_some() => {
+                    Some(self.settings.get_max_insert_logged_errors()? as usize)
+                }
+                _ => None,
+            };
+            self.main_pipeline
+                .add_transform(|transform_input_port, transform_output_port| {
+                    TransformTryCastSchema::try_create(
+                        transform_input_port,
+                        transform_output_port,
+                        select_schema.clone(),
+                        insert_schema.clone(),
+                        self.func_ctx.clone(),
+                        error_log.clone(),
+                        max_logged_errors,
+                    )
+                })?;
+        } else if insert_select.cast_needed {
             self.main_pipeline
                 .add_transform(|transform_input_port, transform_output_port| {
                     TransformCastSchema::try_create(
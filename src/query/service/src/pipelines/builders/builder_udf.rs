@@ -13,11 +13,14 @@
 // limitations under the License.
 
 use databend_common_exception::Result;
+use databend_common_expression::BlockThresholds;
+use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::Udf;
 
 use crate::pipelines::processors::transforms::TransformUdfScript;
 use crate::pipelines::processors::transforms::TransformUdfServer;
+use crate::pipelines::processors::transforms::WasmUdfLimits;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -25,7 +28,11 @@ impl PipelineBuilder {
         self.build_pipeline(&udf.input)?;
 
         if udf.script_udf {
-            let runtimes = TransformUdfScript::init_runtime(&udf.udf_funcs)?;
+            let wasm_limits = WasmUdfLimits {
+                memory_limit: self.settings.get_wasm_udf_memory_limit()? as usize,
+                fuel_limit: self.settings.get_wasm_udf_fuel_limit()?,
+            };
+            let runtimes = TransformUdfScript::init_runtime(&udf.udf_funcs, wasm_limits)?;
             self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformUdfScript::try_create(
                     self.func_ctx.clone(),
@@ -36,6 +43,14 @@ impl PipelineBuilder {
                 )?))
             })
         } else {
+            // Merge the small blocks, so that each request to the UDF server has
+            // `external_server_request_batch_rows` rows if possible.
+            let batch_rows = self.func_ctx.external_server_request_batch_rows as usize;
+            let block_thresholds =
+                BlockThresholds::new(batch_rows, batch_rows, DEFAULT_BLOCK_BUFFER_SIZE);
+            self.main_pipeline
+                .add_transform(self.block_compact_transform_builder(block_thresholds)?)?;
+
             self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformUdfServer::try_create(
                     self.func_ctx.clone(),
@@ -19,6 +19,7 @@ pub use transforms::DeduplicateRowNumber;
 pub use transforms::HashJoinBuildState;
 pub use transforms::HashJoinDesc;
 pub use transforms::HashJoinState;
+pub use transforms::InsertErrorLog;
 pub use transforms::TransformAddStreamColumns;
 pub use transforms::TransformCastSchema;
 pub use transforms::TransformCreateSets;
@@ -26,4 +27,5 @@ pub use transforms::TransformLimit;
 pub use transforms::TransformNullIf;
 pub use transforms::TransformResortAddOn;
 pub use transforms::TransformResortAddOnWithoutSourceSchema;
+pub use transforms::TransformTryCastSchema;
 pub use transforms::TransformWindow;
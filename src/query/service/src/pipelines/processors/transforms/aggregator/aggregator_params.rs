@@ -13,8 +13,12 @@
 // limitations under the License.
 
 use std::alloc::Layout;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use databend_common_base::base::convert_byte_size;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnBuilder;
@@ -46,6 +50,7 @@ pub struct AggregatorParams {
     pub max_block_size: usize,
     // Limit is push down to AggregatorTransform
     pub limit: Option<usize>,
+    pub group_by_keys_limit: Option<GroupByKeysLimit>,
 }
 
 impl AggregatorParams {
@@ -59,6 +64,7 @@ impl AggregatorParams {
         in_cluster: bool,
         max_block_size: usize,
         limit: Option<usize>,
+        group_by_keys_limit: Option<GroupByKeysLimit>,
     ) -> Result<Arc<AggregatorParams>> {
         let mut states_offsets: Vec<usize> = Vec::with_capacity(agg_funcs.len());
         let mut states_layout = None;
@@ -79,6 +85,7 @@ impl AggregatorParams {
             in_cluster,
             max_block_size,
             limit,
+            group_by_keys_limit,
         }))
     }
 
@@ -114,3 +121,55 @@ impl AggregatorParams {
         DataBlock::new_from_columns(columns)
     }
 }
+
+/// Aborts the aggregation once it has seen more group by keys than `max_group_by_keys`,
+/// instead of keeping growing or spilling the hash tables. The memory of the hash tables
+/// holding the keys is accounted along with them and reported in the error.
+pub struct GroupByKeysLimit {
+    max_keys: usize,
+    group_by_display: Vec<String>,
+    // Keys and allocated bytes of the disjoint hash tables that have been accumulated.
+    observed_keys: AtomicUsize,
+    observed_bytes: AtomicUsize,
+}
+
+impl GroupByKeysLimit {
+    pub fn create(max_keys: u64, group_by_display: &[String]) -> Option<GroupByKeysLimit> {
+        if max_keys == 0 {
+            return None;
+        }
+        Some(GroupByKeysLimit {
+            max_keys: max_keys as usize,
+            group_by_display: group_by_display.to_vec(),
+            observed_keys: AtomicUsize::new(0),
+            observed_bytes: AtomicUsize::new(0),
+        })
+    }
+
+    /// Check the number of keys in a single hash table which allocates `bytes`, it's a
+    /// lower bound of the group by keys of the whole aggregation.
+    pub fn check(&self, keys: usize, bytes: usize) -> Result<()> {
+        if keys > self.max_keys {
+            return Err(self.exceeded_error(keys, bytes));
+        }
+        Ok(())
+    }
+
+    /// Accumulate the number of keys and the allocated bytes of hash tables that share
+    /// no key with each other, such as the buckets of the final aggregation.
+    pub fn accumulate(&self, keys: usize, bytes: usize) -> Result<()> {
+        let observed_keys = self.observed_keys.fetch_add(keys, Ordering::Relaxed) + keys;
+        let observed_bytes = self.observed_bytes.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        self.check(observed_keys, observed_bytes)
+    }
+
+    fn exceeded_error(&self, keys: usize, bytes: usize) -> ErrorCode {
+        ErrorCode::TooManyGroupByKeys(format!(
+            "the aggregation has observed at least {} group by keys of GROUP BY ({}) in {} of hash tables, which exceeds max_group_by_keys {}",
+            keys,
+            self.group_by_display.join(", "),
+            convert_byte_size(bytes as f64),
+            self.max_keys
+        ))
+    }
+}
@@ -121,6 +121,10 @@ impl<Method: HashMethodBounds> TransformFinalAggregate<Method> {
         }
 
         if let Some(mut ht) = agg_hashtable {
+            if let Some(limit) = &self.params.group_by_keys_limit {
+                limit.accumulate(ht.len(), ht.allocated_bytes())?;
+            }
+
             let mut blocks = vec![];
             self.flush_state.clear();
 
@@ -286,6 +290,9 @@ where Method: HashMethodBounds
             }
 
             let keys_len = hash_cell.hashtable.len();
+            if let Some(limit) = &self.params.group_by_keys_limit {
+                limit.accumulate(keys_len, hash_cell.allocated_bytes())?;
+            }
             let value_size = estimated_key_size(&hash_cell.hashtable);
 
             let mut group_columns_builder =
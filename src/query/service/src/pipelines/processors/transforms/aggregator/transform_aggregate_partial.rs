@@ -64,6 +64,26 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
     }
 }
 
+impl<Method: HashMethodBounds> HashTable<Method> {
+    fn len(&self) -> usize {
+        match self {
+            HashTable::MovedOut => 0,
+            HashTable::HashTable(cell) => cell.len(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.len(),
+            HashTable::PartitionedHashTable(cell) => cell.len(),
+        }
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            HashTable::MovedOut => 0,
+            HashTable::HashTable(cell) => cell.allocated_bytes(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.allocated_bytes(),
+            HashTable::PartitionedHashTable(cell) => cell.allocated_bytes(),
+        }
+    }
+}
+
 struct AggregateSettings {
     convert_threshold: usize,
     max_memory_usage: usize,
@@ -352,6 +372,10 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
     fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
         self.execute_one_block(block)?;
 
+        if let Some(limit) = &self.params.group_by_keys_limit {
+            limit.check(self.hash_table.len(), self.hash_table.allocated_bytes())?;
+        }
+
         let is_new_agg = self.params.enable_experimental_aggregate_hashtable;
         #[allow(clippy::collapsible_if)]
         if Method::SUPPORT_PARTITIONED {
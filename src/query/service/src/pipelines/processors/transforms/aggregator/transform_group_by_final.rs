@@ -115,6 +115,10 @@ impl<Method: HashMethodBounds> TransformFinalGroupBy<Method> {
         }
 
         if let Some(mut ht) = agg_hashtable {
+            if let Some(limit) = &self.params.group_by_keys_limit {
+                limit.accumulate(ht.len(), ht.allocated_bytes())?;
+            }
+
             let mut blocks = vec![];
             self.flush_state.clear();
 
@@ -211,6 +215,9 @@ where Method: HashMethodBounds
 
             let value_size = estimated_key_size(&hashtable);
             let keys_len = hashtable.len();
+            if let Some(limit) = &self.params.group_by_keys_limit {
+                limit.accumulate(keys_len, hashtable.bytes_len(false))?;
+            }
 
             let mut group_columns_builder =
                 self.method
@@ -60,6 +60,26 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
     }
 }
 
+impl<Method: HashMethodBounds> HashTable<Method> {
+    fn len(&self) -> usize {
+        match self {
+            HashTable::MovedOut => 0,
+            HashTable::HashTable(cell) => cell.len(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.len(),
+            HashTable::PartitionedHashTable(cell) => cell.len(),
+        }
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        match self {
+            HashTable::MovedOut => 0,
+            HashTable::HashTable(cell) => cell.allocated_bytes(),
+            HashTable::AggregateHashTable(hashtable) => hashtable.allocated_bytes(),
+            HashTable::PartitionedHashTable(cell) => cell.allocated_bytes(),
+        }
+    }
+}
+
 struct GroupBySettings {
     convert_threshold: usize,
     max_memory_usage: usize,
@@ -194,6 +214,10 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                 }
             };
 
+            if let Some(limit) = &self.params.group_by_keys_limit {
+                limit.check(self.hash_table.len(), self.hash_table.allocated_bytes())?;
+            }
+
             let is_new_agg = self.params.enable_experimental_aggregate_hashtable;
 
             #[allow(clippy::collapsible_if)]
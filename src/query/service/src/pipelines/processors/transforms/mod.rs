@@ -40,8 +40,10 @@ mod transform_runtime_cast_schema;
 mod transform_sequence_nextval;
 mod transform_sort_spill;
 mod transform_srf;
+mod transform_try_cast_schema;
 mod transform_udf_script;
 mod transform_udf_server;
+mod udf_wasm_runtime;
 mod window;
 
 pub use hash_join::*;
@@ -73,8 +75,12 @@ pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_sequence_nextval::TransformSequenceNextval;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
+pub use transform_try_cast_schema::InsertErrorLog;
+pub use transform_try_cast_schema::InsertRowError;
+pub use transform_try_cast_schema::TransformTryCastSchema;
 pub use transform_udf_script::TransformUdfScript;
 pub use transform_udf_server::TransformUdfServer;
+pub use udf_wasm_runtime::WasmUdfLimits;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
@@ -0,0 +1,268 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+
+/// A row skipped by `INSERT INTO ... SELECT ... ON ERROR SKIP`.
+pub struct InsertRowError {
+    pub error: String,
+    pub source_row: String,
+}
+
+/// The rows skipped by `INSERT INTO ... SELECT ... ON ERROR SKIP` in a query.
+#[derive(Default)]
+pub struct InsertErrorLog {
+    inserted_rows: AtomicU64,
+    skipped_rows: AtomicU64,
+    errors: Mutex<Vec<InsertRowError>>,
+}
+
+impl InsertErrorLog {
+    pub fn inserted_rows(&self) -> u64 {
+        self.inserted_rows.load(Ordering::Relaxed)
+    }
+
+    pub fn set_inserted_rows(&self, rows: u64) {
+        self.inserted_rows.store(rows, Ordering::Relaxed);
+    }
+
+    pub fn skipped_rows(&self) -> u64 {
+        self.skipped_rows.load(Ordering::Relaxed)
+    }
+
+    pub fn take_errors(&self) -> Vec<InsertRowError> {
+        std::mem::take(&mut *self.errors.lock())
+    }
+
+    fn add_skipped_rows(&self, rows: u64) {
+        self.skipped_rows.fetch_add(rows, Ordering::Relaxed);
+    }
+
+    /// Logs the error built by `f`, returns false if `max_errors` errors are already logged.
+    fn log_error(&self, max_errors: usize, f: impl FnOnce() -> InsertRowError) -> bool {
+        let mut errors = self.errors.lock();
+        if errors.len() >= max_errors {
+            return false;
+        }
+        errors.push(f());
+        true
+    }
+}
+
+/// Like [`TransformCastSchema`](super::TransformCastSchema), but the rows which fail to be cast
+/// are skipped and recorded into the [`InsertErrorLog`] instead of failing the query.
+pub struct TransformTryCastSchema {
+    func_ctx: FunctionContext,
+    insert_schema: DataSchemaRef,
+    select_schema: DataSchemaRef,
+    try_exprs: Vec<Expr>,
+    // Only used to get the error message of the skipped rows.
+    exprs: Vec<Expr>,
+    error_log: Arc<InsertErrorLog>,
+    // None if the skipped rows are only counted.
+    max_logged_errors: Option<usize>,
+}
+
+impl TransformTryCastSchema
+where Self: Transform
+{
+    pub fn try_create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        select_schema: DataSchemaRef,
+        insert_schema: DataSchemaRef,
+        func_ctx: FunctionContext,
+        error_log: Arc<InsertErrorLog>,
+        max_logged_errors: Option<usize>,
+    ) -> Result<ProcessorPtr> {
+        let mut try_exprs = Vec::with_capacity(insert_schema.num_fields());
+        let mut exprs = Vec::with_capacity(insert_schema.num_fields());
+        for (index, (from, to)) in select_schema
+            .fields()
+            .iter()
+            .zip(insert_schema.fields().iter())
+            .enumerate()
+        {
+            let expr = Expr::ColumnRef {
+                span: None,
+                id: index,
+                data_type: from.data_type().clone(),
+                display_name: from.name().clone(),
+            };
+            try_exprs.push(check_cast(
+                None,
+                true,
+                expr.clone(),
+                to.data_type(),
+                &BUILTIN_FUNCTIONS,
+            )?);
+            exprs.push(check_cast(
+                None,
+                false,
+                expr,
+                to.data_type(),
+                &BUILTIN_FUNCTIONS,
+            )?);
+        }
+        Ok(ProcessorPtr::create(Transformer::create(
+            input_port,
+            output_port,
+            Self {
+                func_ctx,
+                insert_schema,
+                select_schema,
+                try_exprs,
+                exprs,
+                error_log,
+                max_logged_errors,
+            },
+        )))
+    }
+
+    fn log_errors(&self, data_block: &DataBlock, errors: &[(usize, usize)]) {
+        self.error_log.add_skipped_rows(errors.len() as u64);
+
+        let Some(max_logged_errors) = self.max_logged_errors else {
+            return;
+        };
+        for (row, column) in errors {
+            let logged = self
+                .error_log
+                .log_error(max_logged_errors, || InsertRowError {
+                    error: self.cast_error(data_block, *row, *column),
+                    source_row: source_row(data_block, *row),
+                });
+            if !logged {
+                break;
+            }
+        }
+    }
+
+    // Evaluates the strict cast on the failed row to get the reason.
+    fn cast_error(&self, data_block: &DataBlock, row: usize, column: usize) -> String {
+        let row_block = data_block.slice(row..row + 1);
+        let evaluator = Evaluator::new(&row_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let reason = match evaluator.run(&self.exprs[column]) {
+            Err(err) => err.message(),
+            Ok(_) => "the cast returns NULL".to_string(),
+        };
+        format!(
+            "fail to auto cast column {} ({}) to column {} ({}): {}",
+            self.select_schema.fields[column].name(),
+            self.select_schema.fields[column].data_type(),
+            self.insert_schema.fields[column].name(),
+            self.insert_schema.fields[column].data_type(),
+            reason
+        )
+    }
+}
+
+impl Transform for TransformTryCastSchema {
+    const NAME: &'static str = "TryCastSchemaTransform";
+
+    fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        let mut columns = Vec::with_capacity(self.try_exprs.len());
+        let mut valid = MutableBitmap::from_len_set(num_rows);
+        // (row, column) of the first column failed to be cast in each skipped row.
+        let mut errors = Vec::new();
+        let evaluator = Evaluator::new(&data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (i, (field, expr)) in self
+            .insert_schema
+            .fields()
+            .iter()
+            .zip(self.try_exprs.iter())
+            .enumerate()
+        {
+            let column = evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows);
+            if let Column::Nullable(nullable) = &column {
+                // A NULL produced by try_cast is an error, unless the source value is NULL
+                // and the inserted column is nullable.
+                let target_nullable = field.data_type().is_nullable_or_null();
+                let source = &data_block.get_by_offset(i).value;
+                for row in 0..num_rows {
+                    if !nullable.validity.get_bit(row)
+                        && valid.get(row)
+                        && (!target_nullable || source.index(row) != Some(ScalarRef::Null))
+                    {
+                        valid.set(row, false);
+                        errors.push((row, i));
+                    }
+                }
+            }
+            columns.push(column);
+        }
+
+        let valid: Bitmap = valid.into();
+        if !errors.is_empty() {
+            self.log_errors(&data_block, &errors);
+        }
+
+        let columns = self
+            .insert_schema
+            .fields()
+            .iter()
+            .zip(columns)
+            .map(|(field, column)| {
+                let column = if errors.is_empty() {
+                    column
+                } else {
+                    column.filter(&valid)
+                };
+                let column = if field.data_type().is_nullable_or_null() {
+                    column
+                } else {
+                    column.remove_nullable()
+                };
+                BlockEntry::new(field.data_type().clone(), Value::Column(column))
+            })
+            .collect();
+        Ok(DataBlock::new(columns, num_rows - errors.len()))
+    }
+}
+
+fn source_row(data_block: &DataBlock, row: usize) -> String {
+    let values = data_block
+        .columns()
+        .iter()
+        .map(|entry| entry.value.index(row).unwrap().to_string())
+        .collect::<Vec<_>>();
+    format!("({})", values.join(", "))
+}
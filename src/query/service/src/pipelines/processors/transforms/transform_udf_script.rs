@@ -35,6 +35,8 @@ use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
 use databend_common_sql::plans::UDFType;
 use parking_lot::RwLock;
 
+use super::udf_wasm_runtime::WasmRuntime;
+use super::udf_wasm_runtime::WasmUdfLimits;
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
 use crate::pipelines::processors::Processor;
@@ -46,12 +48,16 @@ static GLOBAL_PYTHON_RUNTIME: std::sync::LazyLock<Arc<RwLock<arrow_udf_python::R
 
 pub enum ScriptRuntime {
     JavaScript(Arc<RwLock<arrow_udf_js::Runtime>>),
-    WebAssembly(Arc<RwLock<arrow_udf_wasm::Runtime>>),
+    WebAssembly(Arc<WasmRuntime>),
     Python,
 }
 
 impl ScriptRuntime {
-    pub fn try_create(lang: &str, code: Option<Vec<u8>>) -> Result<Self, ErrorCode> {
+    pub fn try_create(
+        lang: &str,
+        code: Option<Vec<u8>>,
+        wasm_limits: WasmUdfLimits,
+    ) -> Result<Self, ErrorCode> {
         match lang {
             "javascript" => arrow_udf_js::Runtime::new()
                 .map(|mut runtime| {
@@ -66,7 +72,7 @@ impl ScriptRuntime {
                 .map_err(|err| {
                     ErrorCode::UDFDataError(format!("Cannot create js runtime: {}", err))
                 }),
-            "wasm" => Self::create_wasm_runtime(code),
+            "wasm" => Self::create_wasm_runtime(code, wasm_limits),
             "python" => Ok(Self::Python),
             _ => Err(ErrorCode::from_string(format!(
                 "Invalid {} lang Runtime not supported",
@@ -75,15 +81,15 @@ impl ScriptRuntime {
         }
     }
 
-    fn create_wasm_runtime(code_blob: Option<Vec<u8>>) -> Result<Self, ErrorCode> {
+    fn create_wasm_runtime(
+        code_blob: Option<Vec<u8>>,
+        limits: WasmUdfLimits,
+    ) -> Result<Self, ErrorCode> {
         let decoded_code_blob = code_blob
             .ok_or_else(|| ErrorCode::UDFDataError("WASM module not provided".to_string()))?;
 
-        let runtime = arrow_udf_wasm::Runtime::new(&decoded_code_blob).map_err(|err| {
-            ErrorCode::UDFDataError(format!("Failed to create WASM runtime for module: {}", err))
-        })?;
-
-        Ok(ScriptRuntime::WebAssembly(Arc::new(RwLock::new(runtime))))
+        let runtime = WasmRuntime::try_create(&decoded_code_blob, limits)?;
+        Ok(ScriptRuntime::WebAssembly(Arc::new(runtime)))
     }
 
     pub fn add_function_with_handler(
@@ -167,11 +173,11 @@ impl ScriptRuntime {
                 ));
             }
             ScriptRuntime::WebAssembly(runtime) => {
-                let runtime = runtime.read();
                 runtime.call(&func.func_name, input_batch).map_err(|err| {
                     ErrorCode::UDFDataError(format!(
                         "WASM UDF '{}' execution failed: {}",
-                        func.func_name, err
+                        func.func_name,
+                        err.message()
                     ))
                 })?
             }
@@ -244,13 +250,14 @@ impl TransformUdfScript {
 
     pub fn init_runtime(
         funcs: &[UdfFunctionDesc],
+        wasm_limits: WasmUdfLimits,
     ) -> Result<BTreeMap<String, Arc<ScriptRuntime>>, ErrorCode> {
         let mut script_runtimes: BTreeMap<String, Arc<ScriptRuntime>> = BTreeMap::new();
 
         let start = std::time::Instant::now();
         for func in funcs {
             let (lang, code_opt) = match &func.udf_type {
-                UDFType::Script((lang, _, _code)) => (lang, None),
+                UDFType::Script((lang, _, code)) => (lang, Some(code.clone())),
                 _ => continue,
             };
 
@@ -258,7 +265,7 @@ impl TransformUdfScript {
             let runtime = match script_runtimes.entry(runtime_key.clone()) {
                 Entry::Occupied(entry) => entry.into_mut().clone(),
                 Entry::Vacant(entry) => {
-                    let new_runtime = ScriptRuntime::try_create(lang.trim(), code_opt)
+                    let new_runtime = ScriptRuntime::try_create(lang.trim(), code_opt, wasm_limits)
                         .map(Arc::new)
                         .map_err(|err| {
                             ErrorCode::UDFDataError(format!(
@@ -14,8 +14,11 @@
 
 use std::sync::Arc;
 
+use databend_common_base::fail_point;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::udf_client::is_udf_server_failover_error;
+use databend_common_expression::udf_client::udf_server_addresses;
 use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_expression::variant_transform::contains_variant;
 use databend_common_expression::variant_transform::transform_variant;
@@ -24,12 +27,15 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FunctionContext;
+use databend_common_metrics::udf::metrics_inc_udf_server_failover_count;
+use databend_common_metrics::udf::metrics_inc_udf_server_request_count;
 use databend_common_pipeline_transforms::processors::AsyncRetry;
 use databend_common_pipeline_transforms::processors::AsyncRetryWrapper;
 use databend_common_pipeline_transforms::processors::AsyncTransform;
 use databend_common_pipeline_transforms::processors::AsyncTransformer;
 use databend_common_pipeline_transforms::processors::RetryStrategy;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+use log::warn;
 
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
@@ -38,6 +44,9 @@ use crate::pipelines::processors::Processor;
 pub struct TransformUdfServer {
     func_ctx: FunctionContext,
     funcs: Vec<UdfFunctionDesc>,
+    // The index of the server address of each function to send the requests to,
+    // it moves to the next address on failover.
+    address_indexes: Vec<usize>,
 }
 
 impl TransformUdfServer {
@@ -47,20 +56,133 @@ impl TransformUdfServer {
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
     ) -> Result<Box<dyn Processor>> {
-        let s = Self { func_ctx, funcs };
+        let address_indexes = vec![0; funcs.len()];
+        let s = Self {
+            func_ctx,
+            funcs,
+            address_indexes,
+        };
         let retry_wrapper = AsyncRetryWrapper::create(s);
         Ok(AsyncTransformer::create(input, output, retry_wrapper))
     }
+
+    /// Call the UDF server at `server_addr` with all the rows of `args_block`, at most
+    /// `external_server_request_batch_rows` rows are sent in each request.
+    async fn request_udf_server(
+        &self,
+        func: &UdfFunctionDesc,
+        server_addr: &str,
+        data_schema: &DataSchema,
+        args_block: &DataBlock,
+    ) -> Result<BlockEntry> {
+        fail_point!("udf_server_call");
+        let mut client = UDFFlightClient::connect(
+            server_addr,
+            self.func_ctx.external_server_connect_timeout_secs,
+            self.func_ctx.external_server_request_timeout_secs,
+            self.func_ctx.external_server_request_batch_rows,
+        )
+        .await?;
+
+        // The results are concatenated in the order of the rows.
+        let num_rows = args_block.num_rows();
+        let batch_rows = self.func_ctx.external_server_request_batch_rows as usize;
+        let mut results = Vec::with_capacity(num_rows.div_ceil(batch_rows).max(1));
+        let mut start = 0;
+        loop {
+            let end = num_rows.min(start.saturating_add(batch_rows));
+            let result = Self::call_udf_server(
+                &mut client,
+                func,
+                server_addr,
+                data_schema,
+                args_block.slice(start..end),
+            )
+            .await?;
+            results.push(DataBlock::new(vec![result], end - start));
+            start = end;
+            if start >= num_rows {
+                break;
+            }
+        }
+        Ok(DataBlock::concat(&results)?.get_by_offset(0).clone())
+    }
+
+    /// Call the UDF server with a batch of rows, returns the result column.
+    async fn call_udf_server(
+        client: &mut UDFFlightClient,
+        func: &UdfFunctionDesc,
+        server_addr: &str,
+        data_schema: &DataSchema,
+        batch: DataBlock,
+    ) -> Result<BlockEntry> {
+        let num_rows = batch.num_rows();
+        let input_batch = batch
+            .to_record_batch_with_dataschema(data_schema)
+            .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
+
+        let result_batch = match client.do_exchange(&func.func_name, input_batch).await {
+            Ok(result_batch) => result_batch,
+            Err(err) if err.code() == ErrorCode::UDF_REQUEST_TOO_LARGE => {
+                let max_row_bytes = (0..num_rows)
+                    .map(|row| batch.slice(row..row + 1).memory_size())
+                    .max()
+                    .unwrap_or(0);
+                return Err(ErrorCode::UDFRequestTooLarge(format!(
+                    "The request of UDF '{}' to server {} is too large, it has {} rows of {} bytes and the largest row has {} bytes: {}",
+                    func.func_name,
+                    server_addr,
+                    num_rows,
+                    batch.memory_size(),
+                    max_row_bytes,
+                    err.message()
+                )));
+            }
+            Err(err) => return Err(err),
+        };
+        let schema = DataSchema::try_from(&(*result_batch.schema()))?;
+        let (result_block, result_schema) = DataBlock::from_record_batch(&schema, &result_batch)
+            .map_err(|err| {
+                ErrorCode::UDFDataError(format!(
+                    "Cannot convert arrow record batch to data block: {err}"
+                ))
+            })?;
+
+        let result_fields = result_schema.fields();
+        if result_fields.is_empty() || result_block.is_empty() {
+            return Err(ErrorCode::EmptyDataFromServer(
+                "Get empty data from UDF Server",
+            ));
+        }
+
+        if result_fields[0].data_type() != &*func.data_type {
+            return Err(ErrorCode::UDFSchemaMismatch(format!(
+                "UDF server return incorrect type, expected: {}, but got: {}",
+                func.data_type,
+                result_fields[0].data_type()
+            )));
+        }
+        if result_block.num_rows() != num_rows {
+            return Err(ErrorCode::UDFDataError(format!(
+                "UDF server should return {} rows, but it returned {} rows",
+                num_rows,
+                result_block.num_rows()
+            )));
+        }
+
+        Ok(result_block.get_by_offset(0).clone())
+    }
 }
 
 impl AsyncRetry for TransformUdfServer {
-    fn retry_on(&self, _err: &databend_common_exception::ErrorCode) -> bool {
-        true
+    fn retry_on(&self, err: &databend_common_exception::ErrorCode) -> bool {
+        // Retrying doesn't help if the rows are too large.
+        err.code() != ErrorCode::UDF_REQUEST_TOO_LARGE
     }
 
     fn retry_strategy(&self) -> RetryStrategy {
         RetryStrategy {
-            retry_times: 64,
+            retry_times: self.func_ctx.external_server_request_retry_times as usize,
             retry_sleep_duration: Some(tokio::time::Duration::from_millis(500)),
         }
     }
@@ -72,11 +194,10 @@ impl AsyncTransform for TransformUdfServer {
 
     #[async_backtrace::framed]
     async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
-        let connect_timeout = self.func_ctx.external_server_connect_timeout_secs;
-        let request_timeout = self.func_ctx.external_server_request_timeout_secs;
-        let request_bacth_rows = self.func_ctx.external_server_request_batch_rows;
-        for func in &self.funcs {
-            let server_addr = func.udf_type.as_server().unwrap();
+        for (idx, func) in self.funcs.iter().enumerate() {
+            let addresses = udf_server_addresses(func.udf_type.as_server().unwrap());
+            let address_index = self.address_indexes[idx] % addresses.len();
+            let server_addr = addresses[address_index];
             // construct input record_batch
             let num_rows = data_block.num_rows();
             let block_entries = func
@@ -103,57 +224,37 @@ impl AsyncTransform for TransformUdfServer {
                 .collect::<Vec<_>>();
             let data_schema = DataSchema::new(fields);
 
-            let input_batch = DataBlock::new(block_entries, num_rows)
-                .to_record_batch_with_dataschema(&data_schema)
-                .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
+            let args_block = DataBlock::new(block_entries, num_rows);
 
-            let mut client = UDFFlightClient::connect(
-                server_addr,
-                connect_timeout,
-                request_timeout,
-                request_bacth_rows,
-            )
-            .await?;
-
-            let result_batch = client.do_exchange(&func.func_name, input_batch).await?;
-            let schema = DataSchema::try_from(&(*result_batch.schema()))?;
-            let (result_block, result_schema) =
-                DataBlock::from_record_batch(&schema, &result_batch).map_err(|err| {
-                    ErrorCode::UDFDataError(format!(
-                        "Cannot convert arrow record batch to data block: {err}"
-                    ))
-                })?;
-
-            let result_fields = result_schema.fields();
-            if result_fields.is_empty() || result_block.is_empty() {
-                return Err(ErrorCode::EmptyDataFromServer(
-                    "Get empty data from UDF Server",
-                ));
-            }
-
-            if result_fields[0].data_type() != &*func.data_type {
-                return Err(ErrorCode::UDFSchemaMismatch(format!(
-                    "UDF server return incorrect type, expected: {}, but got: {}",
-                    func.data_type,
-                    result_fields[0].data_type()
-                )));
-            }
-            if result_block.num_rows() != num_rows {
-                return Err(ErrorCode::UDFDataError(format!(
-                    "UDF server should return {} rows, but it returned {} rows",
-                    num_rows,
-                    result_block.num_rows()
-                )));
-            }
+            metrics_inc_udf_server_request_count(server_addr);
+            let result = match self
+                .request_udf_server(func, server_addr, &data_schema, &args_block)
+                .await
+            {
+                Ok(result) => result,
+                Err(err) => {
+                    if addresses.len() > 1 && is_udf_server_failover_error(&err) {
+                        // The retry of the transform sends the requests to the next address.
+                        let next_index = (address_index + 1) % addresses.len();
+                        self.address_indexes[idx] = next_index;
+                        metrics_inc_udf_server_failover_count(server_addr);
+                        warn!(
+                            "UDF server {} of function {} failed, fail over to {}: {}",
+                            server_addr, func.func_name, addresses[next_index], err
+                        );
+                    }
+                    return Err(err);
+                }
+            };
 
             let col = if contains_variant(&func.data_type) {
-                let value = transform_variant(&result_block.get_by_offset(0).value, false)?;
+                let value = transform_variant(&result.value, false)?;
                 BlockEntry {
-                    data_type: result_fields[0].data_type().clone(),
+                    data_type: result.data_type,
                     value,
                 }
             } else {
-                result_block.get_by_offset(0).clone()
+                result
             };
 
             data_block.add_column(col);
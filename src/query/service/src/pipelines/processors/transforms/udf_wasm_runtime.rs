@@ -0,0 +1,292 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runs the WASM modules built with arrow-udf on an embedded wasmtime engine.
+//!
+//! The module exports `alloc`, `dealloc` and a function `arrowudf_<name>` for each UDF,
+//! `<name>` is the base64 encoded signature, e.g. `gcd(int4,int4)->int4`. A UDF takes
+//! the input record batch in the arrow IPC file format and writes the output record batch,
+//! or the error message, to a slice of the module memory.
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::LazyLock;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::writer::FileWriter;
+use base64::alphabet::Alphabet;
+use base64::engine::general_purpose::NO_PAD;
+use base64::engine::GeneralPurpose;
+use base64::Engine as _;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parking_lot::Mutex;
+use wasi_common::sync::WasiCtxBuilder;
+use wasi_common::WasiCtx;
+use wasmtime::Config;
+use wasmtime::Engine;
+use wasmtime::Linker;
+use wasmtime::Memory;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime::StoreLimits;
+use wasmtime::StoreLimitsBuilder;
+use wasmtime::Trap;
+use wasmtime::TypedFunc;
+
+/// All the modules are compiled by the same engine, which meters the fuel of every call.
+static WASM_ENGINE: LazyLock<Engine> = LazyLock::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true);
+    Engine::new(&config).unwrap()
+});
+
+/// Limits of a single WASM UDF call, 0 means unlimited.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WasmUdfLimits {
+    /// Maximum memory in bytes of the instance running the call.
+    pub memory_limit: usize,
+    /// Maximum fuel, about the number of WASM instructions, the call can consume.
+    pub fuel_limit: u64,
+}
+
+pub struct WasmRuntime {
+    module: Module,
+    limits: WasmUdfLimits,
+    /// UDF signature => exported function name
+    functions: HashMap<String, String>,
+    /// Idle instances, an instance runs one call at a time.
+    instances: Mutex<Vec<WasmInstance>>,
+}
+
+impl WasmRuntime {
+    pub fn try_create(code: &[u8], limits: WasmUdfLimits) -> Result<Self> {
+        let module = Module::from_binary(&WASM_ENGINE, code).map_err(|err| {
+            ErrorCode::UDFDataError(format!("Failed to load WASM module: {}", err))
+        })?;
+
+        let mut functions = HashMap::new();
+        for export in module.exports() {
+            if let Some(encoded) = export.name().strip_prefix("arrowudf_") {
+                functions.insert(decode_signature(encoded)?, export.name().to_string());
+            }
+        }
+
+        Ok(WasmRuntime {
+            module,
+            limits,
+            functions,
+            instances: Mutex::new(vec![]),
+        })
+    }
+
+    /// Calls the UDF of signature `name` with the input batch.
+    pub fn call(&self, name: &str, input: &RecordBatch) -> Result<RecordBatch> {
+        let export_name = self.functions.get(name).ok_or_else(|| {
+            ErrorCode::UDFDataError(format!("WASM function '{}' is not found in module", name))
+        })?;
+
+        let instance = self.instances.lock().pop();
+        let mut instance = match instance {
+            Some(instance) => instance,
+            None => WasmInstance::try_create(&self.module, &self.limits)?,
+        };
+
+        let output = instance.call(export_name, input, self.limits.fuel_limit)?;
+        // A failed call may leave the instance in a broken state, only reuse the good ones.
+        self.instances.lock().push(instance);
+        Ok(output)
+    }
+}
+
+struct StoreState {
+    wasi: WasiCtx,
+    limits: StoreLimits,
+}
+
+struct WasmInstance {
+    store: Store<StoreState>,
+    memory: Memory,
+    // fn alloc(len: usize, align: usize) -> *mut u8
+    alloc: TypedFunc<(u32, u32), u32>,
+    // fn dealloc(ptr: *mut u8, len: usize, align: usize)
+    dealloc: TypedFunc<(u32, u32, u32), ()>,
+    instance: wasmtime::Instance,
+}
+
+impl WasmInstance {
+    fn try_create(module: &Module, limits: &WasmUdfLimits) -> Result<Self> {
+        let mut linker = Linker::new(module.engine());
+        wasi_common::sync::add_to_linker(&mut linker, |state: &mut StoreState| &mut state.wasi)
+            .map_err(wasm_error)?;
+
+        let mut builder = StoreLimitsBuilder::new();
+        if limits.memory_limit > 0 {
+            builder = builder.memory_size(limits.memory_limit);
+        }
+        let mut store = Store::new(module.engine(), StoreState {
+            wasi: WasiCtxBuilder::new().build(),
+            limits: builder.build(),
+        });
+        store.limiter(|state| &mut state.limits);
+        set_fuel(&mut store, limits.fuel_limit)?;
+
+        let instance = linker
+            .instantiate(&mut store, module)
+            .map_err(|err| call_error(err, "instantiate the module"))?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| ErrorCode::UDFDataError("WASM module doesn't export memory"))?;
+        let alloc = instance
+            .get_typed_func(&mut store, "alloc")
+            .map_err(wasm_error)?;
+        let dealloc = instance
+            .get_typed_func(&mut store, "dealloc")
+            .map_err(wasm_error)?;
+
+        Ok(WasmInstance {
+            store,
+            memory,
+            alloc,
+            dealloc,
+            instance,
+        })
+    }
+
+    fn call(
+        &mut self,
+        export_name: &str,
+        input: &RecordBatch,
+        fuel_limit: u64,
+    ) -> Result<RecordBatch> {
+        // fn(ptr: *const u8, len: usize, out: *mut CSlice) -> i32
+        let func: TypedFunc<(u32, u32, u32), i32> = self
+            .instance
+            .get_typed_func(&mut self.store, export_name)
+            .map_err(wasm_error)?;
+        let input = encode_record_batch(input)?;
+
+        // The fuel covers the whole call, the memory management included.
+        set_fuel(&mut self.store, fuel_limit)?;
+
+        // The output slice `{ ptr: u32, len: u32 }` is followed by the input.
+        let alloc_len = u32::try_from(input.len() + 8)
+            .map_err(|_| ErrorCode::UDFDataError("WASM UDF input is too large"))?;
+        let alloc_ptr = self
+            .alloc
+            .call(&mut self.store, (alloc_len, 4))
+            .map_err(|err| call_error(err, "allocate the input"))?;
+        if alloc_ptr == 0 {
+            return Err(ErrorCode::UDFDataError(
+                "WASM UDF failed to allocate the input",
+            ));
+        }
+        let in_ptr = alloc_ptr + 8;
+        self.memory
+            .write(&mut self.store, in_ptr as usize, &input)
+            .map_err(wasm_error)?;
+
+        let errno = func
+            .call(&mut self.store, (in_ptr, input.len() as u32, alloc_ptr))
+            .map_err(|err| call_error(err, "run"))?;
+
+        let out_ptr = self.read_u32(alloc_ptr)?;
+        let out_len = self.read_u32(alloc_ptr + 4)?;
+        let output = self
+            .memory
+            .data(&self.store)
+            .get(out_ptr as usize..out_ptr as usize + out_len as usize)
+            .ok_or_else(|| ErrorCode::UDFDataError("WASM UDF output is out of bounds"))?;
+        let result = match errno {
+            0 => decode_record_batch(output),
+            _ => Err(ErrorCode::UDFDataError(
+                String::from_utf8_lossy(output).to_string(),
+            )),
+        };
+
+        self.dealloc
+            .call(&mut self.store, (alloc_ptr, alloc_len, 4))
+            .map_err(|err| call_error(err, "deallocate the input"))?;
+        self.dealloc
+            .call(&mut self.store, (out_ptr, out_len, 1))
+            .map_err(|err| call_error(err, "deallocate the output"))?;
+        result
+    }
+
+    fn read_u32(&self, ptr: u32) -> Result<u32> {
+        let bytes = self
+            .memory
+            .data(&self.store)
+            .get(ptr as usize..ptr as usize + 4)
+            .ok_or_else(|| ErrorCode::UDFDataError("WASM UDF output is out of bounds"))?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+fn set_fuel(store: &mut Store<StoreState>, fuel_limit: u64) -> Result<()> {
+    let fuel = match fuel_limit {
+        0 => u64::MAX,
+        limit => limit,
+    };
+    store.set_fuel(fuel).map_err(wasm_error)
+}
+
+fn wasm_error(err: wasmtime::Error) -> ErrorCode {
+    ErrorCode::UDFDataError(format!("{}", err))
+}
+
+fn call_error(err: wasmtime::Error, action: &str) -> ErrorCode {
+    match err.downcast_ref::<Trap>() {
+        Some(Trap::OutOfFuel) => ErrorCode::UDFDataError(format!(
+            "WASM UDF ran out of fuel while trying to {}, see setting wasm_udf_fuel_limit",
+            action
+        )),
+        _ => ErrorCode::UDFDataError(format!("WASM UDF failed to {}: {:?}", action, err)),
+    }
+}
+
+/// The signatures are encoded with '$' and '_' instead of '+' and '/', which are not valid in
+/// symbol names.
+fn decode_signature(encoded: &str) -> Result<String> {
+    let alphabet =
+        Alphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789$_").unwrap();
+    GeneralPurpose::new(&alphabet, NO_PAD)
+        .decode(encoded)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or_else(|| {
+            ErrorCode::UDFDataError(format!(
+                "Invalid WASM function symbol 'arrowudf_{}'",
+                encoded
+            ))
+        })
+}
+
+fn encode_record_batch(batch: &RecordBatch) -> Result<Vec<u8>> {
+    let mut buf = vec![];
+    let mut writer = FileWriter::try_new(&mut buf, &batch.schema())?;
+    writer.write(batch)?;
+    writer.finish()?;
+    drop(writer);
+    Ok(buf)
+}
+
+fn decode_record_batch(bytes: &[u8]) -> Result<RecordBatch> {
+    let mut reader = FileReader::try_new(Cursor::new(bytes), None)?;
+    reader
+        .next()
+        .ok_or_else(|| ErrorCode::UDFDataError("WASM UDF returned no record batch"))?
+        .map_err(ErrorCode::from)
+}
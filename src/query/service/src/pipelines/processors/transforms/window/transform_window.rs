@@ -42,6 +42,7 @@ use databend_common_sql::plans::WindowFuncFrameUnits;
 
 use super::frame_bound::FrameBound;
 use super::window_function::WindowFuncAggImpl;
+use super::window_function::WindowFuncInterpolateImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
 
@@ -134,6 +135,11 @@ pub struct TransformWindow<T: Number> {
     current_rank: usize,
     current_rank_count: usize,
     current_dense_rank: usize,
+    // used for interpolate: the last non-NULL value before the current row and the next
+    // non-NULL value from the current row, with their row numbers in the partition.
+    // If there is no next non-NULL value, its row number is the one past the partition.
+    interpolate_prev: Option<(f64, usize)>,
+    interpolate_next: (Option<f64>, usize),
 
     // If `is_empty_frame`, the window function result of non-NULL rows will be NULL.
     is_empty_frame: bool,
@@ -626,11 +632,69 @@ impl<T: Number> TransformWindow<T> {
                 };
                 builder.push(ScalarRef::Number(NumberScalar::Float64(cume_dist.into())));
             }
+            WindowFunctionImpl::Interpolate(func) => {
+                // The frame of `interpolate` is always the whole partition. The previous and
+                // the next non-NULL values are carried between the rows, so that each row of
+                // the partition is only scanned once.
+                let current = self.current_row_in_partition;
+                let value = match self.float_value_at(&self.current_row, func.arg) {
+                    Some(value) => {
+                        self.interpolate_prev = Some((value, current));
+                        Some(value)
+                    }
+                    None => {
+                        if self.interpolate_next.1 <= current {
+                            let mut next = None;
+                            let mut row = self.advance_row(self.current_row);
+                            let mut row_in_partition = current + 1;
+                            while row < self.frame_end {
+                                next = self.float_value_at(&row, func.arg);
+                                if next.is_some() {
+                                    break;
+                                }
+                                row = self.advance_row(row);
+                                row_in_partition += 1;
+                            }
+                            self.interpolate_next = (next, row_in_partition);
+                        }
+
+                        match (self.interpolate_prev, self.interpolate_next) {
+                            (Some((prev, prev_row)), (Some(next), next_row)) => {
+                                Some(WindowFuncInterpolateImpl::compute_interpolate(
+                                    prev,
+                                    current - prev_row,
+                                    next,
+                                    next_row - current,
+                                ))
+                            }
+                            _ => None,
+                        }
+                    }
+                };
+
+                let builder = &mut self.blocks[self.current_row.block - self.first_block].builder;
+                match value {
+                    Some(value) => {
+                        builder.push(ScalarRef::Number(NumberScalar::Float64(value.into())))
+                    }
+                    None => builder.push(ScalarRef::Null),
+                }
+            }
         };
 
         Ok(())
     }
 
+    /// Get the `Float64` value of the column at `index`, `None` if the value is NULL.
+    #[inline]
+    fn float_value_at(&self, index: &RowPtr, column_index: usize) -> Option<f64> {
+        let value = &self.block_at(index).get_by_offset(column_index).value;
+        match value.index(index.row).unwrap() {
+            ScalarRef::Number(NumberScalar::Float64(value)) => Some(value.0),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn if_need_check_null_frame(&self) -> bool {
         self.frame_unit.is_range() && self.order_by.len() == 1 && self.order_by[0].is_nullable
@@ -712,6 +776,8 @@ impl TransformWindow<u64> {
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            interpolate_prev: None,
+            interpolate_next: (None, 0),
             input_is_finished: false,
             is_empty_frame,
             is_ranking,
@@ -789,6 +855,8 @@ where T: Number + ResultTypeOfUnary
             current_rank: 1,
             current_rank_count: 1,
             current_dense_rank: 1,
+            interpolate_prev: None,
+            interpolate_next: (None, 0),
             input_is_finished: false,
             is_empty_frame,
             is_ranking,
@@ -1073,6 +1141,8 @@ where T: Number + ResultTypeOfUnary
                 self.current_rank = 1;
                 self.current_rank_count = 1;
                 self.current_dense_rank = 1;
+                self.interpolate_prev = None;
+                self.interpolate_next = (None, 0);
             }
         }
 
@@ -43,6 +43,7 @@ pub enum WindowFunctionInfo {
     NthValue(WindowFuncNthValueImpl),
     Ntile(WindowFuncNtileImpl),
     CumeDist,
+    Interpolate(WindowFuncInterpolateImpl),
 }
 
 pub struct WindowFuncAggImpl {
@@ -133,6 +134,26 @@ impl WindowFuncNtileImpl {
     }
 }
 
+#[derive(Clone)]
+pub struct WindowFuncInterpolateImpl {
+    pub arg: usize,
+    pub return_type: DataType,
+}
+
+impl WindowFuncInterpolateImpl {
+    /// Linear interpolation between the previous non-NULL value `prev` which is `prev_distance`
+    /// rows before the current row and the next non-NULL value `next` which is `next_distance`
+    /// rows after the current row.
+    pub(crate) fn compute_interpolate(
+        prev: f64,
+        prev_distance: usize,
+        next: f64,
+        next_distance: usize,
+    ) -> f64 {
+        prev + (next - prev) * prev_distance as f64 / (prev_distance + next_distance) as f64
+    }
+}
+
 struct NtileBucket {
     // number of rows in a bucket: `(number of rows in partition) / (number of buckets)`.
     rows_per_bucket: usize,
@@ -179,16 +200,18 @@ pub enum WindowFunctionImpl {
     NthValue(WindowFuncNthValueImpl),
     Ntile(WindowFuncNtileImpl),
     CumeDist,
+    Interpolate(WindowFuncInterpolateImpl),
 }
 
 impl WindowFunctionInfo {
     pub fn try_create(window: &WindowFunction, schema: &DataSchema) -> Result<Self> {
         Ok(match window {
             WindowFunction::Aggregate(agg) => {
-                let agg_func = AggregateFunctionFactory::instance().get(
+                let agg_func = AggregateFunctionFactory::instance().get_with_sort_descs(
                     agg.sig.name.as_str(),
                     agg.sig.params.clone(),
                     agg.sig.args.clone(),
+                    agg.sig.sort_descs.clone(),
                 )?;
                 let args = agg
                     .arg_indices
@@ -232,6 +255,13 @@ impl WindowFunctionInfo {
                 return_type: func.return_type.clone(),
             }),
             WindowFunction::CumeDist => Self::CumeDist,
+            WindowFunction::Interpolate(func) => {
+                let new_arg = schema.index_of(&func.arg.to_string())?;
+                Self::Interpolate(WindowFuncInterpolateImpl {
+                    arg: new_arg,
+                    return_type: func.return_type.clone(),
+                })
+            }
         })
     }
 }
@@ -262,6 +292,7 @@ impl WindowFunctionImpl {
             WindowFunctionInfo::NthValue(func) => Self::NthValue(func),
             WindowFunctionInfo::Ntile(func) => Self::Ntile(func),
             WindowFunctionInfo::CumeDist => Self::CumeDist,
+            WindowFunctionInfo::Interpolate(func) => Self::Interpolate(func),
         })
     }
 
@@ -275,6 +306,7 @@ impl WindowFunctionImpl {
             Self::LagLead(f) => f.return_type.clone(),
             Self::NthValue(f) => f.return_type.clone(),
             Self::Ntile(f) => f.return_type.clone(),
+            Self::Interpolate(f) => f.return_type.clone(),
         })
     }
 
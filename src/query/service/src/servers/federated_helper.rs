@@ -17,9 +17,10 @@
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_settings::Settings;
 use regex::Regex;
 
-pub type LazyBlockFunc = fn(&str) -> Option<(TableSchemaRef, DataBlock)>;
+pub type LazyBlockFunc = fn(&Settings, &str) -> Option<(TableSchemaRef, DataBlock)>;
 
 pub struct FederatedHelper {}
 
@@ -41,12 +42,13 @@ impl FederatedHelper {
     }
 
     pub fn lazy_block_match_rule(
+        settings: &Settings,
         query: &str,
         rules: &[(Regex, LazyBlockFunc)],
     ) -> Option<(TableSchemaRef, DataBlock)> {
         for (regex, func) in rules.iter() {
             if regex.is_match(query) {
-                return match func(query) {
+                return match func(settings, query) {
                     None => Some((TableSchemaRefExt::create(vec![]), DataBlock::empty())),
                     Some((schema, data_block)) => Some((schema, data_block)),
                 };
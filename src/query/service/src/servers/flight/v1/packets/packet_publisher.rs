@@ -171,6 +171,7 @@ impl QueryEnv {
 
         query_ctx.set_id(self.query_id.clone());
         query_ctx.attach_query_str(self.query_kind, "".to_string());
+        query_ctx.enable_failpoints()?;
 
         Ok(query_ctx)
     }
@@ -14,6 +14,7 @@
 
 use databend_common_base::base::mask_connection_info;
 use databend_common_base::runtime::drop_guard;
+use databend_common_config::QUERY_GIT_SHA;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::DataSchemaRef;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
@@ -52,6 +53,7 @@ use crate::sessions::QueryAffect;
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
+const HEADER_SERVER_GIT_SHA: &str = "X-DATABEND-SERVER-GIT-SHA";
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -112,6 +114,111 @@ impl QueryResponseField {
     }
 }
 
+/// The encoding rules of the values in `data`, only returned when the request asks for
+/// a `result_format_version`, so clients can deserialize the rows into typed values.
+///
+/// The `type` of each field in `schema` is rendered with the type name grammar
+/// accepted by `CAST(<expr> AS <type>)`, except `NULL`, `Array(Nothing)` and
+/// `Map(Nothing)`, which are the types of untyped literals.
+///
+/// SQL NULL is encoded as JSON null at any level, other scalar values are JSON strings.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ResultFormat {
+    pub version: u64,
+    pub null_value: Option<String>,
+    pub boolean_encoding: BooleanEncoding,
+    pub binary_encoding: BinaryEncoding,
+    pub decimal_encoding: DecimalEncoding,
+    pub date_encoding: DateEncoding,
+    pub timestamp_encoding: TimestampEncoding,
+    pub variant_encoding: VariantEncoding,
+    pub nested_encoding: NestedEncoding,
+    /// The max number of bytes of a chunk of the chunked binary and variant values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_size: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BooleanEncoding {
+    /// `"1"` for true and `"0"` for false.
+    Digit,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BinaryEncoding {
+    Base64,
+    /// An array of base64 strings, each encodes at most `chunk_size` bytes.
+    Base64Chunked,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DecimalEncoding {
+    /// A decimal string with all the digits of the scale, e.g. `"1.50"` for `Decimal(3, 2)`.
+    String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DateEncoding {
+    /// `YYYY-MM-DD`.
+    Iso8601,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampEncoding {
+    /// Microseconds since the unix epoch.
+    EpochMicros,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VariantEncoding {
+    /// The JSON text of the value.
+    Json,
+    /// An array of strings whose concatenation is the JSON text of the value,
+    /// each has at most `chunk_size` bytes.
+    JsonChunked,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NestedEncoding {
+    /// Arrays and tuples are JSON arrays of their elements, maps are JSON arrays
+    /// of `[key, value]` pairs, the elements are encoded with the same rules.
+    JsonArray,
+}
+
+impl ResultFormat {
+    pub fn from_request(version: u64, chunk_size: Option<u64>) -> Option<Self> {
+        let chunk_size = chunk_size.filter(|chunk_size| *chunk_size > 0);
+        match version {
+            0 => None,
+            _ => Some(ResultFormat {
+                version,
+                null_value: None,
+                boolean_encoding: BooleanEncoding::Digit,
+                binary_encoding: match chunk_size {
+                    Some(_) => BinaryEncoding::Base64Chunked,
+                    None => BinaryEncoding::Base64,
+                },
+                decimal_encoding: DecimalEncoding::String,
+                date_encoding: DateEncoding::Iso8601,
+                timestamp_encoding: TimestampEncoding::EpochMicros,
+                variant_encoding: match chunk_size {
+                    Some(_) => VariantEncoding::JsonChunked,
+                    None => VariantEncoding::Json,
+                },
+                nested_encoding: NestedEncoding::JsonArray,
+                chunk_size,
+            }),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueryResponse {
     pub id: String,
@@ -127,7 +234,12 @@ pub struct QueryResponse {
     // about results
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_result_set: Option<bool>,
+    /// True if the last row of the result is the grand-total row of `GROUP BY ... WITH TOTALS`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_totals_row: Option<bool>,
     pub schema: Vec<QueryResponseField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_format: Option<ResultFormat>,
     pub data: Vec<Vec<JsonValue>>,
     pub affect: Option<QueryAffect>,
 
@@ -190,6 +302,7 @@ impl QueryResponse {
             data: data.into(),
             state: state.state,
             schema: state.schema.clone(),
+            result_format: r.result_format,
             session_id: Some(session_id),
             node_id: r.node_id,
             session: r.session,
@@ -203,10 +316,12 @@ impl QueryResponse {
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.map(QueryError::from_error_code),
             has_result_set: r.state.has_result_set,
+            has_totals_row: r.state.has_totals_row,
         })
         .with_header(HEADER_QUERY_ID, id.clone())
         .with_header(HEADER_QUERY_STATE, state.state.to_string())
         .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        .with_header(HEADER_SERVER_GIT_SHA, QUERY_GIT_SHA.as_str())
     }
 }
 
@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use databend_common_exception::Result;
 use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
@@ -21,6 +23,8 @@ use databend_common_formats::field_encoder::FieldEncoderValues;
 use databend_common_io::prelude::FormatSettings;
 use serde_json::Value as JsonValue;
 
+use crate::servers::http::v1::ResultFormat;
+
 #[derive(Debug, Clone, Default)]
 pub struct JsonBlock {
     pub(crate) data: Vec<Vec<JsonValue>>,
@@ -28,9 +32,13 @@ pub struct JsonBlock {
 
 pub type JsonBlockRef = Arc<JsonBlock>;
 
+/// The latest version of the result format, see [`ResultFormat`] for the encoding rules.
+pub const RESULT_FORMAT_VERSION: u64 = 1;
+
 pub fn block_to_json_value(
     block: &DataBlock,
     format: &FormatSettings,
+    result_format: Option<&ResultFormat>,
 ) -> Result<Vec<Vec<JsonValue>>> {
     if block.is_empty() {
         return Ok(vec![]);
@@ -46,10 +54,18 @@ pub fn block_to_json_value(
     let mut res = Vec::new();
     let encoder =
         FieldEncoderValues::create_for_http_handler(format.timezone, format.geometry_format);
+    let typed_encoder = result_format.map(|result_format| TypedFieldEncoder {
+        encoder: &encoder,
+        chunk_size: result_format.chunk_size.map(|size| size as usize),
+    });
     let mut buf = vec![];
     for row_index in 0..rows_size {
         let mut row: Vec<JsonValue> = Vec::with_capacity(block.num_columns());
         for column in &columns {
+            if let Some(typed_encoder) = &typed_encoder {
+                row.push(typed_encoder.encode(column, row_index, &mut buf)?);
+                continue;
+            }
             buf.clear();
             encoder.write_field(column, row_index, &mut buf, false);
             row.push(serde_json::to_value(String::from_utf8_lossy(&buf))?);
@@ -59,14 +75,105 @@ pub fn block_to_json_value(
     Ok(res)
 }
 
+/// Encode the fields with the rules of [`ResultFormat`].
+struct TypedFieldEncoder<'a> {
+    encoder: &'a FieldEncoderValues,
+    chunk_size: Option<usize>,
+}
+
+impl TypedFieldEncoder<'_> {
+    fn encode(&self, column: &Column, row_index: usize, buf: &mut Vec<u8>) -> Result<JsonValue> {
+        match column {
+            Column::Null { .. } => Ok(JsonValue::Null),
+            Column::Nullable(box c) if !c.validity.get_bit(row_index) => Ok(JsonValue::Null),
+            Column::Nullable(box c) => self.encode(&c.column, row_index, buf),
+            Column::EmptyArray { .. } | Column::EmptyMap { .. } => Ok(JsonValue::Array(vec![])),
+            Column::Timestamp(c) => Ok(JsonValue::String(c[row_index].to_string())),
+            Column::Binary(c) => {
+                let value = unsafe { c.index_unchecked(row_index) };
+                Ok(match self.chunk_size {
+                    Some(chunk_size) => JsonValue::Array(
+                        value
+                            .chunks(chunk_size)
+                            .map(|chunk| JsonValue::String(BASE64_STANDARD.encode(chunk)))
+                            .collect(),
+                    ),
+                    None => JsonValue::String(BASE64_STANDARD.encode(value)),
+                })
+            }
+            Column::Variant(c) => {
+                let value = jsonb::to_string(unsafe { c.index_unchecked(row_index) });
+                Ok(match self.chunk_size {
+                    Some(chunk_size) => JsonValue::Array(
+                        split_str(&value, chunk_size)
+                            .map(|chunk| JsonValue::String(chunk.to_string()))
+                            .collect(),
+                    ),
+                    None => JsonValue::String(value),
+                })
+            }
+            // The values of a map are tuples of the key and value, which are encoded as pairs.
+            Column::Array(box c) | Column::Map(box c) => {
+                let start = c.offsets[row_index] as usize;
+                let end = c.offsets[row_index + 1] as usize;
+                let values = (start..end)
+                    .map(|i| self.encode(&c.values, i, buf))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(JsonValue::Array(values))
+            }
+            Column::Tuple(fields) => {
+                let values = fields
+                    .iter()
+                    .map(|field| self.encode(field, row_index, buf))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(JsonValue::Array(values))
+            }
+            _ => {
+                buf.clear();
+                self.encoder.write_field(column, row_index, buf, false);
+                Ok(serde_json::to_value(String::from_utf8_lossy(buf))?)
+            }
+        }
+    }
+}
+
+/// Split the string into chunks of at most `chunk_size` bytes at char boundaries,
+/// a char longer than `chunk_size` is kept in one chunk.
+fn split_str(s: &str, chunk_size: usize) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let mut end = chunk_size.min(rest.len());
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().unwrap().len_utf8();
+        }
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
 impl JsonBlock {
     pub fn empty() -> Self {
         Self { data: vec![] }
     }
 
     pub fn new(block: &DataBlock, format: &FormatSettings) -> Result<Self> {
+        Self::new_with_result_format(block, format, None)
+    }
+
+    pub fn new_with_result_format(
+        block: &DataBlock,
+        format: &FormatSettings,
+        result_format: Option<&ResultFormat>,
+    ) -> Result<Self> {
         Ok(JsonBlock {
-            data: block_to_json_value(block, format)?,
+            data: block_to_json_value(block, format, result_format)?,
         })
     }
 
@@ -24,13 +24,22 @@ pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
+pub use http_query_handlers::BinaryEncoding;
+pub use http_query_handlers::BooleanEncoding;
+pub use http_query_handlers::DateEncoding;
+pub use http_query_handlers::DecimalEncoding;
+pub use http_query_handlers::NestedEncoding;
 pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
+pub use http_query_handlers::ResultFormat;
+pub use http_query_handlers::TimestampEncoding;
+pub use http_query_handlers::VariantEncoding;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
 pub(crate) use login::login_handler;
+pub use query::sized_spsc;
 pub use query::ExecuteStateKind;
 pub use query::ExpiringMap;
 pub use query::ExpiringState;
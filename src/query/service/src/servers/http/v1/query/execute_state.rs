@@ -120,6 +120,7 @@ pub struct ExecuteRunning {
     ctx: Arc<QueryContext>,
     schema: Vec<QueryResponseField>,
     has_result_set: bool,
+    has_totals_row: bool,
     #[allow(dead_code)]
     queue_guard: AcquireQueueGuard,
 }
@@ -127,6 +128,7 @@ pub struct ExecuteRunning {
 pub struct ExecuteStopped {
     pub schema: Vec<QueryResponseField>,
     pub has_result_set: Option<bool>,
+    pub has_totals_row: Option<bool>,
     pub stats: Progresses,
     pub affect: Option<QueryAffect>,
     pub reason: Result<()>,
@@ -176,6 +178,7 @@ impl Executor {
             affect: self.get_affect(),
             schema: self.get_schema(),
             has_result_set: self.has_result_set(),
+            has_totals_row: self.has_totals_row(),
         }
     }
     pub fn get_schema(&self) -> Vec<QueryResponseField> {
@@ -194,6 +197,14 @@ impl Executor {
         }
     }
 
+    pub fn has_totals_row(&self) -> Option<bool> {
+        match &self.state {
+            Starting(_) => None,
+            Running(r) => Some(r.has_totals_row),
+            Stopped(f) => f.has_totals_row,
+        }
+    }
+
     pub fn get_progress(&self) -> Progresses {
         match &self.state {
             Starting(_) => Default::default(),
@@ -289,6 +300,7 @@ impl Executor {
                     stats: Default::default(),
                     schema: vec![],
                     has_result_set: None,
+                    has_totals_row: None,
                     reason,
                     session_state: ExecutorSessionState::new(s.ctx.get_current_session()),
                     query_duration_ms: s.ctx.get_query_duration_ms(),
@@ -308,6 +320,7 @@ impl Executor {
                     stats: Progresses::from_context(&r.ctx),
                     schema: r.schema.clone(),
                     has_result_set: Some(r.has_result_set),
+                    has_totals_row: Some(r.has_totals_row),
                     reason,
                     session_state: ExecutorSessionState::new(r.ctx.get_current_session()),
                     query_duration_ms: r.ctx.get_query_duration_ms(),
@@ -370,6 +383,7 @@ impl ExecuteState {
             queue_guard,
             schema,
             has_result_set,
+            has_totals_row: plan.has_totals_row(),
         };
         info!("http query change state to Running");
         Executor::start_to_running(&executor, Running(running_state)).await;
@@ -415,14 +429,16 @@ async fn execute(
             DataType::String,
             databend_common_expression::Value::Scalar(Scalar::String(err.to_string())),
         );
-        block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+        let block = DataBlock::new(vec![data], 1);
+        let bytes = block.memory_size();
+        block_sender.send(block, 1, bytes).await;
         return Err(err);
     }
     let mut data_stream = data_stream_res.unwrap();
     match data_stream.next().await {
         None => {
             let block = DataBlock::empty_with_schema(schema);
-            block_sender.send(block, 0).await;
+            block_sender.send(block, 0, 0).await;
             Executor::stop(&executor, Ok(())).await;
             block_sender.close();
         }
@@ -432,17 +448,22 @@ async fn execute(
                 DataType::String,
                 databend_common_expression::Value::Scalar(Scalar::String(err.to_string())),
             );
-            block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+            let block = DataBlock::new(vec![data], 1);
+            let bytes = block.memory_size();
+            block_sender.send(block, 1, bytes).await;
             Executor::stop(&executor, Err(err)).await;
             block_sender.close();
         }
         Some(Ok(block)) => {
             let size = block.num_rows();
-            block_sender.send(block, size).await;
+            let bytes = block.memory_size();
+            block_sender.send(block, size, bytes).await;
             while let Some(block_r) = data_stream.next().await {
                 match block_r {
                     Ok(block) => {
-                        block_sender.send(block.clone(), block.num_rows()).await;
+                        let size = block.num_rows();
+                        let bytes = block.memory_size();
+                        block_sender.send(block, size, bytes).await;
                     }
                     Err(err) => {
                         // duplicate codes, but there is an async call
@@ -452,7 +473,9 @@ async fn execute(
                                 err.to_string(),
                             )),
                         );
-                        block_sender.send(DataBlock::new(vec![data], 1), 1).await;
+                        let block = DataBlock::new(vec![data], 1);
+                        let bytes = block.memory_size();
+                        block_sender.send(block, 1, bytes).await;
                         block_sender.close();
                         return Err(err);
                     }
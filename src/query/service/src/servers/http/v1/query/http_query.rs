@@ -45,6 +45,8 @@ use serde::Serialize;
 use super::HttpQueryContext;
 use super::RemoveReason;
 use crate::servers::http::v1::http_query_handlers::QueryResponseField;
+use crate::servers::http::v1::http_query_handlers::ResultFormat;
+use crate::servers::http::v1::json_block::RESULT_FORMAT_VERSION;
 use crate::servers::http::v1::query::execute_state::ExecuteStarting;
 use crate::servers::http::v1::query::execute_state::ExecuteStopped;
 use crate::servers::http::v1::query::execute_state::ExecutorSessionState;
@@ -52,6 +54,7 @@ use crate::servers::http::v1::query::execute_state::Progresses;
 use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::query::expirable::ExpiringState;
 use crate::servers::http::v1::query::sized_spsc::sized_spsc;
+use crate::servers::http::v1::query::sized_spsc::SizedChannelLimits;
 use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
@@ -81,6 +84,14 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    /// The version of the result format, 0 for the legacy format in which all the
+    /// values are strings, see `ResultFormat` for the encoding rules of other versions.
+    #[serde(default)]
+    pub result_format_version: u64,
+    /// Split the binary and variant values into chunks of at most this many bytes,
+    /// only works with `result_format_version` 1 or later.
+    #[serde(default)]
+    pub result_chunk_size: Option<u64>,
 }
 
 impl HttpQueryRequest {
@@ -104,6 +115,7 @@ impl HttpQueryRequest {
             affect: None,
             data: vec![],
             schema: vec![],
+            result_format: None,
             session_id: None,
             warnings: vec![],
             node_id: "".to_string(),
@@ -114,6 +126,7 @@ impl HttpQueryRequest {
             kill_uri: None,
             error: Some(QueryError::from_error_code(err)),
             has_result_set: None,
+            has_totals_row: None,
         })
     }
 }
@@ -127,6 +140,8 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("result_format_version", &self.result_format_version)
+            .field("result_chunk_size", &self.result_chunk_size)
             .finish()
     }
 }
@@ -220,6 +235,7 @@ pub struct StageAttachmentConf {
 #[derive(Debug, Clone)]
 pub struct ResponseState {
     pub has_result_set: Option<bool>,
+    pub has_totals_row: Option<bool>,
     pub schema: Vec<QueryResponseField>,
     pub running_time_ms: i64,
     pub progresses: Progresses,
@@ -235,6 +251,7 @@ pub struct HttpQueryResponseInternal {
     pub session: Option<HttpSessionConf>,
     pub state: ResponseState,
     pub node_id: String,
+    pub result_format: Option<ResultFormat>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -324,6 +341,18 @@ impl HttpQuery {
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
     ) -> Result<Arc<HttpQuery>> {
+        if request.result_format_version > RESULT_FORMAT_VERSION {
+            return Err(ErrorCode::BadArguments(format!(
+                "unsupported result_format_version {}, the latest version is {}",
+                request.result_format_version, RESULT_FORMAT_VERSION
+            )));
+        }
+        if request.result_chunk_size.is_some() && request.result_format_version == 0 {
+            return Err(ErrorCode::BadArguments(
+                "result_chunk_size requires result_format_version 1 or later",
+            ));
+        }
+
         let http_query_manager = HttpQueryManager::instance();
 
         // If session_id is specified, the new query will be attached in the same session.
@@ -407,6 +436,11 @@ impl HttpQuery {
 
         let settings = session.get_settings();
         let result_timeout_secs = settings.get_http_handler_result_timeout_secs()?;
+        let buffer_limits = SizedChannelLimits {
+            max_size: request.pagination.max_rows_in_buffer,
+            max_bytes: settings.get_http_handler_result_buffer_max_bytes()? as usize,
+            global_max_bytes: http_query_manager.result_buffer_global_max_bytes,
+        };
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
         let query_id = ctx.query_id.clone();
@@ -452,7 +486,8 @@ impl HttpQuery {
             None => {}
         };
 
-        let (block_sender, block_receiver) = sized_spsc(request.pagination.max_rows_in_buffer);
+        let (block_sender, block_receiver) =
+            sized_spsc(buffer_limits, ctx.get_result_buffered_bytes());
 
         let state = Arc::new(RwLock::new(Executor {
             query_id: query_id.clone(),
@@ -490,6 +525,7 @@ impl HttpQuery {
                         stats: Progresses::default(),
                         schema: vec![],
                         has_result_set: None,
+                        has_totals_row: None,
                         reason: Err(e.clone()),
                         session_state: ExecutorSessionState::new(ctx_clone.get_current_session()),
                         query_duration_ms: ctx_clone.get_query_duration_ms(),
@@ -509,6 +545,7 @@ impl HttpQuery {
             request.pagination.max_rows_per_page,
             block_receiver,
             format_settings,
+            ResultFormat::from_request(request.result_format_version, request.result_chunk_size),
         )));
 
         let query = HttpQuery {
@@ -539,6 +576,10 @@ impl HttpQuery {
             session: Some(session),
             node_id: self.node_id.clone(),
             session_id: self.session_id.clone(),
+            result_format: ResultFormat::from_request(
+                self.request.result_format_version,
+                self.request.result_chunk_size,
+            ),
         })
     }
 
@@ -553,6 +594,10 @@ impl HttpQuery {
             node_id: self.node_id.clone(),
             state,
             session: Some(session),
+            result_format: ResultFormat::from_request(
+                self.request.result_format_version,
+                self.request.result_chunk_size,
+            ),
         }
     }
 
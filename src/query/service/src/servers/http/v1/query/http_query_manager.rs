@@ -86,6 +86,8 @@ pub struct HttpQueryManager {
     #[allow(clippy::type_complexity)]
     pub(crate) txn_managers: Arc<Mutex<HashMap<String, (TxnManagerRef, task::JoinHandle<()>)>>>,
     pub(crate) sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
+    /// bytes of result blocks that can be buffered by all the queries.
+    pub(crate) result_buffer_global_max_bytes: usize,
 }
 
 impl HttpQueryManager {
@@ -101,6 +103,8 @@ impl HttpQueryManager {
             sessions: Mutex::new(ExpiringMap::default()),
             removed_queries: Arc::new(parking_lot::Mutex::new(LimitedQueue::new(1000))),
             txn_managers: Arc::new(Mutex::new(HashMap::new())),
+            result_buffer_global_max_bytes: cfg.query.http_handler_result_buffer_global_max_bytes
+                as usize,
         }));
 
         Ok(())
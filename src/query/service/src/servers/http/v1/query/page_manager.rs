@@ -29,6 +29,7 @@ use serde_json::Value as JsonValue;
 use crate::servers::http::v1::json_block::block_to_json_value;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
 use crate::servers::http::v1::JsonBlock;
+use crate::servers::http::v1::ResultFormat;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Wait {
@@ -57,6 +58,7 @@ pub struct PageManager {
     row_buffer: VecDeque<Vec<JsonValue>>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: Arc<RwLock<Option<FormatSettings>>>,
+    result_format: Option<ResultFormat>,
 }
 
 impl PageManager {
@@ -64,6 +66,7 @@ impl PageManager {
         max_rows_per_page: usize,
         block_receiver: SizedChannelReceiver<DataBlock>,
         format_settings: Arc<RwLock<Option<FormatSettings>>>,
+        result_format: Option<ResultFormat>,
     ) -> PageManager {
         PageManager {
             total_rows: 0,
@@ -75,6 +78,7 @@ impl PageManager {
             block_receiver,
             max_rows_per_page,
             format_settings,
+            result_format,
         }
     }
 
@@ -138,7 +142,7 @@ impl PageManager {
             let guard = self.format_settings.read();
             guard.as_ref().unwrap().clone()
         };
-        let mut iter = block_to_json_value(&block, &format_settings)?
+        let mut iter = block_to_json_value(&block, &format_settings, self.result_format.as_ref())?
             .into_iter()
             .peekable();
         let chunk: Vec<_> = iter.by_ref().take(remain).collect();
@@ -17,24 +17,50 @@
 //! other features:
 //! 1. it is SPSC, enough for now.
 //! 2. receive can check status of channel with fn is_empty().
+//! 3. it is also bounded with the bytes of items, both of this channel and of all channels
+//!    in this process, the bytes buffered are released once the receiver stops.
 
 use std::collections::VecDeque;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use databend_common_base::base::tokio::sync::Notify;
+use databend_common_metrics::http::metrics_incr_http_result_evicted_bytes;
+use databend_common_metrics::http::metrics_set_http_result_buffered_bytes;
+
+/// bytes buffered by all the channels in this process.
+static GLOBAL_BUFFERED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+pub fn global_buffered_bytes() -> usize {
+    GLOBAL_BUFFERED_BYTES.load(Ordering::Relaxed)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SizedChannelLimits {
+    pub max_size: usize,
+    pub max_bytes: usize,
+    pub global_max_bytes: usize,
+}
 
 struct SizedChannelInner<T> {
-    max_size: usize,
-    values: VecDeque<(T, usize)>,
+    limits: SizedChannelLimits,
+    /// (value, size, bytes)
+    values: VecDeque<(T, usize, usize)>,
+    /// bytes of values, shared with the owner to report it, e.g. in `system.processes`.
+    buffered_bytes: Arc<AtomicUsize>,
     is_recv_stopped: bool,
     is_send_stopped: bool,
 }
 
 struct Stopped {}
 
-pub fn sized_spsc<T>(max_size: usize) -> (SizedChannelSender<T>, SizedChannelReceiver<T>) {
-    let chan = Arc::new(SizedChannel::create(max_size));
+pub fn sized_spsc<T>(
+    limits: SizedChannelLimits,
+    buffered_bytes: Arc<AtomicUsize>,
+) -> (SizedChannelSender<T>, SizedChannelReceiver<T>) {
+    let chan = Arc::new(SizedChannel::create(limits, buffered_bytes));
     let cloned = chan.clone();
     (SizedChannelSender { chan }, SizedChannelReceiver {
         chan: cloned,
@@ -42,10 +68,11 @@ pub fn sized_spsc<T>(max_size: usize) -> (SizedChannelSender<T>, SizedChannelRec
 }
 
 impl<T> SizedChannelInner<T> {
-    pub fn create(max_size: usize) -> Self {
+    pub fn create(limits: SizedChannelLimits, buffered_bytes: Arc<AtomicUsize>) -> Self {
         SizedChannelInner {
-            max_size,
+            limits,
             values: Default::default(),
+            buffered_bytes,
             is_recv_stopped: false,
             is_send_stopped: false,
         }
@@ -55,12 +82,38 @@ impl<T> SizedChannelInner<T> {
         self.values.iter().map(|x| x.1).sum::<usize>()
     }
 
-    pub fn try_send(&mut self, value: T, size: usize) -> Result<Option<T>, Stopped> {
-        let current_size = self.size();
+    fn bytes(&self) -> usize {
+        self.buffered_bytes.load(Ordering::Relaxed)
+    }
+
+    fn add_bytes(&self, bytes: usize) {
+        self.buffered_bytes.fetch_add(bytes, Ordering::Relaxed);
+        let global = GLOBAL_BUFFERED_BYTES.fetch_add(bytes, Ordering::Relaxed) + bytes;
+        metrics_set_http_result_buffered_bytes(global);
+    }
+
+    fn sub_bytes(&self, bytes: usize) {
+        self.buffered_bytes.fetch_sub(bytes, Ordering::Relaxed);
+        let global = GLOBAL_BUFFERED_BYTES.fetch_sub(bytes, Ordering::Relaxed) - bytes;
+        metrics_set_http_result_buffered_bytes(global);
+    }
+
+    /// the first value is always accepted to make sure the receiver can make progress.
+    fn can_accept(&self, size: usize, bytes: usize) -> bool {
+        if self.values.is_empty() {
+            return true;
+        }
+        self.size() + size <= self.limits.max_size
+            && self.bytes() + bytes <= self.limits.max_bytes
+            && global_buffered_bytes() + bytes <= self.limits.global_max_bytes
+    }
+
+    pub fn try_send(&mut self, value: T, size: usize, bytes: usize) -> Result<Option<T>, Stopped> {
         if self.is_recv_stopped || self.is_send_stopped {
             Err(Stopped {})
-        } else if current_size + size <= self.max_size || current_size == 0 {
-            self.values.push_back((value, size));
+        } else if self.can_accept(size, bytes) {
+            self.values.push_back((value, size, bytes));
+            self.add_bytes(bytes);
             Ok(None)
         } else {
             Ok(Some(value))
@@ -68,7 +121,10 @@ impl<T> SizedChannelInner<T> {
     }
 
     pub fn try_recv(&mut self) -> Result<Option<T>, Stopped> {
-        let v = self.values.pop_front().map(|x| x.0);
+        let v = self.values.pop_front().map(|(value, _, bytes)| {
+            self.sub_bytes(bytes);
+            value
+        });
         if v.is_none() && self.is_send_stopped {
             Err(Stopped {})
         } else {
@@ -76,6 +132,15 @@ impl<T> SizedChannelInner<T> {
         }
     }
 
+    /// drop the values no one will receive, release their bytes at once.
+    fn evict(&mut self) {
+        let bytes = self.values.drain(..).map(|x| x.2).sum::<usize>();
+        if bytes > 0 {
+            self.sub_bytes(bytes);
+            metrics_incr_http_result_evicted_bytes(bytes);
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.values.is_empty() && self.is_send_stopped
     }
@@ -85,7 +150,14 @@ impl<T> SizedChannelInner<T> {
     }
 
     pub fn stop_recv(&mut self) {
-        self.is_recv_stopped = true
+        self.is_recv_stopped = true;
+        self.evict();
+    }
+}
+
+impl<T> Drop for SizedChannelInner<T> {
+    fn drop(&mut self) {
+        self.evict();
     }
 }
 
@@ -96,17 +168,17 @@ struct SizedChannel<T> {
 }
 
 impl<T> SizedChannel<T> {
-    fn create(max_size: usize) -> Self {
+    fn create(limits: SizedChannelLimits, buffered_bytes: Arc<AtomicUsize>) -> Self {
         SizedChannel {
-            inner: Mutex::new(SizedChannelInner::create(max_size)),
+            inner: Mutex::new(SizedChannelInner::create(limits, buffered_bytes)),
             notify_on_sent: Default::default(),
             notify_on_recv: Default::default(),
         }
     }
 
-    fn try_send(&self, value: T, size: usize) -> Result<Option<T>, Stopped> {
+    fn try_send(&self, value: T, size: usize, bytes: usize) -> Result<Option<T>, Stopped> {
         let mut guard = self.inner.lock().unwrap();
-        guard.try_send(value, size)
+        guard.try_send(value, size, bytes)
     }
 
     pub fn try_recv(&self) -> Result<Option<T>, Stopped> {
@@ -115,10 +187,10 @@ impl<T> SizedChannel<T> {
     }
 
     #[async_backtrace::framed]
-    pub async fn send(&self, value: T, size: usize) -> bool {
+    pub async fn send(&self, value: T, size: usize, bytes: usize) -> bool {
         let mut to_send = value;
         loop {
-            match self.try_send(to_send, size) {
+            match self.try_send(to_send, size, bytes) {
                 Ok(Some(v)) => {
                     to_send = v;
                     self.notify_on_recv.notified().await;
@@ -199,8 +271,8 @@ pub struct SizedChannelSender<T> {
 
 impl<T> SizedChannelSender<T> {
     #[async_backtrace::framed]
-    pub async fn send(&self, value: T, size: usize) -> bool {
-        self.chan.send(value, size).await
+    pub async fn send(&self, value: T, size: usize, bytes: usize) -> bool {
+        self.chan.send(value, size, bytes).await
     }
 
     pub fn close(&self) {
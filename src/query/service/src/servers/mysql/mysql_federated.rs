@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use ctor::ctor;
@@ -25,16 +24,20 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_settings::Settings;
+use log::warn;
 use regex::Regex;
 
 use crate::servers::federated_helper::FederatedHelper;
 use crate::servers::federated_helper::LazyBlockFunc;
 
-pub struct MySQLFederated {}
+pub struct MySQLFederated {
+    settings: Arc<Settings>,
+}
 
 impl MySQLFederated {
-    pub fn create() -> Self {
-        MySQLFederated {}
+    pub fn create(settings: Arc<Settings>) -> Self {
+        MySQLFederated { settings }
     }
 
     // Build block for select function.
@@ -64,24 +67,45 @@ impl MySQLFederated {
         Some((schema, block))
     }
 
-    // SELECT @@aa, @@bb as cc, @dd...
-    // Block is built by the variables.
-    fn select_variable_data_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
-        let mut default_map = HashMap::new();
-        // DBeaver.
-        default_map.insert("tx_isolation", "REPEATABLE-READ");
-        default_map.insert("session.tx_isolation", "REPEATABLE-READ");
-        default_map.insert("transaction_isolation", "REPEATABLE-READ");
-        default_map.insert("session.transaction_isolation", "REPEATABLE-READ");
-        default_map.insert("session.transaction_read_only", "0");
-        default_map.insert("time_zone", "UTC");
-        default_map.insert("system_time_zone", "UTC");
-        // 128M
-        default_map.insert("max_allowed_packet", "134217728");
-        default_map.insert("interactive_timeout", "31536000");
-        default_map.insert("wait_timeout", "31536000");
-        default_map.insert("net_write_timeout", "31536000");
+    // SHOW [GLOBAL | SESSION] VARIABLES [LIKE 'pattern']
+    // Block is built by the recognized variables which match the pattern.
+    fn show_variables_data_block(
+        settings: &Settings,
+        query: &str,
+    ) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static LIKE_PATTERN: Regex = Regex::new("(?i)\\sLIKE\\s+'([^']*)'").unwrap();
+
+        let pattern = LIKE_PATTERN
+            .captures(query)
+            .map(|caps| like_pattern_to_regex(&caps[1]));
+
+        let mut names = vec![];
+        let mut values = vec![];
+        for (name, value) in settings.get_mysql_variables().ok()? {
+            if pattern.as_ref().map_or(true, |p| p.is_match(&name)) {
+                names.push(name);
+                values.push(value);
+            }
+        }
 
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("Variable_name", TableDataType::String),
+            TableField::new("Value", TableDataType::String),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(values),
+        ]);
+        Some((schema, block))
+    }
+
+    // SELECT @@aa, @@bb as cc, @dd...
+    // Block is built by the variables, unknown variables are NULL.
+    fn select_variable_data_block(
+        settings: &Settings,
+        query: &str,
+    ) -> Option<(TableSchemaRef, DataBlock)> {
         let mut fields = vec![];
         let mut values = vec![];
 
@@ -91,29 +115,30 @@ impl MySQLFederated {
         if vars.len() > 1 {
             vars.remove(0);
             for var in vars {
-                let var = var.trim_end_matches(|c| c == ' ' || c == ',');
-                let vars_as: Vec<&str> = var.split(" as ").collect();
-                if vars_as.len() == 2 {
+                let mut tokens = var
+                    .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+                    .filter(|token| !token.is_empty());
+                // var is 'aa'.
+                let var = tokens.next().unwrap_or_default();
+                let field_name = match (tokens.next(), tokens.next()) {
                     // @@cc as yy:
-                    // var_as is 'yy' as the field name.
-                    let var_as = vars_as[1];
-                    fields.push(TableField::new(var_as, TableDataType::String));
-
-                    // var is 'cc'.
-                    let var = vars_as[0];
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
-                    values.push(StringType::from_data(vec![value]));
-                } else {
-                    // @@aa
-                    // var is 'aa'
-                    fields.push(TableField::new(
-                        &format!("@@{}", var),
-                        TableDataType::String,
-                    ));
+                    // 'yy' is the field name.
+                    (Some("as"), Some(alias)) => alias.to_string(),
+                    _ => format!("@@{}", var),
+                };
 
-                    let value = default_map.get(var).unwrap_or(&"0").to_string();
-                    values.push(StringType::from_data(vec![value]));
-                }
+                let value = match settings.get_mysql_variable(var) {
+                    Ok(Some(value)) => Some(value),
+                    _ => {
+                        warn!("Unknown MySQL system variable `@@{}`, returns NULL", var);
+                        None
+                    }
+                };
+                fields.push(TableField::new(
+                    &field_name,
+                    TableDataType::Nullable(Box::new(TableDataType::String)),
+                ));
+                values.push(StringType::from_opt_data(vec![value]));
             }
         }
 
@@ -136,36 +161,25 @@ impl MySQLFederated {
             ),
         ];
 
-        FederatedHelper::lazy_block_match_rule(query, &SELECT_VARIABLES_LAZY_RULES)
+        FederatedHelper::lazy_block_match_rule(&self.settings, query, &SELECT_VARIABLES_LAZY_RULES)
     }
 
     // Check SHOW VARIABLES LIKE.
     fn federated_show_variables_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
         #[ctor]
-        static SHOW_VARIABLES_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
-            (
-                // sqlalchemy < 1.4.30
-                Regex::new("(?i)^(SHOW VARIABLES LIKE 'sql_mode'(.*))").unwrap(),
-                MySQLFederated::show_variables_block(
-                    "sql_mode",
-                    "ONLY_FULL_GROUP_BY STRICT_TRANS_TABLES NO_ZERO_IN_DATE NO_ZERO_DATE ERROR_FOR_DIVISION_BY_ZERO NO_ENGINE_SUBSTITUTION",
-                ),
-            ),
-            (
-                Regex::new("(?i)^(SHOW VARIABLES LIKE 'lower_case_table_names'(.*))").unwrap(),
-                MySQLFederated::show_variables_block("lower_case_table_names", "0"),
-            ),
-            (
-                Regex::new("(?i)^(show collation where(.*))").unwrap(),
-                MySQLFederated::show_variables_block("", ""),
-            ),
-            (
-                Regex::new("(?i)^(SHOW VARIABLES(.*))").unwrap(),
-                MySQLFederated::show_variables_block("", ""),
-            ),
-        ];
+        static SHOW_VARIABLES_LAZY_RULES: Vec<(Regex, LazyBlockFunc)> = vec![(
+            Regex::new("(?i)^(SHOW (GLOBAL |SESSION )?VARIABLES(.*))").unwrap(),
+            MySQLFederated::show_variables_data_block,
+        )];
+
+        #[ctor]
+        static SHOW_VARIABLES_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![(
+            Regex::new("(?i)^(show collation where(.*))").unwrap(),
+            MySQLFederated::show_variables_block("", ""),
+        )];
 
-        FederatedHelper::block_match_rule(query, &SHOW_VARIABLES_RULES)
+        FederatedHelper::lazy_block_match_rule(&self.settings, query, &SHOW_VARIABLES_LAZY_RULES)
+            .or_else(|| FederatedHelper::block_match_rule(query, &SHOW_VARIABLES_RULES))
     }
 
     // Check for SET or others query, this is the final check of the federated query.
@@ -264,3 +278,23 @@ impl MySQLFederated {
             .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
     }
 }
+
+// Convert the SQL LIKE pattern to a case-insensitive regex.
+fn like_pattern_to_regex(pattern: &str) -> Regex {
+    let mut regex = String::from("(?i)^");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    regex.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).unwrap()
+}
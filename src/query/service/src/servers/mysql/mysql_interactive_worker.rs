@@ -334,7 +334,7 @@ impl InteractiveWorkerBase {
         {
             return None;
         }
-        let federated = MySQLFederated::create();
+        let federated = MySQLFederated::create(self.base.session.get_settings());
         federated.check(query)
     }
 
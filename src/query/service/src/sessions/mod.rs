@@ -25,6 +25,10 @@ mod session_privilege_mgr;
 mod session_status;
 mod session_type;
 
+pub use databend_common_catalog::meta_call_policy::MetaCallMetricValues;
+pub use databend_common_catalog::meta_call_policy::MetaCallMetrics;
+pub use databend_common_catalog::meta_call_policy::MetaCallPolicy;
+pub use databend_common_catalog::meta_call_policy::MetaCircuitBreaker;
 pub use databend_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
 pub use query_ctx::convert_query_log_timestamp;
@@ -37,8 +37,12 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+#[cfg(feature = "failpoints")]
+use databend_common_base::runtime::FailPoints;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
+use databend_common_catalog::meta_call_policy::MetaCallMetricValues;
+use databend_common_catalog::meta_call_policy::MetaCallPolicy;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
@@ -51,6 +55,7 @@ use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::table_context::UDFServerSchema;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -110,6 +115,7 @@ use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::InsertErrorLog;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -274,6 +280,10 @@ impl QueryContext {
         self.shared.get_data_metrics()
     }
 
+    pub fn get_meta_call_metrics(&self) -> MetaCallMetricValues {
+        self.shared.get_meta_call_metrics().as_values()
+    }
+
     pub fn set_affect(self: &Arc<Self>, affect: QueryAffect) {
         self.shared.set_affect(affect)
     }
@@ -282,6 +292,20 @@ impl QueryContext {
         *self.shared.init_query_id.write() = id;
     }
 
+    /// Activate the failpoints configured by the `failpoints` setting for the query,
+    /// they are deactivated when the query context is dropped.
+    /// Without the `failpoints` feature there is nothing to activate.
+    pub fn enable_failpoints(&self) -> Result<()> {
+        #[cfg(feature = "failpoints")]
+        {
+            let failpoints = self.get_settings().get_failpoints()?;
+            if !failpoints.is_empty() {
+                FailPoints::enable(&self.get_id(), &failpoints)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn set_executor(&self, weak_ptr: Arc<PipelineExecutor>) -> Result<()> {
         self.shared.set_executor(weak_ptr)
     }
@@ -299,6 +323,14 @@ impl QueryContext {
         ua.clone()
     }
 
+    pub fn get_insert_error_log(&self) -> Arc<InsertErrorLog> {
+        self.shared.insert_error_log.clone()
+    }
+
+    pub fn get_result_buffered_bytes(&self) -> Arc<AtomicUsize> {
+        self.shared.result_buffered_bytes.clone()
+    }
+
     pub fn get_query_duration_ms(&self) -> i64 {
         let query_start_time = convert_query_log_timestamp(self.shared.created_time);
         let finish_time = *self.shared.finish_time.read();
@@ -453,6 +485,10 @@ impl TableContext for QueryContext {
         self.shared.get_query_cache_metrics()
     }
 
+    fn get_meta_call_policy(&self) -> Result<MetaCallPolicy> {
+        self.shared.get_meta_call_policy()
+    }
+
     fn get_partition(&self) -> Option<PartInfoPtr> {
         if let Some(part) = self.partition_queue.write().pop_front() {
             Profile::record_usize_profile(ProfileStatisticsName::ScanPartitions, 1);
@@ -578,14 +614,7 @@ impl TableContext for QueryContext {
 
     #[async_backtrace::framed]
     async fn get_catalog(&self, catalog_name: &str) -> Result<Arc<dyn Catalog>> {
-        self.shared
-            .catalog_manager
-            .get_catalog(
-                self.get_tenant().tenant_name(),
-                catalog_name.as_ref(),
-                self.txn_mgr(),
-            )
-            .await
+        self.shared.get_catalog(catalog_name).await
     }
 
     fn get_default_catalog(&self) -> Result<Arc<dyn Catalog>> {
@@ -690,12 +719,15 @@ impl TableContext for QueryContext {
             settings.get_external_server_request_timeout_secs()?;
         let external_server_request_batch_rows =
             settings.get_external_server_request_batch_rows()?;
+        let external_server_request_retry_times =
+            settings.get_external_server_request_retry_times()?;
 
         let tz = settings.get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
         let now = Utc::now();
         let numeric_cast_option = settings.get_numeric_cast_option()?;
         let rounding_mode = numeric_cast_option.as_str() == "rounding";
+        let numeric_cast_error = numeric_cast_option.as_str() == "error";
         let disable_variant_check = settings.get_disable_variant_check()?;
         let geometry_output_format = settings.get_geometry_output_format()?;
         let parse_datetime_ignore_remainder = settings.get_parse_datetime_ignore_remainder()?;
@@ -705,6 +737,7 @@ impl TableContext for QueryContext {
             tz,
             now,
             rounding_mode,
+            numeric_cast_error,
             disable_variant_check,
 
             openai_api_key: query_config.openai_api_key.clone(),
@@ -717,6 +750,7 @@ impl TableContext for QueryContext {
             external_server_connect_timeout_secs,
             external_server_request_timeout_secs,
             external_server_request_batch_rows,
+            external_server_request_retry_times,
             geometry_output_format,
             parse_datetime_ignore_remainder,
         })
@@ -794,6 +828,20 @@ impl TableContext for QueryContext {
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
 
+    fn get_udf_server_schema(&self, address: &str, handler: &str) -> Option<UDFServerSchema> {
+        self.shared
+            .session
+            .session_ctx
+            .get_udf_server_schema(address, handler)
+    }
+
+    fn set_udf_server_schema(&self, address: String, handler: String, schema: UDFServerSchema) {
+        self.shared
+            .session
+            .session_ctx
+            .set_udf_server_schema(address, handler, schema)
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         self.shared.get_on_error_map()
     }
@@ -841,10 +889,13 @@ impl TableContext for QueryContext {
             Err(_) => {
                 let user_mgr = UserApiProvider::instance();
                 let tenant = self.get_tenant();
-                Ok(user_mgr
-                    .get_file_format(&tenant, name)
-                    .await?
-                    .file_format_params)
+                let file_format = self
+                    .get_meta_call_policy()?
+                    .read("get_file_format", || {
+                        user_mgr.get_file_format(&tenant, name)
+                    })
+                    .await?;
+                Ok(file_format.file_format_params)
             }
         }
     }
@@ -16,6 +16,7 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -26,9 +27,16 @@ use dashmap::DashMap;
 use databend_common_base::base::short_sql;
 use databend_common_base::base::Progress;
 use databend_common_base::runtime::drop_guard;
+#[cfg(feature = "failpoints")]
+use databend_common_base::runtime::FailPoints;
 use databend_common_base::runtime::Runtime;
+use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
+use databend_common_catalog::meta_call_policy::MetaCallMetrics;
+use databend_common_catalog::meta_call_policy::MetaCallPolicy;
+use databend_common_catalog::meta_call_policy::MetaCircuitBreaker;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics;
@@ -58,6 +66,7 @@ use uuid::Uuid;
 
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
+use crate::pipelines::processors::InsertErrorLog;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
 use crate::storages::Table;
@@ -107,6 +116,7 @@ pub struct QueryContextShared {
         Arc<RwLock<Option<Arc<DashMap<String, HashMap<u16, InputError>>>>>>,
     pub(in crate::sessions) on_error_mode: Arc<RwLock<Option<OnErrorMode>>>,
     pub(in crate::sessions) copy_status: Arc<CopyStatus>,
+    pub(in crate::sessions) insert_error_log: Arc<InsertErrorLog>,
     pub(in crate::sessions) merge_status: Arc<RwLock<MergeStatus>>,
     pub(in crate::sessions) multi_table_insert_status: Arc<Mutex<MultiTableInsertStatus>>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
@@ -132,7 +142,13 @@ pub struct QueryContextShared {
     // Records query level data cache metrics
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
+    // Records the meta service requests made while planning the query
+    pub(in crate::sessions) meta_call_metrics: Arc<MetaCallMetrics>,
+
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    // Bytes of the result blocks buffered and not fetched by the client yet.
+    pub(in crate::sessions) result_buffered_bytes: Arc<AtomicUsize>,
 }
 
 impl QueryContextShared {
@@ -167,6 +183,7 @@ impl QueryContextShared {
             on_error_map: Arc::new(RwLock::new(None)),
             on_error_mode: Arc::new(RwLock::new(None)),
             copy_status: Arc::new(Default::default()),
+            insert_error_log: Arc::new(Default::default()),
             merge_status: Arc::new(Default::default()),
             partitions_shas: Arc::new(RwLock::new(vec![])),
             cacheable: Arc::new(AtomicBool::new(true)),
@@ -180,11 +197,13 @@ impl QueryContextShared {
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
             query_cache_metrics: DataCacheMetrics::new(),
+            meta_call_metrics: Arc::new(MetaCallMetrics::new()),
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            result_buffered_bytes: Arc::new(AtomicUsize::new(0)),
         }))
     }
 
@@ -345,15 +364,13 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
-        let catalog = self
-            .catalog_manager
-            .get_catalog(
-                tenant.tenant_name(),
-                catalog,
-                self.session.session_ctx.txn_mgr(),
-            )
+        let catalog = self.get_catalog(catalog).await?;
+        let cache_table = self
+            .get_meta_call_policy()?
+            .read_catalog(catalog.as_ref(), "get_table", || {
+                catalog.get_table(&tenant, database, table)
+            })
             .await?;
-        let cache_table = catalog.get_table(&tenant, database, table).await?;
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -372,18 +389,20 @@ impl QueryContextShared {
     ) -> Result<()> {
         if table.engine() == "STREAM" {
             let tenant = self.get_tenant();
-            let catalog = self
-                .catalog_manager
-                .get_catalog(
-                    tenant.tenant_name(),
-                    catalog_name,
-                    self.session.session_ctx.txn_mgr(),
-                )
-                .await?;
+            let catalog = self.get_catalog(catalog_name).await?;
+            let policy = self.get_meta_call_policy()?;
 
             let stream = StreamTable::try_from_table(table.as_ref())?;
-            let table_name = stream.source_table_name(catalog.as_ref()).await?;
-            let database = stream.source_database_name(catalog.as_ref()).await?;
+            let table_name = policy
+                .read_catalog(catalog.as_ref(), "get_stream_source_table_name", || {
+                    stream.source_table_name(catalog.as_ref())
+                })
+                .await?;
+            let database = policy
+                .read_catalog(catalog.as_ref(), "get_stream_source_database_name", || {
+                    stream.source_database_name(catalog.as_ref())
+                })
+                .await?;
             let meta_key = (
                 catalog_name.to_string(),
                 database.to_string(),
@@ -395,8 +414,10 @@ impl QueryContextShared {
                 let source_table = match catalog.get_stream_source_table(stream_desc)? {
                     Some(source_table) => source_table,
                     None => {
-                        let source_table = catalog
-                            .get_table(&tenant, &database, &table_name)
+                        let source_table = policy
+                            .read_catalog(catalog.as_ref(), "get_table", || {
+                                catalog.get_table(&tenant, &database, &table_name)
+                            })
                             .await
                             .map_err(|err| {
                                 ErrorCode::IllegalStream(format!(
@@ -560,13 +581,57 @@ impl QueryContextShared {
     pub async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         let user_mgr = UserApiProvider::instance();
         let tenant = self.get_tenant();
-        user_mgr.get_connection(&tenant, name).await
+        self.get_meta_call_policy()?
+            .read("get_connection", || user_mgr.get_connection(&tenant, name))
+            .await
+    }
+
+    /// Only the catalogs stored in the meta service are read under the meta call policy,
+    /// the default catalog and the catalogs of the config are not read from it.
+    pub async fn get_catalog(&self, catalog_name: &str) -> Result<Arc<dyn Catalog>> {
+        let tenant = self.get_tenant();
+        let txn_mgr = self.session.session_ctx.txn_mgr();
+        if catalog_name == CATALOG_DEFAULT
+            || self
+                .catalog_manager
+                .external_catalogs
+                .contains_key(catalog_name)
+        {
+            return self
+                .catalog_manager
+                .get_catalog(tenant.tenant_name(), catalog_name, txn_mgr)
+                .await;
+        }
+        self.get_meta_call_policy()?
+            .read("get_catalog", || {
+                self.catalog_manager.get_catalog(
+                    tenant.tenant_name(),
+                    catalog_name,
+                    txn_mgr.clone(),
+                )
+            })
+            .await
     }
 
     pub fn get_query_cache_metrics(&self) -> &DataCacheMetrics {
         &self.query_cache_metrics
     }
 
+    pub fn get_meta_call_metrics(&self) -> &MetaCallMetrics {
+        &self.meta_call_metrics
+    }
+
+    /// The policy of the idempotent meta service reads made while planning the query,
+    /// the deadline is `meta_request_timeout_secs`.
+    pub fn get_meta_call_policy(&self) -> Result<MetaCallPolicy> {
+        let timeout = self.get_settings().get_meta_request_timeout_secs()?;
+        Ok(MetaCallPolicy::create(
+            Duration::from_secs(timeout),
+            MetaCircuitBreaker::instance(),
+            self.meta_call_metrics.clone(),
+        ))
+    }
+
     pub fn set_priority(&self, priority: u8) {
         if let Some(executor) = self.executor.read().upgrade() {
             executor.change_priority(priority)
@@ -577,6 +642,9 @@ impl QueryContextShared {
 impl Drop for QueryContextShared {
     fn drop(&mut self) {
         drop_guard(move || {
+            #[cfg(feature = "failpoints")]
+            FailPoints::disable(&self.init_query_id.read());
+
             // last_query_id() should return the query_id of the last executed statement,
             // so we set it when the current context drops
             // to avoid returning the query_id of the current statement.
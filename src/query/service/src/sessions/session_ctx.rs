@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use databend_common_catalog::table_context::UDFServerSchema;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::RoleInfo;
@@ -67,6 +69,9 @@ pub struct SessionContext {
     /// We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     /// query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    /// The schemas of the UDF server functions validated in this session, keyed by
+    /// `(address, handler)`.
+    udf_server_schemas: RwLock<HashMap<(String, String), UDFServerSchema>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
 }
@@ -87,6 +92,7 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            udf_server_schemas: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
         })
@@ -292,6 +298,17 @@ impl SessionContext {
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
 
+    pub fn get_udf_server_schema(&self, address: &str, handler: &str) -> Option<UDFServerSchema> {
+        let lock = self.udf_server_schemas.read();
+        lock.get(&(address.to_string(), handler.to_string()))
+            .cloned()
+    }
+
+    pub fn set_udf_server_schema(&self, address: String, handler: String, schema: UDFServerSchema) {
+        let mut lock = self.udf_server_schemas.write();
+        lock.insert((address, handler), schema);
+    }
+
     pub fn txn_mgr(&self) -> TxnManagerRef {
         self.txn_mgr.lock().clone()
     }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -60,6 +61,7 @@ impl Session {
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
             current_query_id: self.get_current_query_id(),
+            result_buffered_bytes: Self::query_result_buffered_bytes(session_ctx),
         }
     }
 
@@ -105,6 +107,16 @@ impl Session {
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
 
+    fn query_result_buffered_bytes(status: &SessionContext) -> u64 {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| {
+                context_shared.result_buffered_bytes.load(Ordering::Relaxed) as u64
+            })
+            .unwrap_or(0)
+    }
+
     fn query_created_time(status: &SessionContext) -> SystemTime {
         match status.get_query_context_shared() {
             None => SystemTime::now(),
@@ -23,6 +23,7 @@ use byte_unit::Byte;
 use byte_unit::ByteUnit;
 use databend_common_base::base::GlobalUniqName;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::fail_point;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::table_context::TableContext;
@@ -119,6 +120,7 @@ impl Spiller {
     /// We should guarantee that the file is managed by this spiller.
     pub async fn read_spilled_file(&self, file: &str) -> Result<DataBlock> {
         debug_assert!(self.columns_layout.contains_key(file));
+        fail_point!("spiller_read");
         let data = self.operator.read(file).await?.to_bytes();
         let bytes = data.len();
 
@@ -144,6 +146,7 @@ impl Spiller {
 
     /// Write a [`DataBlock`] to storage.
     pub async fn spill_block(&mut self, data: DataBlock) -> Result<String> {
+        fail_point!("spiller_write");
         let instant = Instant::now();
         let unique_name = GlobalUniqName::unique();
         let location = format!("{}/{}", self.config.location_prefix, unique_name);
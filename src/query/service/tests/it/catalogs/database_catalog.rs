@@ -92,6 +92,7 @@ async fn test_catalogs_database() -> Result<()> {
             if_exists: false,
             name_ident: DatabaseNameIdent::new(&tenant, "db1"),
             new_db_name: "db2".to_string(),
+            dependents: Default::default(),
         };
         let res = catalog.rename_database(req.clone()).await;
         assert!(res.is_ok());
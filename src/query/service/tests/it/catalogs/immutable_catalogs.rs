@@ -67,6 +67,7 @@ async fn test_immutable_catalogs_database() -> Result<()> {
         name_ident: DatabaseNameIdent::new(&tenant, "system"),
 
         new_db_name: "test".to_string(),
+        dependents: Default::default(),
     };
     let rename_db_req = catalog.rename_database(rename_db_req).await;
     assert!(rename_db_req.is_err());
@@ -77,6 +78,7 @@ async fn test_immutable_catalogs_database() -> Result<()> {
         name_ident: DatabaseNameIdent::new(&tenant, "test"),
 
         new_db_name: "system".to_string(),
+        dependents: Default::default(),
     };
     let rename_db_req = catalog.rename_database(rename_db_req).await;
     assert!(rename_db_req.is_err());
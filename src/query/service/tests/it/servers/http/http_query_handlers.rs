@@ -15,6 +15,9 @@
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::Read;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
 use std::time::Duration;
 
 use base64::engine::general_purpose;
@@ -33,6 +36,8 @@ use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::sized_spsc::sized_spsc;
+use databend_query::servers::http::v1::sized_spsc::SizedChannelLimits;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryError;
@@ -558,6 +563,69 @@ async fn test_buffer_size() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_buffer_bytes_with_slow_client() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let rows = 1000;
+    let sql = format!("select * from numbers({})", rows);
+    // every block exceeds the limit, so at most one block is buffered at any time.
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 1, "max_rows_per_page": 100}, "session": { "settings": {"max_block_size": "10", "http_handler_result_buffer_max_bytes": "1"}}});
+
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let mut num_rows = result.data.len();
+    let mut next_uri = result.next_uri.clone();
+    while let Some(uri) = next_uri {
+        sleep(Duration::from_millis(10)).await;
+        let (status, result) = get_uri_checked(&ep, &uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        num_rows += result.data.len();
+        next_uri = result.next_uri.clone();
+    }
+    assert_eq!(num_rows, rows);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_sized_spsc_bytes_limit() -> Result<()> {
+    let limits = SizedChannelLimits {
+        max_size: usize::MAX,
+        max_bytes: 10,
+        global_max_bytes: usize::MAX,
+    };
+    let buffered_bytes = Arc::new(AtomicUsize::new(0));
+    let (sender, receiver) = sized_spsc::<usize>(limits, buffered_bytes.clone());
+
+    let send_task = tokio::spawn(async move {
+        for i in 0..10 {
+            if !sender.send(i, 1, 4).await {
+                return i;
+            }
+        }
+        sender.close();
+        10
+    });
+
+    for i in 0..5 {
+        sleep(Duration::from_millis(10)).await;
+        // the sender is blocked instead of buffering more than the limit.
+        assert!(buffered_bytes.load(Ordering::Relaxed) <= 8);
+        assert_eq!(receiver.recv().await, Some(i));
+    }
+
+    // values not received yet are released once the receiver stops.
+    receiver.close();
+    assert_eq!(buffered_bytes.load(Ordering::Relaxed), 0);
+    assert!(send_task.await.unwrap() < 10);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_pagination() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -1764,3 +1832,72 @@ async fn test_has_result_set() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_has_totals_row() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let sqls = vec![
+        (
+            "select number % 3 as k, count() from numbers(10) group by k with totals order by k limit 1",
+            true,
+            vec![vec!["0", "4"], vec!["NULL", "10"]],
+        ),
+        (
+            "select number % 3 as k, count() from numbers(10) group by k order by k limit 1",
+            false,
+            vec![vec!["0", "4"]],
+        ),
+        (
+            "select * from (select number % 3 as k, count() from numbers(10) group by k with totals) where k = 0",
+            false,
+            vec![vec!["0", "4"]],
+        ),
+    ];
+
+    let wait_time_secs = 5;
+    for (sql, has_totals_row, data) in sqls {
+        let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": wait_time_secs}});
+        let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+        assert!(reply.error().is_none(), "{:?}", reply.error());
+        assert_eq!(
+            reply.state(),
+            ExecuteStateKind::Succeeded,
+            "SQL '{sql}' not finish after {wait_time_secs} secs"
+        );
+        assert_eq!(reply.last().1.has_totals_row, Some(has_totals_row), "{sql}");
+        assert_eq!(reply.data(), data, "{sql}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_server_git_sha_header() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint().await?;
+
+    let json = serde_json::json!({"sql": "select 1", "pagination": {"wait_time_secs": 5}});
+    let basic = headers::Authorization::basic("root", "");
+    let req = Request::builder()
+        .uri("/v1/query".parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, "application/json")
+        .typed_header(basic)
+        .body(serde_json::to_vec(&json)?);
+
+    let response = ep
+        .call(req)
+        .await
+        .map_err(|e| ErrorCode::Internal(e.to_string()))?;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let git_sha = response
+        .headers()
+        .get("X-DATABEND-SERVER-GIT-SHA")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    assert!(!git_sha.is_empty());
+
+    Ok(())
+}
@@ -14,19 +14,42 @@
 
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::number::Float64Type;
 use databend_common_expression::types::number::Int32Type;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::ALL_NUMERICS_TYPES;
+use databend_common_expression::types::BinaryType;
 use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_io::parse_to_ewkb;
 use databend_common_io::prelude::FormatSettings;
+use databend_common_io::GeometryDataType;
+use databend_common_sql::resolve_type_name_by_str;
 use databend_query::servers::http::v1::json_block::JsonBlock;
+use databend_query::servers::http::v1::json_block::RESULT_FORMAT_VERSION;
+use databend_query::servers::http::v1::BinaryEncoding;
+use databend_query::servers::http::v1::ResultFormat;
+use databend_query::servers::http::v1::VariantEncoding;
+use ethnum::i256;
+use jsonb::parse_value;
 use pretty_assertions::assert_eq;
 use serde::Serialize;
+use serde_json::json;
 use serde_json::to_value;
 use serde_json::Value as JsonValue;
 
@@ -88,3 +111,257 @@ fn test_empty_block() -> Result<()> {
     assert!(json_block.is_empty());
     Ok(())
 }
+
+fn result_format() -> ResultFormat {
+    ResultFormat::from_request(RESULT_FORMAT_VERSION, None).unwrap()
+}
+
+// A sample value of the type and its encoding in the latest result format.
+//
+// The match is exhaustive, so a new data type can't be added without a sample.
+fn sample_value(ty: &DataType) -> (Scalar, JsonValue) {
+    match ty {
+        DataType::Null => (Scalar::Null, JsonValue::Null),
+        DataType::EmptyArray => (Scalar::EmptyArray, json!([])),
+        DataType::EmptyMap => (Scalar::EmptyMap, json!([])),
+        DataType::Boolean => (Scalar::Boolean(true), json!("1")),
+        DataType::Binary => (Scalar::Binary(b"ab".to_vec()), json!("YWI=")),
+        // A string is never confused with NULL.
+        DataType::String => (Scalar::String("NULL".to_string()), json!("NULL")),
+        DataType::Number(ty) => match ty {
+            NumberDataType::UInt8 => (Scalar::Number(NumberScalar::UInt8(u8::MAX)), json!("255")),
+            NumberDataType::UInt16 => (Scalar::Number(NumberScalar::UInt16(1)), json!("1")),
+            NumberDataType::UInt32 => (Scalar::Number(NumberScalar::UInt32(1)), json!("1")),
+            NumberDataType::UInt64 => (
+                Scalar::Number(NumberScalar::UInt64(u64::MAX)),
+                json!("18446744073709551615"),
+            ),
+            NumberDataType::Int8 => (Scalar::Number(NumberScalar::Int8(-1)), json!("-1")),
+            NumberDataType::Int16 => (Scalar::Number(NumberScalar::Int16(-1)), json!("-1")),
+            NumberDataType::Int32 => (Scalar::Number(NumberScalar::Int32(-1)), json!("-1")),
+            NumberDataType::Int64 => (
+                Scalar::Number(NumberScalar::Int64(i64::MIN)),
+                json!("-9223372036854775808"),
+            ),
+            NumberDataType::Float32 => (
+                Scalar::Number(NumberScalar::Float32(1.5.into())),
+                json!("1.5"),
+            ),
+            NumberDataType::Float64 => (
+                Scalar::Number(NumberScalar::Float64((-0.5).into())),
+                json!("-0.5"),
+            ),
+        },
+        DataType::Decimal(DecimalDataType::Decimal128(size)) => (
+            Scalar::Decimal(DecimalScalar::Decimal128(150, *size)),
+            json!("1.50"),
+        ),
+        DataType::Decimal(DecimalDataType::Decimal256(size)) => (
+            Scalar::Decimal(DecimalScalar::Decimal256(i256::from(-1), *size)),
+            json!("-0.00001"),
+        ),
+        DataType::Timestamp => (Scalar::Timestamp(-1), json!("-1")),
+        DataType::Date => (Scalar::Date(1), json!("1970-01-02")),
+        DataType::Nullable(ty) => sample_value(ty),
+        DataType::Array(ty) => {
+            let (value, expected) = sample_value(ty);
+            let mut builder = ColumnBuilder::with_capacity(ty, 2);
+            builder.push(value.as_ref());
+            builder.push(value.as_ref());
+            (
+                Scalar::Array(builder.build()),
+                json!([expected.clone(), expected]),
+            )
+        }
+        DataType::Map(ty) => {
+            let (value, expected) = sample_value(ty);
+            let mut builder = ColumnBuilder::with_capacity(ty, 1);
+            builder.push(value.as_ref());
+            (Scalar::Map(builder.build()), json!([expected]))
+        }
+        DataType::Bitmap => (Scalar::Bitmap(vec![]), json!("<bitmap binary>")),
+        DataType::Tuple(tys) => {
+            let (values, expected): (Vec<_>, Vec<_>) = tys.iter().map(sample_value).unzip();
+            (Scalar::Tuple(values), JsonValue::Array(expected))
+        }
+        DataType::Variant => (
+            Scalar::Variant(parse_value(br#"{"a":[1,null]}"#).unwrap().to_vec()),
+            json!(r#"{"a":[1,null]}"#),
+        ),
+        DataType::Geometry => (
+            Scalar::Geometry(parse_to_ewkb(b"POINT(1 2)", None).unwrap()),
+            json!("POINT(1 2)"),
+        ),
+        DataType::Generic(_) => unreachable!(),
+    }
+}
+
+// Every data type, including the nested ones, is encoded with the rules of the result format.
+#[test]
+fn test_result_format_value_encoding() -> Result<()> {
+    let mut types = vec![
+        DataType::Null,
+        DataType::EmptyArray,
+        DataType::EmptyMap,
+        DataType::Boolean,
+        DataType::Binary,
+        DataType::String,
+        DataType::Decimal(DecimalDataType::from_size(DecimalSize {
+            precision: 10,
+            scale: 2,
+        })?),
+        DataType::Decimal(DecimalDataType::from_size(DecimalSize {
+            precision: 76,
+            scale: 5,
+        })?),
+        DataType::Timestamp,
+        DataType::Date,
+        DataType::Bitmap,
+        DataType::Variant,
+        DataType::Geometry,
+        DataType::Array(Box::new(DataType::Timestamp)),
+        DataType::Array(Box::new(DataType::Binary.wrap_nullable())),
+        DataType::Map(Box::new(DataType::Tuple(vec![
+            DataType::String,
+            DataType::Date.wrap_nullable(),
+        ]))),
+        DataType::Tuple(vec![
+            DataType::Variant,
+            DataType::Array(Box::new(DataType::String)),
+        ]),
+    ];
+    types.extend(ALL_NUMERICS_TYPES.iter().map(|ty| DataType::Number(*ty)));
+
+    let format = FormatSettings {
+        geometry_format: GeometryDataType::WKT,
+        ..Default::default()
+    };
+    let result_format = result_format();
+    for ty in types {
+        let (value, expected) = sample_value(&ty);
+        let ty = ty.wrap_nullable();
+        let mut builder = ColumnBuilder::with_capacity(&ty, 2);
+        builder.push(value.as_ref());
+        builder.push(ScalarRef::Null);
+        let block = DataBlock::new_from_columns(vec![builder.build()]);
+        let json_block = JsonBlock::new_with_result_format(&block, &format, Some(&result_format))?;
+        assert_eq!(
+            json_block.data().clone(),
+            vec![vec![expected], vec![JsonValue::Null]],
+            "type: {ty}"
+        );
+    }
+    Ok(())
+}
+
+// NULL is encoded as JSON null in nested values as well.
+#[test]
+fn test_result_format_nested_null() -> Result<()> {
+    let columns = vec![
+        Column::Array(Box::new(ArrayColumn {
+            values: Int32Type::from_opt_data(vec![Some(1), None]),
+            offsets: vec![0_u64, 2].into(),
+        })),
+        Column::Tuple(vec![
+            StringType::from_opt_data(vec![None::<&str>]),
+            StringType::from_data(vec!["NULL"]),
+        ]),
+    ];
+    let block = DataBlock::new_from_columns(columns);
+
+    let format = FormatSettings::default();
+    let json_block = JsonBlock::new_with_result_format(&block, &format, Some(&result_format()))?;
+    assert_eq!(json_block.data().clone(), vec![vec![
+        json!(["1", null]),
+        json!([null, "NULL"]),
+    ]]);
+    Ok(())
+}
+
+#[test]
+fn test_result_format_chunked() -> Result<()> {
+    let columns = vec![
+        BinaryType::from_data(vec!["abcde".as_bytes(), "".as_bytes()]),
+        VariantType::from_data(vec![
+            parse_value("\"中文\"".as_bytes()).unwrap().to_vec(),
+            parse_value(b"[]").unwrap().to_vec(),
+        ]),
+    ];
+    let block = DataBlock::new_from_columns(columns);
+
+    let format = FormatSettings::default();
+    let result_format = ResultFormat::from_request(RESULT_FORMAT_VERSION, Some(4)).unwrap();
+    assert_eq!(result_format.binary_encoding, BinaryEncoding::Base64Chunked);
+    assert_eq!(result_format.variant_encoding, VariantEncoding::JsonChunked);
+    let json_block = JsonBlock::new_with_result_format(&block, &format, Some(&result_format))?;
+    assert_eq!(json_block.data().clone(), vec![
+        // each chunk has at most 4 bytes, a char is never split
+        vec![json!(["YWJjZA==", "ZQ=="]), json!(["\"中", "文\""])],
+        vec![json!([]), json!(["[]"])],
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_result_format_descriptor() -> Result<()> {
+    assert!(ResultFormat::from_request(0, None).is_none());
+    assert_eq!(
+        serde_json::to_value(result_format())?,
+        json!({
+            "version": 1,
+            "null_value": null,
+            "boolean_encoding": "digit",
+            "binary_encoding": "base64",
+            "decimal_encoding": "string",
+            "date_encoding": "iso8601",
+            "timestamp_encoding": "epoch_micros",
+            "variant_encoding": "json",
+            "nested_encoding": "json_array",
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_result_format_type_names() -> Result<()> {
+    let mut types = vec![
+        DataType::Boolean,
+        DataType::Binary,
+        DataType::String,
+        DataType::Decimal(DecimalDataType::from_size(DecimalSize {
+            precision: 10,
+            scale: 2,
+        })?),
+        DataType::Decimal(DecimalDataType::from_size(DecimalSize {
+            precision: 76,
+            scale: 5,
+        })?),
+        DataType::Timestamp,
+        DataType::Date,
+        DataType::Bitmap,
+        DataType::Variant,
+        DataType::Geometry,
+        DataType::Array(Box::new(
+            DataType::Number(NumberDataType::Int32).wrap_nullable(),
+        )),
+        DataType::Map(Box::new(DataType::Tuple(vec![
+            DataType::String,
+            DataType::Number(NumberDataType::Int64).wrap_nullable(),
+        ]))),
+        DataType::Tuple(vec![DataType::Number(NumberDataType::Int32)]),
+        DataType::Tuple(vec![DataType::String, DataType::Variant.wrap_nullable()]),
+        DataType::Array(Box::new(DataType::Tuple(vec![
+            DataType::Date,
+            DataType::Array(Box::new(DataType::Timestamp)),
+        ]))),
+    ];
+    types.extend(ALL_NUMERICS_TYPES.iter().map(|ty| DataType::Number(*ty)));
+
+    // Every type in the schema of the response can be parsed back by the type name grammar.
+    for ty in types.iter().flat_map(|ty| [ty.clone(), ty.wrap_nullable()]) {
+        let name = ty.wrapped_display();
+        let parsed = DataType::from(&resolve_type_name_by_str(&name, true)?);
+        assert_eq!(parsed, ty, "type name: {name}");
+    }
+    Ok(())
+}
@@ -14,11 +14,13 @@
 
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_settings::Settings;
 use databend_query::servers::MySQLFederated;
 
 #[test]
 fn test_mysql_federated() -> Result<()> {
-    let federated = MySQLFederated::create();
+    let federated = MySQLFederated::create(Settings::create(Tenant::new_literal("default")));
 
     //
     {
@@ -54,11 +56,76 @@ fn test_mysql_federated() -> Result<()> {
 
         if let Some((_, block)) = result {
             let expect = vec![
-                "+----------+----------+----------+----------+----------+----------+----------+----------+------------+----------+-----------+-------------+------------+-----------+-----------+-----------+-----------+-------------------+------------+",
-                "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5 | Column 6 | Column 7 | Column 8   | Column 9 | Column 10 | Column 11   | Column 12  | Column 13 | Column 14 | Column 15 | Column 16 | Column 17         | Column 18  |",
-                "+----------+----------+----------+----------+----------+----------+----------+----------+------------+----------+-----------+-------------+------------+-----------+-----------+-----------+-----------+-------------------+------------+",
-                "| '0'      | '0'      | '0'      | '0'      | '0'      | '0'      | '0'      | '0'      | '31536000' | '0'      | '0'       | '134217728' | '31536000' | '0'       | '0'       | 'UTC'     | 'UTC'     | 'REPEATABLE-READ' | '31536000' |",
-                "+----------+----------+----------+----------+----------+----------+----------+----------+------------+----------+-----------+-------------+------------+-----------+-----------+-----------+-----------+-------------------+------------+",
+                "+----------+-----------+-----------+-----------+-----------+----------------------+----------------------+----------+------------+--------------+-----------+-------------+------------+-----------+-------------------------------------------------------------------------------------------------------------------------+-----------+-----------+-------------------+------------+",
+                "| Column 0 | Column 1  | Column 2  | Column 3  | Column 4  | Column 5             | Column 6             | Column 7 | Column 8   | Column 9     | Column 10 | Column 11   | Column 12  | Column 13 | Column 14                                                                                                               | Column 15 | Column 16 | Column 17         | Column 18  |",
+                "+----------+-----------+-----------+-----------+-----------+----------------------+----------------------+----------+------------+--------------+-----------+-------------+------------+-----------+-------------------------------------------------------------------------------------------------------------------------+-----------+-----------+-------------------+------------+",
+                "| '1'      | 'utf8mb4' | 'utf8mb4' | 'utf8mb4' | 'utf8mb4' | 'utf8mb4_general_ci' | 'utf8mb4_general_ci' | ''       | '31536000' | 'Apache-2.0' | '0'       | '134217728' | '31536000' | '0'       | 'ONLY_FULL_GROUP_BY,STRICT_TRANS_TABLES,NO_ZERO_IN_DATE,NO_ZERO_DATE,ERROR_FOR_DIVISION_BY_ZERO,NO_ENGINE_SUBSTITUTION' | 'UTC'     | 'UTC'     | 'REPEATABLE-READ' | '31536000' |",
+                "+----------+-----------+-----------+-----------+-----------+----------------------+----------------------+----------+------------+--------------+-----------+-------------+------------+-----------+-------------------------------------------------------------------------------------------------------------------------+-----------+-----------+-------------------+------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // unknown variables
+    {
+        let query = "SELECT @@version_comment, @@not_a_variable";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            assert_eq!(schema.field(0).name(), "@@version_comment");
+            assert_eq!(schema.field(1).name(), "@@not_a_variable");
+            let expect = vec![
+                "+------------+----------+",
+                "| Column 0   | Column 1 |",
+                "+------------+----------+",
+                "| 'Databend' | NULL     |",
+                "+------------+----------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // mysqlclient bootstrap
+    {
+        for query in ["SET NAMES utf8mb4", "SET autocommit=0"] {
+            let result = federated.check(query);
+            assert!(result.is_some());
+        }
+
+        let query = "SHOW VARIABLES LIKE 'sql_mode'";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+------------+-------------------------------------------------------------------------------------------------------------------------+",
+                "| Column 0   | Column 1                                                                                                                |",
+                "+------------+-------------------------------------------------------------------------------------------------------------------------+",
+                "| 'sql_mode' | 'ONLY_FULL_GROUP_BY,STRICT_TRANS_TABLES,NO_ZERO_IN_DATE,NO_ZERO_DATE,ERROR_FOR_DIVISION_BY_ZERO,NO_ENGINE_SUBSTITUTION' |",
+                "+------------+-------------------------------------------------------------------------------------------------------------------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // show variables like
+    {
+        let query = "SHOW SESSION VARIABLES LIKE 'tx\\_%'";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+----------------+-------------------+",
+                "| Column 0       | Column 1          |",
+                "+----------------+-------------------+",
+                "| 'tx_isolation' | 'REPEATABLE-READ' |",
+                "| 'tx_read_only' | '0'               |",
+                "+----------------+-------------------+",
             ];
 
             assert_blocks_eq(expect, &[block]);
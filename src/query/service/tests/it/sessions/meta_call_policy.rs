@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::sessions::MetaCallMetrics;
+use databend_query::sessions::MetaCallPolicy;
+use databend_query::sessions::MetaCircuitBreaker;
+
+/// A meta client that answers after `delay`, failing the first `failures` requests.
+struct DelayedMetaClient {
+    delay: Duration,
+    failures: u64,
+    calls: AtomicU64,
+}
+
+impl DelayedMetaClient {
+    fn create(delay: Duration, failures: u64) -> Self {
+        DelayedMetaClient {
+            delay,
+            failures,
+            calls: AtomicU64::new(0),
+        }
+    }
+
+    async fn get_table(&self, name: &str) -> Result<String> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        tokio::time::sleep(self.delay).await;
+        match call < self.failures {
+            true => Err(ErrorCode::MetaServiceError("connection reset")),
+            false => Ok(name.to_string()),
+        }
+    }
+
+    fn calls(&self) -> u64 {
+        self.calls.load(Ordering::Relaxed)
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_meta_read_deadline() -> Result<()> {
+    let client = DelayedMetaClient::create(Duration::from_secs(60), 0);
+    let breaker = Arc::new(MetaCircuitBreaker::create(3, Duration::from_secs(10)));
+    let metrics = Arc::new(MetaCallMetrics::new());
+    let policy = MetaCallPolicy::create(Duration::from_millis(200), breaker, metrics.clone());
+
+    let start = Instant::now();
+    let res = policy.read("get_table", || client.get_table("t")).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::TIMEOUT);
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(client.calls(), 1);
+
+    let values = metrics.as_values();
+    assert_eq!(values.calls, 1);
+    assert_eq!(values.retries, 0);
+    assert!(values.duration_ms >= 200);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_meta_read_retry() -> Result<()> {
+    let breaker = Arc::new(MetaCircuitBreaker::create(3, Duration::from_secs(10)));
+    let metrics = Arc::new(MetaCallMetrics::new());
+    let policy = MetaCallPolicy::create(Duration::from_secs(10), breaker, metrics.clone())
+        .with_retries(2, Duration::from_millis(10));
+
+    // Meta service errors are retried.
+    let client = DelayedMetaClient::create(Duration::from_millis(10), 2);
+    let res = policy.read("get_table", || client.get_table("t")).await?;
+    assert_eq!(res, "t");
+    assert_eq!(client.calls(), 3);
+
+    // The retries are bounded.
+    let client = DelayedMetaClient::create(Duration::from_millis(10), 5);
+    let res = policy.read("get_table", || client.get_table("t")).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_ERROR);
+    assert_eq!(client.calls(), 3);
+
+    // Other errors are returned as is.
+    let calls = AtomicU64::new(0);
+    let res: Result<()> = policy
+        .read("get_table", || async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Err(ErrorCode::UnknownTable("Unknown table 't'"))
+        })
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_TABLE);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    let values = metrics.as_values();
+    assert_eq!(values.calls, 3);
+    assert_eq!(values.retries, 4);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_meta_circuit_breaker() -> Result<()> {
+    let breaker = Arc::new(MetaCircuitBreaker::create(2, Duration::from_millis(500)));
+    let metrics = Arc::new(MetaCallMetrics::new());
+    let policy = MetaCallPolicy::create(Duration::from_millis(100), breaker, metrics)
+        .with_retries(0, Duration::from_millis(10));
+
+    // Requests exceeding the deadline of the query don't open the breaker.
+    let slow = DelayedMetaClient::create(Duration::from_secs(60), 0);
+    for _ in 0..3 {
+        let res = policy.read("get_table", || slow.get_table("t")).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::TIMEOUT);
+    }
+    assert_eq!(slow.calls(), 3);
+
+    let failing = DelayedMetaClient::create(Duration::from_millis(10), u64::MAX);
+    for _ in 0..2 {
+        let res = policy.read("get_table", || failing.get_table("t")).await;
+        assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_ERROR);
+    }
+
+    // The breaker is open, requests fail fast without reaching the meta service.
+    let start = Instant::now();
+    let res = policy.read("get_table", || failing.get_table("t")).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_UNAVAILABLE);
+    assert!(start.elapsed() < Duration::from_millis(100));
+    assert_eq!(failing.calls(), 2);
+
+    // After the cool down a successful request closes the breaker.
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    let fast = DelayedMetaClient::create(Duration::from_millis(10), 0);
+    for _ in 0..2 {
+        let res = policy.read("get_table", || fast.get_table("t")).await?;
+        assert_eq!(res, "t");
+    }
+    assert_eq!(fast.calls(), 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_meta_circuit_breaker_half_open() -> Result<()> {
+    let breaker = MetaCircuitBreaker::create(1, Duration::from_millis(200));
+
+    breaker.check("get_table")?;
+    breaker.record_failure();
+    let res = breaker.check("get_table");
+    assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_UNAVAILABLE);
+
+    // After the cool down a single probe is let through, the other requests are still rejected.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    breaker.check("get_table")?;
+    let res = breaker.check("get_database");
+    assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_UNAVAILABLE);
+
+    // A failed probe opens the breaker again.
+    breaker.record_failure();
+    let res = breaker.check("get_table");
+    assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_UNAVAILABLE);
+
+    // A successful probe closes it.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    breaker.check("get_table")?;
+    breaker.record_success();
+    breaker.check("get_table")?;
+    breaker.check("get_database")?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_meta_circuit_breaker_lost_probe() -> Result<()> {
+    let breaker = MetaCircuitBreaker::create(1, Duration::from_millis(200));
+    breaker.record_failure();
+
+    // The probe never reports back, e.g. its query is killed.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    breaker.check("get_table")?;
+    let res = breaker.check("get_table");
+    assert_eq!(res.unwrap_err().code(), ErrorCode::META_SERVICE_UNAVAILABLE);
+
+    // It is given up after the cool down and another probe is let through.
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    breaker.check("get_table")?;
+
+    Ok(())
+}
@@ -27,6 +27,7 @@ use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::cluster_info::Cluster;
 use databend_common_catalog::database::Database;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
+use databend_common_catalog::meta_call_policy::MetaCallPolicy;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::Partitions;
@@ -39,6 +40,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_context::UDFServerSchema;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
@@ -704,6 +706,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_udf_server_schema(&self, _address: &str, _handler: &str) -> Option<UDFServerSchema> {
+        todo!()
+    }
+
+    fn set_udf_server_schema(&self, _address: String, _handler: String, _schema: UDFServerSchema) {
+        todo!()
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         todo!()
     }
@@ -890,6 +900,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_meta_call_policy(&self) -> Result<MetaCallPolicy> {
+        self.ctx.get_meta_call_policy()
+    }
+
     fn get_queued_queries(&self) -> Vec<ProcessInfo> {
         todo!()
     }
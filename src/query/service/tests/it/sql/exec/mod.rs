@@ -17,6 +17,7 @@ use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::FuseTable;
@@ -165,4 +166,106 @@ pub async fn test_snapshot_consistency() -> Result<()> {
     Ok(())
 }
 
+// Returns the lines of the target table scan in the explain of the merge into.
+async fn explain_merge_target_scan(
+    fixture: &TestFixture,
+    db: &str,
+    analyze: bool,
+) -> Result<String> {
+    let explain = if analyze {
+        "EXPLAIN ANALYZE"
+    } else {
+        "EXPLAIN"
+    };
+    let sql = format!(
+        "{explain} MERGE INTO {db}.t AS t USING {db}.s AS s ON t.a = s.a \
+         WHEN MATCHED THEN UPDATE SET t.b = s.b"
+    );
+    let blocks = fixture
+        .execute_query(&sql)
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let lines = blocks
+        .iter()
+        .flat_map(|block| {
+            let column = block.columns()[0].value.as_column().unwrap();
+            let column = column.as_string().unwrap();
+            column
+                .iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let start = lines
+        .iter()
+        .position(|line| line.ends_with(&format!("table: default.{db}.t")))
+        .unwrap();
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.contains("estimated rows"))
+            .unwrap();
+    Ok(lines[start..end].join("\n"))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+pub async fn test_merge_into_prune_target_by_source_keys() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t(a INT, b INT)"))
+        .await?;
+    // Three blocks with the ranges [1, 3], [10, 12] and [20, 22].
+    for (a1, a2) in [(1, 3), (10, 12), (20, 22)] {
+        fixture
+            .execute_command(&format!(
+                "INSERT INTO {db}.t VALUES({a1}, {a1}), ({a2}, {a2})"
+            ))
+            .await?;
+    }
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.s(a INT, b INT)"))
+        .await?;
+    fixture
+        .execute_command(&format!("INSERT INTO {db}.s VALUES(2, 100), (12, 100)"))
+        .await?;
+
+    // The pruning is disabled by default.
+    let target_scan = explain_merge_target_scan(&fixture, &db, true).await?;
+    assert!(
+        target_scan.contains("partitions scanned: 3"),
+        "{target_scan}"
+    );
+
+    fixture
+        .execute_command("SET merge_into_source_pruning_max_rows = 1000")
+        .await?;
+    // Plain EXPLAIN doesn't execute the source, so the target is not pruned.
+    let target_scan = explain_merge_target_scan(&fixture, &db, false).await?;
+    assert!(
+        target_scan.contains("partitions scanned: 3"),
+        "{target_scan}"
+    );
+
+    let target_scan = explain_merge_target_scan(&fixture, &db, true).await?;
+    // The block [20, 22] is pruned by the range index, and the block [1, 3] doesn't
+    // contain the key 2, so it's pruned by the bloom index.
+    assert!(
+        target_scan.contains("partitions scanned: 1"),
+        "{target_scan}"
+    );
+    assert!(
+        target_scan.contains(
+            "pruning stats: [segments: <range pruning: 3 to 2>, \
+             blocks: <range pruning: 2 to 2, bloom pruning: 2 to 1>]"
+        ),
+        "{target_scan}"
+    );
+
+    Ok(())
+}
+
 mod get_table_bind_test;
@@ -1,5 +1,9 @@
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_exception::Result;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::IndexType;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
 use databend_query::sessions::SessionType;
@@ -26,3 +30,104 @@ async fn test_query_kind() -> Result<()> {
     assert_eq!(kind, QueryKind::CopyIntoTable);
     Ok(())
 }
+
+// Collects the number of pushed down predicates of each scan.
+fn scan_push_down_predicates(s_expr: &SExpr, predicates: &mut Vec<(IndexType, usize)>) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        predicates.push((
+            scan.table_index,
+            scan.push_down_predicates.as_ref().map_or(0, |p| p.len()),
+        ));
+    }
+    for child in s_expr.children() {
+        scan_push_down_predicates(child, predicates);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_merge_into_push_down_conditions() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.t(a INT, b INT) CLUSTER BY(a)"))
+        .await?;
+    fixture
+        .execute_command(&format!("CREATE TABLE {db}.s(a INT, b INT)"))
+        .await?;
+
+    // (on, clauses, target pushed down, source pushed down)
+    let cases = [
+        // Single side ON predicates are pushed down to the scans.
+        (
+            "t.a = s.a AND t.b > 1 AND s.b > 1",
+            "WHEN MATCHED THEN UPDATE SET t.b = s.b",
+            true,
+            true,
+        ),
+        // All the matched clauses have target conditions, push down to the target table.
+        (
+            "t.a = s.a",
+            "WHEN MATCHED AND t.a > 10 THEN UPDATE SET t.b = s.b \
+             WHEN MATCHED AND t.a < 0 THEN DELETE",
+            true,
+            false,
+        ),
+        // Source conditions must not drop joined rows before the multiple matches check.
+        (
+            "t.a = s.a",
+            "WHEN MATCHED AND t.a > 10 AND s.b > 1 THEN UPDATE SET t.b = s.b",
+            true,
+            false,
+        ),
+        (
+            "t.a = s.a",
+            "WHEN MATCHED AND s.b > 1 THEN UPDATE SET t.b = s.b \
+             WHEN MATCHED AND t.a < 0 THEN DELETE",
+            false,
+            false,
+        ),
+        // Some joined rows may match a clause without condition.
+        (
+            "t.a = s.a",
+            "WHEN MATCHED AND t.a > 10 THEN UPDATE SET t.b = s.b WHEN MATCHED THEN DELETE",
+            false,
+            false,
+        ),
+        // Unmatched rows depend on all the target rows.
+        (
+            "t.a = s.a",
+            "WHEN MATCHED AND t.a > 10 THEN UPDATE SET t.b = s.b \
+             WHEN NOT MATCHED THEN INSERT *",
+            false,
+            false,
+        ),
+    ];
+
+    for (on, clauses, target_pushed_down, source_pushed_down) in cases {
+        let ctx = fixture.new_query_ctx().await?;
+        ctx.get_settings().set_setting(
+            "enable_experimental_merge_into".to_string(),
+            "1".to_string(),
+        )?;
+        let mut planner = Planner::new(ctx.clone());
+        let sql = format!("MERGE INTO {db}.t AS t USING {db}.s AS s ON {on} {clauses}");
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let Plan::MergeInto(merge_into) = plan else {
+            panic!("expect merge into plan");
+        };
+        let mut predicates = vec![];
+        scan_push_down_predicates(&merge_into.input, &mut predicates);
+        assert_eq!(predicates.len(), 2, "{sql}");
+        for (table_index, num) in predicates {
+            let pushed_down = if table_index == merge_into.target_table_idx {
+                target_pushed_down
+            } else {
+                source_pushed_down
+            };
+            assert_eq!(num > 0, pushed_down, "{sql}");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_exception::Result;
+use databend_common_sql::Planner;
+use databend_query::test_kits::TestFixture;
+use goldenfile::Mint;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_error_message_with_identifiers() -> Result<()> {
+    let mut mint = Mint::new("tests/it/sql/planner/semantic/testdata");
+    let file = &mut mint.new_goldenfile("error_message.txt").unwrap();
+
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command(
+            "CREATE TABLE t(id INT, \"user name\" INT, \"Weird\"\"Col\" INT, \
+             tup TUPLE(a INT, \"b c\" INT), \
+             wide TUPLE(c1 INT, c2 INT, c3 INT, c4 INT, c5 INT, c6 INT, \
+             c7 INT, c8 INT, c9 INT, c10 INT, c11 INT, c12 INT))",
+        )
+        .await?;
+    fixture
+        .execute_command("CREATE TABLE t2(\"User Name\" INT)")
+        .await?;
+
+    let cases = [
+        "SELECT \"Weird\"\"Col2\" FROM t",
+        "SELECT tup['x y'] FROM t",
+        "SELECT wide['c13'] FROM t",
+        "SELECT * EXCLUDE \"it's\" FROM t",
+        "SELECT * FROM t AS t1 JOIN t AS t2 USING (\"no such\")",
+        "SELECT user_name FROM t2",
+        "SELECT base64(1)",
+    ];
+    for sql in cases {
+        let ctx = fixture.new_query_ctx().await?;
+        let mut planner = Planner::new(ctx);
+        let err = planner.plan_sql(sql).await.unwrap_err();
+        writeln!(file, "---------- Input ----------").unwrap();
+        writeln!(file, "{sql}").unwrap();
+        writeln!(file, "---------- Output ---------").unwrap();
+        writeln!(file, "{}", err.message()).unwrap();
+        writeln!(file, "\n").unwrap();
+    }
+
+    Ok(())
+}
@@ -12,4 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod error_message;
 mod name_resolution;
+mod type_check;
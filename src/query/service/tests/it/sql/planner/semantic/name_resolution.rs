@@ -16,6 +16,9 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
+use databend_query::sql::check_identifier_length;
+use databend_query::sql::display_candidates;
+use databend_query::sql::display_ident;
 use databend_query::sql::normalize_identifier;
 use databend_query::sql::IdentifierNormalizer;
 use databend_query::sql::NameResolutionContext;
@@ -59,6 +62,7 @@ fn test_normalize_identifier_quoted_case_insensitive() {
         unquoted_ident_case_sensitive: false,
         quoted_ident_case_sensitive: false,
         deny_column_reference: false,
+        max_identifier_length: 0,
     };
 
     {
@@ -82,6 +86,7 @@ fn test_normalize_identifier_unquoted_case_sensitive() {
         unquoted_ident_case_sensitive: true,
         quoted_ident_case_sensitive: true,
         deny_column_reference: false,
+        max_identifier_length: 0,
     };
 
     {
@@ -113,3 +118,59 @@ fn test_normalize_identifiers_in_expr() {
         "EXISTS (SELECT func(\"T\".a + 1) AS b)".to_string()
     );
 }
+
+#[test]
+fn test_check_identifier_length() {
+    let ctx = NameResolutionContext {
+        max_identifier_length: 8,
+        ..Default::default()
+    };
+
+    // At the boundary.
+    let ident = Identifier::from_name(None, "abcdefgh");
+    assert!(check_identifier_length(&ident, &ctx).is_ok());
+
+    // The length is counted by characters.
+    let ident = Identifier::from_name_with_quoted(None, "这是一个标识符号", Some('"'));
+    assert!(check_identifier_length(&ident, &ctx).is_ok());
+
+    // Over the boundary.
+    let ident = Identifier::from_name(None, "abcdefghi");
+    let err = check_identifier_length(&ident, &ctx).unwrap_err();
+    assert_eq!(err.code(), 1065);
+    assert_eq!(
+        err.message(),
+        "identifier abcdefghi is too long, its length 9 exceeds the max_identifier_length 8"
+    );
+
+    // No limit.
+    let ctx = NameResolutionContext {
+        max_identifier_length: 0,
+        ..Default::default()
+    };
+    let ident = Identifier::from_name(None, "a".repeat(1024));
+    assert!(check_identifier_length(&ident, &ctx).is_ok());
+}
+
+#[test]
+fn test_display_ident() {
+    assert_eq!(display_ident("user_name"), "user_name");
+    assert_eq!(display_ident("user name"), "`user name`");
+    assert_eq!(display_ident("UserName"), "`UserName`");
+    assert_eq!(display_ident("1st"), "`1st`");
+    assert_eq!(display_ident("a`b"), "`a``b`");
+    assert_eq!(display_ident("标识符"), "`标识符`");
+    assert_eq!(display_ident(""), "``");
+}
+
+#[test]
+fn test_display_candidates() {
+    assert_eq!(display_candidates::<&str>(&[]), "[]");
+    assert_eq!(display_candidates(&["a", "b c"]), "[a, `b c`]");
+
+    let names = (1..=12).map(|i| format!("c{i}")).collect::<Vec<_>>();
+    assert_eq!(
+        display_candidates(&names),
+        "[c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, and 2 more]"
+    );
+}
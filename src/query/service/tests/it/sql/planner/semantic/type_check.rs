@@ -0,0 +1,401 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::plans::AggregateFunction;
+use databend_common_sql::plans::ConstantExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::ScalarExpr;
+use databend_common_sql::BindContext;
+use databend_common_sql::Metadata;
+use databend_common_sql::NameResolutionContext;
+use databend_common_sql::Planner;
+use databend_common_sql::TryCastDiagnostic;
+use databend_common_sql::TypeChecker;
+use databend_common_users::UserApiProvider;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+use parking_lot::RwLock;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_substr_index_zero_rewrite_warning() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    for (dialect, rewritten) in [
+        ("MySQL", true),
+        ("PostgreSQL", false),
+        ("Hive", true),
+        ("Experimental", false),
+    ] {
+        let ctx = fixture.new_query_ctx().await?;
+        ctx.get_settings()
+            .set_setting("sql_dialect".to_string(), dialect.to_string())?;
+
+        let mut planner = Planner::new(ctx.clone());
+        planner.plan_sql("SELECT substr('12345', 0, 1)").await?;
+        let warnings = ctx.pop_warnings();
+        if rewritten {
+            assert_eq!(warnings, vec![format!(
+                "`substr` index 0 is treated as 1 in {dialect} dialect"
+            )]);
+        } else {
+            assert!(warnings.is_empty(), "{dialect}: {warnings:?}");
+        }
+
+        // Non-zero literal index is never rewritten.
+        planner.plan_sql("SELECT substr('12345', 2, 1)").await?;
+        assert!(ctx.pop_warnings().is_empty());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_constant_fold_cache() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // A balanced tree of `+` with 256 leaves of `1`, 511 nodes in total.
+    fn build(depth: usize) -> String {
+        match depth {
+            0 => "1".to_string(),
+            _ => format!("({} + {})", build(depth - 1), build(depth - 1)),
+        }
+    }
+    let sql = build(8);
+    let tokens = tokenize_sql(&sql)?;
+    let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+
+    let settings = ctx.get_settings();
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut bind_context = BindContext::new();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx.clone(),
+        &name_resolution_ctx,
+        metadata,
+        &[],
+        false,
+    )?;
+
+    let (scalar, _) = *type_checker.resolve(&ast)?;
+    match scalar {
+        ScalarExpr::ConstantExpr(ConstantExpr { value, .. }) => {
+            assert_eq!(value.to_string(), "256");
+        }
+        _ => panic!("expect constant, got {scalar:?}"),
+    }
+
+    // Without the cache every one of the 255 `+` would be folded together with
+    // its arguments, identical subtrees are folded only once with the cache.
+    let fold_count = type_checker.constant_fold_count();
+    assert!(
+        fold_count < 50,
+        "constant folder invoked {fold_count} times"
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_alias_shadowing_column_warning() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("CREATE TABLE t_alias(a INT, b INT)")
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let mut planner = Planner::new(ctx.clone());
+
+    // The alias is the column itself.
+    planner
+        .plan_sql("SELECT a FROM t_alias ORDER BY a + 1")
+        .await?;
+    assert!(ctx.pop_warnings().is_empty());
+
+    // The alias shadows the column `a`, the alias is used.
+    planner
+        .plan_sql("SELECT a + 1 AS a FROM t_alias ORDER BY a")
+        .await?;
+    assert_eq!(ctx.pop_warnings(), vec![
+        "a in ORDER BY is resolved to the select list alias rather than the column of the same name"
+            .to_string()
+    ]);
+
+    planner
+        .plan_sql("SELECT b AS a, count(*) FROM t_alias GROUP BY b HAVING a > 1")
+        .await?;
+    assert_eq!(ctx.pop_warnings(), vec![
+        "a in HAVING is resolved to the select list alias rather than the column of the same name"
+            .to_string()
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_expression_depth() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // A SQL nested too deeply is rejected by the parser before being resolved.
+    let sql = format!("SELECT {}1{}", "(".repeat(20000), ")".repeat(20000));
+    let err = Planner::new(ctx.clone()).plan_sql(&sql).await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::SYNTAX_EXCEPTION);
+    assert!(
+        err.message().contains("exceeds the maximum depth 1000"),
+        "{err}"
+    );
+
+    // `(((1 + 1) + 1) ... + 1)` nested 20000 times, the AST is built directly to check
+    // the limit of the resolver alone.
+    fn build(depth: usize) -> Expr {
+        let literal = || Expr::Literal {
+            span: None,
+            value: Literal::UInt64(1),
+        };
+        let mut expr = literal();
+        for _ in 0..depth {
+            expr = Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::Plus,
+                left: Box::new(expr),
+                right: Box::new(literal()),
+            };
+        }
+        expr
+    }
+
+    // Building and dropping the deep AST are recursive, run it with a large stack.
+    let handle = std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(move || -> Result<()> {
+            let resolve = |ast: &Expr| -> Result<ScalarExpr> {
+                let settings = ctx.get_settings();
+                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+                let mut bind_context = BindContext::new();
+                let metadata = Arc::new(RwLock::new(Metadata::default()));
+                let mut type_checker = TypeChecker::try_create(
+                    &mut bind_context,
+                    ctx.clone(),
+                    &name_resolution_ctx,
+                    metadata,
+                    &[],
+                    false,
+                )?;
+                Ok(type_checker.resolve(ast)?.0)
+            };
+
+            let err = resolve(&build(20000)).unwrap_err();
+            assert_eq!(err.code(), ErrorCode::SEMANTIC_ERROR);
+            assert!(
+                err.message().contains("exceeds max_expression_depth 1000"),
+                "{err}"
+            );
+
+            ctx.get_settings()
+                .set_setting("max_expression_depth".to_string(), "10".to_string())?;
+            assert!(resolve(&build(9)).is_ok());
+            let err = resolve(&build(10)).unwrap_err();
+            assert!(
+                err.message().contains("exceeds max_expression_depth 10"),
+                "{err}"
+            );
+            Ok(())
+        })
+        .unwrap();
+    handle.join().unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_max_array_agg_elements() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let resolve = |sql: &str| -> Result<AggregateFunction> {
+        let tokens = tokenize_sql(sql)?;
+        let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let settings = ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let mut bind_context = BindContext::new();
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let mut type_checker = TypeChecker::try_create(
+            &mut bind_context,
+            ctx.clone(),
+            &name_resolution_ctx,
+            metadata,
+            &[],
+            false,
+        )?;
+        match type_checker.resolve(&ast)?.0 {
+            ScalarExpr::AggregateFunction(agg) => Ok(agg),
+            scalar => panic!("expect aggregate function, got {scalar:?}"),
+        }
+    };
+
+    // Unlimited by default.
+    assert_eq!(resolve("array_agg(1)")?.max_elements, 0);
+
+    ctx.get_settings()
+        .set_setting("max_array_agg_elements".to_string(), "100".to_string())?;
+    assert_eq!(resolve("array_agg(1)")?.max_elements, 100);
+    assert_eq!(resolve("list(1)")?.max_elements, 100);
+    assert_eq!(resolve("array_agg(DISTINCT 1)")?.max_elements, 100);
+    assert_eq!(resolve("group_uniq_array(1)")?.max_elements, 100);
+    // Other aggregate functions are not capped.
+    assert_eq!(resolve("sum(1)")?.max_elements, 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_try_cast_diagnostics() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let sql = "SELECT TRY_CAST('1' AS INT), TRY_CAST('2' AS INT), CAST(1 AS VARCHAR), \
+        TRY_CAST(1 AS VARCHAR), TRY_CAST('05/03/2024' AS DATE FORMAT 'DD/MM/YYYY')";
+
+    let try_cast_diagnostics = |plan: Plan| match plan {
+        Plan::Query { bind_context, .. } => bind_context.try_cast_diagnostics,
+        plan => panic!("expect query plan, got {plan:?}"),
+    };
+
+    // Not recorded by default.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    assert!(try_cast_diagnostics(plan).is_empty());
+
+    ctx.get_settings()
+        .set_setting("enable_try_cast_diagnostics".to_string(), "1".to_string())?;
+    let (plan, _) = planner.plan_sql(sql).await?;
+    assert_eq!(try_cast_diagnostics(plan), vec![
+        TryCastDiagnostic {
+            source_type: DataType::String,
+            target_type: DataType::Number(NumberDataType::Int32),
+        },
+        TryCastDiagnostic {
+            source_type: DataType::Number(NumberDataType::UInt8),
+            target_type: DataType::String,
+        },
+        TryCastDiagnostic {
+            source_type: DataType::String,
+            target_type: DataType::Date,
+        },
+    ]);
+
+    // The ones in set operations, subqueries and CTEs are collected into the root context.
+    let string_to_int = TryCastDiagnostic {
+        source_type: DataType::String,
+        target_type: DataType::Number(NumberDataType::Int32),
+    };
+    let uint_to_int = TryCastDiagnostic {
+        source_type: DataType::Number(NumberDataType::UInt8),
+        target_type: DataType::Number(NumberDataType::Int32),
+    };
+    let cases = [
+        "SELECT TRY_CAST('1' AS INT) UNION ALL SELECT TRY_CAST(1 AS INT)",
+        "SELECT TRY_CAST('1' AS INT) EXCEPT SELECT TRY_CAST(1 AS INT)",
+        "SELECT (SELECT TRY_CAST('1' AS INT)) FROM numbers(1) \
+            WHERE EXISTS (SELECT TRY_CAST(1 AS INT))",
+        "SELECT a, b FROM (SELECT TRY_CAST('1' AS INT) AS a) t1, \
+            (SELECT TRY_CAST(1 AS INT) AS b) t2",
+        "WITH t1 AS (SELECT TRY_CAST('1' AS INT) AS a), \
+            t2 AS MATERIALIZED (SELECT TRY_CAST(1 AS INT) AS b) \
+            SELECT a, b FROM t1, t2",
+    ];
+    for sql in cases {
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let diagnostics = try_cast_diagnostics(plan);
+        assert_eq!(diagnostics.len(), 2, "{sql}");
+        assert!(diagnostics.contains(&string_to_int), "{sql}");
+        assert!(diagnostics.contains(&uint_to_int), "{sql}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_udf_server_schema_mismatch() -> Result<()> {
+    let address = "http://127.0.0.1:8815";
+    let mut config = ConfigBuilder::create().build();
+    config.query.enable_udf_server = true;
+    config.query.udf_server_allow_list = vec![address.to_string()];
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // The UDF is added without the schema check of CREATE FUNCTION, and the cached schema
+    // of the UDF server doesn't match it, so the server is never connected.
+    let udf = UserDefinedFunction::create_udf_server(
+        "gcd_mismatch",
+        address,
+        "gcd",
+        "python",
+        vec![
+            DataType::Number(NumberDataType::Int32),
+            DataType::Number(NumberDataType::Int32),
+        ],
+        DataType::Number(NumberDataType::Int32),
+        "",
+    );
+    UserApiProvider::instance()
+        .add_udf(&ctx.get_tenant(), udf, &CreateOption::Create)
+        .await?;
+    ctx.set_udf_server_schema(
+        address.to_string(),
+        "gcd".to_string(),
+        (
+            vec![
+                DataType::Number(NumberDataType::Int64),
+                DataType::Number(NumberDataType::Int64),
+            ],
+            DataType::Number(NumberDataType::Int64),
+        ),
+    );
+
+    let mut planner = Planner::new(ctx.clone());
+    let err = planner
+        .plan_sql("SELECT gcd_mismatch(1, 2)")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INVALID_ARGUMENT);
+    assert_eq!(
+        err.message(),
+        "UDF 'gcd_mismatch' does not match the UDF server, \
+        expected: (Int32, Int32) -> Int32, actual: (Int64, Int64) -> Int64"
+    );
+
+    // The check can be turned off.
+    ctx.get_settings().set_setting(
+        "enable_udf_server_schema_check".to_string(),
+        "0".to_string(),
+    )?;
+    planner.plan_sql("SELECT gcd_mismatch(1, 2)").await?;
+
+    Ok(())
+}
@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_query::storages::fuse::io::DataEncryption;
+use databend_query::storages::fuse::io::EncryptionKeyProvider;
+use databend_query::storages::fuse::io::StaticKeyProvider;
+use databend_query::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+use databend_query::test_kits::*;
+use futures_util::TryStreamExt;
+
+struct MockKeyProvider {
+    keys: HashMap<String, [u8; 32]>,
+}
+
+impl MockKeyProvider {
+    fn create(key_ids: &[&str]) -> Arc<Self> {
+        let keys = key_ids
+            .iter()
+            .enumerate()
+            .map(|(i, key_id)| (key_id.to_string(), [i as u8 + 1; 32]))
+            .collect();
+        Arc::new(Self { keys })
+    }
+}
+
+impl EncryptionKeyProvider for MockKeyProvider {
+    fn get_key(&self, key_id: &str) -> Result<[u8; 32]> {
+        self.keys
+            .get(key_id)
+            .copied()
+            .ok_or_else(|| ErrorCode::StorageOther(format!("unknown key {key_id}")))
+    }
+}
+
+fn collect_block_files(dir: &Path, files: &mut Vec<Vec<u8>>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_block_files(&path, files)?;
+        } else if path
+            .parent()
+            .is_some_and(|p| p.ends_with(FUSE_TBL_BLOCK_PREFIX))
+        {
+            files.push(std::fs::read(&path)?);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_encryption_round_trip() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    DataEncryption::instance().set_provider(MockKeyProvider::create(&["k1", "k2"]));
+
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t(a int, b string) storage_format = 'parquet' encryption_key_id = 'k1'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1, 'a'), (2, 'b')"))
+        .await?;
+
+    // rotate the key, blocks written before are still read with k1.
+    fixture
+        .execute_command(&format!(
+            "alter table {db}.t set options(encryption_key_id = 'k2')"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(3, 'c')"))
+        .await?;
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 'a'      |",
+        "| 2        | 'b'      |",
+        "| 3        | 'c'      |",
+        "+----------+----------+",
+    ];
+    expects_ok(
+        "read encrypted blocks",
+        fixture
+            .execute_query(&format!("select a, b from {db}.t order by a"))
+            .await,
+        expected,
+    )
+    .await?;
+
+    // the stored blocks are not plain parquet files.
+    let mut files = vec![];
+    collect_block_files(Path::new(fixture.storage_root()), &mut files)?;
+    assert_eq!(files.len(), 2);
+    for file in files {
+        assert!(!file.starts_with(b"PAR1"));
+        assert!(!file.ends_with(b"PAR1"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_encryption_missing_key() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    DataEncryption::instance().set_provider(MockKeyProvider::create(&["k1"]));
+
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    // unknown keys are rejected by create and alter table.
+    let res = fixture
+        .execute_command(&format!(
+            "create table {db}.t1(a int) storage_format = 'parquet' encryption_key_id = 'k2'"
+        ))
+        .await;
+    expects_err(
+        "create with unknown key",
+        ErrorCode::TABLE_OPTION_INVALID,
+        res,
+    );
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t(a int) storage_format = 'parquet' encryption_key_id = 'k1'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1), (2)"))
+        .await?;
+
+    let res = fixture
+        .execute_command(&format!(
+            "alter table {db}.t set options(encryption_key_id = 'k2')"
+        ))
+        .await;
+    expects_err("alter to unknown key", ErrorCode::TABLE_OPTION_INVALID, res);
+
+    // the key of the written blocks is gone.
+    DataEncryption::instance().set_provider(MockKeyProvider::create(&["k2"]));
+    let res = match fixture
+        .execute_query(&format!("select * from {db}.t"))
+        .await
+    {
+        Ok(stream) => stream.try_collect::<Vec<DataBlock>>().await,
+        Err(e) => Err(e),
+    };
+    let err = res.expect_err("reading without the key must fail");
+    assert!(
+        err.message().contains("unknown key k1"),
+        "{}",
+        err.message()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_static_key_provider() -> Result<()> {
+    let provider = StaticKeyProvider::try_create(&[format!("k1:{}", "ab".repeat(32))])?;
+    assert_eq!(provider.get_key("k1")?, [0xab; 32]);
+    assert_eq!(
+        provider.get_key("k2").unwrap_err().code(),
+        ErrorCode::STORAGE_OTHER
+    );
+
+    // not a key of 32 bytes
+    assert!(StaticKeyProvider::try_create(&["k1:abcd".to_string()]).is_err());
+    assert!(StaticKeyProvider::try_create(&["abcd".to_string()]).is_err());
+    Ok(())
+}
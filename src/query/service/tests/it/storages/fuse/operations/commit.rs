@@ -26,6 +26,7 @@ use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::cluster_info::Cluster;
 use databend_common_catalog::database::Database;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
+use databend_common_catalog::meta_call_policy::MetaCallPolicy;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::Partitions;
@@ -38,6 +39,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_context::UDFServerSchema;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
@@ -212,6 +214,42 @@ async fn test_fuse_occ_retry() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_concurrent_append_commit() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    let qry = format!("create table {}.t_concurrent(c int)", db);
+    fixture.execute_command(&qry).await?;
+
+    // appends never touch the same blocks, all of them should be committed
+    // by rebasing onto the latest snapshot, without any error surfaced.
+    let num_inserters = 16;
+    let queries = (0..num_inserters)
+        .map(|i| format!("insert into {}.t_concurrent values({})", db, i))
+        .collect::<Vec<_>>();
+    futures::future::try_join_all(queries.iter().map(|qry| fixture.execute_command(qry))).await?;
+
+    let qry = format!("select count(*), sum(c) from {}.t_concurrent", db);
+    let blocks = fixture
+        .execute_query(qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 16       | 120      |",
+        "+----------+----------+",
+    ];
+    databend_common_expression::block_debug::assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_last_snapshot_hint() -> Result<()> {
     let fixture = TestFixture::setup().await?;
@@ -638,6 +676,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_udf_server_schema(&self, _address: &str, _handler: &str) -> Option<UDFServerSchema> {
+        todo!()
+    }
+
+    fn set_udf_server_schema(&self, _address: String, _handler: String, _schema: UDFServerSchema) {
+        todo!()
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         todo!()
     }
@@ -799,6 +845,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_meta_call_policy(&self) -> Result<MetaCallPolicy> {
+        self.ctx.get_meta_call_policy()
+    }
+
     fn get_queued_queries(&self) -> Vec<ProcessInfo> {
         todo!()
     }
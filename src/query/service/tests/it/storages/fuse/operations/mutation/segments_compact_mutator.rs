@@ -777,6 +777,7 @@ impl CompactSegmentTestFixture {
                         None,
                         Compression::Lz4Raw,
                         Some(Utc::now()),
+                        None,
                     );
 
                     collected_blocks.push(block_meta.clone());
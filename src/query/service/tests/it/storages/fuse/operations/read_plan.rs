@@ -102,6 +102,7 @@ fn test_to_partitions() -> Result<()> {
         None,
         meta::Compression::Lz4Raw,
         Some(Utc::now()),
+        None,
     ));
 
     let blocks_metas = (0..num_of_block)
@@ -12,23 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_base::base::tokio;
 use databend_common_catalog::table::Table;
+use databend_common_config::CacheStorageTypeInnerConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::box_render;
 use databend_common_expression::block_debug::pretty_format_blocks;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ComputedExpr;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::AuthType;
+use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UserDefinedFunction;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::storage::StorageFsConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
 use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
+use databend_common_sql::persisted_definition::definition_checksum;
+use databend_common_storages_system::BuildInfoTable;
 use databend_common_storages_system::BuildOptionsTable;
 use databend_common_storages_system::CachesTable;
 use databend_common_storages_system::CatalogsTable;
@@ -40,9 +57,13 @@ use databend_common_storages_system::CreditsTable;
 use databend_common_storages_system::DatabasesTable;
 use databend_common_storages_system::EnginesTable;
 use databend_common_storages_system::FunctionsTable;
+use databend_common_storages_system::InvalidObjectsTable;
 use databend_common_storages_system::MetricsTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::UsersTable;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::QUERY_CHECKSUM;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::UserApiProvider;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::TableContext;
@@ -52,6 +73,7 @@ use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
 use futures::TryStreamExt;
 use goldenfile::Mint;
+use maplit::btreemap;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
 use wiremock::Mock;
@@ -111,6 +133,28 @@ async fn test_build_options_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_build_info_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let table = BuildInfoTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None, true).await?;
+
+    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 6);
+    assert_eq!(block.num_rows(), 1);
+    for entry in block.columns() {
+        let value = entry.value.index(0).unwrap();
+        let value = value.as_string().unwrap();
+        assert!(!value.is_empty());
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_columns_table() -> Result<()> {
     let fixture = TestFixture::setup().await?;
@@ -425,3 +469,257 @@ async fn test_caches_table() -> Result<()> {
 
     Ok(())
 }
+
+// Returns the tables of the database that have data in the table data cache.
+async fn data_cached_tables(fixture: &TestFixture, db_name: &str) -> Result<String> {
+    let sql = format!(
+        "SELECT DISTINCT table FROM system.data_cache \
+         WHERE database = '{db_name}' ORDER BY table"
+    );
+    let blocks = fixture
+        .execute_query(&sql)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(pretty_format_blocks(&blocks).unwrap())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_data_cache_table() -> Result<()> {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let mut config = ConfigBuilder::create().build();
+    config.cache.data_cache_storage = CacheStorageTypeInnerConfig::Disk;
+    config.cache.disk_cache_config.path = cache_dir.path().to_str().unwrap().to_string();
+    config.cache.table_data_cache_population_queue_size = 64;
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    fixture.create_default_database().await?;
+    let db_name = fixture.default_db_name();
+    for table in ["t1", "t2"] {
+        fixture
+            .execute_command(&format!("CREATE TABLE {db_name}.{table}(a INT, b STRING)"))
+            .await?;
+        fixture
+            .execute_command(&format!(
+                "INSERT INTO {db_name}.{table} VALUES(1, 'a'), (2, 'b')"
+            ))
+            .await?;
+        fixture
+            .execute_command(&format!("SELECT * FROM {db_name}.{table}"))
+            .await?;
+    }
+
+    // The cache is populated in background.
+    let mut output = String::new();
+    for _ in 0..100 {
+        output = data_cached_tables(&fixture, &db_name).await?;
+        if output.contains("'t1'") && output.contains("'t2'") {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    assert!(output.contains("'t1'"), "{output}");
+    assert!(output.contains("'t2'"), "{output}");
+
+    fixture
+        .execute_command(&format!("SYSTEM DROP DATA CACHE FOR TABLE {db_name}.t1"))
+        .await?;
+    let output = data_cached_tables(&fixture, &db_name).await?;
+    assert!(!output.contains("'t1'"), "{output}");
+    assert!(output.contains("'t2'"), "{output}");
+
+    fixture.execute_command("SYSTEM DROP DATA CACHE").await?;
+    let output = data_cached_tables(&fixture, &db_name).await?;
+    assert!(!output.contains("'t2'"), "{output}");
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_invalid_objects_table() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let db_name = fixture.default_db_name();
+
+    // Inject definitions that no longer parse, as if the meta entries were corrupted.
+    ctx.get_catalog("default")
+        .await?
+        .create_table(CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: db_name.clone(),
+                table_name: "bad_view".to_string(),
+            },
+            table_meta: TableMeta {
+                engine: VIEW_ENGINE.to_string(),
+                options: btreemap! {QUERY.to_string() => "SELEC * FROM t".to_string()},
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        })
+        .await?;
+    // A view stored with an unqualified table name is bound in its own database,
+    // not in the current one.
+    fixture
+        .execute_command(&format!("CREATE TABLE {db_name}.t(a INT)"))
+        .await?;
+    ctx.get_catalog("default")
+        .await?
+        .create_table(CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: db_name.clone(),
+                table_name: "unqualified_view".to_string(),
+            },
+            table_meta: TableMeta {
+                engine: VIEW_ENGINE.to_string(),
+                options: btreemap! {QUERY.to_string() => "SELECT a FROM t".to_string()},
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        })
+        .await?;
+    assert_ne!(ctx.get_current_database(), db_name);
+    fixture
+        .execute_command(&format!("SELECT * FROM {db_name}.unqualified_view"))
+        .await?;
+    UserApiProvider::instance()
+        .add_udf(
+            &tenant,
+            UserDefinedFunction::create_lambda_udf("bad_udf", vec!["a".to_string()], "(a +", ""),
+            &CreateOption::Create,
+        )
+        .await?;
+
+    let err = fixture
+        .execute_command(&format!("SELECT * FROM {db_name}.bad_view"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INVALID_OBJECT_DEFINITION);
+    assert!(err.message().contains("bad_view"));
+    assert!(err.message().contains("DROP VIEW"));
+
+    let err = fixture
+        .execute_command("SELECT bad_udf(1)")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::INVALID_OBJECT_DEFINITION);
+    assert!(err.message().contains("bad_udf"));
+    assert!(err.message().contains("DROP FUNCTION"));
+
+    let table = InvalidObjectsTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None, true).await?;
+    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let output = pretty_format_blocks(&result).unwrap();
+    assert!(output.contains("bad_view"));
+    assert!(output.contains("bad_udf"));
+    assert!(!output.contains("unqualified_view"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_invalid_objects_checksum() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    let tenant = ctx.get_tenant();
+    let db_name = fixture.default_db_name();
+
+    // The definitions still parse, but don't match the checksums stored with them.
+    ctx.get_catalog("default")
+        .await?
+        .create_table(CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: db_name.clone(),
+                table_name: "corrupted_view".to_string(),
+            },
+            table_meta: TableMeta {
+                engine: VIEW_ENGINE.to_string(),
+                options: btreemap! {
+                    QUERY.to_string() => "SELECT 2".to_string(),
+                    QUERY_CHECKSUM.to_string() => definition_checksum("SELECT 1").to_string(),
+                },
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        })
+        .await?;
+    ctx.get_catalog("default")
+        .await?
+        .create_table(CreateTableReq {
+            create_option: CreateOption::Create,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: db_name.clone(),
+                table_name: "corrupted_table".to_string(),
+            },
+            table_meta: TableMeta {
+                engine: "MEMORY".to_string(),
+                schema: Arc::new(TableSchema::new(vec![
+                    TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+                    TableField::new("b", TableDataType::Number(NumberDataType::Int32))
+                        .with_computed_expr(Some(ComputedExpr::Virtual("a + 2".to_string())))
+                        .with_computed_expr_checksum(Some(definition_checksum("a + 1"))),
+                ])),
+                ..TableMeta::default()
+            },
+            as_dropped: false,
+        })
+        .await?;
+    UserApiProvider::instance()
+        .add_udf(
+            &tenant,
+            UserDefinedFunction {
+                name: "corrupted_udf".to_string(),
+                description: "".to_string(),
+                definition: UDFDefinition::LambdaUDF(LambdaUDF {
+                    parameters: vec!["a".to_string()],
+                    defaults: BTreeMap::new(),
+                    definition: "a + 2".to_string(),
+                    definition_checksum: Some(definition_checksum("a + 1")),
+                }),
+                created_on: Utc::now(),
+            },
+            &CreateOption::Create,
+        )
+        .await?;
+
+    for (sql, name) in [
+        (
+            format!("SELECT * FROM {db_name}.corrupted_view"),
+            "corrupted_view",
+        ),
+        (
+            format!("SELECT * FROM {db_name}.corrupted_table"),
+            "corrupted_table.b",
+        ),
+        ("SELECT corrupted_udf(1)".to_string(), "corrupted_udf"),
+    ] {
+        let err = fixture.execute_command(&sql).await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::INVALID_OBJECT_DEFINITION, "{sql}");
+        assert!(err.message().contains(name), "{}", err.message());
+        assert!(
+            err.message().contains("checksum mismatch"),
+            "{}",
+            err.message()
+        );
+    }
+
+    let table = InvalidObjectsTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None, true).await?;
+    let stream = table.read_data_block_stream(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let output = pretty_format_blocks(&result).unwrap();
+    assert!(output.contains("corrupted_view"));
+    assert!(output.contains("corrupted_table.b"));
+    assert!(output.contains("corrupted_udf"));
+
+    Ok(())
+}
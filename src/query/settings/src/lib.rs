@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod mysql_variables;
 mod settings;
 mod settings_default;
 mod settings_getter_setter;
@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+
+use crate::settings::Settings;
+
+const MYSQL_SQL_MODE: &str = "ONLY_FULL_GROUP_BY,STRICT_TRANS_TABLES,NO_ZERO_IN_DATE,NO_ZERO_DATE,ERROR_FOR_DIVISION_BY_ZERO,NO_ENGINE_SUBSTITUTION";
+
+enum MySQLVariableValue {
+    Static(&'static str),
+    // The value is mapped from a databend setting.
+    Setting(fn(&Settings) -> Result<String>),
+}
+
+// MySQL system variables which are queried by the common MySQL drivers (e.g.
+// mysql-connector-java, mysqlclient) on connect, sorted by name.
+const MYSQL_VARIABLES: &[(&str, MySQLVariableValue)] = &[
+    ("auto_increment_increment", MySQLVariableValue::Static("1")),
+    ("autocommit", MySQLVariableValue::Static("1")),
+    (
+        "character_set_client",
+        MySQLVariableValue::Static("utf8mb4"),
+    ),
+    (
+        "character_set_connection",
+        MySQLVariableValue::Static("utf8mb4"),
+    ),
+    (
+        "character_set_database",
+        MySQLVariableValue::Static("utf8mb4"),
+    ),
+    (
+        "character_set_results",
+        MySQLVariableValue::Static("utf8mb4"),
+    ),
+    (
+        "character_set_server",
+        MySQLVariableValue::Static("utf8mb4"),
+    ),
+    (
+        "collation_connection",
+        MySQLVariableValue::Static("utf8mb4_general_ci"),
+    ),
+    (
+        "collation_database",
+        MySQLVariableValue::Static("utf8mb4_general_ci"),
+    ),
+    (
+        "collation_server",
+        MySQLVariableValue::Static("utf8mb4_general_ci"),
+    ),
+    ("init_connect", MySQLVariableValue::Static("")),
+    (
+        "interactive_timeout",
+        MySQLVariableValue::Static("31536000"),
+    ),
+    ("license", MySQLVariableValue::Static("Apache-2.0")),
+    ("lower_case_table_names", MySQLVariableValue::Static("0")),
+    // 128M
+    (
+        "max_allowed_packet",
+        MySQLVariableValue::Static("134217728"),
+    ),
+    ("net_buffer_length", MySQLVariableValue::Static("16384")),
+    ("net_write_timeout", MySQLVariableValue::Static("31536000")),
+    ("performance_schema", MySQLVariableValue::Static("0")),
+    ("query_cache_size", MySQLVariableValue::Static("0")),
+    ("query_cache_type", MySQLVariableValue::Static("OFF")),
+    ("sql_mode", MySQLVariableValue::Static(MYSQL_SQL_MODE)),
+    ("system_time_zone", MySQLVariableValue::Static("UTC")),
+    (
+        "time_zone",
+        MySQLVariableValue::Setting(Settings::get_timezone),
+    ),
+    (
+        "transaction_isolation",
+        MySQLVariableValue::Static("REPEATABLE-READ"),
+    ),
+    ("transaction_read_only", MySQLVariableValue::Static("0")),
+    (
+        "tx_isolation",
+        MySQLVariableValue::Static("REPEATABLE-READ"),
+    ),
+    ("tx_read_only", MySQLVariableValue::Static("0")),
+    ("version", MySQLVariableValue::Static("8.0.26")),
+    ("version_comment", MySQLVariableValue::Static("Databend")),
+    ("wait_timeout", MySQLVariableValue::Static("31536000")),
+];
+
+impl Settings {
+    /// Get the value of a MySQL system variable, e.g. `@@session.time_zone`.
+    ///
+    /// Returns `None` if the variable is not recognized.
+    pub fn get_mysql_variable(&self, name: &str) -> Result<Option<String>> {
+        let name = name.to_lowercase();
+        let name = ["global.", "session.", "local."]
+            .iter()
+            .find_map(|scope| name.strip_prefix(scope))
+            .unwrap_or(&name);
+
+        match MYSQL_VARIABLES.iter().find(|(n, _)| *n == name) {
+            Some((_, value)) => self.mysql_variable_value(value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Get all the recognized MySQL system variables and their values.
+    pub fn get_mysql_variables(&self) -> Result<Vec<(String, String)>> {
+        MYSQL_VARIABLES
+            .iter()
+            .map(|(name, value)| Ok((name.to_string(), self.mysql_variable_value(value)?)))
+            .collect()
+    }
+
+    fn mysql_variable_value(&self, value: &MySQLVariableValue) -> Result<String> {
+        match value {
+            MySQLVariableValue::Static(v) => Ok(v.to_string()),
+            MySQLVariableValue::Setting(getter) => getter(self),
+        }
+    }
+}
@@ -121,7 +121,8 @@ impl DefaultSettings {
             let global_conf = GlobalConfig::try_get_instance();
             let all_timezones: Vec<String> = chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.to_string()).collect();
 
-            let default_settings = HashMap::from([
+            #[allow(unused_mut)]
+            let mut default_settings = HashMap::from([
                 ("enable_streaming_load", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables streaming load.",
@@ -200,6 +201,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("http_handler_result_buffer_max_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of result blocks buffered by a http query before the execution waits for the client to fetch them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
@@ -254,12 +261,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("max_identifier_length", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(255),
+                    desc: "Sets the maximum length of identifiers (like table, column or function names), longer identifiers are rejected. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("sql_dialect", DefaultSettingValue {
                     value: UserSettingValue::String("PostgreSQL".to_owned()),
                     desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\",  \"Experimental\", \"Prql\", and \"Hive\".",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(vec!["PostgreSQL".into(), "MySQL".into(), "Experimental".into(), "Hive".into(), "Prql".into()])),
                 }),
+                ("default_order_by_null", DefaultSettingValue {
+                    value: UserSettingValue::String("auto".to_owned()),
+                    desc: "Sets where NULLs are placed when ORDER BY omits NULLS FIRST or NULLS LAST. Available values include \"auto\", \"first\" and \"last\", \"auto\" means it's decided by the SQL dialect.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["auto".into(), "first".into(), "last".into()])),
+                }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables dphyp join order algorithm.",
@@ -272,6 +291,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_auto_materialize_cte", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Decides whether to materialize a common table expression referenced multiple times by estimated cost, if it has no MATERIALIZED hint.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("disable_join_reorder", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Disable join reorder optimization.",
@@ -308,9 +333,75 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("max_udf_expansion_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(16),
+                    desc: "Sets the maximum depth of lambda UDFs calling other lambda UDFs when expanding them.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=256)),
+                }),
+                ("max_expression_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum nesting depth of an expression when resolving it, 0 means unlimited. Independently, the parser rejects SQL with parentheses, brackets or braces nested deeper than 1000.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("max_array_agg_elements", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum number of elements an array_agg can collect, 0 means unlimited.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("wasm_udf_memory_limit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum memory in bytes a WASM UDF call can use, 0 means unlimited.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u32::MAX as u64 + 1)),
+                }),
+                ("wasm_udf_fuel_limit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum fuel, about the number of WASM instructions, a WASM UDF call can consume, 0 means unlimited.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("max_commit_retry", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(30),
+                    desc: "Sets the maximum number of times a conflicting table commit is retried, 0 means only the retry time limit applies.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("max_grouping_sets", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4096),
+                    desc: "Sets the maximum number of grouping sets a GROUP BY clause can expand to, e.g. CUBE of 12 items produces 4096 grouping sets.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=u64::MAX)),
+                }),
+                ("enable_order_by_expression_pushdown", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables removing the ORDER BY keys that are folded to constants from the sort keys.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("max_insert_logged_errors", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10000),
+                    desc: "Sets the maximum number of skipped rows an INSERT ... ON ERROR SKIP LOG INTO statement writes to its error table, the rest are only counted.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_try_cast_diagnostics", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables recording the source and target types of the TRY_CAST expressions in a query when planning.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("meta_request_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "Sets the deadline in seconds of a meta service request made while planning a query, including its retries, 0 means no deadline.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("inlist_to_join_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024),
-                    desc: "Set the threshold for converting IN list to JOIN.",
+                    desc: "Set the threshold for converting IN list to JOIN, 0 disables the conversion.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
@@ -328,9 +419,15 @@ impl DefaultSettings {
                 }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("utf8".to_owned()),
-                    desc: "Sets the character collation. Available values include \"utf8\".",
+                    desc: "Sets the character collation. Available values include \"utf8\" and \"binary\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["utf8".into(), "binary".into()])),
+                }),
+                ("enable_implicit_string_temporal_cast", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables implicitly casting string literals to date or timestamp when they are compared with temporal values.",
                     mode: SettingMode::Both,
-                    range: Some(SettingRange::String(vec!["utf8".into()])),
+                    range: Some(SettingRange::Numeric(0..=1)),
                 }),
                 ("max_result_rows", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
@@ -350,6 +447,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("error_on_implicit_cross_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Raise an error if a comma join or an INNER JOIN has no condition connecting its inputs, explicit CROSS JOIN is always allowed.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("broadcast_join_max_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum estimated rows of the build side when broadcast join is enforced, a larger or unknown build side is rejected. 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -453,6 +562,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("max_estimated_group_by_keys", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Refuses the query if the number of GROUP BY keys estimated from the column statistics exceeds this value. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("max_group_by_keys", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Aborts the query if the number of GROUP BY keys observed by an aggregation exceeds this value. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
@@ -516,6 +637,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("merge_into_source_pruning_max_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum estimated rows of a 'MERGE INTO' source whose join keys are collected to prune the target table, 0 disables the pruning.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_distributed_replace_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables distributed execution of 'REPLACE INTO'.",
@@ -626,10 +753,22 @@ impl DefaultSettings {
                 }),
                 ("external_server_request_batch_rows", DefaultSettingValue {
                     value: UserSettingValue::UInt64(65536),
-                    desc: "Request batch rows to external server",
+                    desc: "Sets the maximum number of rows in each request to the external server, the input blocks are split or merged to this size.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=u64::MAX)),
                 }),
+                ("external_server_request_retry_times", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64),
+                    desc: "Sets the maximum number of retries of the failed requests to the external server, the retries fail over to the next address if multiple addresses are given.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_udf_server_schema_check", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables checking the argument types and return type of UDF server functions against the UDF server when planning a query, the checked schemas are cached in the session.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_parquet_prewhere", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables parquet prewhere",
@@ -644,9 +783,9 @@ impl DefaultSettings {
                 }),
                 ("numeric_cast_option", DefaultSettingValue {
                     value: UserSettingValue::String("rounding".to_string()),
-                    desc: "Set numeric cast mode as \"rounding\" or \"truncating\".",
+                    desc: "Set numeric cast mode as \"rounding\", \"truncating\" or \"error\", \"error\" rejects the casts losing precision.",
                     mode: SettingMode::Both,
-                    range: Some(SettingRange::String(vec!["rounding".into(), "truncating".into()])),
+                    range: Some(SettingRange::String(vec!["rounding".into(), "truncating".into(), "error".into()])),
                 }),
                 ("enable_experimental_rbac_check", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
@@ -690,6 +829,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("tuple_access_out_of_bounds_as_null", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Returns NULL instead of an error when accessing a tuple field by an out of bounds index or a non-existent name.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("cost_factor_hash_table_per_row", DefaultSettingValue {
                     value: UserSettingValue::UInt64(COST_FACTOR_HASH_TABLE_PER_ROW),
                     desc: "Cost factor of building hash table for a data row",
@@ -766,6 +911,15 @@ impl DefaultSettings {
                 })
             ]);
 
+            // Failpoints only exist in the builds with the `failpoints` feature.
+            #[cfg(feature = "failpoints")]
+            default_settings.insert("failpoints", DefaultSettingValue {
+                value: UserSettingValue::String("".to_owned()),
+                desc: "For testing only. Activates failpoints of the query, e.g. 'spiller_write=error*1;udf_server_call=timeout%0.5'.",
+                mode: SettingMode::Both,
+                range: None,
+            });
+
             Ok(Arc::new(DefaultSettings {
                 settings: default_settings.into_iter().map(|(k, v)| (k.to_string(), v))
                     .collect()
@@ -257,6 +257,10 @@ impl Settings {
         Ok(self.try_get_u64("quoted_ident_case_sensitive")? != 0)
     }
 
+    pub fn get_max_identifier_length(&self) -> Result<u64> {
+        self.try_get_u64("max_identifier_length")
+    }
+
     pub fn get_max_result_rows(&self) -> Result<u64> {
         self.try_get_u64("max_result_rows")
     }
@@ -269,6 +273,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_cbo")? != 0)
     }
 
+    pub fn get_enable_auto_materialize_cte(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_auto_materialize_cte")? != 0)
+    }
+
     /// # Safety
     pub unsafe fn get_disable_join_reorder(&self) -> Result<bool> {
         Ok(self.unchecked_try_get_u64("disable_join_reorder")? != 0)
@@ -302,6 +310,14 @@ impl Settings {
         Ok(self.try_get_u64("enforce_broadcast_join")? != 0)
     }
 
+    pub fn get_error_on_implicit_cross_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("error_on_implicit_cross_join")? != 0)
+    }
+
+    pub fn get_broadcast_join_max_rows(&self) -> Result<u64> {
+        self.try_get_u64("broadcast_join_max_rows")
+    }
+
     pub fn get_disable_merge_into_join_reorder(&self) -> Result<bool> {
         Ok(self.try_get_u64("disable_merge_into_join_reorder")? != 0)
     }
@@ -310,6 +326,55 @@ impl Settings {
         Ok(self.try_get_u64("max_cte_recursive_depth")? as usize)
     }
 
+    pub fn get_max_udf_expansion_depth(&self) -> Result<usize> {
+        Ok(self.try_get_u64("max_udf_expansion_depth")? as usize)
+    }
+
+    pub fn get_meta_request_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("meta_request_timeout_secs")
+    }
+
+    /// Returns the current value of a readable setting as a string.
+    pub fn get_setting_value(&self, key: &str) -> Result<String> {
+        self.try_get_string(key)
+    }
+
+    pub fn get_max_expression_depth(&self) -> Result<usize> {
+        Ok(self.try_get_u64("max_expression_depth")? as usize)
+    }
+
+    pub fn get_max_array_agg_elements(&self) -> Result<u64> {
+        self.try_get_u64("max_array_agg_elements")
+    }
+
+    pub fn get_wasm_udf_memory_limit(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_memory_limit")
+    }
+
+    pub fn get_wasm_udf_fuel_limit(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_fuel_limit")
+    }
+
+    pub fn get_max_commit_retry(&self) -> Result<u64> {
+        self.try_get_u64("max_commit_retry")
+    }
+
+    pub fn get_max_grouping_sets(&self) -> Result<u64> {
+        self.try_get_u64("max_grouping_sets")
+    }
+
+    pub fn get_enable_order_by_expression_pushdown(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_order_by_expression_pushdown")? != 0)
+    }
+
+    pub fn get_max_insert_logged_errors(&self) -> Result<u64> {
+        self.try_get_u64("max_insert_logged_errors")
+    }
+
+    pub fn get_enable_try_cast_diagnostics(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_try_cast_diagnostics")? != 0)
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -320,6 +385,20 @@ impl Settings {
         }
     }
 
+    /// Returns whether NULLs are placed first if ORDER BY omits NULLS FIRST or NULLS LAST,
+    /// or `None` if it's decided by the SQL dialect.
+    pub fn get_default_order_by_null(&self) -> Result<Option<bool>> {
+        match self
+            .try_get_string("default_order_by_null")?
+            .to_lowercase()
+            .as_str()
+        {
+            "first" => Ok(Some(true)),
+            "last" => Ok(Some(false)),
+            _ => Ok(None),
+        }
+    }
+
     pub fn get_collation(&self) -> Result<&str> {
         match self.try_get_string("collation")?.to_lowercase().as_str() {
             "utf8" => Ok("utf8"),
@@ -327,6 +406,15 @@ impl Settings {
         }
     }
 
+    #[cfg(feature = "failpoints")]
+    pub fn get_failpoints(&self) -> Result<String> {
+        self.try_get_string("failpoints")
+    }
+
+    pub fn get_enable_implicit_string_temporal_cast(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_implicit_string_temporal_cast")? != 0)
+    }
+
     pub fn get_enable_hive_parquet_predict_pushdown(&self) -> Result<u64> {
         self.try_get_u64("enable_hive_parquet_predict_pushdown")
     }
@@ -363,6 +451,10 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_http_handler_result_buffer_max_bytes(&self) -> Result<u64> {
+        self.try_get_u64("http_handler_result_buffer_max_bytes")
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -395,6 +487,14 @@ impl Settings {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
 
+    pub fn get_max_estimated_group_by_keys(&self) -> Result<u64> {
+        self.try_get_u64("max_estimated_group_by_keys")
+    }
+
+    pub fn get_max_group_by_keys(&self) -> Result<u64> {
+        self.try_get_u64("max_group_by_keys")
+    }
+
     pub fn get_enable_experimental_aggregate_hashtable(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_aggregate_hashtable")? == 1)
     }
@@ -464,6 +564,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_merge_into")? != 0)
     }
 
+    pub fn get_merge_into_source_pruning_max_rows(&self) -> Result<u64> {
+        self.try_get_u64("merge_into_source_pruning_max_rows")
+    }
+
     pub fn get_enable_distributed_replace(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_distributed_replace_into")? != 0)
     }
@@ -565,6 +669,14 @@ impl Settings {
         self.try_get_u64("external_server_request_batch_rows")
     }
 
+    pub fn get_external_server_request_retry_times(&self) -> Result<u64> {
+        self.try_get_u64("external_server_request_retry_times")
+    }
+
+    pub fn get_enable_udf_server_schema_check(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_udf_server_schema_check")? != 0)
+    }
+
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
@@ -598,6 +710,10 @@ impl Settings {
         Ok(self.try_get_u64("disable_variant_check")? != 0)
     }
 
+    pub fn get_tuple_access_out_of_bounds_as_null(&self) -> Result<bool> {
+        Ok(self.try_get_u64("tuple_access_out_of_bounds_as_null")? != 0)
+    }
+
     pub fn get_cost_factor_hash_table_per_row(&self) -> Result<u64> {
         self.try_get_u64("cost_factor_hash_table_per_row")
     }
@@ -429,6 +429,7 @@ pub trait PhysicalPlanReplacer {
                 insert_schema: plan.insert_schema.clone(),
                 select_column_bindings: plan.select_column_bindings.clone(),
                 cast_needed: plan.cast_needed,
+                on_error: plan.on_error.clone(),
             },
         )))
     }
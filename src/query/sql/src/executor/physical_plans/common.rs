@@ -19,6 +19,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::Scalar;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::aggregates::AggregateFunctionSortDesc;
 
 use crate::IndexType;
 
@@ -27,12 +28,20 @@ pub struct AggregateFunctionSignature {
     pub name: String,
     pub params: Vec<Scalar>,
     pub args: Vec<DataType>,
+    /// The sort descriptions of the `ORDER BY` clause inside the aggregate function,
+    /// the sort keys are the last `sort_descs.len()` arguments.
+    pub sort_descs: Vec<AggregateFunctionSortDesc>,
 }
 
 impl AggregateFunctionSignature {
     pub fn return_type(&self) -> Result<DataType> {
         AggregateFunctionFactory::instance()
-            .get(&self.name, self.params.clone(), self.args.clone())?
+            .get_with_sort_descs(
+                &self.name,
+                self.params.clone(),
+                self.args.clone(),
+                self.sort_descs.clone(),
+            )?
             .return_type()
     }
 }
@@ -30,6 +30,7 @@ use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::Exchange;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::DummyTableScan;
@@ -122,6 +123,23 @@ impl PhysicalPlanBuilder {
                     .map(|item| Ok(item.scalar.as_expr()?.sql_display()))
                     .collect::<Result<Vec<_>>>()?;
 
+                let settings = self.ctx.get_settings();
+                let max_estimated_group_by_keys = settings.get_max_estimated_group_by_keys()?;
+                if max_estimated_group_by_keys > 0 && !group_items.is_empty() {
+                    let rel_expr = RelExpr::with_s_expr(s_expr);
+                    if let Some(keys) = agg.estimate_group_by_keys(&rel_expr)? {
+                        if keys > max_estimated_group_by_keys as f64 {
+                            return Err(ErrorCode::TooManyGroupByKeys(format!(
+                                "the estimated number of group by keys {} of GROUP BY ({}) exceeds max_estimated_group_by_keys {}, \
+                                set max_estimated_group_by_keys = 0 to run it anyway",
+                                keys as u64,
+                                group_by_display.join(", "),
+                                max_estimated_group_by_keys
+                            )));
+                        }
+                    }
+                }
+
                 let mut agg_funcs: Vec<AggregateFunctionDesc> = agg.aggregate_functions.iter().map(|v| {
                     if let ScalarExpr::AggregateFunction(agg) = &v.scalar {
                         Ok(AggregateFunctionDesc {
@@ -137,6 +155,7 @@ impl PhysicalPlanBuilder {
                                     }
                                 }).collect::<Result<_>>()?,
                                 params: agg.params.clone(),
+                                sort_descs: agg.sort_descs.clone(),
                             },
                             output_column: v.index,
                             arg_indices: agg.args.iter().map(|arg| {
@@ -155,7 +174,6 @@ impl PhysicalPlanBuilder {
                     }
                 }).collect::<Result<_>>()?;
 
-                let settings = self.ctx.get_settings();
                 let group_by_shuffle_mode = settings.get_group_by_shuffle_mode()?;
                 let enable_experimental_aggregate_hashtable =
                     settings.get_enable_experimental_aggregate_hashtable()?;
@@ -324,6 +342,7 @@ impl PhysicalPlanBuilder {
                                     }
                                 }).collect::<Result<_>>()?,
                                 params: agg.params.clone(),
+                                sort_descs: agg.sort_descs.clone(),
                             },
                             output_column: v.index,
                             arg_indices: agg.args.iter().map(|arg| {
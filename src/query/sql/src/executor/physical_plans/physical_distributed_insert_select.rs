@@ -19,6 +19,7 @@ use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::TableInfo;
 
 use crate::executor::PhysicalPlan;
+use crate::plans::InsertOnError;
 use crate::ColumnBinding;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -33,4 +34,6 @@ pub struct DistributedInsertSelect {
     pub select_schema: DataSchemaRef,
     pub select_column_bindings: Vec<ColumnBinding>,
     pub cast_needed: bool,
+    /// Skip the rows which fail to be cast instead of aborting the insertion.
+    pub on_error: Option<InsertOnError>,
 }
@@ -81,6 +81,7 @@ pub enum WindowFunction {
     NthValue(NthValueFunctionDesc),
     Ntile(NtileFunctionDesc),
     CumeDist,
+    Interpolate(InterpolateFunctionDesc),
 }
 
 impl WindowFunction {
@@ -96,6 +97,7 @@ impl WindowFunction {
             WindowFunction::LagLead(f) => Ok(f.return_type.clone()),
             WindowFunction::NthValue(f) => Ok(f.return_type.clone()),
             WindowFunction::Ntile(f) => Ok(f.return_type.clone()),
+            WindowFunction::Interpolate(f) => Ok(f.return_type.clone()),
         }
     }
 }
@@ -113,6 +115,7 @@ impl Display for WindowFunction {
             WindowFunction::NthValue(_) => write!(f, "nth_value"),
             WindowFunction::Ntile(_) => write!(f, "ntile"),
             WindowFunction::CumeDist => write!(f, "cume_dist"),
+            WindowFunction::Interpolate(_) => write!(f, "interpolate"),
         }
     }
 }
@@ -145,6 +148,12 @@ pub struct NtileFunctionDesc {
     pub return_type: DataType,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct InterpolateFunctionDesc {
+    pub arg: usize,
+    pub return_type: DataType,
+}
+
 impl PhysicalPlanBuilder {
     pub(crate) async fn build_window(
         &mut self,
@@ -240,12 +249,17 @@ impl PhysicalPlanBuilder {
             }
         }
 
+        let default_nulls_first = self
+            .ctx
+            .get_settings()
+            .get_default_order_by_null()?
+            .unwrap_or(false);
         let order_by_items = w
             .order_by
             .iter()
             .map(|v| SortDesc {
                 asc: v.asc.unwrap_or(true),
-                nulls_first: v.nulls_first.unwrap_or(false),
+                nulls_first: v.nulls_first.unwrap_or(default_nulls_first),
                 order_by: v.order_by_item.index,
                 display_name: self.metadata.read().column(v.order_by_item.index).name(),
             })
@@ -262,6 +276,7 @@ impl PhysicalPlanBuilder {
                         .map(|s| s.data_type())
                         .collect::<Result<_>>()?,
                     params: agg.params.clone(),
+                    sort_descs: agg.sort_descs.clone(),
                 },
                 output_column: w.index,
                 arg_indices: agg
@@ -321,6 +336,19 @@ impl PhysicalPlanBuilder {
                 n: func.n,
                 return_type: *func.return_type.clone(),
             }),
+            WindowFuncType::Interpolate(func) => {
+                WindowFunction::Interpolate(InterpolateFunctionDesc {
+                    return_type: *func.return_type.clone(),
+                    arg: if let ScalarExpr::BoundColumnRef(col) = &*func.arg {
+                        Ok(col.column.index)
+                    } else {
+                        Err(ErrorCode::Internal(
+                            "Window's interpolate function argument must be a BoundColumnRef"
+                                .to_string(),
+                        ))
+                    }?,
+                })
+            }
             WindowFuncType::RowNumber => WindowFunction::RowNumber,
             WindowFuncType::Rank => WindowFunction::Rank,
             WindowFuncType::DenseRank => WindowFunction::DenseRank,
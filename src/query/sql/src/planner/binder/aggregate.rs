@@ -27,7 +27,10 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr as EExpr;
 use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 
 use super::prune_by_children;
@@ -39,12 +42,14 @@ use crate::binder::Binder;
 use crate::binder::ColumnBinding;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
+use crate::format_scalar;
 use crate::optimizer::SExpr;
 use crate::plans::walk_expr_mut;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::GroupingSets;
@@ -110,6 +115,8 @@ pub struct GroupingSetsInfo {
     pub grouping_id_column: ColumnBinding,
     /// Each grouping set is a list of column indices in `group_items`.
     pub sets: Vec<Vec<IndexType>>,
+    /// The `_grouping_id` of the grand-total row of `GROUP BY ... WITH TOTALS`.
+    pub totals_grouping_id: Option<u32>,
     /// The indices generated to identify the duplicate group items in the execution of the `GROUPING SETS` plan (not including `_grouping_id`).
     ///
     /// If the aggregation function argument is an item in the grouping set, for example:
@@ -151,6 +158,35 @@ pub struct AggregateInfo {
     pub grouping_sets: Option<GroupingSetsInfo>,
 }
 
+impl AggregateInfo {
+    /// The predicate `_grouping_id = <totals grouping id>`, which only holds on the grand-total
+    /// row of `GROUP BY ... WITH TOTALS`.
+    pub fn totals_row_predicate(&self) -> Option<ScalarExpr> {
+        let grouping_sets = self.grouping_sets.as_ref()?;
+        let totals_grouping_id = grouping_sets.totals_grouping_id?;
+        Some(
+            FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![
+                    BoundColumnRef {
+                        span: None,
+                        column: grouping_sets.grouping_id_column.clone(),
+                    }
+                    .into(),
+                    ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt32(totals_grouping_id)),
+                    }
+                    .into(),
+                ],
+            }
+            .into(),
+        )
+    }
+}
+
 pub(super) struct AggregateRewriter<'a> {
     pub bind_context: &'a mut BindContext,
     pub metadata: MetadataRef,
@@ -248,6 +284,8 @@ impl<'a> AggregateRewriter<'a> {
             params: aggregate.params.clone(),
             args: replaced_args,
             return_type: aggregate.return_type.clone(),
+            sort_descs: aggregate.sort_descs.clone(),
+            max_elements: aggregate.max_elements,
         };
 
         agg_info.aggregate_functions.push(ScalarItem {
@@ -267,7 +305,8 @@ impl<'a> AggregateRewriter<'a> {
         if agg_info.grouping_sets.is_none() {
             return Err(ErrorCode::SemanticError(
                 "grouping can only be called in GROUP BY GROUPING SETS clauses",
-            ));
+            )
+            .set_span(function.span));
         }
         let grouping_id_column = agg_info
             .grouping_sets
@@ -284,16 +323,31 @@ impl<'a> AggregateRewriter<'a> {
         // ba -> 00 -> 0
         // _a -> 01 -> 1
         // grouping(b, a) will be rewritten to grouping<1, 0>(grouping_id).
+        if function.arguments.is_empty() {
+            return Err(
+                ErrorCode::SemanticError("grouping requires at least one argument")
+                    .set_span(function.span),
+            );
+        }
         let mut replaced_params = Vec::with_capacity(function.arguments.len());
+        let mut invalid_args = vec![];
         for arg in &function.arguments {
             if let Some(index) = agg_info.group_items_map.get(arg) {
                 replaced_params.push(Scalar::Number(NumberScalar::Int64(*index as _)));
             } else {
-                return Err(ErrorCode::BadArguments(
-                    "Arguments of grouping should be group by expressions",
-                ));
+                invalid_args.push(match arg {
+                    ScalarExpr::BoundColumnRef(column_ref) => column_ref.column.column_name.clone(),
+                    _ => format_scalar(arg),
+                });
             }
         }
+        if !invalid_args.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Arguments of grouping must be GROUP BY expressions, but got: {}",
+                invalid_args.join(", ")
+            ))
+            .set_span(function.span));
+        }
 
         let replaced_func = FunctionCall {
             span: function.span,
@@ -410,24 +464,35 @@ impl Binder {
                     &mut vec![],
                 )
             }
-            GroupBy::GroupingSets(sets) => {
-                self.resolve_grouping_sets(bind_context, select_list, sets, &available_aliases)
-            }
-            // TODO: avoid too many clones.
-            GroupBy::Rollup(exprs) => {
-                // ROLLUP (a,b,c) => GROUPING SETS ((a,b,c), (a,b), (a), ())
-                let mut sets = Vec::with_capacity(exprs.len() + 1);
-                for i in (0..=exprs.len()).rev() {
-                    sets.push(exprs[0..i].to_vec());
-                }
+            GroupBy::GroupingSets(_)
+            | GroupBy::Rollup(_)
+            | GroupBy::Cube(_)
+            | GroupBy::Combined(_) => {
+                let sets = self.expand_grouping_sets(select_list, group_by)?;
                 self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
             }
-            GroupBy::Cube(exprs) => {
-                // CUBE (a,b) => GROUPING SETS ((a,b),(a),(b),()) // All subsets
-                let sets = (0..=exprs.len())
-                    .flat_map(|count| exprs.clone().into_iter().combinations(count))
-                    .collect::<Vec<_>>();
-                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
+            GroupBy::WithTotals(group_by) => {
+                // GROUP BY a, b WITH TOTALS => GROUPING SETS ((a, b), ())
+                // The totals row is the empty grouping set, so it is merged from the partial
+                // aggregate states like any other group, also in distributed execution.
+                let mut sets = self.expand_grouping_sets(select_list, group_by)?;
+                sets.push(vec![]);
+                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)?;
+
+                // The grouping id of the empty set has the bits of all the group items set,
+                // see `build_aggregate_expand`.
+                let agg_info = &mut bind_context.aggregate_info;
+                let group_items = agg_info.group_items.len() - 1;
+                if group_items >= u32::BITS as usize {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "WITH TOTALS supports at most {} GROUP BY items, but got {}",
+                        u32::BITS - 1,
+                        group_items
+                    )));
+                }
+                let grouping_sets = agg_info.grouping_sets.as_mut().unwrap();
+                grouping_sets.totals_grouping_id = Some((1 << group_items) - 1);
+                Ok(())
             }
         }
     }
@@ -482,6 +547,50 @@ impl Binder {
         Ok(new_expr)
     }
 
+    /// Expand the group by clause into the equivalent grouping sets, CUBE and ROLLUP are
+    /// rewritten to the power set and the prefix sets of their items. The number of sets
+    /// is checked against `max_grouping_sets` before the expansion.
+    fn expand_grouping_sets(
+        &mut self,
+        select_list: &SelectList<'_>,
+        group_by: &GroupBy,
+    ) -> Result<Vec<Vec<Expr>>> {
+        let max_grouping_sets = self.ctx.get_settings().get_max_grouping_sets()?;
+        let count = grouping_sets_count(group_by);
+        if count > max_grouping_sets {
+            return Err(ErrorCode::SemanticError(format!(
+                "GROUP BY {group_by} produces {count} grouping sets, which exceeds the limit {max_grouping_sets}, \
+                 consider raising it with the setting max_grouping_sets"
+            )));
+        }
+        let sets = match group_by {
+            GroupBy::Normal(exprs) => vec![exprs.clone()],
+            GroupBy::All => vec![self.resolve_group_all(select_list)?],
+            GroupBy::GroupingSets(sets) => sets.clone(),
+            GroupBy::Rollup(exprs) => rollup_sets(exprs),
+            GroupBy::Cube(exprs) => cube_sets(exprs),
+            GroupBy::Combined(group_bys) => {
+                // GROUP BY a, ROLLUP (b, c) => GROUPING SETS ((a, b, c), (a, b), (a))
+                let mut sets = vec![vec![]];
+                for group_by in group_bys {
+                    let item_sets = self.expand_grouping_sets(select_list, group_by)?;
+                    sets = sets
+                        .iter()
+                        .cartesian_product(item_sets.iter())
+                        .map(|(set, item_set)| set.iter().chain(item_set.iter()).cloned().collect())
+                        .collect();
+                }
+                sets
+            }
+            GroupBy::WithTotals(_) => {
+                return Err(ErrorCode::SemanticError(
+                    "WITH TOTALS can not be nested".to_string(),
+                ));
+            }
+        };
+        Ok(sets)
+    }
+
     fn resolve_grouping_sets(
         &mut self,
         bind_context: &mut BindContext,
@@ -552,6 +661,7 @@ impl Binder {
         let grouping_sets_info = GroupingSetsInfo {
             grouping_id_column,
             sets: grouping_sets,
+            totals_grouping_id: None,
             dup_group_items,
         };
 
@@ -709,9 +819,17 @@ impl Binder {
             return Ok(());
         }
 
+        // Remove constant group items, group by 'a', k ---> group by k.
+        // If all the items are constant, the first one is kept, otherwise the query would become
+        // a global aggregate, which returns one row instead of no rows for an empty input.
         // Remove dependent group items, group by a, f(a, b), f(a), b ---> group by a,b
         let mut results = vec![];
+        let mut first_constant_item = None;
         for item in bind_context.aggregate_info.group_items.iter() {
+            if self.is_constant_group_item(&item.scalar)? {
+                first_constant_item.get_or_insert_with(|| item.clone());
+                continue;
+            }
             let columns: HashSet<ScalarExpr> = bind_context
                 .aggregate_info
                 .group_items
@@ -725,6 +843,9 @@ impl Binder {
             }
             results.push(item.clone());
         }
+        if results.is_empty() {
+            results.extend(first_constant_item);
+        }
 
         bind_context.aggregate_info.group_items_map.clear();
         for (i, item) in results.iter().enumerate() {
@@ -737,6 +858,26 @@ impl Binder {
         Ok(())
     }
 
+    /// Whether the group item can be folded into a constant, such group items
+    /// have no effect on grouping and are still usable in the projection.
+    fn is_constant_group_item(&self, scalar: &ScalarExpr) -> Result<bool> {
+        if let ScalarExpr::ConstantExpr(_) = scalar {
+            return Ok(true);
+        }
+        if !scalar.used_columns().is_empty() {
+            return Ok(false);
+        }
+        let Ok(expr) = scalar.as_expr() else {
+            return Ok(false);
+        };
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Ok(false);
+        }
+        let (expr, _) =
+            ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        Ok(matches!(expr, EExpr::Constant { .. }))
+    }
+
     fn resolve_index_item(
         expr: &Expr,
         index: u64,
@@ -856,3 +997,37 @@ pub fn find_replaced_aggregate_function(
             .build()
         })
 }
+
+/// The number of grouping sets `group_by` expands to, saturated at `u64::MAX`.
+fn grouping_sets_count(group_by: &GroupBy) -> u64 {
+    match group_by {
+        GroupBy::Normal(_) | GroupBy::All => 1,
+        GroupBy::GroupingSets(sets) => sets.len() as u64,
+        GroupBy::Rollup(exprs) => exprs.len() as u64 + 1,
+        GroupBy::Cube(exprs) => u32::try_from(exprs.len())
+            .ok()
+            .and_then(|len| 1u64.checked_shl(len))
+            .unwrap_or(u64::MAX),
+        GroupBy::Combined(group_bys) => group_bys.iter().fold(1u64, |count, group_by| {
+            count.saturating_mul(grouping_sets_count(group_by))
+        }),
+        GroupBy::WithTotals(group_by) => grouping_sets_count(group_by).saturating_add(1),
+    }
+}
+
+/// ROLLUP (a,b,c) => GROUPING SETS ((a,b,c), (a,b), (a), ())
+fn rollup_sets(exprs: &[Expr]) -> Vec<Vec<Expr>> {
+    // TODO: avoid too many clones.
+    let mut sets = Vec::with_capacity(exprs.len() + 1);
+    for i in (0..=exprs.len()).rev() {
+        sets.push(exprs[0..i].to_vec());
+    }
+    sets
+}
+
+/// CUBE (a,b) => GROUPING SETS ((a,b),(a),(b),()) // All subsets
+fn cube_sets(exprs: &[Expr]) -> Vec<Vec<Expr>> {
+    (0..=exprs.len())
+        .flat_map(|count| exprs.iter().cloned().combinations(count))
+        .collect()
+}
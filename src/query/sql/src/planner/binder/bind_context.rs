@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use std::hash::Hash;
 
 use dashmap::DashMap;
+use databend_common_ast::ast::quote::QuotedString;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::TableAlias;
@@ -26,6 +27,8 @@ use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::plan::InvertedIndexInfo;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::is_internal_column;
+use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
@@ -35,12 +38,14 @@ use databend_common_expression::SEARCH_SCORE_COLUMN_ID;
 use enum_as_inner::EnumAsInner;
 use indexmap::IndexMap;
 use itertools::Itertools;
+use simsearch::SimSearch;
 
 use super::AggregateInfo;
 use super::INTERNAL_COLUMN_FACTORY;
 use crate::binder::column_binding::ColumnBinding;
 use crate::binder::window::WindowInfo;
 use crate::binder::ColumnBindingBuilder;
+use crate::display_ident;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::ScalarExpr;
@@ -49,6 +54,9 @@ use crate::IndexType;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
 
+// The max number of similar column names suggested on unresolved column errors.
+const MAX_SIMILAR_COLUMN_SUGGESTIONS: usize = 3;
+
 /// Context of current expression, this is used to check if
 /// the expression is valid in current context.
 #[derive(Debug, Clone, Default, EnumAsInner)]
@@ -147,6 +155,21 @@ pub struct BindContext {
     pub planning_agg_index: bool,
 
     pub window_definitions: DashMap<String, WindowSpec>,
+
+    /// The distinct source and target types of the `TRY_CAST`s resolved in the whole query,
+    /// only filled on the root context of a query if `enable_try_cast_diagnostics` is set.
+    pub try_cast_diagnostics: Vec<TryCastDiagnostic>,
+
+    /// True if the last row of the result is the grand-total row of `GROUP BY ... WITH TOTALS`.
+    pub with_totals: bool,
+}
+
+/// A source to target type pair of `TRY_CAST`, which may turn the values failed to
+/// be converted into NULLs silently.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryCastDiagnostic {
+    pub source_type: DataType,
+    pub target_type: DataType,
 }
 
 #[derive(Clone, Debug)]
@@ -154,6 +177,10 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
     pub materialized: bool,
+    // If true, whether to materialize the cte is decided by cost when it's referenced first time.
+    pub cost_based: bool,
+    // The number of predicates each consumer of the cte can push down if the cte is inlined.
+    pub consumer_predicates: Vec<usize>,
     pub recursive: bool,
     pub cte_idx: IndexType,
     // Record how many times this cte is used
@@ -180,6 +207,8 @@ impl BindContext {
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             window_definitions: DashMap::new(),
+            try_cast_diagnostics: vec![],
+            with_totals: false,
         }
     }
 
@@ -200,6 +229,8 @@ impl BindContext {
             expr_context: ExprContext::default(),
             planning_agg_index: false,
             window_definitions: DashMap::new(),
+            try_cast_diagnostics: vec![],
+            with_totals: false,
         }
     }
 
@@ -250,10 +281,12 @@ impl BindContext {
         if name_resolution_ctx.deny_column_reference {
             let err = if column.is_quoted() {
                 ErrorCode::SemanticError(format!(
-                    "invalid identifier {name}, do you mean '{name}'?"
+                    "invalid identifier {}, do you mean {}?",
+                    display_ident(name),
+                    QuotedString(name, '\'')
                 ))
             } else {
-                ErrorCode::SemanticError(format!("invalid identifier {name}"))
+                ErrorCode::SemanticError(format!("invalid identifier {}", display_ident(name)))
             };
             return Err(err.set_span(column.span));
         }
@@ -294,7 +327,8 @@ impl BindContext {
 
         if result.len() > 1 && !result.iter().all_equal() {
             return Err(ErrorCode::SemanticError(format!(
-                "column {name} reference or alias is ambiguous, please use another alias name",
+                "column {} reference or alias is ambiguous, please use another alias name",
+                display_ident(name)
             ))
             .set_span(column.span));
         }
@@ -302,10 +336,24 @@ impl BindContext {
         if result.is_empty() {
             let err = if column.is_quoted() {
                 ErrorCode::SemanticError(format!(
-                    "column {name} doesn't exist, do you mean '{name}'?"
+                    "column {} doesn't exist, do you mean {}?",
+                    display_ident(name),
+                    QuotedString(name, '\'')
                 ))
             } else {
-                ErrorCode::SemanticError(format!("column {name} doesn't exist"))
+                let possible_columns = self.search_similar_columns(table, name);
+                if possible_columns.is_empty() {
+                    ErrorCode::SemanticError(format!(
+                        "column {} doesn't exist",
+                        display_ident(name)
+                    ))
+                } else {
+                    ErrorCode::SemanticError(format!(
+                        "column {} doesn't exist, do you mean {}?",
+                        display_ident(name),
+                        possible_columns.join(", ")
+                    ))
+                }
             };
             Err(err.set_span(column.span))
         } else {
@@ -313,6 +361,58 @@ impl BindContext {
         }
     }
 
+    // Search the column names similar to the unresolved column from the current and
+    // parent contexts, the qualified `table.column` forms are also taken as candidates.
+    // The candidates are matched by their raw names and suggested in the quoted form if
+    // needed, so they can be copied into the query.
+    fn search_similar_columns(&self, table: Option<&str>, column: &str) -> Vec<String> {
+        let mut candidates = vec![];
+        let mut bind_context: &BindContext = self;
+        loop {
+            for column_binding in bind_context.columns.iter() {
+                if column_binding.visibility == Visibility::InVisible
+                    || is_internal_column(&column_binding.column_name)
+                {
+                    continue;
+                }
+                let column_name = &column_binding.column_name;
+                if column_binding.visibility == Visibility::Visible {
+                    candidates.push((display_ident(column_name), column_name.clone()));
+                }
+                if let Some(table_name) = &column_binding.table_name {
+                    candidates.push((
+                        format!(
+                            "{}.{}",
+                            display_ident(table_name),
+                            display_ident(column_name)
+                        ),
+                        format!("{table_name}.{column_name}"),
+                    ));
+                }
+            }
+
+            if let Some(ref parent) = bind_context.parent {
+                bind_context = parent;
+            } else {
+                break;
+            }
+        }
+
+        let mut engine: SimSearch<String> = SimSearch::new();
+        for (candidate, content) in candidates.into_iter().unique() {
+            engine.insert(candidate, &content);
+        }
+        let name = match table {
+            Some(table) => format!("{table}.{column}"),
+            None => column.to_string(),
+        };
+        engine
+            .search(&name)
+            .into_iter()
+            .take(MAX_SIMILAR_COLUMN_SUGGESTIONS)
+            .collect()
+    }
+
     pub fn search_column_position(
         &self,
         span: Span,
@@ -14,7 +14,10 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::CTEMaterialization;
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::With;
@@ -30,6 +33,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::ScalarExpr;
 use crate::plans::Sort;
 use crate::plans::SortItem;
+use crate::CteMaterializationInfo;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -40,19 +44,30 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         // Initialize cte map.
         self.init_cte(bind_context, &query.with)?;
+        self.init_cte_consumers(query);
 
         // Extract limit and offset from query.
         let (limit, offset) = self.extract_limit_and_offset(query)?;
 
         // Bind query body.
         let (mut s_expr, mut bind_context) =
-            Box::pin(self.bind_set_expr(bind_context, &query.body, &query.order_by, limit)).await?;
+            Box::pin(self.bind_set_expr(bind_context, &query.body, &query.order_by, limit, offset))
+                .await?;
 
         // Bind order by for `SetOperation` and `Values`.
         s_expr = self.bind_query_order_by(&mut bind_context, query, s_expr)?;
 
+        // The totals row of `GROUP BY ... WITH TOTALS` is the last row and is not counted by
+        // the limit, which is bound together with the select, see `bind_totals_limit`.
+        bind_context.with_totals = matches!(
+            &query.body,
+            SetExpr::Select(stmt) if matches!(stmt.group_by, Some(GroupBy::WithTotals(_)))
+        );
+
         // Bind limit.
-        s_expr = self.bind_query_limit(query, s_expr, limit, offset);
+        if !bind_context.with_totals {
+            s_expr = self.bind_query_limit(query, s_expr, limit, offset);
+        }
 
         Ok((s_expr, bind_context))
     }
@@ -85,12 +100,31 @@ impl Binder {
             let cte_info = CteInfo {
                 columns_alias: column_name,
                 query: *cte.query.clone(),
-                materialized: cte.materialized,
+                materialized: cte.materialization == CTEMaterialization::Materialized,
+                cost_based: cte.materialization == CTEMaterialization::Auto
+                    && !with.recursive
+                    && self.auto_materialize_cte,
+                consumer_predicates: vec![],
                 recursive: with.recursive,
                 cte_idx: idx,
                 used_count: 0,
                 columns: vec![],
             };
+            if !cte_info.cost_based {
+                let reason = match cte.materialization {
+                    CTEMaterialization::Materialized => "MATERIALIZED hint",
+                    CTEMaterialization::NotMaterialized => "NOT MATERIALIZED hint",
+                    CTEMaterialization::Auto if with.recursive => "recursive cte",
+                    CTEMaterialization::Auto => "auto materialization is disabled or unsupported",
+                };
+                self.metadata
+                    .write()
+                    .add_cte_materialization(CteMaterializationInfo {
+                        cte_name: table_name.clone(),
+                        materialized: cte_info.materialized,
+                        reason: reason.to_string(),
+                    });
+            }
             self.ctes_map.insert(table_name.clone(), cte_info.clone());
             bind_context.cte_map_ref.insert(table_name, cte_info);
         }
@@ -112,6 +146,11 @@ impl Binder {
             return Ok(child);
         }
 
+        let default_nulls_first = self
+            .ctx
+            .get_settings()
+            .get_default_order_by_null()?
+            .unwrap_or(false);
         let mut scalar_binder = ScalarBinder::new(
             bind_context,
             self.ctx.clone(),
@@ -124,6 +163,24 @@ impl Binder {
         let mut order_by_items = Vec::with_capacity(query.order_by.len());
         for order in query.order_by.iter() {
             match order.expr {
+                Expr::Literal {
+                    value: Literal::UInt64(position),
+                    ..
+                } => {
+                    let position = position as usize;
+                    if position == 0 || position > bind_context.columns.len() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "ORDER BY position {} is not in select list",
+                            position
+                        ))
+                        .set_span(order.expr.span()));
+                    }
+                    order_by_items.push(SortItem {
+                        index: bind_context.columns[position - 1].index,
+                        asc: order.asc.unwrap_or(true),
+                        nulls_first: order.nulls_first.unwrap_or(default_nulls_first),
+                    });
+                }
                 Expr::ColumnRef { .. } => {
                     let scalar = scalar_binder.bind(&order.expr)?.0;
                     match scalar {
@@ -131,7 +188,7 @@ impl Binder {
                             let order_by_item = SortItem {
                                 index: column.index,
                                 asc: order.asc.unwrap_or(true),
-                                nulls_first: order.nulls_first.unwrap_or(false),
+                                nulls_first: order.nulls_first.unwrap_or(default_nulls_first),
                             };
                             order_by_items.push(order_by_item);
                         }
@@ -142,10 +199,10 @@ impl Binder {
                     }
                 }
                 _ => {
-                    return Err(
-                        ErrorCode::SemanticError("can only order by column".to_string())
-                            .set_span(order.expr.span()),
-                    );
+                    return Err(ErrorCode::SemanticError(
+                        "can only order by column or position".to_string(),
+                    )
+                    .set_span(order.expr.span()));
                 }
             }
         }
@@ -0,0 +1,323 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::Span;
+use databend_common_exception::Result;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+
+use crate::binder::CteInfo;
+use crate::normalize_identifier;
+use crate::optimizer::CollectStatisticsOptimizer;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::optimizer::DEFAULT_SELECTIVITY;
+use crate::planner::binder::BindContext;
+use crate::planner::binder::Binder;
+use crate::plans::RelOperator;
+use crate::CteMaterializationInfo;
+use crate::NameResolutionContext;
+
+/// The cost of writing a row to or reading a row from a materialized cte,
+/// relative to the cost of processing a row by an operator.
+const MATERIALIZED_ROW_COST: f64 = 2.0;
+
+/// Collect the number of predicates in `WHERE` clause of each `SELECT` that consumes the ctes,
+/// only the predicates referencing nothing but the cte consumer are counted,
+/// these predicates could be pushed down into the cte if it's inlined.
+#[derive(Visitor)]
+#[visitor(SelectStmt(enter))]
+struct CteConsumerCollector {
+    name_resolution_ctx: NameResolutionContext,
+    ctes: HashSet<String>,
+    consumers: HashMap<String, Vec<usize>>,
+}
+
+impl CteConsumerCollector {
+    fn enter_select_stmt(&mut self, stmt: &SelectStmt) {
+        // (name of the table reference, name of the cte if it's a cte consumer)
+        let mut table_refs = vec![];
+        for table_ref in stmt.from.iter() {
+            self.collect_table_reference(table_ref, &mut table_refs);
+        }
+
+        let mut conjunctions = vec![];
+        if let Some(selection) = &stmt.selection {
+            split_conjunctions(selection, &mut conjunctions);
+        }
+        let qualifiers = conjunctions
+            .into_iter()
+            .filter_map(|expr| self.column_qualifiers(expr))
+            .collect::<Vec<_>>();
+
+        for (ref_name, cte_name) in table_refs.iter() {
+            let Some(cte_name) = cte_name else {
+                continue;
+            };
+            let predicates = qualifiers
+                .iter()
+                .filter(|qualifiers| {
+                    qualifiers.iter().all(|qualifier| match qualifier {
+                        Some(table) => table == ref_name,
+                        // Unqualified columns can only be resolved to the consumer
+                        // if it's the only table in `FROM` clause.
+                        None => table_refs.len() == 1,
+                    })
+                })
+                .count();
+            self.consumers
+                .entry(cte_name.clone())
+                .or_default()
+                .push(predicates);
+        }
+    }
+
+    fn collect_table_reference(
+        &self,
+        table_ref: &TableReference,
+        table_refs: &mut Vec<(String, Option<String>)>,
+    ) {
+        match table_ref {
+            TableReference::Table {
+                database,
+                table,
+                alias,
+                ..
+            } => {
+                let name = normalize_identifier(table, &self.name_resolution_ctx).name;
+                let ref_name = match alias {
+                    Some(alias) => {
+                        normalize_identifier(&alias.name, &self.name_resolution_ctx).name
+                    }
+                    None => name.clone(),
+                };
+                let cte_name = (database.is_none() && self.ctes.contains(&name)).then_some(name);
+                table_refs.push((ref_name, cte_name));
+            }
+            TableReference::Join { join, .. } => {
+                self.collect_table_reference(&join.left, table_refs);
+                self.collect_table_reference(&join.right, table_refs);
+            }
+            // Other table references are not cte consumers, record them so that
+            // unqualified columns are not attributed to a cte consumer.
+            _ => table_refs.push((String::new(), None)),
+        }
+    }
+
+    // The table qualifiers of the columns referenced by the predicate, `None` if the
+    // predicate references no column or contains a subquery.
+    fn column_qualifiers(&self, expr: &Expr) -> Option<Vec<Option<String>>> {
+        let mut visitor = ColumnQualifierVisitor::default();
+        expr.drive(&mut visitor);
+        if visitor.has_subquery || visitor.qualifiers.is_empty() {
+            return None;
+        }
+        Some(
+            visitor
+                .qualifiers
+                .into_iter()
+                .map(|table| {
+                    table.map(|table| normalize_identifier(&table, &self.name_resolution_ctx).name)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Default, Visitor)]
+#[visitor(ColumnRef(enter), Query(enter))]
+struct ColumnQualifierVisitor {
+    qualifiers: Vec<Option<Identifier>>,
+    has_subquery: bool,
+}
+
+impl ColumnQualifierVisitor {
+    fn enter_column_ref(&mut self, column: &ColumnRef) {
+        self.qualifiers.push(column.table.clone());
+    }
+
+    fn enter_query(&mut self, _query: &Query) {
+        self.has_subquery = true;
+    }
+}
+
+fn split_conjunctions<'a>(expr: &'a Expr, conjunctions: &mut Vec<&'a Expr>) {
+    match expr {
+        Expr::BinaryOp {
+            op: BinaryOperator::And,
+            left,
+            right,
+            ..
+        } => {
+            split_conjunctions(left, conjunctions);
+            split_conjunctions(right, conjunctions);
+        }
+        _ => conjunctions.push(expr),
+    }
+}
+
+fn contains_cte_scan(s_expr: &SExpr) -> bool {
+    matches!(
+        s_expr.plan(),
+        RelOperator::CteScan(_) | RelOperator::RecursiveCteScan(_)
+    ) || s_expr.children().any(contains_cte_scan)
+}
+
+// The number of rows processed by all the operators.
+fn estimate_cost(s_expr: &SExpr) -> Result<f64> {
+    let mut cost = RelExpr::with_s_expr(s_expr)
+        .derive_cardinality()?
+        .cardinality;
+    for child in s_expr.children() {
+        cost += estimate_cost(child)?;
+    }
+    Ok(cost)
+}
+
+impl Binder {
+    pub(crate) fn init_cte_consumers(&mut self, query: &Query) {
+        let Some(with) = &query.with else {
+            return;
+        };
+        let ctes = with
+            .ctes
+            .iter()
+            .map(|cte| self.normalize_identifier(&cte.alias.name).name)
+            .filter(|name| self.ctes_map.get(name).is_some_and(|info| info.cost_based))
+            .collect::<HashSet<_>>();
+        if ctes.is_empty() {
+            return;
+        }
+
+        let mut collector = CteConsumerCollector {
+            name_resolution_ctx: self.name_resolution_ctx.clone(),
+            ctes,
+            consumers: HashMap::new(),
+        };
+        query.drive(&mut collector);
+        for (name, consumer_predicates) in collector.consumers {
+            self.ctes_map.entry(name).and_modify(|cte_info| {
+                cte_info.consumer_predicates = consumer_predicates;
+            });
+        }
+    }
+
+    // Bind the cte when it's referenced first time, and decide whether to materialize it
+    // by comparing the estimated cost of materializing and inlining.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_cost_based_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &String,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let (s_expr, cte_bind_ctx) = self
+            .bind_cte(span, bind_context, table_name, alias, cte_info)
+            .await?;
+        // Subqueries are bound by a separate binder whose materialized ctes are not wrapped
+        // into the main plan, so the cte is always inlined there.
+        if !self.auto_materialize_cte {
+            return Ok((s_expr, cte_bind_ctx));
+        }
+
+        let (materialized, reason) = self.decide_cte_materialization(cte_info, &s_expr).await;
+        self.metadata
+            .write()
+            .add_cte_materialization(CteMaterializationInfo {
+                cte_name: table_name.clone(),
+                materialized,
+                reason,
+            });
+        self.ctes_map
+            .entry(table_name.clone())
+            .and_modify(|cte_info| {
+                cte_info.cost_based = false;
+                cte_info.materialized = materialized;
+            });
+        if !materialized {
+            return Ok((s_expr, cte_bind_ctx));
+        }
+
+        self.set_m_cte_bound(table_name, cte_info.cte_idx, s_expr, &cte_bind_ctx);
+        let s_expr = self.bind_m_cte_scan(table_name)?;
+        Ok((s_expr, cte_bind_ctx))
+    }
+
+    // Returns the estimated cost and output rows of the cte.
+    async fn estimate_cte(&self, s_expr: &SExpr) -> Result<(f64, f64)> {
+        let s_expr = CollectStatisticsOptimizer::new(self.ctx.clone(), self.metadata.clone())
+            .run(s_expr)
+            .await?;
+        let cost = estimate_cost(&s_expr)?;
+        let output_rows = RelExpr::with_s_expr(&s_expr)
+            .derive_cardinality()?
+            .cardinality;
+        Ok((cost, output_rows))
+    }
+
+    async fn decide_cte_materialization(
+        &self,
+        cte_info: &CteInfo,
+        s_expr: &SExpr,
+    ) -> (bool, String) {
+        let consumer_predicates = &cte_info.consumer_predicates;
+        if consumer_predicates.len() <= 1 {
+            return (false, "referenced once".to_string());
+        }
+        // The statistics of materialized ctes are collected with the main plan.
+        if contains_cte_scan(s_expr) {
+            return (
+                false,
+                "statistics of the referenced cte are unavailable".to_string(),
+            );
+        }
+
+        let Ok((body_cost, output_rows)) = self.estimate_cte(s_expr).await else {
+            return (false, "cost of the cte is unknown".to_string());
+        };
+
+        // Each consumer recomputes the cte if it's inlined, but only the rows that pass
+        // the predicates it pushes down.
+        let inline_cost = consumer_predicates
+            .iter()
+            .map(|predicates| body_cost * DEFAULT_SELECTIVITY.powi(*predicates as i32))
+            .sum::<f64>();
+        // The cte is computed once and its output is written once and read by each consumer.
+        let materialize_cost = body_cost
+            + output_rows * MATERIALIZED_ROW_COST * (consumer_predicates.len() + 1) as f64;
+
+        let materialized = materialize_cost < inline_cost;
+        let reason = format!(
+            "referenced {} times, estimated rows: {:.2}, inline cost: {:.2}, materialize cost: {:.2}",
+            consumer_predicates.len(),
+            output_rows,
+            inline_cost,
+            materialize_cost
+        );
+        (materialized, reason)
+    }
+}
@@ -39,9 +39,13 @@ use derive_visitor::Visitor;
 use log::warn;
 
 use crate::binder::project_set::SrfCollector;
+use crate::binder::split_conjunctions;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
+use crate::planner::binder::bind_table_reference::implicit_cross_join_error;
 use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
+use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::AsyncFunctionRewriter;
 use crate::ColumnBinding;
@@ -69,6 +73,7 @@ impl Binder {
         stmt: &SelectStmt,
         order_by: &[OrderByExpr],
         limit: Option<usize>,
+        offset: usize,
     ) -> Result<(SExpr, BindContext)> {
         if let Some(hints) = &stmt.hints {
             if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
@@ -105,6 +110,7 @@ impl Binder {
             self.bind_table_reference(bind_context, &cross_joins)
                 .await?
         };
+        let from_s_expr = s_expr.clone();
 
         let mut rewriter = SelectRewriter::new(
             from_context.all_column_bindings(),
@@ -138,6 +144,12 @@ impl Binder {
 
         // This will potentially add some alias group items to `from_context` if find some.
         if let Some(group_by) = stmt.group_by.as_ref() {
+            if stmt.distinct && matches!(group_by, GroupBy::WithTotals(_)) {
+                return Err(ErrorCode::SemanticError(
+                    "SELECT DISTINCT is not supported with GROUP BY ... WITH TOTALS".to_string(),
+                )
+                .set_span(stmt.span));
+            }
             self.analyze_group_items(&mut from_context, &select_list, group_by)
                 .await?;
         }
@@ -166,6 +178,10 @@ impl Binder {
             None
         };
 
+        if stmt.from.len() > 1 && self.ctx.get_settings().get_error_on_implicit_cross_join()? {
+            check_comma_join_conditions(&stmt.from, &from_s_expr, where_scalar.as_ref())?;
+        }
+
         // `analyze_projection` should behind `analyze_aggregate_select` because `analyze_aggregate_select` will rewrite `grouping`.
         let (mut scalar_items, projections) = self.analyze_projection(
             &from_context.aggregate_info,
@@ -188,7 +204,7 @@ impl Binder {
             None
         };
 
-        let order_items = self
+        let mut order_items = self
             .analyze_order_items(
                 &mut from_context,
                 &mut scalar_items,
@@ -198,6 +214,8 @@ impl Binder {
                 stmt.distinct,
             )
             .await?;
+        let totals_column =
+            self.analyze_totals_order_item(&from_context, &mut scalar_items, &mut order_items)?;
 
         // After all analysis is done.
         if set_returning_functions.is_empty() {
@@ -245,7 +263,23 @@ impl Binder {
             )?;
         }
 
-        if !order_by.is_empty() {
+        if let Some(totals_column) = &totals_column {
+            if limit.is_some() || offset > 0 {
+                s_expr = self
+                    .bind_totals_limit(
+                        &from_context,
+                        totals_column,
+                        &order_items,
+                        &mut scalar_items,
+                        limit,
+                        offset,
+                        s_expr,
+                    )
+                    .await?;
+            }
+        }
+
+        if !order_items.items.is_empty() {
             s_expr = self
                 .bind_order_by(
                     &from_context,
@@ -356,6 +390,8 @@ impl<'a> SelectRewriter<'a> {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             }),
             alias,
@@ -552,3 +588,77 @@ impl MaxColumnPosition {
         }
     }
 }
+
+/// Check that the tables of a comma join are connected by the conditions in the WHERE clause,
+/// otherwise the comma join is an implicit cross join.
+fn check_comma_join_conditions(
+    from: &[TableReference],
+    from_s_expr: &SExpr,
+    where_scalar: Option<&ScalarExpr>,
+) -> Result<()> {
+    // The comma join is a left-deep tree of cross joins, the right child of
+    // each join is the next table reference in the FROM clause.
+    let mut table_columns = Vec::with_capacity(from.len());
+    // The tables joined with conditions, e.g. correlated lateral subqueries.
+    let mut joined_with_conditions = Vec::new();
+    let mut s_expr = from_s_expr;
+    for i in (1..from.len()).rev() {
+        let RelOperator::Join(join) = s_expr.plan() else {
+            return Ok(());
+        };
+        if !join.left_conditions.is_empty() || !join.non_equi_conditions.is_empty() {
+            joined_with_conditions.push(i);
+        }
+        table_columns.push(
+            RelExpr::with_s_expr(s_expr.child(1)?)
+                .derive_relational_prop()?
+                .output_columns
+                .clone(),
+        );
+        s_expr = s_expr.child(0)?;
+    }
+    table_columns.push(
+        RelExpr::with_s_expr(s_expr)
+            .derive_relational_prop()?
+            .output_columns
+            .clone(),
+    );
+    table_columns.reverse();
+
+    // Union the tables referenced by the same predicate.
+    let mut groups = (0..from.len()).collect::<Vec<_>>();
+    for i in joined_with_conditions {
+        groups[i] = 0;
+    }
+    let predicates = where_scalar.map(split_conjunctions).unwrap_or_default();
+    for predicate in predicates.iter() {
+        let used_columns = predicate.used_columns();
+        let tables = table_columns
+            .iter()
+            .enumerate()
+            .filter(|(_, columns)| !columns.is_disjoint(&used_columns))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        for window in tables.windows(2) {
+            let (left, right) = (
+                find_group(&mut groups, window[0]),
+                find_group(&mut groups, window[1]),
+            );
+            groups[right] = left;
+        }
+    }
+
+    for i in 1..from.len() {
+        if find_group(&mut groups, i) != find_group(&mut groups, 0) {
+            return Err(implicit_cross_join_error(&from[0], &from[i]));
+        }
+    }
+    Ok(())
+}
+
+fn find_group(groups: &mut [usize], i: usize) -> usize {
+    if groups[i] != i {
+        groups[i] = find_group(groups, groups[i]);
+    }
+    groups[i]
+}
@@ -28,10 +28,11 @@ impl Binder {
         set_expr: &SetExpr,
         order_by: &[OrderByExpr],
         limit: Option<usize>,
+        offset: usize,
     ) -> Result<(SExpr, BindContext)> {
         match set_expr {
             SetExpr::Select(stmt) => {
-                Box::pin(self.bind_select(bind_context, stmt, order_by, limit)).await
+                Box::pin(self.bind_select(bind_context, stmt, order_by, limit, offset)).await
             }
             SetExpr::Query(stmt) => Box::pin(self.bind_query(bind_context, stmt)).await,
             SetExpr::SetOperation(set_operation) => {
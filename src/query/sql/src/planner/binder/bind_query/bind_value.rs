@@ -199,11 +199,18 @@ impl Binder {
             )
             .set_span(span));
         }
-        let same_length = values.windows(2).all(|v| v[0].len() == v[1].len());
-        if !same_length {
-            return Err(ErrorCode::SemanticError(
-                "Values lists must all be the same length".to_string(),
-            )
+        let num_columns = values[0].len();
+        if let Some((row_idx, row)) = values
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != num_columns)
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "Values lists must all be the same length, row {} has {} values but row 1 has {}",
+                row_idx + 1,
+                row.len(),
+                num_columns
+            ))
             .set_span(span));
         }
         Ok(())
@@ -455,10 +462,13 @@ pub fn bind_values(
                         Some(new_common_type) => column_common_type[column_idx] = new_common_type,
                         None => {
                             return Err(ErrorCode::SemanticError(format!(
-                                "{} and {} don't have common data type",
-                                common_type, data_type
+                                "{} and {} don't have common data type, in column {} of row {}",
+                                common_type,
+                                data_type,
+                                column_idx + 1,
+                                row_idx + 1
                             ))
-                            .set_span(span));
+                            .set_span(expr.span()));
                         }
                     }
                 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bind;
+mod bind_cte_materialization;
 mod bind_limit;
 mod bind_select;
 mod bind_set_expr;
@@ -21,6 +21,7 @@ use databend_common_ast::ast::split_equivalent_predicate_expr;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::JoinCondition;
 use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::TableReference;
 use databend_common_ast::Span;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -31,6 +32,7 @@ use crate::binder::CteInfo;
 use crate::binder::Finder;
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
+use crate::display_ident;
 use crate::normalize_identifier;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::FlattenInfo;
@@ -106,6 +108,18 @@ impl Binder {
             )
             .await?;
 
+        if join.op == JoinOperator::Inner
+            && join_conditions.left_conditions.is_empty()
+            && join_conditions.non_equi_conditions.is_empty()
+            && self.ctx.get_settings().get_error_on_implicit_cross_join()?
+            && RelExpr::with_s_expr(&right_child)
+                .derive_relational_prop()?
+                .outer_columns
+                .is_empty()
+        {
+            return Err(implicit_cross_join_error(&join.left, &join.right));
+        }
+
         let build_side_cache_info = self.expression_scan_context.generate_cache_info(cache_idx);
 
         let join_type = join_type(&join.op);
@@ -753,7 +767,7 @@ impl<'a> JoinConditionResolver<'a> {
             } else {
                 return Err(ErrorCode::SemanticError(format!(
                     "column {} specified in USING clause does not exist in left table",
-                    join_key_name
+                    display_ident(join_key_name)
                 ))
                 .set_span(*span));
             };
@@ -770,7 +784,7 @@ impl<'a> JoinConditionResolver<'a> {
             } else {
                 return Err(ErrorCode::SemanticError(format!(
                     "column {} specified in USING clause does not exist in right table",
-                    join_key_name
+                    display_ident(join_key_name)
                 ))
                 .set_span(*span));
             };
@@ -926,6 +940,38 @@ fn join_type(join_type: &JoinOperator) -> JoinType {
     }
 }
 
+/// The name of a table reference in error messages, the alias if it has one.
+fn table_reference_name(table_ref: &TableReference) -> String {
+    match table_ref {
+        TableReference::Table {
+            alias: Some(alias), ..
+        }
+        | TableReference::TableFunction {
+            alias: Some(alias), ..
+        }
+        | TableReference::Subquery {
+            alias: Some(alias), ..
+        }
+        | TableReference::Location {
+            alias: Some(alias), ..
+        } => display_ident(&alias.name.name),
+        TableReference::Table { table, .. } => display_ident(&table.name),
+        TableReference::TableFunction { name, .. } => display_ident(&name.name),
+        _ => table_ref.to_string(),
+    }
+}
+
+pub(crate) fn implicit_cross_join_error(
+    left: &TableReference,
+    right: &TableReference,
+) -> ErrorCode {
+    ErrorCode::SemanticError(format!(
+        "Missing join condition between {} and {}, use CROSS JOIN explicitly or set error_on_implicit_cross_join = 0 to allow the cross join",
+        table_reference_name(left),
+        table_reference_name(right)
+    ))
+}
+
 fn join_bind_context(
     join_type: &JoinType,
     bind_context: BindContext,
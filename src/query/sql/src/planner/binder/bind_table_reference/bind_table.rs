@@ -23,10 +23,16 @@ use databend_common_catalog::table::TimeNavigation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::QUERY_CHECKSUM;
 use databend_storages_common_table_meta::table::get_change_type;
 
 use crate::binder::Binder;
 use crate::optimizer::SExpr;
+use crate::persisted_definition::check_computed_columns;
+use crate::persisted_definition::invalid_object_definition;
+use crate::persisted_definition::parse_view_definition;
+use crate::persisted_definition::PersistedObjectKind;
+use crate::planner::semantic::check_identifier_length;
 use crate::BindContext;
 
 impl Binder {
@@ -45,6 +51,9 @@ impl Binder {
         temporal: &Option<TemporalClause>,
         consume: bool,
     ) -> Result<(SExpr, BindContext)> {
+        for ident in catalog.iter().chain(database.iter()).chain([table]) {
+            check_identifier_length(ident, &self.name_resolution_ctx)?;
+        }
         let (catalog, database, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let table_alias_name = alias
@@ -74,7 +83,10 @@ impl Binder {
                     ))
                     .set_span(*span));
                 }
-                return if cte_info.materialized {
+                return if cte_info.cost_based {
+                    self.bind_cost_based_cte(*span, bind_context, &table_name, alias, cte_info)
+                        .await
+                } else if cte_info.materialized {
                     self.bind_m_cte(bind_context, cte_info, &table_name, alias, span)
                         .await
                 } else if cte_info.recursive {
@@ -118,7 +130,16 @@ impl Binder {
                     let bind_context = parent.unwrap().as_mut();
                     let ctes_map = self.ctes_map.clone();
                     if let Some(cte_info) = ctes_map.get(&table_name) {
-                        return if !cte_info.materialized {
+                        return if cte_info.cost_based {
+                            self.bind_cost_based_cte(
+                                *span,
+                                bind_context,
+                                &table_name,
+                                alias,
+                                cte_info,
+                            )
+                            .await
+                        } else if !cte_info.materialized {
                             self.bind_cte(*span, bind_context, &table_name, alias, cte_info)
                                 .await
                         } else {
@@ -222,48 +243,60 @@ impl Binder {
                 // TODO(leiysky): this check is error-prone,
                 // we should find a better way to do this.
                 Self::check_view_dep(bind_context, &database, &table_name)?;
+                let view_name = format!("{database}.{table_name}");
                 let query = table_meta
                     .options()
                     .get(QUERY)
-                    .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
-                let tokens = tokenize_sql(query.as_str())?;
-                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                    .ok_or_else(|| {
+                        invalid_object_definition(
+                            PersistedObjectKind::View,
+                            &view_name,
+                            &ErrorCode::Internal("missing view query"),
+                        )
+                    })
+                    .and_then(|query| {
+                        let checksum = table_meta
+                            .options()
+                            .get(QUERY_CHECKSUM)
+                            .and_then(|checksum| checksum.parse().ok());
+                        parse_view_definition(&database, &table_name, query, checksum, self.dialect)
+                    })
+                    .map_err(|e| e.set_span(*span))?;
                 // For view, we need use a new context to bind it.
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
                 new_bind_context.view_info = Some((database.clone(), table_name));
-                if let Statement::Query(query) = &stmt {
-                    self.metadata.write().add_table(
-                        catalog,
-                        database.clone(),
-                        table_meta,
-                        table_alias_name,
-                        false,
-                        false,
-                        false,
-                        false,
-                    );
-                    let (s_expr, mut new_bind_context) =
-                        self.bind_query(&mut new_bind_context, query).await?;
-                    if let Some(alias) = alias {
-                        // view maybe has alias, e.g. select v1.col1 from v as v1;
-                        new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
-                    } else {
-                        // e.g. select v0.c0 from v0;
-                        for column in new_bind_context.columns.iter_mut() {
-                            column.database_name = None;
-                            column.table_name = Some(self.normalize_identifier(table).name);
-                        }
-                    }
-                    new_bind_context.parent = Some(Box::new(bind_context.clone()));
-                    Ok((s_expr, new_bind_context))
+                self.metadata.write().add_table(
+                    catalog,
+                    database.clone(),
+                    table_meta,
+                    table_alias_name,
+                    false,
+                    false,
+                    false,
+                    false,
+                );
+                let (s_expr, mut new_bind_context) = self
+                    .bind_query(&mut new_bind_context, &query)
+                    .await
+                    .map_err(|e| {
+                        e.add_message_back(format!(" (while binding VIEW {view_name})"))
+                    })?;
+                if let Some(alias) = alias {
+                    // view maybe has alias, e.g. select v1.col1 from v as v1;
+                    new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 } else {
-                    Err(
-                        ErrorCode::Internal(format!("Invalid VIEW object: {}", table_meta.name()))
-                            .set_span(*span),
-                    )
+                    // e.g. select v0.c0 from v0;
+                    for column in new_bind_context.columns.iter_mut() {
+                        column.database_name = None;
+                        column.table_name = Some(self.normalize_identifier(table).name);
+                    }
                 }
+                new_bind_context.parent = Some(Box::new(bind_context.clone()));
+                Ok((s_expr, new_bind_context))
             }
             _ => {
+                check_computed_columns(&database, &table_name, &table_meta.schema())
+                    .map_err(|e| e.set_span(*span))?;
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -93,6 +93,8 @@ impl Binder {
                             args,
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                         },
                     }),
                     alias: None,
@@ -105,7 +107,7 @@ impl Binder {
                 qualify: None,
             };
             let (srf_expr, mut bind_context) = self
-                .bind_select(bind_context, &select_stmt, &[], None)
+                .bind_select(bind_context, &select_stmt, &[], None, 0)
                 .await?;
 
             return self
@@ -346,6 +348,8 @@ impl Binder {
                             params: vec![],
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                         },
                     };
                     let srfs = vec![srf.clone()];
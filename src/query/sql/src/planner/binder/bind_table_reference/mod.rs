@@ -19,5 +19,6 @@ mod bind_subquery;
 mod bind_table;
 mod bind_table_function;
 
+pub(crate) use bind_join::implicit_cross_join_error;
 pub use bind_join::JoinConditions;
 pub use bind_table_function::parse_result_scan_args;
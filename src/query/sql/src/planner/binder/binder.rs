@@ -100,6 +100,9 @@ pub struct Binder {
     /// For the recursive cte, the cte table name occurs in the recursive cte definition and main query
     /// if meet recursive cte table name in cte definition, set `bind_recursive_cte` true and treat it as `CteScan`.
     pub bind_recursive_cte: bool,
+    /// Materialized ctes are wrapped to the plan of query statement, so only the ctes bound
+    /// in a query statement can be materialized by cost.
+    pub auto_materialize_cte: bool,
 }
 
 impl<'a> Binder {
@@ -121,6 +124,7 @@ impl<'a> Binder {
             ctes_map: Box::default(),
             expression_scan_context: ExpressionScanContext::new(),
             bind_recursive_cte: false,
+            auto_materialize_cte: false,
         }
     }
 
@@ -148,7 +152,13 @@ impl<'a> Binder {
     ) -> Result<Plan> {
         let plan = match stmt {
             Statement::Query(query) => {
-                let (mut s_expr, bind_context) = self.bind_query(bind_context, query).await?;
+                self.auto_materialize_cte =
+                    self.ctx.get_settings().get_enable_auto_materialize_cte()?;
+                let (mut s_expr, mut bind_context) = self.bind_query(bind_context, query).await?;
+                // The `TRY_CAST`s in CTEs, set operations and subqueries are bound in their own
+                // contexts, collect all of them from the metadata shared by the query.
+                bind_context.try_cast_diagnostics =
+                    self.metadata.read().try_cast_diagnostics().to_vec();
 
                 // Wrap `LogicalMaterializedCte` to `s_expr`
                 for (_, cte_info) in self.ctes_map.iter().rev() {
@@ -227,6 +237,7 @@ impl<'a> Binder {
             Statement::ShowSettings { show_options } => self.bind_show_settings(bind_context, show_options).await?,
             Statement::ShowIndexes { show_options } => self.bind_show_indexes(bind_context, show_options).await?,
             Statement::ShowLocks(stmt) => self.bind_show_locks(bind_context, stmt).await?,
+            Statement::CheckObjects => self.bind_rewrite_to_query(bind_context, "SELECT database, name, type, definition, error FROM system.invalid_objects ORDER BY database, name, type", RewriteKind::CheckObjects).await?,
             // Catalogs
             Statement::ShowCatalogs(stmt) => self.bind_show_catalogs(bind_context, stmt).await?,
             Statement::ShowCreateCatalog(stmt) => self.bind_show_create_catalogs(stmt).await?,
@@ -446,6 +457,7 @@ impl<'a> Binder {
                 if_exists,
                 udf_name,
             } => self.bind_drop_udf(*if_exists, udf_name).await?,
+            Statement::DescribeUDF { udf_name } => self.bind_describe_udf(udf_name).await?,
             Statement::Call(stmt) => self.bind_call(bind_context, stmt).await?,
 
             Statement::Presign(stmt) => self.bind_presign(bind_context, stmt).await?,
@@ -618,7 +630,7 @@ impl<'a> Binder {
             Statement::SetPriority {priority, object_id} => {
                 self.bind_set_priority(priority, object_id).await?
             },
-            Statement::System(stmt) => self.bind_system(stmt).await?,
+            Statement::System(stmt) => self.bind_system(bind_context, stmt).await?,
         };
 
         match plan.kind() {
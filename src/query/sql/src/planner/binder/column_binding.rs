@@ -41,6 +41,21 @@ pub struct ColumnBinding {
     pub virtual_computed_expr: Option<String>,
 }
 
+impl ColumnBinding {
+    /// Name of the column qualified by its database and table, if known.
+    pub fn qualified_name(&self) -> String {
+        [
+            self.database_name.as_deref(),
+            self.table_name.as_deref(),
+            Some(self.column_name.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(".")
+    }
+}
+
 impl ColumnIndex for ColumnBinding {}
 
 pub struct ColumnBindingBuilder {
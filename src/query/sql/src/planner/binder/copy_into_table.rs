@@ -226,6 +226,7 @@ impl<'a> Binder {
                         expr: Box::new(column),
                         target_type: TypeName::Variant,
                         pg_style: false,
+                        format: None,
                     }
                 } else {
                     column
@@ -659,8 +660,10 @@ pub async fn resolve_stage_location(
     let stage = if names[0] == "~" {
         StageInfo::new_user_stage(&ctx.get_current_user()?.name)
     } else {
-        UserApiProvider::instance()
-            .get_stage(&ctx.get_tenant(), names[0])
+        let user_mgr = UserApiProvider::instance();
+        let tenant = ctx.get_tenant();
+        ctx.get_meta_call_policy()?
+            .read("get_stage", || user_mgr.get_stage(&tenant, names[0]))
             .await?
     };
 
@@ -146,20 +146,25 @@ impl Binder {
         let catalog_name = self.ctx.get_current_catalog();
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let policy = self.ctx.get_meta_call_policy()?;
         match source {
             AccountMgrLevel::Global => Ok(GrantObject::Global),
             AccountMgrLevel::Table(database_name, table_name) => {
                 let database_name = database_name
                     .clone()
                     .unwrap_or_else(|| self.ctx.get_current_database());
-                let db_id = catalog
-                    .get_database(&tenant, &database_name)
+                let db_id = policy
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database_name)
+                    })
                     .await?
                     .get_db_info()
                     .ident
                     .db_id;
-                let table_id = catalog
-                    .get_table(&tenant, &database_name, table_name)
+                let table_id = policy
+                    .read_catalog(catalog.as_ref(), "get_table", || {
+                        catalog.get_table(&tenant, &database_name, table_name)
+                    })
                     .await?
                     .get_id();
                 Ok(GrantObject::TableById(catalog_name, db_id, table_id))
@@ -168,8 +173,10 @@ impl Binder {
                 let database_name = database_name
                     .clone()
                     .unwrap_or_else(|| self.ctx.get_current_database());
-                let db_id = catalog
-                    .get_database(&tenant, &database_name)
+                let db_id = policy
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database_name)
+                    })
                     .await?
                     .get_db_info()
                     .ident
@@ -191,20 +198,25 @@ impl Binder {
         let catalog_name = self.ctx.get_current_catalog();
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let policy = self.ctx.get_meta_call_policy()?;
         match source {
             AccountMgrLevel::Global => Ok(vec![GrantObject::Global]),
             AccountMgrLevel::Table(database_name, table_name) => {
                 let database_name = database_name
                     .clone()
                     .unwrap_or_else(|| self.ctx.get_current_database());
-                let db_id = catalog
-                    .get_database(&tenant, &database_name)
+                let db_id = policy
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database_name)
+                    })
                     .await?
                     .get_db_info()
                     .ident
                     .db_id;
-                let table_id = catalog
-                    .get_table(&tenant, &database_name, table_name)
+                let table_id = policy
+                    .read_catalog(catalog.as_ref(), "get_table", || {
+                        catalog.get_table(&tenant, &database_name, table_name)
+                    })
                     .await?
                     .get_id();
                 Ok(vec![
@@ -216,8 +228,10 @@ impl Binder {
                 let database_name = database_name
                     .clone()
                     .unwrap_or_else(|| self.ctx.get_current_database());
-                let db_id = catalog
-                    .get_database(&tenant, &database_name)
+                let db_id = policy
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database_name)
+                    })
                     .await?
                     .get_db_info()
                     .ident
@@ -253,14 +267,13 @@ impl Binder {
         for option in user_options {
             user_option.apply(option);
         }
-        UserApiProvider::instance()
-            .verify_password(
-                &self.ctx.get_tenant(),
-                &user_option,
-                auth_option,
-                None,
-                None,
-            )
+        let user_mgr = UserApiProvider::instance();
+        let tenant = self.ctx.get_tenant();
+        self.ctx
+            .get_meta_call_policy()?
+            .read("verify_password", || {
+                user_mgr.verify_password(&tenant, &user_option, auth_option, None, None)
+            })
             .await?;
 
         let plan = CreateUserPlan {
@@ -286,12 +299,17 @@ impl Binder {
             auth_option,
             user_options,
         } = stmt;
+        let user_mgr = UserApiProvider::instance();
+        let tenant = self.ctx.get_tenant();
+        let policy = self.ctx.get_meta_call_policy()?;
         // None means current user
         let user_info = if user.is_none() {
             self.ctx.get_current_user()?
         } else {
-            UserApiProvider::instance()
-                .get_user(&self.ctx.get_tenant(), user.clone().unwrap().into())
+            policy
+                .read("get_user", || {
+                    user_mgr.get_user(&tenant, user.clone().unwrap().into())
+                })
                 .await?
         };
 
@@ -307,14 +325,16 @@ impl Binder {
                 &auth_option.password,
             )?;
             // verify the password if changed
-            UserApiProvider::instance()
-                .verify_password(
-                    &self.ctx.get_tenant(),
-                    &user_option,
-                    auth_option,
-                    Some(&user_info),
-                    Some(&auth_info),
-                )
+            policy
+                .read("verify_password", || {
+                    user_mgr.verify_password(
+                        &tenant,
+                        &user_option,
+                        auth_option,
+                        Some(&user_info),
+                        Some(&auth_info),
+                    )
+                })
                 .await?;
             if user_info.auth_info == auth_info {
                 None
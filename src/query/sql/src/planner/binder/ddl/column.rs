@@ -48,12 +48,16 @@ impl Binder {
             }
         };
         let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let tenant = self.ctx.get_tenant();
+        let policy = self.ctx.get_meta_call_policy()?;
         let database = match database {
             None => self.ctx.get_current_database(),
             Some(ident) => {
                 let database = normalize_identifier(ident, &self.name_resolution_ctx).name;
-                catalog
-                    .get_database(&self.ctx.get_tenant(), &database)
+                policy
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database)
+                    })
                     .await?;
                 database
             }
@@ -61,8 +65,10 @@ impl Binder {
 
         let table = {
             let table = normalize_identifier(table, &self.name_resolution_ctx).name;
-            catalog
-                .get_table(&self.ctx.get_tenant(), database.as_str(), &table)
+            policy
+                .read_catalog(catalog.as_ref(), "get_table", || {
+                    catalog.get_table(&tenant, database.as_str(), &table)
+                })
                 .await?;
             table
         };
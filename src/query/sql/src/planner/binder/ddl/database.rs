@@ -35,6 +35,7 @@ use databend_common_meta_app::share::share_name_ident::ShareNameIdentRaw;
 use log::debug;
 
 use crate::binder::Binder;
+use crate::planner::semantic::check_identifier_length;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
@@ -140,6 +141,7 @@ impl Binder {
 
         match &action {
             AlterDatabaseAction::RenameDatabase { new_db } => {
+                check_identifier_length(new_db, &self.name_resolution_ctx)?;
                 let new_database = new_db.name.clone();
                 let entry = RenameDatabaseEntity {
                     if_exists: *if_exists,
@@ -216,6 +218,7 @@ impl Binder {
             from_share,
         } = stmt;
 
+        check_identifier_length(database, &self.name_resolution_ctx)?;
         let tenant = self.ctx.get_tenant();
         let catalog = catalog
             .as_ref()
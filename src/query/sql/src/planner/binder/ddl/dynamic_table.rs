@@ -70,8 +70,13 @@ impl Binder {
             options.insert(OPT_KEY_TARGET_LAG.to_owned(), format!("{target_lag}"));
 
             let catalog = self.ctx.get_catalog(&catalog_name).await?;
-            let db = catalog
-                .get_database(&self.ctx.get_tenant(), &database)
+            let tenant = self.ctx.get_tenant();
+            let db = self
+                .ctx
+                .get_meta_call_policy()?
+                .read_catalog(catalog.as_ref(), "get_database", || {
+                    catalog.get_database(&tenant, &database)
+                })
                 .await?;
             let db_id = db.get_db_info().ident.db_id;
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
@@ -311,7 +311,13 @@ impl Binder {
             name_ident: IndexNameIdent::new(tenant, &index_name),
         };
 
-        let res = catalog.get_index(get_index_req).await?;
+        let res = self
+            .ctx
+            .get_meta_call_policy()?
+            .read_catalog(catalog.as_ref(), "get_index", || {
+                catalog.get_index(get_index_req.clone())
+            })
+            .await?;
 
         let index_id = res.index_id;
         let index_meta = res.index_meta;
@@ -94,6 +94,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
+use crate::planner::semantic::check_identifier_length;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
@@ -389,10 +390,13 @@ impl Binder {
             None => Ok(self.ctx.get_current_database()),
             Some(ident) => {
                 let database = normalize_identifier(ident, &self.name_resolution_ctx).name;
+                let catalog = self.ctx.get_catalog(&ctl_name).await?;
+                let tenant = self.ctx.get_tenant();
                 self.ctx
-                    .get_catalog(&ctl_name)
-                    .await?
-                    .get_database(&self.ctx.get_tenant(), &database)
+                    .get_meta_call_policy()?
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database)
+                    })
                     .await?;
                 Ok(database)
             }
@@ -418,6 +422,9 @@ impl Binder {
             uri_location,
         } = stmt;
 
+        for ident in database.iter().chain([table]) {
+            check_identifier_length(ident, &self.name_resolution_ctx)?;
+        }
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
@@ -577,8 +584,13 @@ impl Binder {
             // Later, when database id is kept, let say in `TableInfo`, we can
             // safely eliminate this "FUSE" constant and the table meta option entry.
             let catalog = self.ctx.get_catalog(&catalog).await?;
-            let db = catalog
-                .get_database(&self.ctx.get_tenant(), &database)
+            let tenant = self.ctx.get_tenant();
+            let db = self
+                .ctx
+                .get_meta_call_policy()?
+                .read_catalog(catalog.as_ref(), "get_database", || {
+                    catalog.get_database(&tenant, &database)
+                })
                 .await?;
             let db_id = db.get_db_info().ident.db_id;
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
@@ -820,11 +832,21 @@ impl Binder {
         };
 
         match action {
-            AlterTableAction::RenameTable { new_table } => {
+            AlterTableAction::RenameTable {
+                new_database,
+                new_table,
+            } => {
+                for ident in new_database.iter().chain([new_table]) {
+                    check_identifier_length(ident, &self.name_resolution_ctx)?;
+                }
+                let new_database = new_database
+                    .as_ref()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| database.clone());
                 Ok(Plan::RenameTable(Box::new(RenameTablePlan {
                     tenant,
                     if_exists: *if_exists,
-                    new_database: database.clone(),
+                    new_database,
                     new_table: normalize_identifier(new_table, &self.name_resolution_ctx).name,
                     catalog,
                     database,
@@ -1035,14 +1057,17 @@ impl Binder {
         let (catalog, db_name, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        for ident in new_database.iter().chain([new_table]) {
+            check_identifier_length(ident, &self.name_resolution_ctx)?;
+        }
         let (new_catalog, new_database, new_table) =
             self.normalize_object_identifier_triple(new_catalog, new_database, new_table);
 
-        if new_catalog != catalog || new_database != db_name {
-            return Err(ErrorCode::BadArguments(
-                "Rename table not allow modify catalog or database",
-            )
-            .set_span(database.as_ref().and_then(|ident| ident.span)));
+        if new_catalog != catalog {
+            return Err(
+                ErrorCode::BadArguments("Rename table not allow modify catalog")
+                    .set_span(stmt.new_catalog.as_ref().and_then(|ident| ident.span)),
+            );
         }
 
         Ok(Plan::RenameTable(Box::new(RenameTablePlan {
@@ -1065,6 +1090,7 @@ impl Binder {
             catalog,
             database,
             table,
+            restart_streams,
         } = stmt;
 
         let (catalog, database, table) =
@@ -1074,6 +1100,7 @@ impl Binder {
             catalog,
             database,
             table,
+            restart_streams: *restart_streams,
         })))
     }
 
@@ -1244,6 +1271,7 @@ impl Binder {
         new_column: &Identifier,
         table_schema: TableSchemaRef,
     ) -> Result<(TableSchema, String, String)> {
+        check_identifier_length(new_column, &self.name_resolution_ctx)?;
         let old_name = normalize_identifier(old_column, &self.name_resolution_ctx).name;
         let new_name = normalize_identifier(new_column, &self.name_resolution_ctx).name;
 
@@ -1279,6 +1307,7 @@ impl Binder {
         column: &ColumnDefinition,
         table_schema: TableSchemaRef,
     ) -> Result<(TableField, String)> {
+        check_identifier_length(&column.name, &self.name_resolution_ctx)?;
         let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
         let not_null = self.is_column_not_null();
         let data_type = resolve_type_name(&column.data_type, not_null)?;
@@ -1321,6 +1350,7 @@ impl Binder {
         let mut fields_comments = Vec::with_capacity(columns.len());
         let not_null = self.is_column_not_null();
         for column in columns.iter() {
+            check_identifier_length(&column.name, &self.name_resolution_ctx)?;
             let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
             let schema_data_type = resolve_type_name(&column.data_type, not_null)?;
             fields_comments.push(column.comment.clone().unwrap_or_default());
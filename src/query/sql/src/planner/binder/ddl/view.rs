@@ -26,6 +26,7 @@ use derive_visitor::DriveMut;
 use log::debug;
 
 use crate::binder::Binder;
+use crate::planner::semantic::check_identifier_length;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
 use crate::plans::CreateViewPlan;
@@ -53,6 +54,9 @@ impl Binder {
         } = stmt;
         let mut query = *query.clone();
         let tenant = self.ctx.get_tenant();
+        for ident in database.iter().chain([view]).chain(columns) {
+            check_identifier_length(ident, &self.name_resolution_ctx)?;
+        }
         let (catalog, database, view_name) =
             self.normalize_object_identifier_triple(catalog, database, view);
         let column_names = columns
@@ -92,6 +96,9 @@ impl Binder {
 
         let mut query = *query.clone();
         let tenant = self.ctx.get_tenant();
+        for ident in database.iter().chain([view]).chain(columns) {
+            check_identifier_length(ident, &self.name_resolution_ctx)?;
+        }
         let (catalog, database, view_name) =
             self.normalize_object_identifier_triple(catalog, database, view);
         let column_names = columns
@@ -293,8 +293,12 @@ impl Binder {
             None => self.ctx.get_current_database(),
             Some(ident) => {
                 let database = normalize_identifier(ident, &self.name_resolution_ctx).name;
-                catalog
-                    .get_database(&self.ctx.get_tenant(), &database)
+                let tenant = self.ctx.get_tenant();
+                self.ctx
+                    .get_meta_call_policy()?
+                    .read_catalog(catalog.as_ref(), "get_database", || {
+                        catalog.get_database(&tenant, &database)
+                    })
                     .await?;
                 database
             }
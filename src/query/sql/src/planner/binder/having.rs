@@ -15,10 +15,8 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
-use super::Finder;
 use crate::binder::aggregate::AggregateRewriter;
 use crate::binder::split_conjunctions;
 use crate::binder::ExprContext;
@@ -26,8 +24,8 @@ use crate::binder::ScalarBinder;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::GroupingChecker;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::ScalarExpr;
-use crate::plans::Visitor;
 use crate::plans::VisitorMut as _;
 use crate::BindContext;
 use crate::Binder;
@@ -54,6 +52,18 @@ impl Binder {
         let (mut scalar, _) = scalar_binder.bind(having)?;
         let mut rewriter = AggregateRewriter::new(bind_context, self.metadata.clone());
         rewriter.visit(&mut scalar)?;
+
+        // Like ClickHouse, `GROUP BY ... WITH TOTALS` computes the totals before HAVING:
+        // the grand-total row aggregates all the groups and is never filtered out.
+        if let Some(totals) = bind_context.aggregate_info.totals_row_predicate() {
+            scalar = FunctionCall {
+                span: scalar.span(),
+                func_name: "or".to_string(),
+                params: vec![],
+                arguments: vec![scalar, totals],
+            }
+            .into();
+        }
         Ok(scalar)
     }
 
@@ -66,16 +76,6 @@ impl Binder {
     ) -> Result<SExpr> {
         bind_context.set_expr_context(ExprContext::HavingClause);
 
-        let f = |scalar: &ScalarExpr| matches!(scalar, ScalarExpr::WindowFunction(_));
-        let mut finder = Finder::new(&f);
-        finder.visit(&having)?;
-        if !finder.scalars().is_empty() {
-            return Err(ErrorCode::SemanticError(
-                "Having clause can't contain window functions".to_string(),
-            )
-            .set_span(having.span()));
-        }
-
         let scalar = if bind_context.in_grouping {
             // If we are in grouping context, we will perform the grouping check
             let mut having = having;
@@ -21,6 +21,7 @@ use databend_common_ast::ast::InsertStmt;
 use databend_common_ast::ast::Statement;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::FileFormatOptionsReader;
@@ -29,10 +30,12 @@ use databend_common_meta_app::principal::OnErrorMode;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
+use crate::persisted_definition::check_computed_columns;
 use crate::plans::insert::InsertValue;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
+use crate::plans::InsertOnError;
 use crate::plans::Plan;
 use crate::BindContext;
 
@@ -84,6 +87,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            on_error,
             ..
         } = stmt;
 
@@ -95,8 +99,40 @@ impl Binder {
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
+        check_computed_columns(&database_name, &table_name, &table.schema())?;
         let schema = self.schema_project(&table.schema(), columns)?;
 
+        let on_error = match on_error {
+            Some(on_error) => {
+                if !matches!(source, InsertSource::Select { .. }) {
+                    return Err(ErrorCode::SemanticError(
+                        "ON ERROR is only supported by INSERT INTO ... SELECT",
+                    ));
+                }
+                let log_table = match &on_error.log_into {
+                    Some(log_into) => {
+                        let log_table = self.normalize_object_identifier_triple(
+                            &log_into.catalog,
+                            &log_into.database,
+                            &log_into.table,
+                        );
+                        if (&log_table.0, &log_table.1, &log_table.2)
+                            == (&catalog_name, &database_name, &table_name)
+                        {
+                            return Err(ErrorCode::SemanticError(
+                                "the error table of ON ERROR can't be the table being inserted into",
+                            ));
+                        }
+                        self.check_insert_error_table(&log_table).await?;
+                        Some(log_table)
+                    }
+                    None => None,
+                };
+                Some(InsertOnError { log_table })
+            }
+            None => None,
+        };
+
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
                 format,
@@ -189,8 +225,43 @@ impl Binder {
             overwrite: *overwrite,
             source: input_source?,
             table_info: None,
+            on_error,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    /// The error table of `ON ERROR SKIP LOG INTO` stores the error message and the
+    /// serialized source row of each skipped row into its `error` and `source_row` columns,
+    /// the other columns are filled with their default values, so they must have one.
+    async fn check_insert_error_table(&self, log_table: &(String, String, String)) -> Result<()> {
+        let (catalog, database, table) = log_table;
+        let schema = self.ctx.get_table(catalog, database, table).await?.schema();
+        for column in ["error", "source_row"] {
+            let is_string = schema
+                .field_with_name(column)
+                .map(|field| field.data_type().remove_nullable() == TableDataType::String)
+                .unwrap_or(false);
+            if !is_string {
+                return Err(ErrorCode::SemanticError(format!(
+                    "error table {database}.{table} must have a string column `{column}`"
+                )));
+            }
+        }
+        for field in schema.fields() {
+            if field.name() == "error"
+                || field.name() == "source_row"
+                || field.is_nullable()
+                || field.default_expr().is_some()
+                || field.computed_expr().is_some()
+            {
+                continue;
+            }
+            return Err(ErrorCode::SemanticError(format!(
+                "column `{}` of error table {database}.{table} must be nullable or have a default value",
+                field.name()
+            )));
+        }
+        Ok(())
+    }
 }
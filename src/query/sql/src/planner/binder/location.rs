@@ -166,6 +166,18 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
     }
     .to_string();
 
+    let server_side_encryption = l
+        .connection
+        .get("server_side_encryption")
+        .cloned()
+        .unwrap_or_default();
+
+    let server_side_encryption_aws_kms_key_id = l
+        .connection
+        .get("server_side_encryption_aws_kms_key_id")
+        .cloned()
+        .unwrap_or_default();
+
     // If role_arn is empty and we don't allow allow insecure, we should disable credential loader.
     let disable_credential_loader =
         role_arn.is_empty() && !GlobalConfig::instance().storage.allow_insecure;
@@ -183,6 +195,8 @@ fn parse_s3_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
         enable_virtual_host_style,
         role_arn,
         external_id,
+        server_side_encryption,
+        server_side_encryption_aws_kms_key_id,
     });
 
     l.connection
@@ -257,9 +271,16 @@ fn parse_oss_params(l: &mut UriLocation, root: String) -> Result<StorageParams>
             .cloned()
             .unwrap_or_default(),
         root,
-        // TODO(xuanwo): Support SSE in stage later.
-        server_side_encryption: "".to_string(),
-        server_side_encryption_key_id: "".to_string(),
+        server_side_encryption: l
+            .connection
+            .get("server_side_encryption")
+            .cloned()
+            .unwrap_or_default(),
+        server_side_encryption_key_id: l
+            .connection
+            .get("server_side_encryption_key_id")
+            .cloned()
+            .unwrap_or_default(),
     });
 
     l.connection
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_ast::ast::BinaryOperator;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Join;
 use databend_common_ast::ast::JoinCondition;
@@ -389,10 +390,27 @@ impl Binder {
             columns_set.insert(row_id_index);
         }
 
+        // If the table alias is not None, after the binding phase, the bound columns will have
+        // a database of 'None' and the table named as the alias.
+        // Thus, we adjust them accordingly.
+        let target_name = if let Some(target_identify) = target_alias {
+            normalize_identifier(&target_identify.name, &self.name_resolution_ctx)
+                .name
+                .clone()
+        } else {
+            table_name.clone()
+        };
+
         // add join, we use _row_id to check_duplicate join row.
+        let join_expr = self.push_down_matched_conditions(
+            join_expr,
+            &target_name,
+            &merge_type,
+            &matched_clauses,
+        );
         let join = Join {
             op: join_type,
-            condition: JoinCondition::On(Box::new(join_expr.clone())),
+            condition: JoinCondition::On(Box::new(join_expr)),
             left: Box::new(target_table),
             // use source as build table
             right: Box::new(source_data.clone()),
@@ -414,17 +432,6 @@ impl Binder {
             columns_set = columns_set.union(&join_column_set).cloned().collect();
         }
 
-        // If the table alias is not None, after the binding phase, the bound columns will have
-        // a database of 'None' and the table named as the alias.
-        // Thus, we adjust them accordingly.
-        let target_name = if let Some(target_identify) = target_alias {
-            normalize_identifier(&target_identify.name, &self.name_resolution_ctx)
-                .name
-                .clone()
-        } else {
-            table_name.clone()
-        };
-
         let has_update = self.has_update(&matched_clauses);
         let update_row_version = if table.change_tracking_enabled() && has_update {
             Some(Self::update_row_version(
@@ -733,6 +740,101 @@ impl Binder {
         false
     }
 
+    // For a merge into with only matched clauses, a target row which doesn't satisfy any
+    // of the matched conditions is neither updated nor deleted, and it's not checked for
+    // multiple matches either. So if every matched clause has a condition, the disjunction
+    // of their predicates on the target table alone can be added to the (inner) join
+    // condition and pushed down into the target scan. Predicates referencing the source are
+    // kept out: they would drop joined rows before the duplicate match check.
+    fn push_down_matched_conditions(
+        &self,
+        join_expr: &Expr,
+        target_name: &str,
+        merge_type: &MergeIntoType,
+        matched_clauses: &[MatchedClause],
+    ) -> Expr {
+        if *merge_type != MergeIntoType::MatchedOnly {
+            return join_expr.clone();
+        }
+
+        let mut conditions = Vec::with_capacity(matched_clauses.len());
+        for clause in matched_clauses {
+            let Some(selection) = &clause.selection else {
+                return join_expr.clone();
+            };
+            let mut conjuncts = vec![];
+            self.collect_target_conjuncts(selection, target_name, &mut conjuncts);
+            let Some(condition) = conjuncts.into_iter().reduce(|left, right| Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::And,
+                left: Box::new(left),
+                right: Box::new(right),
+            }) else {
+                // this clause may match any target row.
+                return join_expr.clone();
+            };
+            conditions.push(condition);
+        }
+
+        match conditions.into_iter().reduce(|left, right| Expr::BinaryOp {
+            span: None,
+            op: BinaryOperator::Or,
+            left: Box::new(left),
+            right: Box::new(right),
+        }) {
+            Some(predicate) => Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::And,
+                left: Box::new(join_expr.clone()),
+                right: Box::new(predicate),
+            },
+            None => join_expr.clone(),
+        }
+    }
+
+    fn collect_target_conjuncts(&self, expr: &Expr, target_name: &str, conjuncts: &mut Vec<Expr>) {
+        match expr {
+            Expr::BinaryOp {
+                op: BinaryOperator::And,
+                left,
+                right,
+                ..
+            } => {
+                self.collect_target_conjuncts(left, target_name, conjuncts);
+                self.collect_target_conjuncts(right, target_name, conjuncts);
+            }
+            _ if self.is_target_only(expr, target_name) => conjuncts.push(expr.clone()),
+            _ => {}
+        }
+    }
+
+    // Only columns qualified by the target table and simple operators are accepted,
+    // unqualified columns may come from the source.
+    fn is_target_only(&self, expr: &Expr, target_name: &str) -> bool {
+        match expr {
+            Expr::ColumnRef { column, .. } => column.table.as_ref().is_some_and(|table| {
+                normalize_identifier(table, &self.name_resolution_ctx).name == target_name
+            }),
+            Expr::Literal { .. } => true,
+            Expr::BinaryOp { left, right, .. } => {
+                self.is_target_only(left, target_name) && self.is_target_only(right, target_name)
+            }
+            Expr::UnaryOp { expr, .. } | Expr::IsNull { expr, .. } | Expr::Cast { expr, .. } => {
+                self.is_target_only(expr, target_name)
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => [expr, low, high]
+                .iter()
+                .all(|e| self.is_target_only(e, target_name)),
+            Expr::InList { expr, list, .. } => {
+                self.is_target_only(expr, target_name)
+                    && list.iter().all(|e| self.is_target_only(e, target_name))
+            }
+            _ => false,
+        }
+    }
+
     fn has_star_clause(
         &self,
         matched_clauses: &Vec<MatchedClause>,
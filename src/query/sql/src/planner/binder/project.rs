@@ -25,8 +25,6 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::SelectTarget;
-use databend_common_ast::parser::parse_expr;
-use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -47,11 +45,14 @@ use crate::binder::window::WindowInfo;
 use crate::binder::ExprContext;
 use crate::binder::Visibility;
 use crate::optimizer::SExpr;
+use crate::persisted_definition::parse_persisted_expr;
+use crate::persisted_definition::PersistedObjectKind;
 use crate::planner::binder::scalar::ScalarBinder;
 use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
 use crate::planner::binder::ColumnBinding;
 use crate::planner::semantic::compare_table_name;
+use crate::planner::semantic::display_ident;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::GroupingChecker;
 use crate::plans::BoundColumnRef;
@@ -309,7 +310,7 @@ impl Binder {
         select_target: &'a SelectTarget,
         column_binding: ColumnBinding,
     ) -> Result<SelectItem<'a>> {
-        let scalar = match column_binding.virtual_computed_expr {
+        let scalar = match &column_binding.virtual_computed_expr {
             Some(virtual_computed_expr) => {
                 let mut input_context = input_context.clone();
                 let mut scalar_binder = ScalarBinder::new(
@@ -321,10 +322,19 @@ impl Binder {
                     self.m_cte_bound_ctx.clone(),
                     self.ctes_map.clone(),
                 );
-                let sql_tokens = tokenize_sql(virtual_computed_expr.as_str())?;
-                let expr = parse_expr(&sql_tokens, self.dialect)?;
+                let name = column_binding.qualified_name();
+                let expr = parse_persisted_expr(
+                    PersistedObjectKind::ComputedColumn,
+                    &name,
+                    virtual_computed_expr,
+                    // The checksum is verified when the table is bound.
+                    None,
+                    self.dialect,
+                )?;
 
-                let (scalar, _) = scalar_binder.bind(&expr)?;
+                let (scalar, _) = scalar_binder.bind(&expr).map_err(|e| {
+                    e.add_message_back(format!(" (while resolving COMPUTED COLUMN {name})"))
+                })?;
                 scalar
             }
             None => ScalarExpr::BoundColumnRef(BoundColumnRef {
@@ -356,7 +366,8 @@ impl Binder {
                 let exclude = normalize_identifier(ex, &self.name_resolution_ctx).name;
                 if to_exclude_columns.contains(&exclude) {
                     return Err(ErrorCode::SemanticError(format!(
-                        "Duplicate entry `{exclude}` in EXCLUDE list"
+                        "Duplicate entry {} in EXCLUDE list",
+                        display_ident(&exclude)
                     )));
                 }
                 to_exclude_columns.insert(exclude);
@@ -449,7 +460,8 @@ impl Binder {
         for exclude in to_exclude_columns {
             if !excluded_columns.contains(&exclude) {
                 return Err(ErrorCode::SemanticError(format!(
-                    "Column `{exclude}` in EXCLUDE list not found in FROM clause"
+                    "Column {} in EXCLUDE list not found in FROM clause",
+                    display_ident(&exclude)
                 )));
             }
         }
@@ -496,6 +508,8 @@ impl Binder {
                     distinct: false,
                     params: vec![],
                     window: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             };
 
@@ -61,6 +61,8 @@ impl SrfCollector {
             params,
             window,
             lambda,
+            order_by,
+            filter,
         } = func;
 
         if BUILTIN_FUNCTIONS
@@ -78,6 +80,8 @@ impl SrfCollector {
                     params: params.to_vec(),
                     window: window.clone(),
                     lambda: lambda.clone(),
+                    order_by: order_by.clone(),
+                    filter: filter.clone(),
                 },
             });
         }
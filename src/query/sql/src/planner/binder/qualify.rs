@@ -77,7 +77,9 @@ impl Binder {
         finder.visit(&qualify)?;
         if !finder.scalars().is_empty() {
             return Err(ErrorCode::SemanticError(
-                "Qualify clause must not contain aggregate functions".to_string(),
+                "Qualify clause must not contain aggregate functions outside of window functions, \
+                 use HAVING to filter on aggregates"
+                    .to_string(),
             )
             .set_span(qualify.span()));
         }
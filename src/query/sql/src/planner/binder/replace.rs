@@ -25,6 +25,7 @@ use databend_common_meta_app::principal::OnErrorMode;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
+use crate::persisted_definition::check_computed_columns;
 use crate::plans::insert::InsertValue;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::InsertInputSource;
@@ -56,6 +57,7 @@ impl Binder {
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
+        check_computed_columns(&database_name, &table_name, &table.schema())?;
         let table_id = table.get_id();
 
         let schema = if columns.is_empty() {
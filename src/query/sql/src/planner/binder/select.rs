@@ -126,7 +126,7 @@ impl Binder {
         cte_name: Option<String>,
     ) -> Result<(SExpr, BindContext)> {
         let (left_expr, left_bind_context) =
-            self.bind_set_expr(bind_context, left, &[], None).await?;
+            self.bind_set_expr(bind_context, left, &[], None, 0).await?;
         if let Some(cte_name) = cte_name.as_ref() {
             if !all {
                 return Err(ErrorCode::Internal(
@@ -149,8 +149,9 @@ impl Binder {
                 mut_cte_info.columns.push(col);
             }
         }
-        let (right_expr, right_bind_context) =
-            self.bind_set_expr(bind_context, right, &[], None).await?;
+        let (right_expr, right_bind_context) = self
+            .bind_set_expr(bind_context, right, &[], None, 0)
+            .await?;
 
         if left_bind_context.columns.len() != right_bind_context.columns.len() {
             return Err(ErrorCode::SemanticError(
@@ -21,19 +21,28 @@ use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::OrderByExpr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
 
 use super::ExprContext;
 use crate::binder::aggregate::AggregateRewriter;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::select::SelectList;
+use crate::binder::window::WindowFunctionInfo;
+use crate::binder::window::WindowOrderByInfo;
 use crate::binder::window::WindowRewriter;
 use crate::binder::Binder;
 use crate::binder::ColumnBinding;
+use crate::binder::ColumnBindingBuilder;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::GroupingChecker;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
@@ -42,8 +51,13 @@ use crate::plans::Sort;
 use crate::plans::SortItem;
 use crate::plans::UDFCall;
 use crate::plans::VisitorMut as _;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::BindContext;
 use crate::IndexType;
+use crate::Visibility;
 use crate::WindowChecker;
 
 #[derive(Debug)]
@@ -73,12 +87,13 @@ impl Binder {
         bind_context.set_expr_context(ExprContext::OrderByClause);
         // null is the largest value in databend, smallest in hive
         // TODO: rewrite after https://github.com/jorgecarleitao/arrow2/pull/1286 is merged
-        let default_nulls_first = !self
-            .ctx
-            .get_settings()
-            .get_sql_dialect()
-            .unwrap()
-            .is_null_biggest();
+        let settings = self.ctx.get_settings();
+        let default_nulls_first = match settings.get_default_order_by_null()? {
+            Some(nulls_first) => nulls_first,
+            None => !settings.get_sql_dialect()?.is_null_biggest(),
+        };
+
+        let fold_constant_keys = settings.get_enable_order_by_expression_pushdown()?;
 
         let mut order_items = Vec::with_capacity(order_by.len());
         for order in order_by {
@@ -117,6 +132,17 @@ impl Binder {
                     );
                     let (bound_expr, _) = scalar_binder.bind(&order.expr)?;
 
+                    // A constant sort key, e.g. `ORDER BY 1 + 0`, has been folded by the type
+                    // checker and doesn't change the order of the rows, so drop it instead of
+                    // sorting by it. `ORDER BY 1` is a position of the select list, see above.
+                    if fold_constant_keys && matches!(bound_expr, ScalarExpr::ConstantExpr(_)) {
+                        self.ctx.push_warning(format!(
+                            "ORDER BY {:#} is a constant and has no effect, it is removed from the sort keys",
+                            order.expr
+                        ));
+                        continue;
+                    }
+
                     if let Some((idx, (alias, _))) = aliases
                         .iter()
                         .enumerate()
@@ -149,6 +175,13 @@ impl Binder {
                                             return Ok(Some(scalar_item.scalar.clone()));
                                         }
                                     }
+                                    // The window functions referenced by aliases have been rewritten
+                                    // in `analyze_window`, keep them to avoid rewriting them again.
+                                    if let ScalarExpr::WindowFunction(_) = nest_scalar {
+                                        if aliases.iter().any(|(_, scalar)| scalar == nest_scalar) {
+                                            return Ok(Some(nest_scalar.clone()));
+                                        }
+                                    }
                                     Ok(None)
                                 },
                             )
@@ -182,6 +215,168 @@ impl Binder {
         Ok(OrderItems { items: order_items })
     }
 
+    /// Sort the grand-total row of `GROUP BY ... WITH TOTALS` after all the grouped rows,
+    /// the other order items only apply to the grouped rows.
+    pub(crate) fn analyze_totals_order_item(
+        &mut self,
+        bind_context: &BindContext,
+        scalar_items: &mut HashMap<IndexType, ScalarItem>,
+        order_items: &mut OrderItems,
+    ) -> Result<Option<ColumnBinding>> {
+        let Some(totals) = bind_context.aggregate_info.totals_row_predicate() else {
+            return Ok(None);
+        };
+        let column_binding = self.create_derived_column_binding(
+            "_totals_row".to_string(),
+            totals.data_type()?,
+            Some(totals.clone()),
+        );
+        scalar_items.insert(column_binding.index, ScalarItem {
+            scalar: totals,
+            index: column_binding.index,
+        });
+        order_items.items.insert(0, OrderItem {
+            index: column_binding.index,
+            name: column_binding.column_name.clone(),
+            asc: true,
+            nulls_first: false,
+        });
+        Ok(Some(column_binding))
+    }
+
+    /// LIMIT and OFFSET of `GROUP BY ... WITH TOTALS` only count the grouped rows, the
+    /// grand-total row is always returned.
+    ///
+    /// The grouped rows are numbered by `row_number()` in the order of the ORDER BY items,
+    /// the totals row is a window partition on its own, then the rows out of the LIMIT are
+    /// filtered out. The final sort is bound on top of it.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn bind_totals_limit(
+        &mut self,
+        from_context: &BindContext,
+        totals_column: &ColumnBinding,
+        order_items: &OrderItems,
+        scalar_items: &mut HashMap<IndexType, ScalarItem>,
+        limit: Option<usize>,
+        offset: usize,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        // Evaluate the order items before the window, the final sort reuses them.
+        let mut scalars = vec![];
+        let mut window_order_by = vec![];
+        for order in order_items.items.iter() {
+            if let Entry::Occupied(entry) = scalar_items.entry(order.index) {
+                if !matches!(entry.get().scalar, ScalarExpr::BoundColumnRef(_)) {
+                    let (index, item) = entry.remove_entry();
+                    let mut scalar = item.scalar;
+                    let mut group_checker = GroupingChecker::new(from_context);
+                    group_checker.visit(&mut scalar)?;
+                    scalars.push(ScalarItem { scalar, index });
+                }
+            }
+            if order.index == totals_column.index {
+                continue;
+            }
+            let data_type = self.metadata.read().column(order.index).data_type();
+            let column = ColumnBindingBuilder::new(
+                order.name.clone(),
+                order.index,
+                Box::new(data_type),
+                Visibility::Visible,
+            )
+            .build();
+            window_order_by.push(WindowOrderByInfo {
+                order_by_item: ScalarItem {
+                    scalar: BoundColumnRef { span: None, column }.into(),
+                    index: order.index,
+                },
+                asc: Some(order.asc),
+                nulls_first: Some(order.nulls_first),
+            });
+        }
+        let child = if !scalars.is_empty() {
+            let eval_scalar = EvalScalar { items: scalars };
+            SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(child))
+        } else {
+            child
+        };
+
+        let totals_row: ScalarExpr = BoundColumnRef {
+            span: None,
+            column: totals_column.clone(),
+        }
+        .into();
+        let row_number_column = self.create_derived_column_binding(
+            "_totals_row_number".to_string(),
+            DataType::Number(NumberDataType::UInt64),
+            None,
+        );
+        let window_info = WindowFunctionInfo {
+            span: None,
+            index: row_number_column.index,
+            func: WindowFuncType::RowNumber,
+            arguments: vec![],
+            partition_by_items: vec![ScalarItem {
+                scalar: totals_row.clone(),
+                index: totals_column.index,
+            }],
+            order_by_items: window_order_by,
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+        };
+        let child = self.bind_window_function(&window_info, child).await?;
+
+        // _totals_row OR (_totals_row_number > offset AND _totals_row_number <= offset + limit)
+        let row_number: ScalarExpr = BoundColumnRef {
+            span: None,
+            column: row_number_column,
+        }
+        .into();
+        let compare = |func_name: &str, value: usize| -> ScalarExpr {
+            FunctionCall {
+                span: None,
+                func_name: func_name.to_string(),
+                params: vec![],
+                arguments: vec![
+                    row_number.clone(),
+                    ConstantExpr {
+                        span: None,
+                        value: Scalar::Number(NumberScalar::UInt64(value as u64)),
+                    }
+                    .into(),
+                ],
+            }
+            .into()
+        };
+        let mut in_limit = compare("gt", offset);
+        if let Some(limit) = limit {
+            in_limit = FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![in_limit, compare("lte", offset.saturating_add(limit))],
+            }
+            .into();
+        }
+        let predicate = FunctionCall {
+            span: None,
+            func_name: "or".to_string(),
+            params: vec![],
+            arguments: vec![totals_row, in_limit],
+        }
+        .into();
+        let filter = Filter {
+            predicates: vec![predicate],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(child),
+        ))
+    }
+
     #[async_backtrace::framed]
     pub async fn bind_order_by(
         &mut self,
@@ -16,19 +16,43 @@ use databend_common_ast::ast::SystemAction as AstSystemAction;
 use databend_common_ast::ast::SystemStmt;
 use databend_common_exception::Result;
 
+use crate::planner::binder::BindContext;
 use crate::planner::binder::Binder;
 use crate::plans::Plan;
+use crate::plans::RewriteKind;
 use crate::plans::SystemAction;
 use crate::plans::SystemPlan;
 
 impl Binder {
     #[async_backtrace::framed]
-    pub(super) async fn bind_system(&mut self, stmt: &SystemStmt) -> Result<Plan> {
+    pub(super) async fn bind_system(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &SystemStmt,
+    ) -> Result<Plan> {
         let SystemStmt { action } = stmt;
         match action {
             AstSystemAction::Backtrace(switch) => Ok(Plan::System(Box::new(SystemPlan {
                 action: SystemAction::Backtrace(*switch),
             }))),
+            AstSystemAction::ShowDataCache => {
+                let query =
+                    "SELECT * FROM system.data_cache ORDER BY node, database, table, column_id";
+                self.bind_rewrite_to_query(bind_context, query, RewriteKind::ShowDataCache)
+                    .await
+            }
+            AstSystemAction::DropDataCache {
+                catalog,
+                database,
+                table,
+            } => {
+                let table = table
+                    .as_ref()
+                    .map(|table| self.normalize_object_identifier_triple(catalog, database, table));
+                Ok(Plan::System(Box::new(SystemPlan {
+                    action: SystemAction::DropDataCache(table),
+                })))
+            }
         }
     }
 }
@@ -305,6 +305,8 @@ impl Binder {
             planning_agg_index: false,
             allow_internal_columns: true,
             window_definitions: DashMap::new(),
+            try_cast_diagnostics: vec![],
+            with_totals: false,
         };
 
         let (s_expr, mut res_bind_context) = self
@@ -358,13 +360,7 @@ impl Binder {
             let (cte_s_expr, cte_bind_ctx) = self
                 .bind_cte(*span, bind_context, table_name, alias, cte_info)
                 .await?;
-            self.ctes_map
-                .entry(table_name.clone())
-                .and_modify(|cte_info| {
-                    cte_info.columns = cte_bind_ctx.columns.clone();
-                });
-            self.set_m_cte_bound_ctx(cte_info.cte_idx, cte_bind_ctx.clone());
-            self.set_m_cte_bound_s_expr(cte_info.cte_idx, cte_s_expr);
+            self.set_m_cte_bound(table_name, cte_info.cte_idx, cte_s_expr, &cte_bind_ctx);
             cte_bind_ctx
         } else {
             // If the cte has been bound, get the bound context from `Binder`'s `m_cte_bound_ctx`
@@ -382,6 +378,28 @@ impl Binder {
             bound_ctx.parent = bind_context.parent.clone();
             bound_ctx
         };
+        let s_expr = self.bind_m_cte_scan(table_name)?;
+        Ok((s_expr, new_bind_context))
+    }
+
+    // Save the bound materialized cte, which will be wrapped to the main plan.
+    pub(crate) fn set_m_cte_bound(
+        &mut self,
+        table_name: &String,
+        cte_idx: IndexType,
+        cte_s_expr: SExpr,
+        cte_bind_ctx: &BindContext,
+    ) {
+        self.ctes_map
+            .entry(table_name.clone())
+            .and_modify(|cte_info| {
+                cte_info.columns = cte_bind_ctx.columns.clone();
+            });
+        self.set_m_cte_bound_ctx(cte_idx, cte_bind_ctx.clone());
+        self.set_m_cte_bound_s_expr(cte_idx, cte_s_expr);
+    }
+
+    pub(crate) fn bind_m_cte_scan(&mut self, table_name: &String) -> Result<SExpr> {
         // `bind_context` is the main BindContext for the whole query
         // Update the `used_count` which will be used in runtime phase
         self.ctes_map
@@ -390,8 +408,7 @@ impl Binder {
                 cte_info.used_count += 1;
             });
         let cte_info = self.ctes_map.get(table_name).unwrap().clone();
-        let s_expr = self.bind_cte_scan(&cte_info)?;
-        Ok((s_expr, new_bind_context))
+        self.bind_cte_scan(&cte_info)
     }
 
     #[async_backtrace::framed]
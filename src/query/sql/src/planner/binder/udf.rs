@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use chrono::Utc;
@@ -23,7 +24,6 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
-use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
@@ -31,10 +31,14 @@ use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
 
 use crate::normalize_identifier;
+use crate::persisted_definition::definition_checksum;
 use crate::planner::resolve_type_name;
+use crate::planner::udf_validator::check_udf_server_addresses;
+use crate::planner::udf_validator::connect_udf_server;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CreateUDFPlan;
+use crate::plans::DescribeUDFPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::Plan;
 use crate::Binder;
@@ -46,6 +50,17 @@ impl Binder {
         allowed_languages.contains(&language.to_lowercase().as_str())
     }
 
+    /// WASM UDFs exchange booleans, numbers, strings and binaries with the module,
+    /// optionally nullable.
+    fn check_wasm_udf_type(ty: &DataType) -> Result<()> {
+        match ty.remove_nullable() {
+            DataType::Boolean | DataType::Number(_) | DataType::String | DataType::Binary => Ok(()),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unsupported type {ty} for WASM UDF, must be a boolean, number, string or binary type, or their nullable types"
+            ))),
+        }
+    }
+
     pub(in crate::planner::binder) async fn bind_udf_definition(
         &mut self,
         udf_name: &Identifier,
@@ -56,6 +71,7 @@ impl Binder {
         match udf_definition {
             UDFDefinition::LambdaUDF {
                 parameters,
+                defaults,
                 definition,
             } => {
                 let mut validator = UDFValidator {
@@ -63,13 +79,23 @@ impl Binder {
                     parameters: parameters.iter().map(|v| v.to_string()).collect(),
                     ..Default::default()
                 };
+                let defaulted_parameters = &parameters[parameters.len() - defaults.len()..];
+                let mut parameter_defaults = BTreeMap::new();
+                for (parameter, default) in defaulted_parameters.iter().zip(defaults) {
+                    let parameter = parameter.to_string();
+                    validator.verify_default_expr(&parameter, default)?;
+                    parameter_defaults.insert(parameter, default.to_string());
+                }
                 validator.verify_definition_expr(definition)?;
+                let definition = definition.to_string();
                 Ok(UserDefinedFunction {
                     name: validator.name,
                     description: udf_description.clone().unwrap_or_default(),
                     definition: PlanUDFDefinition::LambdaUDF(LambdaUDF {
                         parameters: validator.parameters,
-                        definition: definition.to_string(),
+                        defaults: parameter_defaults,
+                        definition_checksum: Some(definition_checksum(&definition)),
+                        definition,
                     }),
                     created_on: Utc::now(),
                 })
@@ -80,6 +106,7 @@ impl Binder {
                 address,
                 handler,
                 language,
+                deterministic,
             } => {
                 if !GlobalConfig::instance().query.enable_udf_server {
                     return Err(ErrorCode::Unimplemented(
@@ -87,15 +114,7 @@ impl Binder {
                     ));
                 }
 
-                let udf_server_allow_list = &GlobalConfig::instance().query.udf_server_allow_list;
-                if udf_server_allow_list
-                    .iter()
-                    .all(|addr| addr.trim_end_matches('/') != address.trim_end_matches('/'))
-                {
-                    return Err(ErrorCode::InvalidArgument(format!(
-                        "Unallowed UDF server address, '{address}' is not in udf_server_allow_list"
-                    )));
-                }
+                let addresses = check_udf_server_addresses(address)?;
 
                 let mut arg_datatypes = Vec::with_capacity(arg_types.len());
                 for arg_type in arg_types {
@@ -103,19 +122,8 @@ impl Binder {
                 }
                 let return_type = DataType::from(&resolve_type_name(return_type, true)?);
 
-                let mut client = UDFFlightClient::connect(
-                    address,
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_connect_timeout_secs()?,
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_request_timeout_secs()?,
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_request_batch_rows()?,
-                )
-                .await?;
+                // Check the schema with the first reachable address.
+                let mut client = connect_udf_server(&self.ctx.get_settings(), &addresses).await?;
                 client
                     .check_schema(handler, &arg_datatypes, &return_type)
                     .await?;
@@ -129,6 +137,7 @@ impl Binder {
                         return_type,
                         handler: handler.clone(),
                         language: language.clone(),
+                        deterministic: *deterministic,
                     }),
                     created_on: Utc::now(),
                 })
@@ -153,6 +162,12 @@ impl Binder {
                     )));
                 }
 
+                if language.eq_ignore_ascii_case("wasm") {
+                    for ty in arg_datatypes.iter().chain(std::iter::once(&return_type)) {
+                        Self::check_wasm_udf_type(ty)?;
+                    }
+                }
+
                 let mut runtime_version = runtime_version.to_string();
                 if runtime_version.is_empty() && language.to_lowercase() == "python" {
                     runtime_version = "3.12.2".to_string();
@@ -209,4 +224,12 @@ impl Binder {
             udf: name,
         })))
     }
+
+    pub(in crate::planner::binder) async fn bind_describe_udf(
+        &mut self,
+        udf_name: &Identifier,
+    ) -> Result<Plan> {
+        let name = normalize_identifier(udf_name, &self.name_resolution_ctx).to_string();
+        Ok(Plan::DescribeUDF(Box::new(DescribeUDFPlan { udf: name })))
+    }
 }
@@ -29,6 +29,7 @@ use crate::plans::walk_expr_mut;
 use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::EvalScalar;
+use crate::plans::InterpolateFunction;
 use crate::plans::LagLeadFunction;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
@@ -90,12 +91,11 @@ impl Binder {
             child
         };
 
-        let default_nulls_first = !self
-            .ctx
-            .get_settings()
-            .get_sql_dialect()
-            .unwrap()
-            .is_null_biggest();
+        let settings = self.ctx.get_settings();
+        let default_nulls_first = match settings.get_default_order_by_null()? {
+            Some(nulls_first) => nulls_first,
+            None => !settings.get_sql_dialect()?.is_null_biggest(),
+        };
 
         let mut sort_items: Vec<SortItem> = vec![];
         if !window_plan.partition_by.is_empty() {
@@ -325,6 +325,8 @@ impl<'a> WindowRewriter<'a> {
                     params: agg.params.clone(),
                     args: replaced_args,
                     return_type: agg.return_type.clone(),
+                    sort_descs: agg.sort_descs.clone(),
+                    max_elements: agg.max_elements,
                 })
             }
             WindowFuncType::LagLead(ll) => {
@@ -355,6 +357,21 @@ impl<'a> WindowRewriter<'a> {
                     return_type: func.return_type.clone(),
                 })
             }
+            WindowFuncType::Interpolate(func) => {
+                let mut arg = (*func.arg).clone();
+                let mut aggregate_rewriter = self.as_window_aggregate_rewriter();
+                aggregate_rewriter.visit(&mut arg)?;
+                let name = format!("{window_func_name}_arg");
+                let replaced_arg = self.replace_expr(&name, &arg)?;
+                window_args.push(ScalarItem {
+                    index: replaced_arg.column.index,
+                    scalar: arg,
+                });
+                WindowFuncType::Interpolate(InterpolateFunction {
+                    arg: Box::new(replaced_arg.into()),
+                    return_type: func.return_type.clone(),
+                })
+            }
             func => func.clone(),
         };
 
@@ -189,6 +189,8 @@ impl Dataframe {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             }),
             alias: None,
@@ -150,6 +150,7 @@ impl Plan {
             Plan::CreateUDF(_) => Ok("CreateUDF".to_string()),
             Plan::AlterUDF(_) => Ok("AlterUDF".to_string()),
             Plan::DropUDF(_) => Ok("DropUDF".to_string()),
+            Plan::DescribeUDF(_) => Ok("DescribeUDF".to_string()),
             Plan::AlterUser(_) => Ok("AlterUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
@@ -30,6 +30,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use parking_lot::RwLock;
 
+use crate::binder::TryCastDiagnostic;
 use crate::optimizer::SExpr;
 use crate::ScalarExpr;
 
@@ -67,9 +68,21 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Whether the common table expressions are materialized, displayed by `EXPLAIN CTE`.
+    cte_materializations: Vec<CteMaterializationInfo>,
+    /// The distinct source and target types of the `TRY_CAST`s in the query, including
+    /// the ones in CTEs, set operations and subqueries.
+    try_cast_diagnostics: Vec<TryCastDiagnostic>,
     max_column_position: usize, // for CSV
 }
 
+#[derive(Clone, Debug)]
+pub struct CteMaterializationInfo {
+    pub cte_name: String,
+    pub materialized: bool,
+    pub reason: String,
+}
+
 impl Metadata {
     pub fn table(&self, index: IndexType) -> &TableEntry {
         self.tables.get(index).expect("metadata must contain table")
@@ -295,6 +308,24 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_cte_materialization(&mut self, info: CteMaterializationInfo) {
+        self.cte_materializations.push(info);
+    }
+
+    pub fn cte_materializations(&self) -> &[CteMaterializationInfo] {
+        &self.cte_materializations
+    }
+
+    pub fn add_try_cast_diagnostic(&mut self, diagnostic: TryCastDiagnostic) {
+        if !self.try_cast_diagnostics.contains(&diagnostic) {
+            self.try_cast_diagnostics.push(diagnostic);
+        }
+    }
+
+    pub fn try_cast_diagnostics(&self) -> &[TryCastDiagnostic] {
+        &self.try_cast_diagnostics
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
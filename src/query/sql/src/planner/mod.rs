@@ -23,6 +23,7 @@ pub mod binder;
 pub mod dataframe;
 mod expression_parser;
 pub mod optimizer;
+pub mod persisted_definition;
 pub mod plans;
 mod stream_column;
 mod udf_validator;
@@ -34,6 +35,7 @@ pub use binder::ColumnBinding;
 pub use binder::ColumnBindingBuilder;
 pub use binder::ScalarBinder;
 pub use binder::SelectBuilder;
+pub use binder::TryCastDiagnostic;
 pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
@@ -64,6 +64,8 @@ impl SubqueryRewriter {
                     params: agg.params.clone(),
                     args,
                     return_type: agg.return_type.clone(),
+                    sort_descs: agg.sort_descs.clone(),
+                    max_elements: agg.max_elements,
                 }))
             }
             ScalarExpr::FunctionCall(func) => {
@@ -451,6 +451,8 @@ impl SubqueryRewriter {
                             params: vec![],
                             args: vec![],
                             return_type: Box::new(agg_func.return_type()?),
+                            sort_descs: vec![],
+                            max_elements: 0,
                         }
                         .into(),
                         index: agg_func_index,
@@ -650,6 +652,8 @@ impl SubqueryRewriter {
                 column: subquery.output_column.clone(),
             })],
             return_type: Box::new(DataType::Number(NumberDataType::UInt64)),
+            sort_descs: vec![],
+            max_elements: 0,
             display_name: "count".to_string(),
         });
         let any_func = ScalarExpr::AggregateFunction(AggregateFunction {
@@ -661,6 +665,8 @@ impl SubqueryRewriter {
                 span: None,
                 column: subquery.output_column.clone(),
             })],
+            sort_descs: vec![],
+            max_elements: 0,
             display_name: "any".to_string(),
         });
         // Add `count_func` and `any_func` to metadata
@@ -207,6 +207,9 @@ impl PullUpFilterOptimizer {
                     WindowFuncType::NthValue(func) => {
                         Self::replace_predicate(&mut func.arg, items, metadata)?;
                     }
+                    WindowFuncType::Interpolate(func) => {
+                        Self::replace_predicate(&mut func.arg, items, metadata)?;
+                    }
                     _ => (),
                 };
 
@@ -53,4 +53,5 @@ pub use rule::RuleSet;
 pub use rule::DEFAULT_REWRITE_RULES;
 pub use s_expr::get_udf_names;
 pub use s_expr::SExpr;
+pub use statistics::CollectStatisticsOptimizer;
 pub use util::contains_local_table_scan;
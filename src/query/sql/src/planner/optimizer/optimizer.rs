@@ -40,6 +40,7 @@ use crate::optimizer::join::SingleToInnerOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::statistics::CollectStatisticsOptimizer;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::RelExpr;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -371,12 +372,26 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
         }
     };
 
+    if enable_distributed_query {
+        check_enforced_broadcast_joins(opt_ctx.table_ctx.clone(), &s_expr)?;
+    }
+
     s_expr =
         RecursiveOptimizer::new([RuleID::EliminateEvalScalar].as_slice(), &opt_ctx).run(&s_expr)?;
 
     Ok(s_expr)
 }
 
+fn check_enforced_broadcast_joins(ctx: Arc<dyn TableContext>, s_expr: &SExpr) -> Result<()> {
+    if let RelOperator::Join(join) = s_expr.plan() {
+        join.check_enforced_broadcast_join(ctx.clone(), &RelExpr::with_s_expr(s_expr))?;
+    }
+    for child in s_expr.children() {
+        check_enforced_broadcast_joins(ctx.clone(), child)?;
+    }
+    Ok(())
+}
+
 // TODO(leiysky): reuse the optimization logic with `optimize_query`
 async fn get_optimized_memo(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Result<Memo> {
     let enable_distributed_query = opt_ctx.enable_distributed_optimization
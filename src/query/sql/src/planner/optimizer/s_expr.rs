@@ -278,6 +278,11 @@ impl SExpr {
                             udfs.insert(*udf);
                         });
                     }
+                    WindowFuncType::Interpolate(func) => {
+                        get_udf_names(&func.arg)?.iter().for_each(|udf| {
+                            udfs.insert(*udf);
+                        });
+                    }
                     _ => {}
                 }
                 for arg in &op.arguments {
@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use derive_visitor::DriveMut;
+
+use crate::ViewRewriter;
+
+/// Upper bound of the SQL text stored in meta for a single object definition.
+/// Anything larger is treated as a corrupted entry instead of being parsed.
+pub const MAX_PERSISTED_DEFINITION_LEN: usize = 1024 * 1024;
+
+/// Objects whose definition is persisted in meta as SQL text and
+/// re-parsed every time they are referenced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersistedObjectKind {
+    View,
+    Udf,
+    ComputedColumn,
+}
+
+impl PersistedObjectKind {
+    fn hint(&self) -> &'static str {
+        match self {
+            PersistedObjectKind::View => "use ALTER VIEW to redefine it or DROP VIEW to remove it",
+            PersistedObjectKind::Udf => {
+                "use ALTER FUNCTION to redefine it or DROP FUNCTION to remove it"
+            }
+            PersistedObjectKind::ComputedColumn => {
+                "use ALTER TABLE ... MODIFY COLUMN to redefine it or ALTER TABLE ... DROP COLUMN to remove it"
+            }
+        }
+    }
+}
+
+impl Display for PersistedObjectKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersistedObjectKind::View => write!(f, "VIEW"),
+            PersistedObjectKind::Udf => write!(f, "FUNCTION"),
+            PersistedObjectKind::ComputedColumn => write!(f, "COMPUTED COLUMN"),
+        }
+    }
+}
+
+/// Wrap the failure of loading a persisted definition into an error naming the owning object.
+pub fn invalid_object_definition(
+    kind: PersistedObjectKind,
+    name: &str,
+    cause: &ErrorCode,
+) -> ErrorCode {
+    ErrorCode::InvalidObjectDefinition(format!(
+        "{kind} {name} has an invalid definition: {}; {}",
+        cause.message(),
+        kind.hint()
+    ))
+}
+
+/// Checksum stored with a definition, to detect it's corrupted when it's loaded.
+/// It's the same as `ComputedExpr::checksum` of the computed columns.
+pub fn definition_checksum(definition: &str) -> u32 {
+    crc32fast::hash(definition.as_bytes())
+}
+
+/// Check the size and the checksum of a persisted definition before it's parsed.
+///
+/// The definitions written by old versions have no checksum and are only checked by size.
+pub fn check_definition(
+    kind: PersistedObjectKind,
+    name: &str,
+    definition: &str,
+    checksum: Option<u32>,
+) -> Result<()> {
+    if definition.len() > MAX_PERSISTED_DEFINITION_LEN {
+        return Err(invalid_object_definition(
+            kind,
+            name,
+            &ErrorCode::Internal(format!(
+                "definition size {} exceeds the limit of {} bytes",
+                definition.len(),
+                MAX_PERSISTED_DEFINITION_LEN
+            )),
+        ));
+    }
+    if let Some(checksum) = checksum {
+        let actual = definition_checksum(definition);
+        if actual != checksum {
+            return Err(invalid_object_definition(
+                kind,
+                name,
+                &ErrorCode::Internal(format!(
+                    "definition checksum mismatch, expected {checksum:#010x} but got {actual:#010x}, the stored definition may be corrupted"
+                )),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check the definitions of the computed columns of a table.
+pub fn check_computed_columns(database: &str, table: &str, schema: &TableSchema) -> Result<()> {
+    for field in schema.fields() {
+        if let Some(computed_expr) = field.computed_expr() {
+            check_definition(
+                PersistedObjectKind::ComputedColumn,
+                &format!("{database}.{table}.{}", field.name()),
+                computed_expr.expr(),
+                field.computed_expr_checksum(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse the stored query of a view, the statement must be a query.
+///
+/// The tables referenced without a database are qualified with the database of the
+/// view, so the view is bound the same way whatever the current database is.
+pub fn parse_view_definition(
+    database: &str,
+    view: &str,
+    definition: &str,
+    checksum: Option<u32>,
+    dialect: Dialect,
+) -> Result<Query> {
+    let kind = PersistedObjectKind::View;
+    let name = format!("{database}.{view}");
+    check_definition(kind, &name, definition, checksum)?;
+    let stmt = tokenize_sql(definition)
+        .and_then(|tokens| parse_sql(&tokens, dialect).map(|(stmt, _)| stmt))
+        .map_err(|e| invalid_object_definition(kind, &name, &ErrorCode::from(e)))?;
+    match stmt {
+        Statement::Query(mut query) => {
+            let mut rewriter = ViewRewriter {
+                current_database: database.to_string(),
+            };
+            query.drive_mut(&mut rewriter);
+            Ok(*query)
+        }
+        _ => Err(invalid_object_definition(
+            kind,
+            &name,
+            &ErrorCode::SyntaxException("definition is not a query"),
+        )),
+    }
+}
+
+/// Parse the stored expression of a lambda udf or a virtual computed column.
+pub fn parse_persisted_expr(
+    kind: PersistedObjectKind,
+    name: &str,
+    definition: &str,
+    checksum: Option<u32>,
+    dialect: Dialect,
+) -> Result<Expr> {
+    check_definition(kind, name, definition, checksum)?;
+    tokenize_sql(definition)
+        .and_then(|tokens| parse_expr(&tokens, dialect))
+        .map_err(|e| invalid_object_definition(kind, name, &ErrorCode::from(e)))
+}
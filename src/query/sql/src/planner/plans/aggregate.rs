@@ -89,6 +89,20 @@ impl Aggregate {
         }
         Ok(col_set)
     }
+
+    /// Estimate the number of group by keys with the NDV of group items, it's bounded by
+    /// the cardinality of input. Returns `None` if any group item has no statistics.
+    pub fn estimate_group_by_keys(&self, rel_expr: &RelExpr) -> Result<Option<f64>> {
+        let stat_info = rel_expr.derive_cardinality_child(0)?;
+        let mut keys = 1.0;
+        for item in self.group_items.iter() {
+            match stat_info.statistics.column_stats.get(&item.index) {
+                Some(item_stat) => keys *= item_stat.ndv,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(f64::min(keys, stat_info.cardinality)))
+    }
 }
 
 impl Operator for Aggregate {
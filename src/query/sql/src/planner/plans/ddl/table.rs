@@ -403,6 +403,8 @@ pub struct TruncateTablePlan {
     pub database: String,
     /// The table name
     pub table: String,
+    /// Restart the streams on the table from the truncated version
+    pub restart_streams: bool,
 }
 
 impl TruncateTablePlan {
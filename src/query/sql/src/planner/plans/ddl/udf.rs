@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_meta_app::principal::UserDefinedFunction;
 use databend_common_meta_app::schema::CreateOption;
 
@@ -31,3 +35,17 @@ pub struct DropUDFPlan {
     pub if_exists: bool,
     pub udf: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescribeUDFPlan {
+    pub udf: String,
+}
+
+impl DescribeUDFPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("property", DataType::String),
+            DataField::new("value", DataType::String),
+        ])
+    }
+}
@@ -15,9 +15,14 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::FormatTreeNode;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
@@ -68,6 +73,25 @@ pub struct Insert {
     // it should be provided as some `table_info`.
     // otherwise, the table being inserted will be resolved by using `catalog`.`database`.`table`
     pub table_info: Option<TableInfo>,
+    pub on_error: Option<InsertOnError>,
+}
+
+/// `ON ERROR SKIP [LOG INTO table]` of `INSERT INTO ... SELECT`, rows which fail to
+/// be cast to the inserted columns are skipped instead of aborting the statement.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InsertOnError {
+    /// `(catalog, database, table)` which the skipped rows are logged into.
+    pub log_table: Option<(String, String, String)>,
+}
+
+impl std::fmt::Display for InsertOnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "skip")?;
+        if let Some((catalog, database, table)) = &self.log_table {
+            write!(f, ", log into {catalog}.{database}.{table}")?;
+        }
+        Ok(())
+    }
 }
 
 impl PartialEq for Insert {
@@ -84,6 +108,23 @@ impl Insert {
         Arc::new(self.schema.clone().into())
     }
 
+    /// With `ON ERROR SKIP`, the numbers of the inserted and the skipped rows are returned.
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.on_error.is_none() {
+            return Arc::new(DataSchema::empty());
+        }
+        DataSchemaRefExt::create(vec![
+            DataField::new(
+                "number of rows inserted",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+            DataField::new(
+                "number of rows skipped",
+                DataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+
     pub fn has_select_plan(&self) -> bool {
         matches!(&self.source, InsertInputSource::SelectPlan(_))
     }
@@ -104,6 +145,7 @@ impl Insert {
             // table_info only used create table as select.
             table_info: _,
             source,
+            on_error,
         } = self;
 
         let table_name = format!("{}.{}.{}", catalog, database, table);
@@ -114,11 +156,14 @@ impl Insert {
             .collect::<Vec<_>>()
             .join(",");
 
-        let children = vec![
+        let mut children = vec![
             FormatTreeNode::new(format!("table: {table_name}")),
             FormatTreeNode::new(format!("inserted columns: [{inserted_columns}]")),
             FormatTreeNode::new(format!("overwrite: {overwrite}")),
         ];
+        if let Some(on_error) = on_error {
+            children.push(FormatTreeNode::new(format!("on error: {on_error}")));
+        }
 
         let formatted_plan = format_insert_source("InsertPlan", source, verbose, children)?;
 
@@ -252,6 +297,7 @@ impl std::fmt::Debug for Insert {
             .field("table", &self.table)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("on_error", &self.on_error)
             .finish()
     }
 }
@@ -19,6 +19,7 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::F64;
 use databend_common_storage::Datum;
@@ -370,6 +371,65 @@ impl Join {
 
         Ok(join_card)
     }
+
+    // Broadcasting a huge build side to every node can run out of memory, so the enforced
+    // broadcast join is only used if the estimated build side is within `broadcast_join_max_rows`.
+    // Without statistics the build side is unknown, and it's not broadcast if there is a limit.
+    fn can_broadcast_build_side(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        build_stat_info: &StatInfo,
+    ) -> Result<bool> {
+        let max_rows = ctx.get_settings().get_broadcast_join_max_rows()?;
+        if max_rows == 0 {
+            return Ok(true);
+        }
+        Ok(!Self::missing_statistics(build_stat_info)
+            && build_stat_info.cardinality <= max_rows as f64)
+    }
+
+    fn missing_statistics(stat_info: &StatInfo) -> bool {
+        stat_info.cardinality == 0.0 && stat_info.statistics.precise_cardinality.is_none()
+    }
+
+    fn support_broadcast(&self) -> bool {
+        !matches!(
+            self.join_type,
+            JoinType::Right
+                | JoinType::Full
+                | JoinType::RightAnti
+                | JoinType::RightSemi
+                | JoinType::LeftMark
+                | JoinType::RightSingle
+        )
+    }
+
+    /// Reject the join of an optimized plan if `enforce_broadcast_join` is set but its build
+    /// side can't be broadcast. The broadcast alternative of such a join is only pruned during
+    /// the exploration, so that the other alternatives can still be explored.
+    pub fn check_enforced_broadcast_join(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        rel_expr: &RelExpr,
+    ) -> Result<()> {
+        if !ctx.get_settings().get_enforce_broadcast_join()? || !self.support_broadcast() {
+            return Ok(());
+        }
+        let build_stat_info = rel_expr.derive_cardinality_child(1)?;
+        if self.can_broadcast_build_side(ctx.clone(), &build_stat_info)? {
+            return Ok(());
+        }
+        let max_rows = ctx.get_settings().get_broadcast_join_max_rows()?;
+        if Self::missing_statistics(&build_stat_info) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Cannot enforce broadcast join: the build side has no statistics to check against broadcast_join_max_rows ({max_rows})"
+            )));
+        }
+        Err(ErrorCode::SemanticError(format!(
+            "Cannot enforce broadcast join: the estimated build side has {} rows, which exceeds broadcast_join_max_rows ({max_rows})",
+            build_stat_info.cardinality as u64
+        )))
+    }
 }
 
 impl Operator for Join {
@@ -558,8 +618,10 @@ impl Operator for Join {
                 // Use a very large value to prevent broadcast join.
                 1000.0
             };
+            let enforce_broadcast_join = ctx.get_settings().get_enforce_broadcast_join()?
+                && self.can_broadcast_build_side(ctx.clone(), &right_stat_info)?;
             if right_stat_info.cardinality * broadcast_join_threshold < left_stat_info.cardinality
-                || ctx.get_settings().get_enforce_broadcast_join()?
+                || enforce_broadcast_join
             {
                 if child_index == 1 {
                     required.distribution = Distribution::Broadcast;
@@ -583,12 +645,19 @@ impl Operator for Join {
     fn compute_required_prop_children(
         &self,
         ctx: Arc<dyn TableContext>,
-        _rel_expr: &RelExpr,
+        rel_expr: &RelExpr,
         _required: &RequiredProperty,
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
-        if self.join_type != JoinType::Cross && !ctx.get_settings().get_enforce_broadcast_join()? {
+        // The broadcast alternative is pruned if the enforced broadcast join can't broadcast the
+        // build side, such a join is rejected by `check_enforced_broadcast_join` after the
+        // optimization.
+        let enforce_broadcast_join = ctx.get_settings().get_enforce_broadcast_join()?;
+        let can_broadcast = !enforce_broadcast_join
+            || self
+                .can_broadcast_build_side(ctx.clone(), &rel_expr.derive_cardinality_child(1)?)?;
+        if self.join_type != JoinType::Cross && !(enforce_broadcast_join && can_broadcast) {
             // (Hash, Hash)
             children_required.extend(
                 self.left_conditions
@@ -607,15 +676,7 @@ impl Operator for Join {
             );
         }
 
-        if !matches!(
-            self.join_type,
-            JoinType::Right
-                | JoinType::Full
-                | JoinType::RightAnti
-                | JoinType::RightSemi
-                | JoinType::LeftMark
-                | JoinType::RightSingle
-        ) {
+        if self.support_broadcast() && can_broadcast {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
             let right_distribution = Distribution::Broadcast;
@@ -77,6 +77,7 @@ pub use expression_scan::*;
 pub use filter::*;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
+pub use insert::InsertOnError;
 pub use insert_multi_table::*;
 pub use join::*;
 pub use kill::KillPlan;
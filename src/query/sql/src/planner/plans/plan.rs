@@ -75,6 +75,7 @@ use crate::plans::DescPasswordPolicyPlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
+use crate::plans::DescribeUDFPlan;
 use crate::plans::DescribeViewPlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropConnectionPlan;
@@ -270,6 +271,7 @@ pub enum Plan {
     CreateUDF(Box<CreateUDFPlan>),
     AlterUDF(Box<AlterUDFPlan>),
     DropUDF(Box<DropUDFPlan>),
+    DescribeUDF(Box<DescribeUDFPlan>),
 
     // Role
     ShowRoles(Box<ShowRolesPlan>),
@@ -378,6 +380,8 @@ pub enum RewriteKind {
     ShowIndexes,
 
     ShowLocks,
+    CheckObjects,
+    ShowDataCache,
 
     ShowCatalogs,
     ShowDatabases,
@@ -479,9 +483,11 @@ impl Plan {
             Plan::ExecuteTask(plan) => plan.schema(),
             Plan::DescNotification(plan) => plan.schema(),
             Plan::DescConnection(plan) => plan.schema(),
+            Plan::DescribeUDF(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
             Plan::ExecuteImmediate(plan) => plan.schema(),
             Plan::InsertMultiTable(plan) => plan.schema(),
+            Plan::Insert(plan) => plan.schema(),
 
             _ => Arc::new(DataSchema::empty()),
         }
@@ -491,6 +497,11 @@ impl Plan {
         !self.schema().fields().is_empty()
     }
 
+    /// Whether the last row of the result is the grand-total row of `GROUP BY ... WITH TOTALS`.
+    pub fn has_totals_row(&self) -> bool {
+        matches!(self, Plan::Query { bind_context, .. } if bind_context.with_totals)
+    }
+
     pub fn remove_exchange_for_select(&self) -> Self {
         if let Plan::Query {
             s_expr,
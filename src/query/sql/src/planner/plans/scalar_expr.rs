@@ -25,6 +25,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::AggregateFunctionSortDesc;
 use educe::Educe;
 use enum_as_inner::EnumAsInner;
 use itertools::Itertools;
@@ -532,6 +533,12 @@ pub struct AggregateFunction {
     pub params: Vec<Scalar>,
     pub args: Vec<ScalarExpr>,
     pub return_type: Box<DataType>,
+    /// The sort descriptions of the `ORDER BY` clause inside the aggregate function,
+    /// e.g. `string_agg(a, ',' ORDER BY b)`. The sort keys are appended to `args`.
+    pub sort_descs: Vec<AggregateFunctionSortDesc>,
+    /// The maximum number of elements `array_agg` can collect, from the setting
+    /// `max_array_agg_elements`. 0 means unlimited.
+    pub max_elements: u64,
 
     pub display_name: String,
 }
@@ -558,6 +565,14 @@ pub struct NthValueFunction {
     pub return_type: Box<DataType>,
 }
 
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct InterpolateFunction {
+    /// The value to fill, NULLs are replaced by the linear interpolation
+    /// between the nearest non-NULL values before and after it.
+    pub arg: Box<ScalarExpr>,
+    pub return_type: Box<DataType>,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct NtileFunction {
     pub n: u64,
@@ -812,6 +827,9 @@ pub trait VisitorWithParent<'a>: Sized {
                 WindowFuncType::NthValue(func) => {
                     visitor.visit_with_parent(Some(current), &func.arg)?
                 }
+                WindowFuncType::Interpolate(func) => {
+                    visitor.visit_with_parent(Some(current), &func.arg)?
+                }
                 WindowFuncType::LagLead(func) => {
                     visitor.visit_with_parent(Some(current), &func.arg)?;
                     if let Some(default) = func.default.as_ref() {
@@ -962,6 +980,7 @@ pub fn walk_window<'a, V: Visitor<'a>>(visitor: &mut V, window: &'a WindowFunc)
     match &window.func {
         WindowFuncType::Aggregate(func) => visitor.visit_aggregate_function(func)?,
         WindowFuncType::NthValue(func) => visitor.visit(&func.arg)?,
+        WindowFuncType::Interpolate(func) => visitor.visit(&func.arg)?,
         WindowFuncType::LagLead(func) => {
             visitor.visit(&func.arg)?;
             if let Some(default) = func.default.as_ref() {
@@ -1063,6 +1082,7 @@ pub fn walk_window_mut<'a, V: VisitorMut<'a>>(
     match &mut window.func {
         WindowFuncType::Aggregate(func) => visitor.visit_aggregate_function(func)?,
         WindowFuncType::NthValue(func) => visitor.visit(&mut func.arg)?,
+        WindowFuncType::Interpolate(func) => visitor.visit(&mut func.arg)?,
         WindowFuncType::LagLead(func) => {
             visitor.visit(&mut func.arg)?;
             if let Some(default) = func.default.as_mut() {
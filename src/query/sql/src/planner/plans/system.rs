@@ -23,4 +23,7 @@ pub struct SystemPlan {
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub enum SystemAction {
     Backtrace(bool),
+    /// Drop the disk cache of the data of the table `(catalog, database, table)`,
+    /// or the data of all tables if `None`.
+    DropDataCache(Option<(String, String, String)>),
 }
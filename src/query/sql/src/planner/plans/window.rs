@@ -39,10 +39,12 @@ use crate::optimizer::RelationalProperty;
 use crate::optimizer::RequiredProperty;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics;
+use crate::plans::InterpolateFunction;
 use crate::plans::LagLeadFunction;
 use crate::plans::NtileFunction;
 use crate::plans::Operator;
 use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::IndexType;
 
@@ -95,11 +97,15 @@ impl Window {
 
     // `Window.partition_by_columns` used in `RulePushDownFilterWindow` only consider `partition_by` field,
     // like `Aggregate.group_columns` only consider `group_items` field.
+    // The columns referenced by a partition expression are not included, e.g. filtering on `v`
+    // before `PARTITION BY k + v` would change the rows of a partition.
     pub fn partition_by_columns(&self) -> Result<ColumnSet> {
         let mut col_set = ColumnSet::new();
         for part in self.partition_by.iter() {
             col_set.insert(part.index);
-            col_set.extend(part.scalar.used_columns())
+            if let ScalarExpr::BoundColumnRef(column) = &part.scalar {
+                col_set.insert(column.column.index);
+            }
         }
         Ok(col_set)
     }
@@ -264,6 +270,7 @@ pub enum WindowFuncType {
     NthValue(NthValueFunction),
     Ntile(NtileFunction),
     CumeDist,
+    Interpolate(InterpolateFunction),
 }
 
 impl WindowFuncType {
@@ -293,6 +300,7 @@ impl WindowFuncType {
             WindowFuncType::NthValue(_) => "nth_value".to_string(),
             WindowFuncType::Ntile(_) => "ntile".to_string(),
             WindowFuncType::CumeDist => "cume_dist".to_string(),
+            WindowFuncType::Interpolate(_) => "interpolate".to_string(),
         }
     }
 
@@ -311,6 +319,7 @@ impl WindowFuncType {
                     .collect(),
             },
             WindowFuncType::NthValue(func) => func.arg.used_columns(),
+            WindowFuncType::Interpolate(func) => func.arg.used_columns(),
             _ => ColumnSet::new(),
         }
     }
@@ -327,6 +336,7 @@ impl WindowFuncType {
             WindowFuncType::LagLead(lag_lead) => *lag_lead.return_type.clone(),
             WindowFuncType::NthValue(nth_value) => *nth_value.return_type.clone(),
             WindowFuncType::Ntile(buckets) => *buckets.return_type.clone(),
+            WindowFuncType::Interpolate(func) => *func.return_type.clone(),
         }
     }
 }
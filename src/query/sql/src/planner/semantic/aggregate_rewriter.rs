@@ -33,10 +33,11 @@ impl AggregateRewriter {
                         name,
                         args,
                         window,
+                        filter,
                         ..
                     },
                 ..
-            } if !*distinct && args.len() == 1 && window.is_none() => {
+            } if !*distinct && args.len() == 1 && window.is_none() && filter.is_none() => {
                 match name.name.to_ascii_lowercase().to_lowercase().as_str() {
                     "sum" => self.rewrite_sum(args),
                     "avg" => Some(self.rewrite_avg(args)),
@@ -80,6 +81,8 @@ impl AggregateRewriter {
                                         params: vec![],
                                         window: None,
                                         lambda: None,
+                                        order_by: vec![],
+                                        filter: None,
                                     },
                                 }),
                             }),
@@ -92,6 +95,8 @@ impl AggregateRewriter {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             }),
                         };
@@ -111,6 +116,8 @@ impl AggregateRewriter {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             }),
                             right: Box::new(Expr::BinaryOp {
@@ -126,6 +133,8 @@ impl AggregateRewriter {
                                         params: vec![],
                                         window: None,
                                         lambda: None,
+                                        order_by: vec![],
+                                        filter: None,
                                     },
                                 }),
                             }),
@@ -155,6 +164,8 @@ impl AggregateRewriter {
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             }),
             right: Box::new(Expr::FunctionCall {
@@ -175,6 +186,8 @@ impl AggregateRewriter {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             }),
                             right: Box::new(Expr::Literal {
@@ -195,12 +208,16 @@ impl AggregateRewriter {
                                 params: vec![],
                                 window: None,
                                 lambda: None,
+                                order_by: vec![],
+                                filter: None,
                             },
                         },
                     ],
                     params: vec![],
                     window: None,
                     lambda: None,
+                    order_by: vec![],
+                    filter: None,
                 },
             }),
         }
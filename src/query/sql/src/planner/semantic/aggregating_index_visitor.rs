@@ -58,10 +58,14 @@ impl AggregatingIndexRewriter {
                         args,
                         window,
                         lambda,
+                        order_by,
+                        filter,
                         ..
                     },
                 ..
             } if !*distinct
+                && order_by.is_empty()
+                && filter.is_none()
                 && SUPPORTED_AGGREGATING_INDEX_FUNCTIONS
                     .contains(&&*name.name.to_ascii_lowercase().to_lowercase())
                 && window.is_none()
@@ -82,7 +86,7 @@ impl AggregatingIndexRewriter {
                     self.extracted_aggs.insert(agg);
                 }
             }
-            Expr::CountAll { window, .. } if window.is_none() => {
+            Expr::CountAll { window, filter, .. } if window.is_none() && filter.is_none() => {
                 self.agg_func_positions
                     .insert(self.current_position.unwrap());
                 self.extracted_aggs.insert("COUNT()".to_string());
@@ -214,6 +218,8 @@ impl AggregatingIndexChecker {
             args: _,
             window: _,
             lambda: _,
+            order_by: _,
+            filter: _,
         } = func;
 
         if self.not_support {
@@ -284,18 +290,24 @@ impl RefreshAggregatingIndexRewriter {
                         distinct,
                         name,
                         window,
+                        filter,
                         ..
                     },
                 ..
             } if !*distinct
                 && SUPPORTED_AGGREGATING_INDEX_FUNCTIONS
                     .contains(&&*name.name.to_ascii_lowercase().to_lowercase())
-                && window.is_none() =>
+                && window.is_none()
+                && filter.is_none() =>
             {
                 self.has_agg_function = true;
                 name.name = format!("{}_STATE", name.name);
             }
-            Expr::CountAll { span, window } if window.is_none() => {
+            Expr::CountAll {
+                span,
+                window,
+                filter,
+            } if window.is_none() && filter.is_none() => {
                 self.has_agg_function = true;
                 *expr = Expr::FunctionCall {
                     span: None,
@@ -306,6 +318,8 @@ impl RefreshAggregatingIndexRewriter {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 };
             }
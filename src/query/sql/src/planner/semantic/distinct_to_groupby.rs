@@ -53,6 +53,7 @@ impl DistinctToGroupBy {
                                 distinct,
                                 name,
                                 args,
+                                filter,
                                 ..
                             },
                     },
@@ -63,6 +64,7 @@ impl DistinctToGroupBy {
                 if ((name.name.to_ascii_lowercase() == "count" && *distinct)
                     || name.name.to_ascii_lowercase() == "count_distinct")
                     && args.iter().all(|arg| !matches!(arg, Expr::Literal { .. }))
+                    && filter.is_none()
                 {
                     let subquery = Query {
                         span: None,
@@ -116,6 +118,8 @@ impl DistinctToGroupBy {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             }),
                             alias: alias.clone(),
@@ -35,7 +35,10 @@ pub use count_set_ops::CountSetOps;
 pub use distinct_to_groupby::DistinctToGroupBy;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
+pub use name_resolution::check_identifier_length;
 pub use name_resolution::compare_table_name;
+pub use name_resolution::display_candidates;
+pub use name_resolution::display_ident;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
@@ -12,15 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::quote::ident_needs_quote;
+use databend_common_ast::ast::quote::QuotedIdent;
 use databend_common_ast::ast::Identifier;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use databend_common_settings::Settings;
 use derive_visitor::VisitorMut;
+use itertools::Itertools;
+
+/// The max number of candidate names listed in an error message.
+const MAX_DISPLAYED_CANDIDATES: usize = 10;
 
 #[derive(Debug, Clone)]
 pub struct NameResolutionContext {
     pub unquoted_ident_case_sensitive: bool,
     pub quoted_ident_case_sensitive: bool,
     pub deny_column_reference: bool,
+    /// The max length of identifiers, 0 means no limit.
+    pub max_identifier_length: u64,
 }
 
 impl Default for NameResolutionContext {
@@ -29,6 +39,7 @@ impl Default for NameResolutionContext {
             unquoted_ident_case_sensitive: false,
             quoted_ident_case_sensitive: true,
             deny_column_reference: false,
+            max_identifier_length: 0,
         }
     }
 }
@@ -39,11 +50,13 @@ impl TryFrom<&Settings> for NameResolutionContext {
     fn try_from(settings: &Settings) -> databend_common_exception::Result<Self> {
         let unquoted_ident_case_sensitive = settings.get_unquoted_ident_case_sensitive()?;
         let quoted_ident_case_sensitive = settings.get_quoted_ident_case_sensitive()?;
+        let max_identifier_length = settings.get_max_identifier_length()?;
 
         Ok(Self {
             unquoted_ident_case_sensitive,
             quoted_ident_case_sensitive,
             deny_column_reference: false,
+            max_identifier_length,
         })
     }
 }
@@ -59,6 +72,46 @@ pub fn normalize_identifier(ident: &Identifier, context: &NameResolutionContext)
     }
 }
 
+/// Check the length of identifier doesn't exceed the `max_identifier_length` of `NameResolutionContext`
+pub fn check_identifier_length(ident: &Identifier, context: &NameResolutionContext) -> Result<()> {
+    let len = ident.name.chars().count();
+    if context.max_identifier_length > 0 && len as u64 > context.max_identifier_length {
+        return Err(ErrorCode::SemanticError(format!(
+            "identifier {} is too long, its length {} exceeds the max_identifier_length {}",
+            ident, len, context.max_identifier_length
+        ))
+        .set_span(ident.span));
+    }
+    Ok(())
+}
+
+/// Display a name in user-facing messages. A name which can't be written as an
+/// unquoted identifier is quoted with backticks, which are accepted by all dialects,
+/// so that it can be copied into a query as is.
+pub fn display_ident(name: &str) -> String {
+    if ident_needs_quote(name) || name.chars().any(|c| c.is_ascii_uppercase()) {
+        QuotedIdent(name, '`').to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Display the candidate names in user-facing messages, e.g. ``[a, b, `c d`, and 3 more]``.
+pub fn display_candidates<T: AsRef<str>>(names: &[T]) -> String {
+    let mut candidates = names
+        .iter()
+        .take(MAX_DISPLAYED_CANDIDATES)
+        .map(|name| display_ident(name.as_ref()))
+        .join(", ");
+    if names.len() > MAX_DISPLAYED_CANDIDATES {
+        candidates.push_str(&format!(
+            ", and {} more",
+            names.len() - MAX_DISPLAYED_CANDIDATES
+        ));
+    }
+    format!("[{candidates}]")
+}
+
 pub fn compare_table_name(
     table_name1: &str,
     table_name2: &str,
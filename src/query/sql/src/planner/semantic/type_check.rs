@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::Cell;
+use std::cell::RefCell;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::VecDeque;
@@ -30,6 +32,7 @@ use databend_common_ast::ast::IntervalKind as ASTIntervalKind;
 use databend_common_ast::ast::Lambda;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::MapAccessor;
+use databend_common_ast::ast::OrderByExpr;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
@@ -42,8 +45,6 @@ use databend_common_ast::ast::Window;
 use databend_common_ast::ast::WindowFrame;
 use databend_common_ast::ast::WindowFrameBound;
 use databend_common_ast::ast::WindowFrameUnits;
-use databend_common_ast::parser::parse_expr;
-use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_ast::Span;
 use databend_common_async_functions::resolve_async_function;
@@ -70,8 +71,10 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::F32;
-use databend_common_expression::ColumnIndex;
+use databend_common_expression::variant_transform::contains_variant;
+use databend_common_expression::BlockEntry;
 use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::Expr as EExpr;
@@ -80,15 +83,21 @@ use databend_common_expression::FunctionKind;
 use databend_common_expression::RawExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
+use databend_common_expression::Value;
+use databend_common_expression::ARRAY_SORT_BY_COMPARATOR;
+use databend_common_expression::ARRAY_SORT_BY_FUNCTIONS;
+use databend_common_expression::MAP_LAMBDA_FUNCTIONS;
 use databend_common_expression::SEARCH_MATCHED_COL_NAME;
 use databend_common_expression::SEARCH_SCORE_COL_NAME;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::aggregates::AggregateFunctionSortDesc;
 use databend_common_functions::is_builtin_function;
 use databend_common_functions::ASYNC_FUNCTIONS;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use databend_common_functions::GENERAL_SEARCH_FUNCTIONS;
 use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
+use databend_common_functions::ORDERED_AGGREGATE_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
@@ -101,23 +110,34 @@ use indexmap::IndexMap;
 use itertools::Itertools;
 use jsonb::keypath::KeyPath;
 use jsonb::keypath::KeyPaths;
+use log::warn;
 use simsearch::SimSearch;
 
+use super::name_resolution::check_identifier_length;
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
 use crate::binder::bind_values;
 use crate::binder::resolve_file_location;
 use crate::binder::wrap_cast;
 use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
 use crate::binder::InternalColumnBinding;
 use crate::binder::NameResolutionResult;
+use crate::binder::TryCastDiagnostic;
+use crate::binder::Visibility;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::parse_lambda_expr;
+use crate::persisted_definition::parse_persisted_expr;
+use crate::persisted_definition::PersistedObjectKind;
 use crate::planner::metadata::optimize_remove_count_args;
+use crate::planner::semantic::display_candidates;
+use crate::planner::semantic::display_ident;
 use crate::planner::semantic::lowering::TypeCheck;
+use crate::planner::udf_validator::check_udf_server_addresses;
+use crate::planner::udf_validator::connect_udf_server;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
@@ -126,6 +146,7 @@ use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
+use crate::plans::InterpolateFunction;
 use crate::plans::LagLeadFunction;
 use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
@@ -150,6 +171,18 @@ use crate::ColumnEntry;
 use crate::IndexType;
 use crate::MetadataRef;
 
+/// A single step of a map access expression such as `a[1]`, `a['k']` or `a:k`.
+enum MapAccessPath {
+    /// 1-based index, a negative value counts from the end.
+    Index(i64),
+    Name {
+        name: String,
+        case_sensitive: bool,
+    },
+    /// `[*]`, all elements of a variant array.
+    Wildcard,
+}
+
 /// A helper for type checking.
 ///
 /// `TypeChecker::resolve` will resolve types of `Expr` and transform `Expr` into
@@ -179,6 +212,21 @@ pub struct TypeChecker<'a> {
     // This is used to allow aggregation function in window's aggregate function.
     in_window_function: bool,
     forbid_udf: bool,
+    // The lambda UDFs being expanded, from the outermost to the innermost.
+    // This is used to stop the mutually recursive UDFs.
+    udf_expansion_chain: Vec<String>,
+    // The nesting depth of the expression being resolved, limited by `max_expression_depth`
+    // to avoid overflowing the stack, 0 means unlimited. Resolving one level takes several
+    // large frames, the default 1000 keeps it well within the 20 MiB stack of the query
+    // runtime threads, the same as the nesting depth accepted by the parser.
+    expression_depth: usize,
+    max_expression_depth: usize,
+
+    // Results of constant folding keyed by the checked expression, so that
+    // identical sub-expressions are only folded once.
+    constant_fold_cache: RefCell<HashMap<EExpr<ColumnBinding>, Option<Scalar>>>,
+    // Number of times `ConstantFolder::fold` is actually invoked.
+    constant_fold_count: Cell<usize>,
 }
 
 impl<'a> TypeChecker<'a> {
@@ -191,7 +239,9 @@ impl<'a> TypeChecker<'a> {
         forbid_udf: bool,
     ) -> Result<Self> {
         let func_ctx = ctx.get_function_context()?;
-        let dialect = ctx.get_settings().get_sql_dialect()?;
+        let settings = ctx.get_settings();
+        let dialect = settings.get_sql_dialect()?;
+        let max_expression_depth = settings.get_max_expression_depth()?;
         Ok(Self {
             bind_context,
             ctx,
@@ -205,6 +255,11 @@ impl<'a> TypeChecker<'a> {
             in_aggregate_function: false,
             in_window_function: false,
             forbid_udf,
+            udf_expansion_chain: vec![],
+            expression_depth: 0,
+            max_expression_depth,
+            constant_fold_cache: RefCell::new(HashMap::new()),
+            constant_fold_count: Cell::new(0),
         })
     }
 
@@ -226,6 +281,20 @@ impl<'a> TypeChecker<'a> {
     }
 
     pub fn resolve(&mut self, expr: &Expr) -> Result<Box<(ScalarExpr, DataType)>> {
+        if self.max_expression_depth > 0 && self.expression_depth >= self.max_expression_depth {
+            return Err(ErrorCode::SemanticError(format!(
+                "Expression is nested too deeply, exceeds max_expression_depth {}",
+                self.max_expression_depth
+            ))
+            .set_span(expr.span()));
+        }
+        self.expression_depth += 1;
+        let result = self.resolve_expr(expr);
+        self.expression_depth -= 1;
+        result
+    }
+
+    fn resolve_expr(&mut self, expr: &Expr) -> Result<Box<(ScalarExpr, DataType)>> {
         if let Some(scalar) = self.bind_context.srfs.get(&expr.to_string()) {
             if !matches!(self.bind_context.expr_context, ExprContext::SelectClause) {
                 return Err(ErrorCode::SemanticError(
@@ -248,6 +317,9 @@ impl<'a> TypeChecker<'a> {
                         column: ident,
                     },
             } => {
+                for ident in database.iter().chain(table.iter()) {
+                    check_identifier_length(ident, self.name_resolution_ctx)?;
+                }
                 let database = database
                     .as_ref()
                     .map(|ident| normalize_identifier(ident, self.name_resolution_ctx).name);
@@ -256,6 +328,7 @@ impl<'a> TypeChecker<'a> {
                     .map(|ident| normalize_identifier(ident, self.name_resolution_ctx).name);
                 let result = match ident {
                     ColumnID::Name(ident) => {
+                        check_identifier_length(ident, self.name_resolution_ctx)?;
                         let column = normalize_identifier(ident, self.name_resolution_ctx);
                         self.bind_context.resolve_name(
                             database.as_deref(),
@@ -275,10 +348,12 @@ impl<'a> TypeChecker<'a> {
 
                 let (scalar, data_type) = match result {
                     NameResolutionResult::Column(column) => {
-                        if let Some(virtual_computed_expr) = column.virtual_computed_expr {
-                            let sql_tokens = tokenize_sql(virtual_computed_expr.as_str())?;
-                            let expr = parse_expr(&sql_tokens, self.dialect)?;
-                            return self.resolve(&expr);
+                        if let Some(virtual_computed_expr) = &column.virtual_computed_expr {
+                            return self.resolve_virtual_computed_expr(
+                                *span,
+                                &column,
+                                virtual_computed_expr,
+                            );
                         } else {
                             let data_type = *column.data_type.clone();
                             (
@@ -298,10 +373,12 @@ impl<'a> TypeChecker<'a> {
                             self.metadata.clone(),
                             true,
                         )?;
-                        if let Some(virtual_computed_expr) = column.virtual_computed_expr {
-                            let sql_tokens = tokenize_sql(virtual_computed_expr.as_str())?;
-                            let expr = parse_expr(&sql_tokens, self.dialect)?;
-                            return self.resolve(&expr);
+                        if let Some(virtual_computed_expr) = &column.virtual_computed_expr {
+                            return self.resolve_virtual_computed_expr(
+                                *span,
+                                &column,
+                                virtual_computed_expr,
+                            );
                         } else {
                             let data_type = *column.data_type.clone();
                             (
@@ -314,7 +391,10 @@ impl<'a> TypeChecker<'a> {
                             )
                         }
                     }
-                    NameResolutionResult::Alias { scalar, .. } => {
+                    NameResolutionResult::Alias { alias, scalar } => {
+                        if let ColumnID::Name(ident) = ident {
+                            self.warn_alias_shadowing_column(ident, &alias, &scalar);
+                        }
                         (scalar.clone(), scalar.data_type()?)
                     }
                 };
@@ -333,6 +413,39 @@ impl<'a> TypeChecker<'a> {
                 }
             }
 
+            Expr::IsBool {
+                span,
+                expr,
+                value,
+                not,
+            } => {
+                // `expr IS TRUE` is never NULL, rewrite it to `is_true(expr)`
+                // and `expr IS FALSE` to `is_true(NOT expr)`.
+                let not_expr = Expr::UnaryOp {
+                    span: *span,
+                    op: UnaryOperator::Not,
+                    expr: expr.clone(),
+                };
+                let arg = if *value { expr.as_ref() } else { &not_expr };
+                let box (scalar, data_type) =
+                    self.resolve_function(*span, "is_true", vec![], &[arg])?;
+                if *not {
+                    self.resolve_scalar_function_call(*span, "not", vec![], vec![scalar])?
+                } else {
+                    Box::new((scalar, data_type))
+                }
+            }
+
+            Expr::IsJson { span, expr, not } => {
+                let box (scalar, data_type) =
+                    self.resolve_function(*span, "is_parsable_json", vec![], &[expr.as_ref()])?;
+                if *not {
+                    self.resolve_scalar_function_call(*span, "not", vec![], vec![scalar])?
+                } else {
+                    Box::new((scalar, data_type))
+                }
+            }
+
             Expr::IsDistinctFrom {
                 span,
                 left,
@@ -392,7 +505,14 @@ impl<'a> TypeChecker<'a> {
                 not,
                 ..
             } => {
-                if list.len() >= self.ctx.get_settings().get_inlist_to_join_threshold()? {
+                if list.iter().any(|e| matches!(e, Expr::Literal { .. })) {
+                    let box (_, left_type) = self.resolve(expr)?;
+                    self.check_inlist_element_types(expr, &left_type, list)?;
+                }
+                // A threshold of 0 disables the conversion of IN list to subquery.
+                let inlist_to_join_threshold =
+                    self.ctx.get_settings().get_inlist_to_join_threshold()?;
+                if inlist_to_join_threshold > 0 && list.len() >= inlist_to_join_threshold {
                     if *not {
                         return self.resolve_unary_op(*span, &UnaryOperator::Not, &Expr::InList {
                             span: *span,
@@ -419,6 +539,8 @@ impl<'a> TypeChecker<'a> {
                             params: vec![],
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                             distinct: false,
                         },
                     };
@@ -433,6 +555,8 @@ impl<'a> TypeChecker<'a> {
                                 params: vec![],
                                 window: None,
                                 lambda: None,
+                                order_by: vec![],
+                                filter: None,
                             },
                         })?
                     } else {
@@ -579,6 +703,21 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_unary_op(*span, op, expr.as_ref())?
             }
 
+            Expr::Cast {
+                span,
+                expr,
+                target_type,
+                format: Some(format),
+                ..
+            } => self.resolve_cast_with_format(*span, expr, target_type, format, false)?,
+
+            Expr::TryCast {
+                span,
+                expr,
+                target_type,
+                format: Some(format),
+            } => self.resolve_cast_with_format(*span, expr, target_type, format, true)?,
+
             Expr::Cast {
                 expr, target_type, ..
             } => {
@@ -590,6 +729,13 @@ impl<'a> TypeChecker<'a> {
                         return result;
                     }
                 }
+                if target_type == &TypeName::String {
+                    if let Some(result) =
+                        self.resolve_cast_to_string(expr.span(), &data_type, &scalar, false)
+                    {
+                        return result;
+                    }
+                }
 
                 let raw_expr = RawExpr::Cast {
                     span: expr.span(),
@@ -626,6 +772,7 @@ impl<'a> TypeChecker<'a> {
                 expr, target_type, ..
             } => {
                 let box (scalar, data_type) = self.resolve(expr)?;
+                self.record_try_cast(&data_type, target_type)?;
                 if target_type == &TypeName::Variant {
                     if let Some(result) =
                         self.resolve_cast_to_variant(expr.span(), &data_type, &scalar, true)
@@ -633,6 +780,13 @@ impl<'a> TypeChecker<'a> {
                         return result;
                     }
                 }
+                if target_type == &TypeName::String {
+                    if let Some(result) =
+                        self.resolve_cast_to_string(expr.span(), &data_type, &scalar, true)
+                    {
+                        return result;
+                    }
+                }
 
                 let raw_expr = RawExpr::Cast {
                     span: expr.span(),
@@ -680,6 +834,8 @@ impl<'a> TypeChecker<'a> {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             };
                             arguments.push(equal_expr)
@@ -729,10 +885,19 @@ impl<'a> TypeChecker<'a> {
                         params,
                         window,
                         lambda,
+                        order_by,
+                        filter,
                     },
             } => {
+                check_identifier_length(name, self.name_resolution_ctx)?;
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
+                // `array_sort(arr, (a, b) -> ...)` sorts the array with a comparator lambda.
+                let func_name = if func_name == "array_sort" && lambda.is_some() {
+                    "array_sort_by"
+                } else {
+                    func_name
+                };
                 if !is_builtin_function(func_name)
                     && !Self::all_sugar_functions().contains(&func_name)
                 {
@@ -760,16 +925,18 @@ impl<'a> TypeChecker<'a> {
                         let possible_funcs = engine
                             .search(func_name)
                             .iter()
-                            .map(|name| format!("'{name}'"))
+                            .map(|name| display_ident(name))
                             .collect::<Vec<_>>();
                         if possible_funcs.is_empty() {
                             return Err(ErrorCode::UnknownFunction(format!(
-                                "no function matches the given name: {func_name}"
+                                "no function matches the given name: {}",
+                                display_ident(func_name)
                             ))
                             .set_span(*span));
                         } else {
                             return Err(ErrorCode::UnknownFunction(format!(
-                                "no function matches the given name: '{func_name}', do you mean {}?",
+                                "no function matches the given name: {}, do you mean {}?",
+                                display_ident(func_name),
                                 possible_funcs.join(", ")
                             ))
                             .set_span(*span));
@@ -794,6 +961,55 @@ impl<'a> TypeChecker<'a> {
                     )
                     .set_span(*span));
                 }
+                // check order by in aggregate function legal
+                if !order_by.is_empty() {
+                    if !ORDERED_AGGREGATE_FUNCTIONS
+                        .iter()
+                        .any(|name| func_name.eq_ignore_ascii_case(name))
+                    {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "ORDER BY is not supported in function {func_name}, only {} allow it",
+                            ORDERED_AGGREGATE_FUNCTIONS.join(", ")
+                        ))
+                        .set_span(*span));
+                    }
+                    if window.is_some() {
+                        return Err(ErrorCode::SemanticError(
+                            "ORDER BY in aggregate function can not be used with window syntax",
+                        )
+                        .set_span(*span));
+                    }
+                    if *distinct {
+                        return Err(ErrorCode::SemanticError(
+                            "ORDER BY in aggregate function can not be used with DISTINCT",
+                        )
+                        .set_span(*span));
+                    }
+                }
+
+                // check filter clause in aggregate function legal
+                if filter.is_some() {
+                    if !AggregateFunctionFactory::instance().contains(func_name) {
+                        return Err(ErrorCode::SemanticError(
+                            "only aggregate functions allowed in FILTER syntax",
+                        )
+                        .set_span(*span));
+                    }
+                    if window.is_some() {
+                        return Err(ErrorCode::SemanticError(
+                            "FILTER clause is not supported in window function",
+                        )
+                        .set_span(*span));
+                    }
+                }
+
+                // `xxx(DISTINCT ...) OVER (...)` is not supported by the window transform
+                if *distinct && window.is_some() {
+                    return Err(ErrorCode::SemanticError(
+                        "DISTINCT is not supported in window functions",
+                    )
+                    .set_span(*span));
+                }
 
                 let args: Vec<&Expr> = args.iter().collect();
 
@@ -820,6 +1036,13 @@ impl<'a> TypeChecker<'a> {
                         .set_span(*span));
                     }
 
+                    if matches!(self.bind_context.expr_context, ExprContext::QualifyClause) {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "set-returning function {func_name} is not allowed in QUALIFY clause"
+                        ))
+                        .set_span(*span));
+                    }
+
                     if !matches!(self.bind_context.expr_context, ExprContext::SelectClause) {
                         return Err(ErrorCode::SemanticError(
                             "set-returning functions can only be used in SELECT".to_string(),
@@ -865,7 +1088,14 @@ impl<'a> TypeChecker<'a> {
                     self.in_window_function = self.in_window_function || window.is_some();
                     let in_aggregate_function = self.in_aggregate_function;
                     let (new_agg_func, data_type) = self.resolve_aggregate_function(
-                        *span, func_name, expr, *distinct, new_params, &args,
+                        *span,
+                        func_name,
+                        expr,
+                        *distinct,
+                        new_params,
+                        &args,
+                        order_by,
+                        filter.as_deref(),
                     )?;
                     self.in_window_function = in_window;
                     self.in_aggregate_function = in_aggregate_function;
@@ -926,9 +1156,27 @@ impl<'a> TypeChecker<'a> {
                 }
             }
 
-            Expr::CountAll { span, window } => {
-                let (new_agg_func, data_type) =
-                    self.resolve_aggregate_function(*span, "count", expr, false, vec![], &[])?;
+            Expr::CountAll {
+                span,
+                window,
+                filter,
+            } => {
+                if filter.is_some() && window.is_some() {
+                    return Err(ErrorCode::SemanticError(
+                        "FILTER clause is not supported in window function",
+                    )
+                    .set_span(*span));
+                }
+                let (new_agg_func, data_type) = self.resolve_aggregate_function(
+                    *span,
+                    "count",
+                    expr,
+                    false,
+                    vec![],
+                    &[],
+                    &[],
+                    filter.as_deref(),
+                )?;
 
                 if let Some(window) = window {
                     // aggregate window function
@@ -993,18 +1241,50 @@ impl<'a> TypeChecker<'a> {
                     let path = match accessor {
                         MapAccessor::Bracket {
                             key: box Expr::Literal { value, .. },
-                        } => {
-                            if !matches!(value, Literal::UInt64(_) | Literal::String(_)) {
+                        } => match value {
+                            Literal::UInt64(idx) => {
+                                MapAccessPath::Index(Self::map_access_index(*span, *idx, false)?)
+                            }
+                            Literal::String(name) => MapAccessPath::Name {
+                                name: name.clone(),
+                                case_sensitive: true,
+                            },
+                            _ => {
                                 return Err(ErrorCode::SemanticError(format!(
                                     "Unsupported accessor: {:?}",
                                     value
                                 ))
                                 .set_span(*span));
                             }
-                            value.clone()
+                        },
+                        // Negative index counts from the end, e.g. `arr[-1]` is the last element.
+                        MapAccessor::Bracket {
+                            key:
+                                box Expr::UnaryOp {
+                                    op: UnaryOperator::Minus,
+                                    expr:
+                                        box Expr::Literal {
+                                            value: Literal::UInt64(idx),
+                                            ..
+                                        },
+                                    ..
+                                },
+                        } => MapAccessPath::Index(Self::map_access_index(*span, *idx, true)?),
+                        MapAccessor::Colon { key } => {
+                            let case_sensitive = if key.is_quoted() {
+                                self.name_resolution_ctx.quoted_ident_case_sensitive
+                            } else {
+                                self.name_resolution_ctx.unquoted_ident_case_sensitive
+                            };
+                            MapAccessPath::Name {
+                                name: key.name.clone(),
+                                case_sensitive,
+                            }
+                        }
+                        MapAccessor::DotNumber { key } => {
+                            MapAccessPath::Index(Self::map_access_index(*span, *key, false)?)
                         }
-                        MapAccessor::Colon { key } => Literal::String(key.name.clone()),
-                        MapAccessor::DotNumber { key } => Literal::UInt64(*key),
+                        MapAccessor::Wildcard => MapAccessPath::Wildcard,
                         _ => {
                             return Err(ErrorCode::SemanticError(format!(
                                 "Unsupported accessor: {:?}",
@@ -1070,140 +1350,565 @@ impl<'a> TypeChecker<'a> {
             Expr::Position {
                 substr_expr,
                 str_expr,
+                position_from,
                 span,
-                ..
-            } => self.resolve_function(*span, "locate", vec![], &[
-                substr_expr.as_ref(),
-                str_expr.as_ref(),
-            ])?,
+            } => {
+                let mut args = vec![substr_expr.as_ref(), str_expr.as_ref()];
+                if let Some(position_from) = position_from {
+                    args.push(position_from.as_ref());
+                }
+                self.resolve_function(*span, "locate", vec![], &args)?
+            }
 
             Expr::Map { span, kvs, .. } => self.resolve_map(*span, kvs)?,
 
             Expr::Tuple { span, exprs, .. } => self.resolve_tuple(*span, exprs)?,
 
             Expr::Hole { .. } => unreachable!("hole is impossible in trivial query"),
+
+            Expr::SystemVariable { span, name } => self.resolve_system_variable(*span, name)?,
         };
 
         Ok(Box::new((scalar, data_type)))
     }
 
-    // TODO: remove this function
-    fn rewrite_substring(args: &mut [ScalarExpr]) {
-        if let ScalarExpr::ConstantExpr(expr) = &args[1] {
-            if let databend_common_expression::Scalar::Number(NumberScalar::UInt8(0)) = expr.value {
-                args[1] = ConstantExpr {
-                    span: expr.span,
-                    value: databend_common_expression::Scalar::Number(1i64.into()),
+    /// Dispatch `to_timestamp` by the type of its first argument:
+    /// - a number is an epoch, `to_timestamp(epoch[, scale])`. Integers without a scale
+    ///   detect the unit by their magnitude, fractional numbers default to seconds.
+    /// - a string is parsed, `to_timestamp(string[, format])`.
+    fn rewrite_to_timestamp_args(
+        span: Span,
+        func_name: &str,
+        args: &mut Vec<ScalarExpr>,
+        arg_types: &[DataType],
+    ) -> Result<()> {
+        let Some(arg_type) = arg_types.first().map(|ty| ty.remove_nullable()) else {
+            return Ok(());
+        };
+        match arg_type {
+            DataType::Number(_) | DataType::Decimal(_) => {
+                if args.len() > 2 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "{func_name}(epoch[, scale]) expects at most 2 arguments, but got {}",
+                        args.len()
+                    ))
+                    .set_span(span));
+                }
+                if let Some(scale_type) = arg_types.get(1) {
+                    let scale_type = scale_type.remove_nullable();
+                    if !scale_type.is_unsigned_numeric() && scale_type != DataType::Null {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "The scale of {func_name}(epoch, scale) must be an unsigned integer, but got {}",
+                            arg_types[1]
+                        ))
+                        .set_span(span));
+                    }
+                }
+                if arg_type.is_integer() {
+                    return Ok(());
+                }
+
+                // Keep the fraction of the epoch, which defaults to seconds. It is scaled to
+                // micros with the range checked by `to_timestamp(Float64, UInt64)`.
+                if args.len() == 1 {
+                    args.push(
+                        ConstantExpr {
+                            span,
+                            value: Scalar::Number(0u64.into()),
+                        }
+                        .into(),
+                    );
+                }
+                let mut target_type = DataType::Number(NumberDataType::Float64);
+                if arg_types[0].is_nullable() {
+                    target_type = target_type.wrap_nullable();
+                }
+                let epoch = args.remove(0);
+                args.insert(
+                    0,
+                    CastExpr {
+                        span,
+                        is_try: false,
+                        argument: Box::new(epoch),
+                        target_type: Box::new(target_type),
+                    }
+                    .into(),
+                );
+            }
+            DataType::String => {
+                if let Some(format_type) = arg_types.get(1) {
+                    let format_type = format_type.remove_nullable();
+                    if format_type != DataType::String && format_type != DataType::Null {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "The format of {func_name}(string, format) must be a string, but got {}",
+                            arg_types[1]
+                        ))
+                        .set_span(span));
+                    }
                 }
-                .into();
             }
+            _ => {}
         }
+        Ok(())
     }
 
-    fn resolve_window(
-        &mut self,
+    /// Check the arguments of `bitmap_subset_in_range(bitmap, start, end)`, a constant
+    /// range must not be negative and its start must not be greater than its end.
+    fn check_bitmap_range_args(
         span: Span,
-        display_name: String,
-        window: &Window,
-        func: WindowFuncType,
-    ) -> Result<Box<(ScalarExpr, DataType)>> {
-        if self.in_aggregate_function {
-            // Reset the state
-            self.in_aggregate_function = false;
-            return Err(ErrorCode::SemanticError(
-                "aggregate function calls cannot contain window function calls".to_string(),
-            )
+        func_name: &str,
+        args: &[ScalarExpr],
+        arg_types: &[DataType],
+    ) -> Result<()> {
+        if args.len() != 3 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects 3 arguments (bitmap, start, end), but got {}",
+                args.len()
+            ))
             .set_span(span));
         }
-        if self.in_window_function {
-            // Reset the state
-            self.in_window_function = false;
-            return Err(ErrorCode::SemanticError(
-                "window function calls cannot be nested".to_string(),
-            )
+        let bitmap_type = arg_types[0].remove_nullable();
+        if bitmap_type != DataType::Bitmap && bitmap_type != DataType::Null {
+            return Err(ErrorCode::SemanticError(format!(
+                "The first argument of {func_name} must be a bitmap, but got {}",
+                arg_types[0]
+            ))
             .set_span(span));
         }
-
-        let spec = match window {
-            Window::WindowSpec(spec) => spec.clone(),
-            Window::WindowReference(w) => self
-                .bind_context
-                .window_definitions
-                .get(&w.window_name.name)
-                .ok_or_else(|| {
-                    ErrorCode::SyntaxException(format!(
-                        "Window definition {} not found",
-                        w.window_name.name
+        let mut range = Vec::with_capacity(2);
+        for (arg, arg_type) in args[1..].iter().zip(&arg_types[1..]) {
+            let range_type = arg_type.remove_nullable();
+            if !range_type.is_integer() && range_type != DataType::Null {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The range of {func_name} must be integers, but got {}",
+                    arg_type
+                ))
+                .set_span(span));
+            }
+            if let ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Scalar::Number(num),
+                ..
+            }) = arg
+            {
+                let value = num.integer_to_i128().unwrap();
+                if value < 0 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The range of {func_name} must not be negative, but got {value}"
                     ))
-                })?
-                .value()
-                .clone(),
-        };
-
-        self.in_window_function = true;
-        let mut partitions = Vec::with_capacity(spec.partition_by.len());
-        for p in spec.partition_by.iter() {
-            let box (part, _part_type) = self.resolve(p)?;
-            partitions.push(part);
-        }
-
-        let mut order_by = Vec::with_capacity(spec.order_by.len());
-        for o in spec.order_by.iter() {
-            let box (order, _) = self.resolve(&o.expr)?;
-            order_by.push(WindowOrderBy {
-                expr: order,
-                asc: o.asc,
-                nulls_first: o.nulls_first,
-            })
-        }
-        self.in_window_function = false;
-
-        let frame = self.resolve_window_frame(
-            span,
-            &func,
-            &partitions,
-            &mut order_by,
-            spec.window_frame.clone(),
-        )?;
-        let data_type = func.return_type();
-        let window_func = WindowFunc {
-            span,
-            display_name,
-            func,
-            partition_by: partitions,
-            order_by,
-            frame,
-        };
-        Ok(Box::new((window_func.into(), data_type)))
-    }
-
-    // just support integer
-    #[inline]
-    fn resolve_rows_offset(&self, expr: &Expr) -> Result<Scalar> {
-        if let Expr::Literal { value, .. } = expr {
-            let box (value, _) = self.resolve_literal_scalar(value)?;
-            match value {
-                Scalar::Number(NumberScalar::UInt8(v)) => {
-                    return Ok(Scalar::Number(NumberScalar::UInt64(v as u64)));
-                }
-                Scalar::Number(NumberScalar::UInt16(v)) => {
-                    return Ok(Scalar::Number(NumberScalar::UInt64(v as u64)));
-                }
-                Scalar::Number(NumberScalar::UInt32(v)) => {
-                    return Ok(Scalar::Number(NumberScalar::UInt64(v as u64)));
+                    .set_span(span));
                 }
-                Scalar::Number(NumberScalar::UInt64(_)) => return Ok(value),
-                _ => {}
+                range.push(value);
             }
         }
-
-        Err(ErrorCode::SemanticError(
-            "Only unsigned numbers are allowed in ROWS offset".to_string(),
-        )
-        .set_span(expr.span()))
+        if let [start, end] = range[..] {
+            if start > end {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The range start {start} of {func_name} must not be greater than the range end {end}"
+                ))
+                .set_span(span));
+            }
+        }
+        Ok(())
     }
 
-    #[inline]
+    /// Check the arguments of vector functions such as `cosine_distance(a, b)` and
+    /// `vector_norm(a)`, the vectors must be numeric arrays and constant vectors
+    /// must have the same dimension.
+    fn check_vector_args(
+        span: Span,
+        func_name: &str,
+        args: &[ScalarExpr],
+        arg_types: &[DataType],
+    ) -> Result<()> {
+        let expected = if func_name == "vector_norm" { 1 } else { 2 };
+        if args.len() != expected {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects {expected} vector argument(s), but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+        let mut dimensions = Vec::with_capacity(args.len());
+        for (arg, arg_type) in args.iter().zip(arg_types) {
+            match arg_type.remove_nullable() {
+                DataType::Array(box inner)
+                    if matches!(
+                        inner.remove_nullable(),
+                        DataType::Number(_) | DataType::Decimal(_)
+                    ) => {}
+                DataType::Null | DataType::EmptyArray => {}
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The arguments of {func_name} must be numeric arrays, but got {arg_type}"
+                    ))
+                    .set_span(span));
+                }
+            }
+            if let ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Scalar::Array(column),
+                ..
+            }) = arg
+            {
+                dimensions.push(column.len());
+            }
+        }
+        if let [lhs, rhs] = dimensions[..] {
+            if lhs != rhs {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The vectors of {func_name} must have the same dimension, but got {lhs} and {rhs}"
+                ))
+                .set_span(span));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the arguments of `regexp_extract_all(str, pattern[, group])`, a constant pattern
+    /// must be a valid regular expression which has the capture group of a constant group index.
+    fn check_regexp_extract_all_args(
+        span: Span,
+        func_name: &str,
+        args: &[ScalarExpr],
+        arg_types: &[DataType],
+    ) -> Result<()> {
+        if args.len() != 2 && args.len() != 3 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects 2 or 3 arguments (str, pattern[, group]), but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+        let mut captures_len = None;
+        if let ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::String(pattern),
+            ..
+        }) = &args[1]
+        {
+            let re = regex::Regex::new(pattern).map_err(|err| {
+                ErrorCode::SemanticError(format!(
+                    "The pattern of {func_name} is not a valid regular expression: {err}"
+                ))
+                .set_span(args[1].span().or(span))
+            })?;
+            captures_len = Some(re.captures_len());
+        }
+        if args.len() == 3 {
+            let group_type = arg_types[2].remove_nullable();
+            if !group_type.is_integer() && group_type != DataType::Null {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The group index of {func_name} must be an integer, but got {}",
+                    arg_types[2]
+                ))
+                .set_span(span));
+            }
+            if let ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Scalar::Number(num),
+                ..
+            }) = &args[2]
+            {
+                let group = num.integer_to_i128().unwrap();
+                if group < 0 {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The group index of {func_name} must not be negative, but got {group}"
+                    ))
+                    .set_span(args[2].span().or(span)));
+                }
+                if let Some(captures_len) = captures_len {
+                    if group >= captures_len as i128 {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "The group index {group} of {func_name} is out of range, the pattern has {} groups",
+                            captures_len - 1
+                        ))
+                        .set_span(args[2].span().or(span)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the arguments of `regexp_replace(str, pattern, replacement, ...)`, a constant
+    /// pattern must be a valid regular expression, and an empty one only raises a warning.
+    fn check_regexp_replace_args(
+        &self,
+        span: Span,
+        func_name: &str,
+        args: &[ScalarExpr],
+    ) -> Result<()> {
+        let Some(ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::String(pattern),
+            ..
+        })) = args.get(1)
+        else {
+            return Ok(());
+        };
+        if pattern.is_empty() {
+            self.ctx.push_warning(format!(
+                "The pattern of `{func_name}` is empty, the source string is returned unchanged"
+            ));
+            return Ok(());
+        }
+        regex::Regex::new(pattern).map_err(|err| {
+            ErrorCode::SemanticError(format!(
+                "The pattern of {func_name} is not a valid regular expression: {err}"
+            ))
+            .set_span(args[1].span().or(span))
+        })?;
+        Ok(())
+    }
+
+    /// Check the arguments of `json_object(k1, v1, k2, v2, ...)` are key-value pairs
+    /// with string keys, NULL keys are allowed as the pairs are skipped.
+    fn check_json_object_args(span: Span, func_name: &str, arg_types: &[DataType]) -> Result<()> {
+        if arg_types.len() % 2 != 0 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects an even number of arguments as key-value pairs, but got {}",
+                arg_types.len()
+            ))
+            .set_span(span));
+        }
+        for (i, key_type) in arg_types.iter().step_by(2).enumerate() {
+            let inner_type = key_type.remove_nullable();
+            if inner_type != DataType::String && inner_type != DataType::Null {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The key of {func_name} must be a string, but the key of pair {} is {}",
+                    i + 1,
+                    key_type
+                ))
+                .set_span(span));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the arguments of `json_strip_nulls(variant[, recursive])`, the input must be
+    /// a variant and the optional flag a constant boolean, stripping recursively by default.
+    fn check_json_strip_nulls_args(
+        span: Span,
+        func_name: &str,
+        args: &[ScalarExpr],
+        arg_types: &[DataType],
+    ) -> Result<()> {
+        if args.len() != 1 && args.len() != 2 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects 1 or 2 arguments (variant[, recursive]), but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+        let input_type = arg_types[0].remove_nullable();
+        if input_type != DataType::Variant && input_type != DataType::Null {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects a variant argument, but got {}",
+                arg_types[0]
+            ))
+            .set_span(args[0].span().or(span)));
+        }
+        if let Some(recursive) = args.get(1) {
+            if !matches!(
+                recursive,
+                ScalarExpr::ConstantExpr(ConstantExpr {
+                    value: Scalar::Boolean(_),
+                    ..
+                })
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The recursive flag of {func_name} must be a constant boolean"
+                ))
+                .set_span(recursive.span().or(span)));
+            }
+        }
+        Ok(())
+    }
+
+    /// `map_from_arrays(keys, values)` builds a map from an array of keys and an array of
+    /// values, the arrays having different lengths is an error raised at runtime.
+    fn check_map_from_arrays_args(
+        span: Span,
+        func_name: &str,
+        args: &[ScalarExpr],
+        arg_types: &[DataType],
+    ) -> Result<()> {
+        if args.len() != 2 {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects 2 arguments (keys, values), but got {}",
+                args.len()
+            ))
+            .set_span(span));
+        }
+        for (arg, arg_type) in args.iter().zip(arg_types) {
+            if !matches!(
+                arg_type.remove_nullable(),
+                DataType::Array(_) | DataType::EmptyArray | DataType::Null
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{func_name} expects array arguments, but got {arg_type}"
+                ))
+                .set_span(arg.span().or(span)));
+            }
+        }
+        if let DataType::Array(key_type) = arg_types[0].remove_nullable() {
+            check_map_key_type(&infer_schema_type(&key_type)?)
+                .map_err(|err| err.set_span(args[0].span().or(span)))?;
+        }
+        Ok(())
+    }
+
+    /// `upper` and `lower` map unicode characters under utf8 collation, and
+    /// resolve to the ascii-only `{name}_binary` variant under binary collation.
+    fn function_need_collation(
+        &self,
+        func_name: &str,
+        arg_types: &[DataType],
+    ) -> Result<Option<String>> {
+        let func_name = BUILTIN_FUNCTIONS
+            .aliases
+            .get(func_name)
+            .map(|name| name.as_str())
+            .unwrap_or(func_name);
+        if !["lower", "upper"].contains(&func_name)
+            || !arg_types
+                .first()
+                .is_some_and(|ty| ty.remove_nullable() == DataType::String)
+            || self.ctx.get_settings().get_collation()? != "binary"
+        {
+            return Ok(None);
+        }
+        Ok(Some(format!("{func_name}_binary")))
+    }
+
+    // TODO: remove this function
+    // Returns true if the literal index `0` has been rewritten to `1`.
+    fn rewrite_substring(args: &mut [ScalarExpr]) -> bool {
+        if let Some(ScalarExpr::ConstantExpr(expr)) = args.get(1) {
+            if let databend_common_expression::Scalar::Number(NumberScalar::UInt8(0)) = expr.value {
+                args[1] = ConstantExpr {
+                    span: expr.span,
+                    value: databend_common_expression::Scalar::Number(1i64.into()),
+                }
+                .into();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn resolve_window(
+        &mut self,
+        span: Span,
+        display_name: String,
+        window: &Window,
+        func: WindowFuncType,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if self.in_aggregate_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(
+                "aggregate function calls cannot contain window function calls".to_string(),
+            )
+            .set_span(span));
+        }
+        if self.in_window_function {
+            // Reset the state
+            self.in_window_function = false;
+            return Err(ErrorCode::SemanticError(
+                "window function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+        let clause = match self.bind_context.expr_context {
+            ExprContext::WhereClause => Some("WHERE"),
+            ExprContext::HavingClause => Some("HAVING"),
+            _ => None,
+        };
+        if let Some(clause) = clause {
+            return Err(ErrorCode::SemanticError(format!(
+                "window function {} is not allowed in {clause} clause",
+                func.func_name()
+            ))
+            .set_span(span));
+        }
+
+        let spec = match window {
+            Window::WindowSpec(spec) => spec.clone(),
+            Window::WindowReference(w) => self
+                .bind_context
+                .window_definitions
+                .get(&w.window_name.name)
+                .ok_or_else(|| {
+                    ErrorCode::SyntaxException(format!(
+                        "Window definition {} not found",
+                        w.window_name.name
+                    ))
+                })?
+                .value()
+                .clone(),
+        };
+
+        self.in_window_function = true;
+        let mut partitions = Vec::with_capacity(spec.partition_by.len());
+        for p in spec.partition_by.iter() {
+            let box (part, _part_type) = self.resolve(p)?;
+            partitions.push(part);
+        }
+
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        for o in spec.order_by.iter() {
+            let box (order, _) = self.resolve(&o.expr)?;
+            order_by.push(WindowOrderBy {
+                expr: order,
+                asc: o.asc,
+                nulls_first: o.nulls_first,
+            })
+        }
+        self.in_window_function = false;
+
+        let frame = self.resolve_window_frame(
+            span,
+            &func,
+            &partitions,
+            &mut order_by,
+            spec.window_frame.clone(),
+        )?;
+        let data_type = func.return_type();
+        let window_func = WindowFunc {
+            span,
+            display_name,
+            func,
+            partition_by: partitions,
+            order_by,
+            frame,
+        };
+        Ok(Box::new((window_func.into(), data_type)))
+    }
+
+    // just support integer
+    #[inline]
+    fn resolve_rows_offset(&self, expr: &Expr) -> Result<Scalar> {
+        if let Expr::Literal { value, .. } = expr {
+            let box (value, _) = self.resolve_literal_scalar(value)?;
+            match value {
+                Scalar::Number(NumberScalar::UInt8(v)) => {
+                    return Ok(Scalar::Number(NumberScalar::UInt64(v as u64)));
+                }
+                Scalar::Number(NumberScalar::UInt16(v)) => {
+                    return Ok(Scalar::Number(NumberScalar::UInt64(v as u64)));
+                }
+                Scalar::Number(NumberScalar::UInt32(v)) => {
+                    return Ok(Scalar::Number(NumberScalar::UInt64(v as u64)));
+                }
+                Scalar::Number(NumberScalar::UInt64(_)) => return Ok(value),
+                _ => {}
+            }
+        }
+
+        Err(ErrorCode::SemanticError(
+            "Only unsigned numbers are allowed in ROWS offset".to_string(),
+        )
+        .set_span(expr.span()))
+    }
+
+    #[inline]
     fn resolve_literal(
         &self,
         span: Span,
@@ -1324,6 +2029,20 @@ impl<'a> TypeChecker<'a> {
                     end_bound: WindowFuncFrameBound::Following(None),
                 });
             }
+            WindowFuncType::Interpolate(_) => {
+                if order_by.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "interpolate window function requires ORDER BY in the window",
+                    )
+                    .set_span(span));
+                }
+                // The neighboring non-NULL values may be anywhere in the partition.
+                return Ok(WindowFuncFrame {
+                    units: WindowFuncFrameUnits::Rows,
+                    start_bound: WindowFuncFrameBound::Preceding(None),
+                    end_bound: WindowFuncFrameBound::Following(None),
+                });
+            }
             WindowFuncType::LagLead(lag_lead) if lag_lead.is_lag => {
                 return Ok(WindowFuncFrame {
                     units: WindowFuncFrameUnits::Rows,
@@ -1447,6 +2166,7 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_nth_value_window_function(func_name, &arguments, &arg_types)
             }
             "ntile" => self.resolve_ntile_window_function(&arguments),
+            "interpolate" => self.resolve_interpolate_window_function(&arguments, &arg_types),
             _ => Err(ErrorCode::UnknownFunction(format!(
                 "Unknown window function: {func_name}"
             ))),
@@ -1622,8 +2342,41 @@ impl<'a> TypeChecker<'a> {
         }))
     }
 
-    /// Resolve aggregation function call.
+    fn resolve_interpolate_window_function(
+        &mut self,
+        args: &[ScalarExpr],
+        arg_types: &[DataType],
+    ) -> Result<WindowFuncType> {
+        if args.len() != 1 {
+            return Err(ErrorCode::InvalidArgument(
+                "Function interpolate can only take one argument".to_string(),
+            ));
+        }
+        let arg_type = arg_types[0].remove_nullable();
+        if !arg_type.is_numeric() && !arg_type.is_decimal() && arg_type != DataType::Null {
+            return Err(ErrorCode::SemanticError(format!(
+                "The argument of `interpolate` must be a number, but got {}",
+                arg_types[0]
+            ))
+            .set_span(args[0].span()));
+        }
+
+        // Interpolated values are fractional, compute them in Float64.
+        let return_type = DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64)));
+        let arg = ScalarExpr::CastExpr(CastExpr {
+            span: args[0].span(),
+            is_try: false,
+            argument: Box::new(args[0].clone()),
+            target_type: Box::new(return_type.clone()),
+        });
+        Ok(WindowFuncType::Interpolate(InterpolateFunction {
+            arg: Box::new(arg),
+            return_type: Box::new(return_type),
+        }))
+    }
 
+    /// Resolve aggregation function call.
+    #[allow(clippy::too_many_arguments)]
     fn resolve_aggregate_function(
         &mut self,
         span: Span,
@@ -1632,6 +2385,8 @@ impl<'a> TypeChecker<'a> {
         distinct: bool,
         params: Vec<Scalar>,
         args: &[&Expr],
+        order_by: &[OrderByExpr],
+        filter: Option<&Expr>,
     ) -> Result<(AggregateFunction, DataType)> {
         if matches!(
             self.bind_context.expr_context,
@@ -1651,6 +2406,15 @@ impl<'a> TypeChecker<'a> {
             )
             .set_span(span));
         }
+        if matches!(self.bind_context.expr_context, ExprContext::QualifyClause)
+            && !self.in_window_function
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "aggregate function {func_name} is not allowed in QUALIFY clause unless it is used \
+                 as a window function, use HAVING to filter on aggregates"
+            ))
+            .set_span(span));
+        }
         if self.in_aggregate_function {
             if self.in_window_function {
                 // The aggregate function can be in window function call,
@@ -1668,6 +2432,54 @@ impl<'a> TypeChecker<'a> {
             }
         }
 
+        // Rewrite `agg(a, b, ...) FILTER (WHERE p)` to `agg_if(a, b, ..., is_true(p))`, the If
+        // combinator skips the rows that don't satisfy the predicate, including the ones it's
+        // NULL for. The combinators can't be nested, so `agg(DISTINCT a, b, ...) FILTER (WHERE p)`
+        // is rewritten to `agg(DISTINCT if(p, a, NULL), if(p, b, NULL), ...)` instead, the rows
+        // with a NULL argument are ignored by the distinct aggregate functions.
+        let filtered_args: Vec<Expr>;
+        let mut args = args.to_vec();
+        let mut predicate = None;
+        if let Some(filter) = filter {
+            // These functions keep the NULL values, so can't be rewritten.
+            if matches!(
+                func_name.to_lowercase().as_str(),
+                "array_agg" | "list" | "group_array_moving_avg" | "group_array_moving_sum"
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "FILTER clause is not supported in aggregate function {func_name}"
+                ))
+                .set_span(span));
+            }
+            let call = |name: &str, args: Vec<Expr>| Expr::FunctionCall {
+                span,
+                func: ASTFunctionCall {
+                    distinct: false,
+                    name: Identifier::from_name(span, name),
+                    args,
+                    params: vec![],
+                    window: None,
+                    lambda: None,
+                    order_by: vec![],
+                    filter: None,
+                },
+            };
+            if distinct {
+                filtered_args = args
+                    .iter()
+                    .map(|arg| {
+                        call("if", vec![filter.clone(), (*arg).clone(), Expr::Literal {
+                            span,
+                            value: Literal::Null,
+                        }])
+                    })
+                    .collect();
+                args = filtered_args.iter().collect();
+            } else {
+                predicate = Some(call("is_true", vec![filter.clone()]));
+            }
+        }
+
         // Check aggregate function
         self.in_aggregate_function = true;
         let mut arguments = vec![];
@@ -1677,21 +2489,72 @@ impl<'a> TypeChecker<'a> {
             arguments.push(argument);
             arg_types.push(arg_type);
         }
+        let predicate = match &predicate {
+            Some(predicate) => Some(self.resolve(predicate)?),
+            None => None,
+        };
+        // The sort keys are appended after the arguments, see `AggregateFunctionSortAdaptor`.
+        let default_nulls_first = match self.ctx.get_settings().get_default_order_by_null()? {
+            Some(nulls_first) => nulls_first,
+            None => !self.dialect.is_null_biggest(),
+        };
+        let mut sort_args = Vec::with_capacity(order_by.len());
+        let mut sort_arg_types = Vec::with_capacity(order_by.len());
+        let mut sort_descs = Vec::with_capacity(order_by.len());
+        for order in order_by.iter() {
+            let box (argument, arg_type) = self.resolve(&order.expr)?;
+            sort_args.push(argument);
+            sort_arg_types.push(arg_type);
+            sort_descs.push(AggregateFunctionSortDesc {
+                asc: order.asc.unwrap_or(true),
+                nulls_first: order.nulls_first.unwrap_or(default_nulls_first),
+            });
+        }
         self.in_aggregate_function = false;
 
+        // The standard deviation and variance functions only accept numeric arguments,
+        // `stddev` and `variance` are the sample variants, while `std` is the population
+        // standard deviation as in MySQL.
+        if matches!(
+            func_name.to_lowercase().as_str(),
+            "stddev"
+                | "stddev_samp"
+                | "stddev_pop"
+                | "std"
+                | "variance"
+                | "var_samp"
+                | "var_pop"
+                | "variance_samp"
+                | "variance_pop"
+        ) {
+            if let Some(arg_type) = arg_types.first() {
+                if !matches!(
+                    arg_type.remove_nullable(),
+                    DataType::Number(_) | DataType::Decimal(_) | DataType::Null
+                ) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "{func_name} expects a numeric argument, but got {arg_type}"
+                    ))
+                    .set_span(span));
+                }
+            }
+        }
+
         // Convert the delimiter of string_agg to params
-        let params = if func_name.eq_ignore_ascii_case("string_agg")
-            && arguments.len() == 2
-            && params.is_empty()
-        {
+        let is_string_agg = func_name.eq_ignore_ascii_case("string_agg")
+            || func_name.eq_ignore_ascii_case("group_concat");
+        let params = if is_string_agg && arguments.len() == 2 && params.is_empty() {
             let delimiter_value = ConstantExpr::try_from(arguments[1].clone());
             if arg_types[1] != DataType::String || delimiter_value.is_err() {
-                return Err(ErrorCode::SemanticError(
-                    "The delimiter of `string_agg` must be a constant string",
-                ));
+                return Err(ErrorCode::SemanticError(format!(
+                    "The delimiter of `{func_name}` must be a constant string"
+                )));
             }
             let delimiter = delimiter_value.unwrap();
             vec![delimiter.value]
+        } else if func_name.eq_ignore_ascii_case("group_concat") && params.is_empty() {
+            // The default delimiter of `group_concat` is a comma, as in MySQL.
+            vec![Scalar::String(",".to_string())]
         } else {
             params
         };
@@ -1731,16 +2594,40 @@ impl<'a> TypeChecker<'a> {
             func_name.to_string()
         };
 
+        // The predicate of the FILTER clause is the last argument of the If combinator.
+        let func_name = match predicate {
+            Some(box (predicate, predicate_type)) => {
+                arguments.push(predicate);
+                arg_types.push(predicate_type);
+                format!("{func_name}_if")
+            }
+            None => func_name,
+        };
+
+        arguments.extend(sort_args);
+        arg_types.extend(sort_arg_types);
         let agg_func = AggregateFunctionFactory::instance()
-            .get(&func_name, params.clone(), arg_types)
+            .get_with_sort_descs(&func_name, params.clone(), arg_types, sort_descs.clone())
             .map_err(|e| e.set_span(span))?;
 
-        let args = if optimize_remove_count_args(&func_name, distinct, args) {
+        let args = if optimize_remove_count_args(&func_name, distinct, &args) {
             vec![]
         } else {
             arguments
         };
 
+        // Record the cap of `array_agg`, so the executor can stop collecting elements beyond it.
+        let max_elements = match func_name
+            .to_lowercase()
+            .trim_end_matches("_if")
+            .trim_end_matches("_distinct")
+        {
+            "array_agg" | "list" | "group_uniq_array" => {
+                self.ctx.get_settings().get_max_array_agg_elements()?
+            }
+            _ => 0,
+        };
+
         let display_name = format!("{:#}", expr);
         let new_agg_func = AggregateFunction {
             display_name,
@@ -1749,6 +2636,8 @@ impl<'a> TypeChecker<'a> {
             params,
             args,
             return_type: Box::new(agg_func.return_type()?),
+            sort_descs,
+            max_elements,
         };
 
         let data_type = agg_func.return_type()?;
@@ -1821,49 +2710,100 @@ impl<'a> TypeChecker<'a> {
             .map(|param| param.name.to_lowercase())
             .collect::<Vec<_>>();
 
-        // TODO: support multiple params
-        // ARRAY_REDUCE have two params
-        if params.len() != 1 && func_name != "array_reduce" {
-            return Err(ErrorCode::SemanticError(format!(
-                "incorrect number of parameters in lambda function, {} expects 1 parameter, but got {}",
-                func_name, params.len()
-            ))
-            .set_span(span));
-        } else if func_name == "array_reduce" && params.len() != 2 {
-            return Err(ErrorCode::SemanticError(format!(
-                "incorrect number of parameters in lambda function, {} expects 2 parameters, but got {}",
-                func_name, params.len()
-            ))
-            .set_span(span));
+        if MAP_LAMBDA_FUNCTIONS.contains(&func_name) {
+            return self.resolve_map_lambda_function(span, func_name, args, &params, lambda);
         }
 
-        if args.len() != 1 {
+        // ARRAY_SORT_BY sorts the array by the keys computed by the lambda function,
+        // the optional arguments after the array are the sorting order and the null sorting order.
+        // With two params, the lambda is a comparator of two elements instead.
+        let (func_name, args) = if func_name == "array_sort_by" && params.len() == 2 {
+            if args.len() != 1 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid arguments for lambda function, {} with a comparator expects 1 argument, but got {}",
+                    func_name,
+                    args.len()
+                ))
+                .set_span(span));
+            }
+            (ARRAY_SORT_BY_COMPARATOR, args)
+        } else if func_name == "array_sort_by" {
+            if args.is_empty() || args.len() > 3 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid arguments for lambda function, {} expects 1 to 3 arguments, but got {}",
+                    func_name,
+                    args.len()
+                ))
+                .set_span(span));
+            }
+            let order = self.resolve_sort_order(&args[1..])?;
+            let (func_name, _) = ARRAY_SORT_BY_FUNCTIONS
+                .iter()
+                .find(|(_, func_order)| *func_order == order)
+                .unwrap();
+            (*func_name, &args[..1])
+        } else {
+            (func_name, args)
+        };
+
+        // ARRAY_REDUCE has one array argument and two params (accumulator and element),
+        // so does the comparator of ARRAY_SORT_BY (two elements),
+        // other lambda functions take one param for each array argument.
+        if func_name == "array_reduce" || func_name == ARRAY_SORT_BY_COMPARATOR {
+            if params.len() != 2 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "incorrect number of parameters in lambda function, {} expects 2 parameters, but got {}",
+                    func_name, params.len()
+                ))
+                .set_span(span));
+            }
+            if args.len() != 1 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "invalid arguments for lambda function, {} expects 1 argument, but got {}",
+                    func_name,
+                    args.len()
+                ))
+                .set_span(span));
+            }
+        } else if params.len() != args.len() {
             return Err(ErrorCode::SemanticError(format!(
-                "invalid arguments for lambda function, {} expects 1 argument, but got {}",
+                "incorrect number of parameters in lambda function, {} expects {} parameter(s) for {} array argument(s), but got {}",
                 func_name,
-                args.len()
+                args.len(),
+                args.len(),
+                params.len()
             ))
             .set_span(span));
         }
-        let box (mut arg, arg_type) = self.resolve(args[0])?;
 
-        let inner_ty = match arg_type.remove_nullable() {
-            DataType::Array(box inner_ty) => inner_ty.clone(),
-            DataType::Null | DataType::EmptyArray => DataType::Null,
-            _ => {
-                return Err(ErrorCode::SemanticError(
-                    "invalid arguments for lambda function, argument data type must be an array"
-                        .to_string(),
-                )
-                .set_span(span));
-            }
-        };
+        let mut resolved_args = Vec::with_capacity(args.len());
+        let mut arg_types = Vec::with_capacity(args.len());
+        let mut inner_tys = Vec::with_capacity(args.len());
+        for arg in args {
+            let box (arg, arg_type) = self.resolve(arg)?;
+            let inner_ty = match arg_type.remove_nullable() {
+                DataType::Array(box inner_ty) => inner_ty.clone(),
+                DataType::Null | DataType::EmptyArray => DataType::Null,
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "invalid arguments for lambda function, argument data type must be an array"
+                            .to_string(),
+                    )
+                    .set_span(span));
+                }
+            };
+            resolved_args.push(arg);
+            arg_types.push(arg_type);
+            inner_tys.push(inner_ty);
+        }
 
         let inner_tys = if func_name == "array_reduce" {
-            let max_ty = self.transform_to_max_type(&inner_ty)?;
+            let max_ty = self.transform_to_max_type(&inner_tys[0])?;
             vec![max_ty.clone(), max_ty.clone()]
+        } else if func_name == ARRAY_SORT_BY_COMPARATOR {
+            vec![inner_tys[0].clone(), inner_tys[0].clone()]
         } else {
-            vec![inner_ty.clone()]
+            inner_tys
         };
 
         let columns = params
@@ -1872,12 +2812,46 @@ impl<'a> TypeChecker<'a> {
             .map(|(col, ty)| (col.clone(), ty.clone()))
             .collect::<Vec<_>>();
 
-        let box (lambda_expr, lambda_type) =
+        let box (mut lambda_expr, lambda_type) =
             parse_lambda_expr(self.ctx.clone(), &columns, &lambda.expr)?;
 
+        if func_name == ARRAY_SORT_BY_COMPARATOR {
+            // The comparator returns the ordering as an integer, casting it to Int64
+            // so that the executor only handles one type.
+            let target_type = match lambda_type.remove_nullable() {
+                DataType::Number(ty) if ty.is_integer() => DataType::Number(NumberDataType::Int64),
+                DataType::Null => DataType::Number(NumberDataType::Int64),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "invalid comparator for `array_sort`, the result data type of lambda function must be integer, but got {}",
+                        lambda_type
+                    ))
+                    .set_span(span));
+                }
+            };
+            let target_type = if lambda_type.is_nullable_or_null() {
+                target_type.wrap_nullable()
+            } else {
+                target_type
+            };
+            lambda_expr = ScalarExpr::CastExpr(CastExpr {
+                span: lambda_expr.span(),
+                is_try: false,
+                argument: Box::new(lambda_expr),
+                target_type: Box::new(target_type),
+            });
+        }
+
+        let is_nullable = arg_types.iter().any(|ty| ty.is_nullable());
         let return_type = if func_name == "array_filter" {
             if lambda_type.remove_nullable() == DataType::Boolean {
-                arg_type.clone()
+                // filter keeps the elements of the first array
+                let array_type = DataType::Array(Box::new(inner_tys[0].clone()));
+                if is_nullable {
+                    array_type.wrap_nullable()
+                } else {
+                    array_type
+                }
             } else {
                 return Err(ErrorCode::SemanticError(
                     "invalid lambda function for `array_filter`, the result data type of lambda function must be boolean".to_string()
@@ -1887,7 +2861,7 @@ impl<'a> TypeChecker<'a> {
         } else if func_name == "array_reduce" {
             // transform arg type
             let max_ty = inner_tys[0].clone();
-            let target_type = if arg_type.is_nullable() {
+            let target_type = if is_nullable {
                 Box::new(DataType::Nullable(Box::new(DataType::Array(Box::new(
                     max_ty.clone(),
                 )))))
@@ -1897,75 +2871,255 @@ impl<'a> TypeChecker<'a> {
             // we should convert arg to max_ty to avoid overflow in 'ADD'/'SUB',
             // so if arg_type(origin_type) != target_type(max_type), cast arg
             // for example, if arg = [1INT8, 2INT8, 3INT8], after cast it be [1INT64, 2INT64, 3INT64]
-            if arg_type != *target_type {
-                arg = ScalarExpr::CastExpr(CastExpr {
-                    span: arg.span(),
-                    is_try: false,
-                    argument: Box::new(arg),
-                    target_type,
-                });
+            if arg_types[0] != *target_type {
+                let arg = resolved_args.remove(0);
+                resolved_args.insert(
+                    0,
+                    ScalarExpr::CastExpr(CastExpr {
+                        span: arg.span(),
+                        is_try: false,
+                        argument: Box::new(arg),
+                        target_type,
+                    }),
+                );
             }
             max_ty.wrap_nullable()
-        } else if arg_type.is_nullable() {
+        } else if func_name.starts_with("array_sort_by") || func_name == "array_distinct_by" {
+            // sort and distinct keep the elements of the array
+            arg_types[0].clone()
+        } else if is_nullable {
             DataType::Nullable(Box::new(DataType::Array(Box::new(lambda_type.clone()))))
         } else {
             DataType::Array(Box::new(lambda_type.clone()))
         };
 
-        let (lambda_func, data_type) = match arg_type.remove_nullable() {
-            // Null and Empty array can convert to ConstantExpr
-            DataType::Null => (
+        // Null and Empty array can convert to ConstantExpr
+        if arg_types
+            .iter()
+            .any(|ty| ty.remove_nullable() == DataType::Null)
+        {
+            return Ok(Box::new((
                 ConstantExpr {
                     span,
                     value: Scalar::Null,
                 }
                 .into(),
                 DataType::Null,
-            ),
-            DataType::EmptyArray => (
+            )));
+        }
+        if arg_types
+            .iter()
+            .all(|ty| ty.remove_nullable() == DataType::EmptyArray)
+        {
+            return Ok(Box::new((
                 ConstantExpr {
                     span,
                     value: Scalar::EmptyArray,
                 }
                 .into(),
                 DataType::EmptyArray,
-            ),
-            _ => {
-                // generate lambda expression
-                let lambda_schema = if inner_tys.len() == 1 {
-                    let lambda_field = DataField::new("0", inner_tys[0].clone());
-                    DataSchema::new(vec![lambda_field])
-                } else {
-                    let lambda_field0 = DataField::new("0", inner_tys[0].clone());
-                    let lambda_field1 = DataField::new("1", inner_tys[1].clone());
-                    DataSchema::new(vec![lambda_field0, lambda_field1])
-                };
+            )));
+        }
+        // Zipped with other arrays, an empty array has elements of type `NULL`.
+        for (arg, arg_type) in resolved_args.iter_mut().zip(arg_types.iter()) {
+            if arg_type.remove_nullable() == DataType::EmptyArray {
+                let target_type = DataType::Array(Box::new(DataType::Null));
+                *arg = ScalarExpr::CastExpr(CastExpr {
+                    span: arg.span(),
+                    is_try: false,
+                    argument: Box::new(arg.clone()),
+                    target_type: Box::new(if arg_type.is_nullable() {
+                        target_type.wrap_nullable()
+                    } else {
+                        target_type
+                    }),
+                });
+            }
+        }
 
-                let expr = lambda_expr
-                    .type_check(&lambda_schema)?
-                    .project_column_ref(|index| {
-                        lambda_schema.index_of(&index.to_string()).unwrap()
-                    });
-                let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
-                let remote_lambda_expr = expr.as_remote_expr();
-                let lambda_display = format!("{:?} -> {}", params, expr.sql_display());
+        // generate lambda expression
+        let lambda_schema = DataSchema::new(
+            inner_tys
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| DataField::new(&i.to_string(), ty.clone()))
+                .collect(),
+        );
 
-                (
-                    LambdaFunc {
+        let expr = lambda_expr
+            .type_check(&lambda_schema)?
+            .project_column_ref(|index| lambda_schema.index_of(&index.to_string()).unwrap());
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let remote_lambda_expr = expr.as_remote_expr();
+        let lambda_display = format!("{:?} -> {}", params, expr.sql_display());
+
+        let lambda_func = LambdaFunc {
+            span,
+            func_name: func_name.to_string(),
+            args: resolved_args,
+            lambda_expr: Box::new(remote_lambda_expr),
+            lambda_display,
+            return_type: Box::new(return_type.clone()),
+        }
+        .into();
+        Ok(Box::new((lambda_func, return_type)))
+    }
+
+    /// Resolve the optional sorting order and null sorting order arguments of the array sort
+    /// functions, e.g. `array_sort(arr, 'DESC', 'NULLS LAST')`, returns `(asc, nulls_first)`.
+    fn resolve_sort_order(&mut self, args: &[&Expr]) -> Result<(bool, bool)> {
+        let mut asc = true;
+        let mut nulls_first = true;
+        if let Some(arg) = args.first() {
+            let box (arg, _) = self.resolve(arg)?;
+            let Ok(arg) = ConstantExpr::try_from(arg) else {
+                return Err(ErrorCode::SemanticError(
+                    "Sorting order must be a constant string",
+                ));
+            };
+            match arg.value {
+                Scalar::String(sort_order) if sort_order.eq_ignore_ascii_case("asc") => {
+                    asc = true;
+                }
+                Scalar::String(sort_order) if sort_order.eq_ignore_ascii_case("desc") => {
+                    asc = false;
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "Sorting order must be either ASC or DESC",
+                    ));
+                }
+            }
+        }
+        if let Some(arg) = args.get(1) {
+            let box (arg, _) = self.resolve(arg)?;
+            let Ok(arg) = ConstantExpr::try_from(arg) else {
+                return Err(ErrorCode::SemanticError(
+                    "Null sorting order must be a constant string",
+                ));
+            };
+            match arg.value {
+                Scalar::String(nulls_order) if nulls_order.eq_ignore_ascii_case("nulls first") => {
+                    nulls_first = true;
+                }
+                Scalar::String(nulls_order) if nulls_order.eq_ignore_ascii_case("nulls last") => {
+                    nulls_first = false;
+                }
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "Null sorting order must be either NULLS FIRST or NULLS LAST",
+                    ));
+                }
+            }
+        }
+        Ok((asc, nulls_first))
+    }
+
+    fn resolve_map_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+        params: &[String],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if params.len() != 2 {
+            return Err(ErrorCode::SemanticError(format!(
+                "incorrect number of parameters in lambda function, {} expects 2 parameters, but got {}",
+                func_name,
+                params.len()
+            ))
+            .set_span(span));
+        }
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for lambda function, {} expects 1 argument, but got {}",
+                func_name,
+                args.len()
+            ))
+            .set_span(span));
+        }
+        let box (arg, arg_type) = self.resolve(args[0])?;
+
+        let (key_ty, value_ty) = match arg_type.remove_nullable() {
+            DataType::Map(box DataType::Tuple(kv_tys)) => (kv_tys[0].clone(), kv_tys[1].clone()),
+            DataType::Null => {
+                return Ok(Box::new((
+                    ConstantExpr {
+                        span,
+                        value: Scalar::Null,
+                    }
+                    .into(),
+                    DataType::Null,
+                )));
+            }
+            DataType::EmptyMap => {
+                return Ok(Box::new((
+                    ConstantExpr {
                         span,
-                        func_name: func_name.to_string(),
-                        args: vec![arg],
-                        lambda_expr: Box::new(remote_lambda_expr),
-                        lambda_display,
-                        return_type: Box::new(return_type.clone()),
+                        value: Scalar::EmptyMap,
                     }
                     .into(),
-                    return_type,
+                    DataType::EmptyMap,
+                )));
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "invalid arguments for lambda function, argument data type must be a map"
+                        .to_string(),
+                )
+                .set_span(span));
+            }
+        };
+
+        let columns = vec![
+            (params[0].clone(), key_ty.clone()),
+            (params[1].clone(), value_ty.clone()),
+        ];
+        let box (lambda_expr, lambda_type) =
+            parse_lambda_expr(self.ctx.clone(), &columns, &lambda.expr)?;
+
+        let return_type = if func_name == "map_filter" {
+            if lambda_type.remove_nullable() != DataType::Boolean {
+                return Err(ErrorCode::SemanticError(
+                    "invalid lambda function for `map_filter`, the result data type of lambda function must be boolean".to_string()
                 )
+                .set_span(span));
+            }
+            arg_type.clone()
+        } else {
+            let map_type =
+                DataType::Map(Box::new(DataType::Tuple(vec![key_ty.clone(), lambda_type])));
+            if arg_type.is_nullable() {
+                map_type.wrap_nullable()
+            } else {
+                map_type
             }
         };
 
-        Ok(Box::new((lambda_func, data_type)))
+        let lambda_schema = DataSchema::new(vec![
+            DataField::new("0", key_ty),
+            DataField::new("1", value_ty),
+        ]);
+        let expr = lambda_expr
+            .type_check(&lambda_schema)?
+            .project_column_ref(|index| lambda_schema.index_of(&index.to_string()).unwrap());
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let remote_lambda_expr = expr.as_remote_expr();
+        let lambda_display = format!("{:?} -> {}", params, expr.sql_display());
+
+        Ok(Box::new((
+            LambdaFunc {
+                span,
+                func_name: func_name.to_string(),
+                args: vec![arg],
+                lambda_expr: Box::new(remote_lambda_expr),
+                lambda_display,
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
     }
 
     fn resolve_score_search_function(
@@ -2288,10 +3442,10 @@ impl<'a> TypeChecker<'a> {
         }
 
         if index_schema.is_none() {
-            let column_names = query_fields.iter().map(|c| c.0.clone()).join(", ");
+            let column_names = query_fields.iter().map(|c| &c.0).collect::<Vec<_>>();
             return Err(ErrorCode::SemanticError(format!(
                 "columns {} don't have inverted index",
-                column_names
+                display_candidates(&column_names)
             ))
             .set_span(span));
         }
@@ -2369,16 +3523,88 @@ impl<'a> TypeChecker<'a> {
             arg_types.push(arg_type);
         }
 
-        // rewrite substr('xx', 0, xx) -> substr('xx', 1, xx)
+        // substring_index(str, delim, count), a negative count counts the delimiters from the right.
+        if func_name == "substring_index" || func_name == "substr_index" {
+            if args.len() != 3 {
+                return Err(ErrorCode::SemanticError(format!(
+                    "{func_name} expects 3 arguments (str, delim, count), but got {}",
+                    args.len()
+                ))
+                .set_span(span));
+            }
+            let count_type = arg_types[2].remove_nullable();
+            if !count_type.is_integer() && count_type != DataType::Null {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The count of {func_name} must be an integer, but got {}",
+                    arg_types[2]
+                ))
+                .set_span(span));
+            }
+        }
+
+        // locate(substr, str, pos), the search starts from the position `pos`.
+        if func_name == "locate" && args.len() == 3 {
+            let pos_type = arg_types[2].remove_nullable();
+            if !pos_type.is_integer() && pos_type != DataType::Null {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The start position of {func_name} must be an integer, but got {}",
+                    arg_types[2]
+                ))
+                .set_span(span));
+            }
+        }
+
+        if matches!(
+            func_name,
+            "to_timestamp" | "to_datetime" | "try_to_timestamp" | "try_to_datetime"
+        ) {
+            Self::rewrite_to_timestamp_args(span, func_name, &mut args, &arg_types)?;
+        }
+
+        if func_name == "bitmap_subset_in_range" || func_name == "bitmap_range" {
+            Self::check_bitmap_range_args(span, func_name, &args, &arg_types)?;
+        }
+
+        if func_name == "regexp_extract_all" {
+            Self::check_regexp_extract_all_args(span, func_name, &args, &arg_types)?;
+        }
+
+        if func_name == "regexp_replace" {
+            self.check_regexp_replace_args(span, func_name, &args)?;
+        }
+
+        if func_name == "json_object" || func_name == "json_object_keep_null" {
+            Self::check_json_object_args(span, func_name, &arg_types)?;
+        }
+
+        if func_name == "json_strip_nulls" || func_name == "json_remove_nulls" {
+            Self::check_json_strip_nulls_args(span, func_name, &args, &arg_types)?;
+        }
+
+        if func_name == "map_from_arrays" {
+            Self::check_map_from_arrays_args(span, func_name, &args, &arg_types)?;
+        }
+
+        if matches!(
+            func_name,
+            "cosine_distance" | "l1_distance" | "l2_distance" | "vector_norm"
+        ) {
+            Self::check_vector_args(span, func_name, &args, &arg_types)?;
+        }
+
+        // rewrite substr('xx', 0, xx) -> substr('xx', 1, xx) in MySQL and Hive dialects
+        let dialect = self.ctx.get_settings().get_sql_dialect()?;
         if (func_name == "substr" || func_name == "substring")
-            && self
-                .ctx
-                .get_settings()
-                .get_sql_dialect()
-                .unwrap()
-                .substr_index_zero_literal_as_one()
+            && dialect.substr_index_zero_literal_as_one()
+            && Self::rewrite_substring(&mut args)
         {
-            Self::rewrite_substring(&mut args);
+            self.ctx.push_warning(format!(
+                "`{func_name}` index 0 is treated as 1 in {dialect:?} dialect"
+            ));
+        }
+
+        if let Some(collation_func_name) = self.function_need_collation(func_name, &arg_types)? {
+            return self.resolve_scalar_function_call(span, &collation_func_name, params, args);
         }
 
         if func_name == "grouping" {
@@ -2397,6 +3623,71 @@ impl<'a> TypeChecker<'a> {
         self.resolve_scalar_function_call(span, func_name, params, args)
     }
 
+    /// The flags of `regexp_like(str, pattern, flags)` must be a constant string of
+    /// `c` (case-sensitive), `i` (case-insensitive), `m` (multi-line) and `n` (`.` matches
+    /// newlines).
+    fn check_regexp_flags(&self, flags: &EExpr<ColumnBinding>) -> Result<()> {
+        let flags_value = self.try_fold_constant(flags).map(|box (scalar, _)| scalar);
+        let flags_value = match flags_value {
+            Some(ScalarExpr::ConstantExpr(ConstantExpr { value, .. })) => value,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "The flags of regexp_like must be a constant string".to_string(),
+                )
+                .set_span(flags.span()));
+            }
+        };
+        match flags_value {
+            Scalar::String(flags_value) => {
+                match flags_value.chars().find(|c| !matches!(c, 'c' | 'i' | 'm' | 'n')) {
+                    Some(c) => Err(ErrorCode::SemanticError(format!(
+                        "Invalid flag '{c}' of regexp_like, the allowed flags are 'c', 'i', 'm' and 'n'"
+                    ))
+                    .set_span(flags.span())),
+                    None => Ok(()),
+                }
+            }
+            Scalar::Null => Ok(()),
+            _ => Err(ErrorCode::SemanticError(
+                "The flags of regexp_like must be a constant string".to_string(),
+            )
+            .set_span(flags.span())),
+        }
+    }
+
+    /// Reject comparing a temporal value with a string literal if the implicit cast
+    /// is disabled by `enable_implicit_string_temporal_cast`.
+    fn check_implicit_string_temporal_cast(
+        &self,
+        span: Span,
+        temporal_arg: &ScalarExpr,
+        literal_arg: &ScalarExpr,
+    ) -> Result<()> {
+        let temporal_type = temporal_arg.data_type()?.remove_nullable();
+        if !matches!(temporal_type, DataType::Timestamp | DataType::Date) {
+            return Ok(());
+        }
+        let ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::String(literal),
+            ..
+        }) = literal_arg
+        else {
+            return Ok(());
+        };
+        if self
+            .ctx
+            .get_settings()
+            .get_enable_implicit_string_temporal_cast()?
+        {
+            return Ok(());
+        }
+        Err(ErrorCode::SemanticError(format!(
+            "Cannot compare {temporal_type} with string literal '{literal}' because enable_implicit_string_temporal_cast is disabled, cast the literal explicitly, e.g. '{literal}'::{}",
+            temporal_type.sql_name()
+        ))
+        .set_span(span))
+    }
+
     pub fn resolve_scalar_function_call(
         &self,
         span: Span,
@@ -2430,6 +3721,11 @@ impl<'a> TypeChecker<'a> {
             params.push(Scalar::Number(NumberScalar::Int64(scale)));
         }
 
+        if ComparisonOp::try_from_func_name(func_name).is_some() && args.len() == 2 {
+            self.check_implicit_string_temporal_cast(span, &args[0], &args[1])?;
+            self.check_implicit_string_temporal_cast(span, &args[1], &args[0])?;
+        }
+
         let raw_expr = RawExpr::FunctionCall {
             span,
             name: func_name.to_string(),
@@ -2438,6 +3734,17 @@ impl<'a> TypeChecker<'a> {
         };
         let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
 
+        if func_name == "regexp_like" {
+            if let databend_common_expression::Expr::FunctionCall {
+                args: checked_args, ..
+            } = &expr
+            {
+                if let Some(flags) = checked_args.get(2) {
+                    self.check_regexp_flags(flags)?;
+                }
+            }
+        }
+
         // Run constant folding for arguments of the scalar function.
         // This will be helpful to simplify some constant expressions, especially
         // the implicitly casted literal values, e.g. `timestamp > '2001-01-01'`
@@ -2585,21 +3892,54 @@ impl<'a> TypeChecker<'a> {
         interval: &Expr,
         date: &Expr,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
-        let func_name = format!("add_{}s", interval_kind.to_string().to_lowercase());
-
-        let mut args = vec![];
-        let mut arg_types = vec![];
+        let (mut date, date_type) = *self.resolve(date)?;
+        let (mut interval, _) = *self.resolve(interval)?;
 
-        let (date, date_type) = *self.resolve(date)?;
-        args.push(date);
-        arg_types.push(date_type);
-
-        let (interval, interval_type) = *self.resolve(interval)?;
+        // Week, day of year and day of week are all counted in days.
+        let func_name = match interval_kind {
+            ASTIntervalKind::Week => {
+                interval = FunctionCall {
+                    span,
+                    func_name: "multiply".to_string(),
+                    params: vec![],
+                    arguments: vec![
+                        interval,
+                        ConstantExpr {
+                            span,
+                            value: Scalar::Number(7u64.into()),
+                        }
+                        .into(),
+                    ],
+                }
+                .into();
+                "add_days".to_string()
+            }
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => "add_days".to_string(),
+            _ => format!("add_{}s", interval_kind.to_string().to_lowercase()),
+        };
 
-        args.push(interval);
-        arg_types.push(interval_type);
+        // Date arithmetic in days or larger units stays a Date, while hours, minutes
+        // and seconds promote the Date to a Timestamp in the session timezone first.
+        if date_type.remove_nullable() == DataType::Date
+            && matches!(
+                interval_kind,
+                ASTIntervalKind::Hour | ASTIntervalKind::Minute | ASTIntervalKind::Second
+            )
+        {
+            let mut target_type = DataType::Timestamp;
+            if date_type.is_nullable() {
+                target_type = target_type.wrap_nullable();
+            }
+            date = CastExpr {
+                span,
+                is_try: false,
+                argument: Box::new(date),
+                target_type: Box::new(target_type),
+            }
+            .into();
+        }
 
-        self.resolve_scalar_function_call(span, &func_name, vec![], args)
+        self.resolve_scalar_function_call(span, &func_name, vec![], vec![date, interval])
     }
 
     pub fn resolve_date_trunc(
@@ -2757,6 +4097,8 @@ impl<'a> TypeChecker<'a> {
             "database",
             "currentdatabase",
             "current_database",
+            "currentcatalog",
+            "current_catalog",
             "version",
             "user",
             "currentuser",
@@ -2764,6 +4106,7 @@ impl<'a> TypeChecker<'a> {
             "current_role",
             "connection_id",
             "timezone",
+            "current_setting",
             "nullif",
             "ifnull",
             "nvl",
@@ -2777,12 +4120,44 @@ impl<'a> TypeChecker<'a> {
             "array_aggregate",
             "to_variant",
             "try_to_variant",
+            "array_to_json",
+            "map_to_json",
+            "to_string",
+            "to_varchar",
+            "to_text",
             "greatest",
             "least",
             "stream_has_data",
         ]
     }
 
+    /// Resolve `coalesce(non_null_constant, ...)` into the constant, the constant is cast to
+    /// the type the coalesce returns, i.e. the nullable common super type of all the arguments.
+    fn resolve_coalesce_constant(
+        &mut self,
+        constant: &Expr,
+        others: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let box (scalar, mut common_type) = self.resolve(constant)?;
+        let auto_cast_rules = BUILTIN_FUNCTIONS.get_auto_cast_rules("if");
+        for arg in others {
+            let box (_, arg_type) = self.resolve(arg)?;
+            common_type = type_check::common_super_type(
+                common_type.clone(),
+                arg_type.clone(),
+                auto_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "The arguments of coalesce have incompatible types {common_type} and {arg_type}"
+                ))
+                .set_span(arg.span())
+            })?;
+        }
+        let common_type = common_type.wrap_nullable();
+        Ok(Box::new((wrap_cast(&scalar, &common_type), common_type)))
+    }
+
     fn try_rewrite_sugar_function(
         &mut self,
         span: Span,
@@ -2796,6 +4171,10 @@ impl<'a> TypeChecker<'a> {
                     value: Literal::String(self.ctx.get_current_database()),
                 }))
             }
+            ("currentcatalog" | "current_catalog", &[]) => Some(self.resolve(&Expr::Literal {
+                span,
+                value: Literal::String(self.ctx.get_current_catalog()),
+            })),
             ("version", &[]) => Some(self.resolve(&Expr::Literal {
                 span,
                 value: Literal::String(self.ctx.get_fuse_version()),
@@ -2829,6 +4208,34 @@ impl<'a> TypeChecker<'a> {
                     value: Literal::String(tz),
                 }))
             }
+            ("current_setting", &[arg]) => {
+                // current_setting(name) is folded to the current value of the setting.
+                let res: Result<String> = try {
+                    let box (scalar, _) = self.resolve(arg)?;
+                    let ScalarExpr::ConstantExpr(ConstantExpr {
+                        value: Scalar::String(name),
+                        ..
+                    }) = scalar
+                    else {
+                        return Some(Err(ErrorCode::SemanticError(
+                            "current_setting only supports a constant setting name",
+                        )
+                        .set_span(span)));
+                    };
+                    self.ctx
+                        .get_settings()
+                        .get_setting_value(&name.to_lowercase())
+                        .map_err(|e| e.set_span(span))?
+                };
+
+                Some(match res {
+                    Ok(value) => self.resolve(&Expr::Literal {
+                        span,
+                        value: Literal::String(value),
+                    }),
+                    Err(e) => Err(e),
+                })
+            }
             ("nullif", &[arg_x, arg_y]) => {
                 // Rewrite nullif(x, y) to if(x = y, null, x)
                 Some(self.resolve_function(span, "if", vec![], &[
@@ -2878,6 +4285,8 @@ impl<'a> TypeChecker<'a> {
                             params: vec![],
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                         },
                     }),
                 )
@@ -2894,6 +4303,8 @@ impl<'a> TypeChecker<'a> {
                             params: vec![],
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                         },
                     }),
                 )
@@ -2913,6 +4324,8 @@ impl<'a> TypeChecker<'a> {
                             params: vec![],
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                         },
                     };
                     new_args.push(is_not_error);
@@ -2930,17 +4343,33 @@ impl<'a> TypeChecker<'a> {
                 // coalesce(arg0, arg1, ..., argN) is essentially
                 // if(is_not_null(arg0), assume_not_null(arg0), is_not_null(arg1), assume_not_null(arg1), ..., argN)
                 // with constant Literal::Null arguments removed.
-                let mut new_args = Vec::with_capacity(args.len() * 2 + 1);
-
-                for arg in args.iter() {
-                    if let Expr::Literal {
-                        span: _,
-                        value: Literal::Null,
-                    } = arg
-                    {
-                        continue;
+                let args = args
+                    .iter()
+                    .copied()
+                    .filter(|arg| {
+                        !matches!(arg, Expr::Literal {
+                            value: Literal::Null,
+                            ..
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                match args.first() {
+                    // coalesce(all_null) => null
+                    None => {
+                        return Some(self.resolve(&Expr::Literal {
+                            span,
+                            value: Literal::Null,
+                        }));
                     }
+                    // coalesce(non_null_constant, ...) => non_null_constant
+                    Some(arg @ Expr::Literal { .. }) => {
+                        return Some(self.resolve_coalesce_constant(arg, &args[1..]));
+                    }
+                    _ => {}
+                }
 
+                let mut new_args = Vec::with_capacity(args.len() * 2 + 1);
+                for arg in args.iter() {
                     let is_not_null_expr = Expr::IsNull {
                         span,
                         expr: Box::new((*arg).clone()),
@@ -2956,6 +4385,8 @@ impl<'a> TypeChecker<'a> {
                             params: vec![],
                             window: None,
                             lambda: None,
+                            order_by: vec![],
+                            filter: None,
                         },
                     };
 
@@ -2967,18 +4398,6 @@ impl<'a> TypeChecker<'a> {
                     value: Literal::Null,
                 });
 
-                // coalesce(all_null) => null
-                if new_args.len() == 1 {
-                    new_args.push(Expr::Literal {
-                        span,
-                        value: Literal::Null,
-                    });
-                    new_args.push(Expr::Literal {
-                        span,
-                        value: Literal::Null,
-                    });
-                }
-
                 let args_ref: Vec<&Expr> = new_args.iter().collect();
                 Some(self.resolve_function(span, "if", vec![], &args_ref))
             }
@@ -3029,56 +4448,10 @@ impl<'a> TypeChecker<'a> {
                 if args.is_empty() || args.len() > 3 {
                     return None;
                 }
-                let mut asc = true;
-                let mut nulls_first = true;
-                if args.len() >= 2 {
-                    let box (arg, _) = self.resolve(args[1]).ok()?;
-                    if let Ok(arg) = ConstantExpr::try_from(arg) {
-                        if let Scalar::String(sort_order) = arg.value {
-                            if sort_order.eq_ignore_ascii_case("asc") {
-                                asc = true;
-                            } else if sort_order.eq_ignore_ascii_case("desc") {
-                                asc = false;
-                            } else {
-                                return Some(Err(ErrorCode::SemanticError(
-                                    "Sorting order must be either ASC or DESC",
-                                )));
-                            }
-                        } else {
-                            return Some(Err(ErrorCode::SemanticError(
-                                "Sorting order must be either ASC or DESC",
-                            )));
-                        }
-                    } else {
-                        return Some(Err(ErrorCode::SemanticError(
-                            "Sorting order must be a constant string",
-                        )));
-                    }
-                }
-                if args.len() == 3 {
-                    let box (arg, _) = self.resolve(args[2]).ok()?;
-                    if let Ok(arg) = ConstantExpr::try_from(arg) {
-                        if let Scalar::String(nulls_order) = arg.value {
-                            if nulls_order.eq_ignore_ascii_case("nulls first") {
-                                nulls_first = true;
-                            } else if nulls_order.eq_ignore_ascii_case("nulls last") {
-                                nulls_first = false;
-                            } else {
-                                return Some(Err(ErrorCode::SemanticError(
-                                    "Null sorting order must be either NULLS FIRST or NULLS LAST",
-                                )));
-                            }
-                        } else {
-                            return Some(Err(ErrorCode::SemanticError(
-                                "Null sorting order must be either NULLS FIRST or NULLS LAST",
-                            )));
-                        }
-                    } else {
-                        return Some(Err(ErrorCode::SemanticError(
-                            "Null sorting order must be a constant string",
-                        )));
-                    }
-                }
+                let (asc, nulls_first) = match self.resolve_sort_order(&args[1..]) {
+                    Ok(order) => order,
+                    Err(e) => return Some(Err(e)),
+                };
                 let func_name = match (asc, nulls_first) {
                     (true, true) => "array_sort_asc_null_first",
                     (false, true) => "array_sort_desc_null_first",
@@ -3118,14 +4491,18 @@ impl<'a> TypeChecker<'a> {
                 let box (scalar, data_type) = self.resolve(args[0]).ok()?;
                 self.resolve_cast_to_variant(span, &data_type, &scalar, true)
             }
-            ("greatest", args) => {
-                let (array, _) = *self.resolve_function(span, "array", vec![], args).ok()?;
-                Some(self.resolve_scalar_function_call(span, "array_max", vec![], vec![array]))
-            }
-            ("least", args) => {
-                let (array, _) = *self.resolve_function(span, "array", vec![], args).ok()?;
-                Some(self.resolve_scalar_function_call(span, "array_min", vec![], vec![array]))
+            (func_name @ ("array_to_json" | "map_to_json"), &[arg]) => {
+                Some(self.resolve_container_to_variant(span, func_name, arg))
             }
+            ("to_string" | "to_varchar" | "to_text", &[arg]) => Some(self.resolve(&Expr::Cast {
+                span,
+                expr: Box::new(arg.clone()),
+                target_type: TypeName::String,
+                pg_style: false,
+                format: None,
+            })),
+            ("greatest", args) => Some(self.resolve_greatest_least(span, "array_max", args)),
+            ("least", args) => Some(self.resolve_greatest_least(span, "array_min", args)),
             _ => None,
         }
     }
@@ -3207,6 +4584,17 @@ impl<'a> TypeChecker<'a> {
         let mut vals = Vec::with_capacity(kvs.len());
         for (key_expr, val_expr) in kvs {
             let box (key_arg, _data_type) = self.resolve_literal(span, key_expr)?;
+            if let ScalarExpr::ConstantExpr(ConstantExpr { value, .. }) = &key_arg {
+                let is_duplicated = keys.iter().any(|key| {
+                    matches!(key, ScalarExpr::ConstantExpr(ConstantExpr { value: v, .. }) if v == value)
+                });
+                if is_duplicated {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "map keys have to be unique, but key {key_expr} is duplicated"
+                    ))
+                    .set_span(span));
+                }
+            }
             keys.push(key_arg);
             let box (val_arg, _data_type) = self.resolve(val_expr)?;
             vals.push(val_arg);
@@ -3230,6 +4618,32 @@ impl<'a> TypeChecker<'a> {
         self.resolve_scalar_function_call(span, "tuple", vec![], args)
     }
 
+    /// Resolve the MySQL-compatible system variable `@@name` to a string literal.
+    /// Unknown variables are resolved to `NULL` with a warning.
+    fn resolve_system_variable(
+        &mut self,
+        span: Span,
+        name: &str,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let settings = self.ctx.get_settings();
+        if settings.get_sql_dialect()? != Dialect::MySQL {
+            return Err(ErrorCode::SemanticError(format!(
+                "system variable `@@{name}` is only supported in MySQL dialect"
+            ))
+            .set_span(span));
+        }
+
+        let value = match settings.get_mysql_variable(name)? {
+            Some(value) => Literal::String(value),
+            None => {
+                self.ctx
+                    .push_warning(format!("unknown system variable `@@{name}`, returns NULL"));
+                Literal::Null
+            }
+        };
+        self.resolve(&Expr::Literal { span, value })
+    }
+
     fn resolve_like(
         &mut self,
         op: &BinaryOperator,
@@ -3275,8 +4689,11 @@ impl<'a> TypeChecker<'a> {
             return Ok(None);
         }
 
+        let user_mgr = UserApiProvider::instance();
+        let tenant = self.ctx.get_tenant();
+        let policy = self.ctx.get_meta_call_policy()?;
         let udf = databend_common_base::runtime::block_on(
-            UserApiProvider::instance().get_udf(&self.ctx.get_tenant(), udf_name),
+            policy.read("get_udf", || user_mgr.get_udf(&tenant, udf_name)),
         )?;
 
         let Some(udf) = udf else {
@@ -3311,16 +4728,8 @@ impl<'a> TypeChecker<'a> {
             ));
         }
 
-        let udf_server_allow_list = &GlobalConfig::instance().query.udf_server_allow_list;
         let address = &udf_definition.address;
-        if udf_server_allow_list
-            .iter()
-            .all(|addr| addr.trim_end_matches('/') != address.trim_end_matches('/'))
-        {
-            return Err(ErrorCode::InvalidArgument(format!(
-                "Unallowed UDF server address, '{address}' is not in udf_server_allow_list"
-            )));
-        }
+        let addresses = check_udf_server_addresses(address)?;
 
         if arguments.len() != udf_definition.arg_types.len() {
             return Err(ErrorCode::InvalidArgument(format!(
@@ -3331,20 +4740,61 @@ impl<'a> TypeChecker<'a> {
             .set_span(span));
         }
 
+        if self
+            .ctx
+            .get_settings()
+            .get_enable_udf_server_schema_check()?
+        {
+            databend_common_base::runtime::block_on(self.check_udf_server_schema(
+                &name,
+                &addresses,
+                &udf_definition,
+            ))
+            .map_err(|err| err.set_span(span))?;
+        }
+
         let mut args = Vec::with_capacity(arguments.len());
-        for (argument, dest_type) in arguments.iter().zip(udf_definition.arg_types.iter()) {
+        for (idx, (argument, dest_type)) in arguments
+            .iter()
+            .zip(udf_definition.arg_types.iter())
+            .enumerate()
+        {
             let box (arg, ty) = self.resolve(argument)?;
             if ty != *dest_type {
-                args.push(wrap_cast(&arg, dest_type));
+                let cast_arg = wrap_cast(&arg, dest_type);
+                // Type check the cast, so that an argument which can't be cast to the
+                // declared type is reported at plan time instead of failing at runtime.
+                if cast_arg.as_expr().is_err() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Argument {} of UDF '{}' has type {}, which cannot be cast to the declared type {}",
+                        idx + 1,
+                        name,
+                        ty,
+                        dest_type
+                    ))
+                    .set_span(argument.span()));
+                }
+                args.push(cast_arg);
             } else {
                 args.push(arg);
             }
         }
 
+        // Calls of a deterministic function don't prevent the result from being cached,
+        // and the calls with constant arguments are folded at plan time.
+        if udf_definition.deterministic {
+            if let Some(folded) =
+                self.try_fold_udf_server_call(span, &addresses, &udf_definition, &args)
+            {
+                return Ok(folded);
+            }
+        } else {
+            self.ctx.set_cacheable(false);
+        }
+
         let arg_names = arguments.iter().map(|arg| format!("{}", arg)).join(", ");
         let display_name = format!("{}({})", udf_definition.handler, arg_names);
 
-        self.ctx.set_cacheable(false);
         Ok(Box::new((
             UDFCall {
                 span,
@@ -3361,6 +4811,143 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Evaluates a call of a deterministic UDF server function by calling the server once if
+    /// all of its arguments are constants. The request is bounded by the external server
+    /// timeouts, the call is kept and evaluated at runtime if it can't be folded.
+    fn try_fold_udf_server_call(
+        &self,
+        span: Span,
+        addresses: &[&str],
+        udf_definition: &UDFServer,
+        args: &[ScalarExpr],
+    ) -> Option<Box<(ScalarExpr, DataType)>> {
+        // Variant values need to be transformed before being sent to the server.
+        if args.is_empty() || contains_variant(&udf_definition.return_type) {
+            return None;
+        }
+
+        let mut entries = Vec::with_capacity(args.len());
+        for arg in args {
+            let expr = arg.as_expr().ok()?;
+            match ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS) {
+                (
+                    EExpr::Constant {
+                        scalar, data_type, ..
+                    },
+                    _,
+                ) if !contains_variant(&data_type) => {
+                    entries.push(BlockEntry::new(data_type, Value::Scalar(scalar)));
+                }
+                _ => return None,
+            }
+        }
+
+        let fields = entries
+            .iter()
+            .enumerate()
+            .map(|(idx, entry)| DataField::new(&format!("arg{}", idx + 1), entry.data_type.clone()))
+            .collect::<Vec<_>>();
+        let data_schema = DataSchema::new(fields);
+        let args_block = DataBlock::new(entries, 1);
+
+        let scalar = match databend_common_base::runtime::block_on(self.call_udf_server_once(
+            addresses,
+            udf_definition,
+            &data_schema,
+            args_block,
+        )) {
+            Ok(scalar) => scalar,
+            Err(err) => {
+                warn!(
+                    "Failed to fold the call of UDF '{}' at plan time: {}",
+                    udf_definition.handler, err
+                );
+                return None;
+            }
+        };
+
+        let scalar = shrink_scalar(scalar);
+        let ty = scalar.as_ref().infer_data_type();
+        Some(Box::new((
+            ConstantExpr {
+                span,
+                value: scalar,
+            }
+            .into(),
+            ty,
+        )))
+    }
+
+    /// Calls the UDF server with a single row of arguments, returns the result of the row.
+    async fn call_udf_server_once(
+        &self,
+        addresses: &[&str],
+        udf_definition: &UDFServer,
+        data_schema: &DataSchema,
+        args_block: DataBlock,
+    ) -> Result<Scalar> {
+        let input_batch = args_block.to_record_batch_with_dataschema(data_schema)?;
+        let mut client = connect_udf_server(&self.ctx.get_settings(), addresses).await?;
+        let result_batch = client
+            .do_exchange(&udf_definition.handler, input_batch)
+            .await?;
+
+        let schema = DataSchema::try_from(&(*result_batch.schema()))?;
+        let (result_block, result_schema) = DataBlock::from_record_batch(&schema, &result_batch)?;
+        let result_fields = result_schema.fields();
+        if result_fields.is_empty() || result_block.num_rows() != 1 {
+            return Err(ErrorCode::UDFDataError(format!(
+                "UDF server should return 1 row, but it returned {} rows",
+                result_block.num_rows()
+            )));
+        }
+        if result_fields[0].data_type() != &udf_definition.return_type {
+            return Err(ErrorCode::UDFSchemaMismatch(format!(
+                "UDF server return incorrect type, expected: {}, but got: {}",
+                udf_definition.return_type,
+                result_fields[0].data_type()
+            )));
+        }
+
+        let entry = result_block.get_by_offset(0);
+        Ok(entry.value.index(0).unwrap().to_owned())
+    }
+
+    /// Check the declared argument types and return type of a UDF server function against
+    /// the schema exposed by the server, which is cached in the session once fetched.
+    async fn check_udf_server_schema(
+        &self,
+        name: &str,
+        addresses: &[&str],
+        udf_definition: &UDFServer,
+    ) -> Result<()> {
+        let address = &udf_definition.address;
+        let handler = &udf_definition.handler;
+        let (arg_types, return_type) = match self.ctx.get_udf_server_schema(address, handler) {
+            Some(schema) => schema,
+            None => {
+                let mut client = connect_udf_server(&self.ctx.get_settings(), addresses).await?;
+                let schema = client.get_schema(handler).await?;
+                self.ctx
+                    .set_udf_server_schema(address.clone(), handler.clone(), schema.clone());
+                schema
+            }
+        };
+
+        if arg_types != udf_definition.arg_types || return_type != udf_definition.return_type {
+            let display_types =
+                |types: &[DataType]| types.iter().map(|ty| ty.to_string()).join(", ");
+            return Err(ErrorCode::InvalidArgument(format!(
+                "UDF '{name}' does not match the UDF server, expected: ({}) -> {}, actual: ({}) -> {}",
+                display_types(&udf_definition.arg_types),
+                udf_definition.return_type,
+                display_types(&arg_types),
+                return_type
+            )));
+        }
+        Ok(())
+    }
+
     async fn resolve_udf_with_stage(&mut self, udf_definition: &UDFScript) -> Result<UDFType> {
         let file_location = match udf_definition.code.strip_prefix('@') {
             Some(location) => FileLocation::Stage(location.to_string()),
@@ -3473,52 +5060,301 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
-    fn resolve_lambda_udf(
+    fn resolve_lambda_udf(
+        &mut self,
+        span: Span,
+        func_name: String,
+        arguments: &[Expr],
+        udf_definition: LambdaUDF,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let parameters = udf_definition.parameters;
+        let defaults = udf_definition.defaults;
+        let required = parameters.len() - defaults.len();
+        if arguments.len() < required || arguments.len() > parameters.len() {
+            let expected = if arguments.len() < required {
+                required
+            } else {
+                parameters.len()
+            };
+            return Err(ErrorCode::SyntaxException(format!(
+                "Require {} parameters, but got: {}",
+                expected,
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+        let settings = self.ctx.get_settings();
+        let sql_dialect = settings.get_sql_dialect()?;
+        let expr = parse_persisted_expr(
+            PersistedObjectKind::Udf,
+            &func_name,
+            &udf_definition.definition,
+            udf_definition.definition_checksum,
+            sql_dialect,
+        )
+        .map_err(|e| e.set_span(span))?;
+        let mut args_map = HashMap::new();
+        for (idx, parameter) in parameters.iter().enumerate() {
+            let argument = match arguments.get(idx) {
+                Some(argument) => (*argument).clone(),
+                // The missing trailing arguments are filled with the defaults.
+                None => {
+                    let default = defaults.get(parameter).ok_or_else(|| {
+                        ErrorCode::SyntaxException(format!(
+                            "Parameter {parameter} of FUNCTION {func_name} has no default value"
+                        ))
+                        .set_span(span)
+                    })?;
+                    parse_persisted_expr(
+                        PersistedObjectKind::Udf,
+                        &func_name,
+                        default,
+                        None,
+                        sql_dialect,
+                    )
+                    .map_err(|e| e.set_span(span))?
+                }
+            };
+            args_map.insert(parameter, argument);
+        }
+        let udf_expr = self
+            .clone_expr_with_replacement(&expr, &|nest_expr| {
+                if let Expr::ColumnRef { column, .. } = nest_expr {
+                    if let Some(arg) = args_map.get(&column.column.name().to_string()) {
+                        return Ok(Some(arg.clone()));
+                    }
+                }
+                Ok(None)
+            })
+            .map_err(|e| e.set_span(span))?;
+
+        let max_depth = settings.get_max_udf_expansion_depth()?;
+        if self.udf_expansion_chain.len() >= max_depth {
+            return Err(ErrorCode::SemanticError(format!(
+                "UDF expansion depth exceeds max_udf_expansion_depth {max_depth}: {} -> {func_name}",
+                self.udf_expansion_chain.join(" -> ")
+            ))
+            .set_span(span));
+        }
+        self.udf_expansion_chain.push(func_name.clone());
+        let result = self.resolve(&udf_expr);
+        self.udf_expansion_chain.pop();
+        let scalar = result
+            .map_err(|e| e.add_message_back(format!(" (while resolving FUNCTION {func_name})")))?;
+        Ok(Box::new((
+            UDFLambdaCall {
+                span,
+                func_name,
+                scalar: Box::new(scalar.0),
+            }
+            .into(),
+            scalar.1,
+        )))
+    }
+
+    fn resolve_virtual_computed_expr(
+        &mut self,
+        span: Span,
+        column: &ColumnBinding,
+        virtual_computed_expr: &str,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let name = column.qualified_name();
+        let expr = parse_persisted_expr(
+            PersistedObjectKind::ComputedColumn,
+            &name,
+            virtual_computed_expr,
+            // The checksum is verified when the table is bound.
+            None,
+            self.dialect,
+        )
+        .map_err(|e| e.set_span(span))?;
+        self.resolve(&expr)
+            .map_err(|e| e.add_message_back(format!(" (while resolving COMPUTED COLUMN {name})")))
+    }
+
+    /// An alias in ORDER BY or HAVING takes precedence over the column of the same name
+    /// in the FROM clause, push a warning if the alias is not the column itself.
+    fn warn_alias_shadowing_column(&self, ident: &Identifier, alias: &str, scalar: &ScalarExpr) {
+        let clause = match self.bind_context.expr_context {
+            ExprContext::OrderByClause => "ORDER BY",
+            ExprContext::HavingClause => "HAVING",
+            _ => return,
+        };
+        let column = normalize_identifier(ident, self.name_resolution_ctx);
+        let Ok(NameResolutionResult::Column(column_binding)) =
+            self.bind_context
+                .resolve_name(None, None, &column, &[], self.name_resolution_ctx)
+        else {
+            return;
+        };
+        if let ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }) = scalar {
+            if column.index == column_binding.index {
+                return;
+            }
+        }
+        self.ctx.push_warning(format!(
+            "{} in {clause} is resolved to the select list alias rather than the column of the same name",
+            display_ident(alias)
+        ));
+    }
+
+    /// Resolve `CAST(expr AS target_type FORMAT 'format')` by rewriting it to the
+    /// corresponding format function, e.g. `to_date(expr, '%d/%m/%Y')` for
+    /// `CAST(expr AS DATE FORMAT 'DD/MM/YYYY')`.
+    fn resolve_cast_with_format(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        target_type: &TypeName,
+        format: &str,
+        is_try: bool,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let format = cast_format_to_strftime(format).map_err(|e| e.set_span(span))?;
+        let box (scalar, data_type) = self.resolve(expr)?;
+        if is_try {
+            self.record_try_cast(&data_type, target_type)?;
+        }
+        let func_name = match (data_type.remove_nullable(), target_type) {
+            // The format doesn't matter for NULL, which is cast to NULL of the target type.
+            (DataType::Null, TypeName::Date | TypeName::Timestamp | TypeName::String) => {
+                let target_type =
+                    DataType::from(&resolve_type_name(target_type, true)?).wrap_nullable();
+                return Ok(Box::new((
+                    CastExpr {
+                        span,
+                        is_try,
+                        argument: Box::new(scalar),
+                        target_type: Box::new(target_type.clone()),
+                    }
+                    .into(),
+                    target_type,
+                )));
+            }
+            (DataType::String, TypeName::Date) if is_try => "try_to_date",
+            (DataType::String, TypeName::Date) => "to_date",
+            (DataType::String, TypeName::Timestamp) if is_try => "try_to_timestamp",
+            (DataType::String, TypeName::Timestamp) => "to_timestamp",
+            (DataType::Date | DataType::Timestamp, TypeName::String) => "to_string",
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "CAST with FORMAT is only supported between STRING and DATE/TIMESTAMP, but got {data_type} to {target_type}"
+                ))
+                .set_span(span));
+            }
+        };
+        let format = ConstantExpr {
+            span,
+            value: Scalar::String(format),
+        };
+        self.resolve_scalar_function_call(span, func_name, vec![], vec![scalar, format.into()])
+    }
+
+    /// Record the source and target types of a `TRY_CAST` into the metadata shared by
+    /// the whole query if `enable_try_cast_diagnostics` is set.
+    fn record_try_cast(&mut self, source_type: &DataType, target_type: &TypeName) -> Result<()> {
+        if !self.ctx.get_settings().get_enable_try_cast_diagnostics()? {
+            return Ok(());
+        }
+        let diagnostic = TryCastDiagnostic {
+            source_type: source_type.clone(),
+            target_type: DataType::from(&resolve_type_name(target_type, true)?),
+        };
+        self.metadata.write().add_try_cast_diagnostic(diagnostic);
+        Ok(())
+    }
+
+    /// Resolve `greatest` and `least` as `array_max` and `array_min` of the arguments,
+    /// the result is folded to a constant if all the arguments are constants.
+    fn resolve_greatest_least(
+        &mut self,
+        span: Span,
+        array_func_name: &str,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args {
+            let box (argument, _) = self.resolve(arg)?;
+            arguments.push(argument);
+        }
+
+        if !arguments.is_empty()
+            && arguments
+                .iter()
+                .all(|arg| matches!(arg, ScalarExpr::ConstantExpr(_)))
+        {
+            let raw_expr = RawExpr::FunctionCall {
+                span,
+                name: array_func_name.to_string(),
+                params: vec![],
+                args: vec![RawExpr::FunctionCall {
+                    span,
+                    name: "array".to_string(),
+                    params: vec![],
+                    args: arguments.iter().map(|arg| arg.as_raw_expr()).collect(),
+                }],
+            };
+            let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
+            if let Some(constant) = self.try_fold_constant(&expr) {
+                return Ok(constant);
+            }
+        }
+
+        let (array, _) = *self.resolve_scalar_function_call(span, "array", vec![], arguments)?;
+        self.resolve_scalar_function_call(span, array_func_name, vec![], vec![array])
+    }
+
+    /// Resolve casting the named tuples of base table columns to string.
+    ///
+    /// Arrays, maps and tuples are formatted as JSON by `check_cast` on both the explicit
+    /// and the implicit casts, e.g. inserting an array into a string column. Only the
+    /// explicit casts know the field names of the tuples, which are kept as the object keys
+    /// like casting them to variant, the implicit casts format the tuples as JSON arrays.
+    fn resolve_cast_to_string(
+        &mut self,
+        span: Span,
+        source_type: &DataType,
+        scalar: &ScalarExpr,
+        is_try: bool,
+    ) -> Option<Result<Box<(ScalarExpr, DataType)>>> {
+        let variant = match self.resolve_cast_to_variant(span, source_type, scalar, is_try)? {
+            Ok(box (variant, _)) => variant,
+            Err(err) => return Some(Err(err)),
+        };
+        Some(self.resolve_scalar_function_call(span, "to_string", vec![], vec![variant]))
+    }
+
+    /// Resolve `array_to_json` and `map_to_json`, which serialize an array or a map to variant.
+    fn resolve_container_to_variant(
         &mut self,
         span: Span,
-        func_name: String,
-        arguments: &[Expr],
-        udf_definition: LambdaUDF,
+        func_name: &str,
+        arg: &Expr,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
-        let parameters = udf_definition.parameters;
-        if parameters.len() != arguments.len() {
-            return Err(ErrorCode::SyntaxException(format!(
-                "Require {} parameters, but got: {}",
-                parameters.len(),
-                arguments.len()
+        let box (scalar, data_type) = self.resolve(arg)?;
+        let (expected, valid) = if func_name == "array_to_json" {
+            let valid = matches!(
+                data_type.remove_nullable(),
+                DataType::Null | DataType::EmptyArray | DataType::Array(_)
+            );
+            ("ARRAY", valid)
+        } else {
+            let valid = matches!(
+                data_type.remove_nullable(),
+                DataType::Null | DataType::EmptyMap | DataType::Map(_)
+            );
+            ("MAP", valid)
+        };
+        if !valid {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} expects an argument of type {expected}, but got {}",
+                data_type.sql_name()
             ))
             .set_span(span));
         }
-        let settings = self.ctx.get_settings();
-        let sql_dialect = settings.get_sql_dialect()?;
-        let sql_tokens = tokenize_sql(udf_definition.definition.as_str())?;
-        let expr = parse_expr(&sql_tokens, sql_dialect)?;
-        let mut args_map = HashMap::new();
-        arguments.iter().enumerate().for_each(|(idx, argument)| {
-            if let Some(parameter) = parameters.get(idx) {
-                args_map.insert(parameter, (*argument).clone());
-            }
-        });
-        let udf_expr = self
-            .clone_expr_with_replacement(&expr, &|nest_expr| {
-                if let Expr::ColumnRef { column, .. } = nest_expr {
-                    if let Some(arg) = args_map.get(&column.column.name().to_string()) {
-                        return Ok(Some(arg.clone()));
-                    }
-                }
-                Ok(None)
-            })
-            .map_err(|e| e.set_span(span))?;
-        let scalar = self.resolve(&udf_expr)?;
-        Ok(Box::new((
-            UDFLambdaCall {
-                span,
-                func_name,
-                scalar: Box::new(scalar.0),
-            }
-            .into(),
-            scalar.1,
-        )))
+        if let Some(result) = self.resolve_cast_to_variant(span, &data_type, &scalar, false) {
+            return result;
+        }
+        self.resolve_scalar_function_call(span, "to_variant", vec![], vec![scalar])
     }
 
     fn resolve_cast_to_variant(
@@ -3528,15 +5364,26 @@ impl<'a> TypeChecker<'a> {
         scalar: &ScalarExpr,
         is_try: bool,
     ) -> Option<Result<Box<(ScalarExpr, DataType)>>> {
-        if !matches!(source_type.remove_nullable(), DataType::Tuple(_)) {
+        if !matches!(
+            source_type.remove_nullable(),
+            DataType::Tuple(_) | DataType::Array(_) | DataType::Map(_)
+        ) {
             return None;
         }
         // If the type of source column is a tuple, rewrite to json_object_keep_null function,
         // using the name of tuple inner fields as the object name.
+        // The tuples nested in arrays and map values are rewritten element by element.
         if let ScalarExpr::BoundColumnRef(BoundColumnRef { ref column, .. }) = scalar {
             let column_entry = self.metadata.read().column(column.index).clone();
             if let ColumnEntry::BaseTableColumn(BaseTableColumn { data_type, .. }) = column_entry {
-                let new_scalar = Self::rewrite_cast_to_variant(span, scalar, &data_type, is_try);
+                if !Self::contains_named_tuple(&data_type) {
+                    return None;
+                }
+                let new_scalar =
+                    match Self::rewrite_cast_to_variant(span, scalar, &data_type, is_try) {
+                        Ok(new_scalar) => new_scalar,
+                        Err(err) => return Some(Err(err)),
+                    };
                 let return_type = if is_try || source_type.is_nullable() {
                     DataType::Nullable(Box::new(DataType::Variant))
                 } else {
@@ -3548,13 +5395,26 @@ impl<'a> TypeChecker<'a> {
         None
     }
 
+    /// Whether the data type has tuples whose field names should be kept as the object keys
+    /// when it is cast to variant.
+    fn contains_named_tuple(data_type: &TableDataType) -> bool {
+        match data_type.remove_nullable() {
+            TableDataType::Tuple { .. } => true,
+            TableDataType::Array(box inner_type) => Self::contains_named_tuple(&inner_type),
+            TableDataType::Map(box TableDataType::Tuple { fields_type, .. }) => {
+                Self::contains_named_tuple(&fields_type[1])
+            }
+            _ => false,
+        }
+    }
+
     fn rewrite_cast_to_variant(
         span: Span,
         scalar: &ScalarExpr,
         data_type: &TableDataType,
         is_try: bool,
-    ) -> ScalarExpr {
-        match data_type.remove_nullable() {
+    ) -> Result<ScalarExpr> {
+        Ok(match data_type.remove_nullable() {
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
@@ -3577,12 +5437,11 @@ impl<'a> TypeChecker<'a> {
                     }
                     .into();
 
-                    let value =
-                        if matches!(field_type.remove_nullable(), TableDataType::Tuple { .. }) {
-                            Self::rewrite_cast_to_variant(span, &value, field_type, is_try)
-                        } else {
-                            value
-                        };
+                    let value = if Self::contains_named_tuple(field_type) {
+                        Self::rewrite_cast_to_variant(span, &value, field_type, is_try)?
+                    } else {
+                        value
+                    };
 
                     args.push(key);
                     args.push(value);
@@ -3600,6 +5459,44 @@ impl<'a> TypeChecker<'a> {
                 }
                 .into()
             }
+            TableDataType::Array(box inner_type) if Self::contains_named_tuple(&inner_type) => {
+                // array_transform(arr, x -> <rewrite x>)
+                let lambda_columns = vec![("x".to_string(), DataType::from(&inner_type))];
+                let lambda_func = Self::rewrite_cast_to_variant_lambda(
+                    span,
+                    "array_transform",
+                    scalar,
+                    lambda_columns,
+                    &inner_type,
+                    data_type.is_nullable(),
+                    is_try,
+                    |lambda_type| DataType::Array(Box::new(lambda_type)),
+                )?;
+                Self::rewrite_cast_to_variant(span, &lambda_func, &TableDataType::Variant, is_try)?
+            }
+            TableDataType::Map(box TableDataType::Tuple { fields_type, .. })
+                if Self::contains_named_tuple(&fields_type[1]) =>
+            {
+                // map_transform_values(map, (k, v) -> <rewrite v>)
+                let key_type = DataType::from(&fields_type[0]);
+                let lambda_columns = vec![
+                    ("k".to_string(), key_type.clone()),
+                    ("v".to_string(), DataType::from(&fields_type[1])),
+                ];
+                let lambda_func = Self::rewrite_cast_to_variant_lambda(
+                    span,
+                    "map_transform_values",
+                    scalar,
+                    lambda_columns,
+                    &fields_type[1],
+                    data_type.is_nullable(),
+                    is_try,
+                    |lambda_type| {
+                        DataType::Map(Box::new(DataType::Tuple(vec![key_type, lambda_type])))
+                    },
+                )?;
+                Self::rewrite_cast_to_variant(span, &lambda_func, &TableDataType::Variant, is_try)?
+            }
             _ => {
                 let func_name = if is_try {
                     "try_to_variant".to_string()
@@ -3614,20 +5511,94 @@ impl<'a> TypeChecker<'a> {
                 }
                 .into()
             }
+        })
+    }
+
+    /// Build the lambda function which casts the array elements or the map values to variant,
+    /// the last lambda column is the element to be cast.
+    #[allow(clippy::too_many_arguments)]
+    fn rewrite_cast_to_variant_lambda(
+        span: Span,
+        func_name: &str,
+        scalar: &ScalarExpr,
+        lambda_columns: Vec<(String, DataType)>,
+        element_type: &TableDataType,
+        is_nullable: bool,
+        is_try: bool,
+        lambda_return_type: impl FnOnce(DataType) -> DataType,
+    ) -> Result<ScalarExpr> {
+        let (element_name, element_data_type) = lambda_columns.last().unwrap().clone();
+        let element = BoundColumnRef {
+            span,
+            column: ColumnBindingBuilder::new(
+                element_name,
+                lambda_columns.len() - 1,
+                Box::new(element_data_type),
+                Visibility::Visible,
+            )
+            .build(),
+        }
+        .into();
+        let lambda_scalar = Self::rewrite_cast_to_variant(span, &element, element_type, is_try)?;
+
+        let lambda_schema = DataSchema::new(
+            lambda_columns
+                .iter()
+                .enumerate()
+                .map(|(i, (_, ty))| DataField::new(&i.to_string(), ty.clone()))
+                .collect(),
+        );
+        let expr = lambda_scalar
+            .type_check(&lambda_schema)?
+            .project_column_ref(|index| lambda_schema.index_of(&index.to_string()).unwrap());
+        let params = lambda_columns
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        let lambda_display = format!("{:?} -> {}", params, expr.sql_display());
+
+        let return_type = lambda_return_type(expr.data_type().clone());
+        let return_type = if is_nullable {
+            return_type.wrap_nullable()
+        } else {
+            return_type
+        };
+        Ok(LambdaFunc {
+            span,
+            func_name: func_name.to_string(),
+            args: vec![scalar.clone()],
+            lambda_expr: Box::new(expr.as_remote_expr()),
+            lambda_display,
+            return_type: Box::new(return_type),
         }
+        .into())
     }
 
     fn resolve_map_access(
         &mut self,
         expr: &Expr,
-        mut paths: VecDeque<(Span, Literal)>,
+        mut paths: VecDeque<(Span, MapAccessPath)>,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
         let box (mut scalar, data_type) = self.resolve(expr)?;
         // Variant type can be converted to `get_by_keypath` function.
         if data_type.remove_nullable() == DataType::Variant {
             return self.resolve_variant_map_access(scalar, &mut paths);
         }
+        if let Some((span, _)) = paths
+            .iter()
+            .find(|(_, path)| matches!(path, MapAccessPath::Wildcard))
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "wildcard accessor `[*]` is only supported on variant type, but got {}",
+                data_type
+            ))
+            .set_span(*span));
+        }
 
+        let out_of_bounds_as_null = self
+            .ctx
+            .get_settings()
+            .get_tuple_access_out_of_bounds_as_null()?;
         let mut table_data_type = infer_schema_type(&data_type)?;
         // If it is a tuple column, convert it to the internal column specified by the paths.
         // For other types of columns, convert it to get functions.
@@ -3646,6 +5617,7 @@ impl<'a> TypeChecker<'a> {
                                 column.clone(),
                                 &mut table_data_type,
                                 &mut paths,
+                                out_of_bounds_as_null,
                             )?;
                         scalar = inner_scalar;
                     }
@@ -3654,43 +5626,40 @@ impl<'a> TypeChecker<'a> {
         }
 
         // Otherwise, desugar it into a `get` function.
-        while let Some((span, path_lit)) = paths.pop_front() {
+        while let Some((span, path)) = paths.pop_front() {
             table_data_type = table_data_type.remove_nullable();
             if let TableDataType::Tuple {
                 fields_name,
                 fields_type,
             } = table_data_type
             {
-                let idx = match path_lit {
-                    Literal::UInt64(idx) => {
-                        if idx == 0 {
-                            return Err(ErrorCode::SemanticError(
-                                "tuple index is starting from 1, but 0 is found".to_string(),
-                            ));
-                        }
-                        if idx as usize > fields_type.len() {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "tuple index {} is out of bounds for length {}",
-                                idx,
-                                fields_type.len()
-                            )));
-                        }
-                        table_data_type = fields_type.get(idx as usize - 1).unwrap().clone();
-                        idx as usize
+                let idx = match path {
+                    MapAccessPath::Index(idx) => {
+                        let Some(idx) =
+                            Self::check_tuple_index(idx, fields_type.len(), out_of_bounds_as_null)?
+                        else {
+                            return Ok(Self::tuple_access_null(span));
+                        };
+                        table_data_type = fields_type.get(idx - 1).unwrap().clone();
+                        idx
                     }
-                    Literal::String(name) => match fields_name.iter().position(|k| k == &name) {
-                        Some(idx) => {
-                            table_data_type = fields_type.get(idx).unwrap().clone();
-                            idx + 1
-                        }
-                        None => {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "tuple name `{}` does not exist, available names are: {:?}",
-                                name, &fields_name
-                            )));
-                        }
-                    },
-                    _ => unreachable!(),
+                    MapAccessPath::Name {
+                        name,
+                        case_sensitive,
+                    } => {
+                        let Some(idx) = Self::resolve_tuple_field_index(
+                            &fields_name,
+                            &name,
+                            case_sensitive,
+                            out_of_bounds_as_null,
+                        )?
+                        else {
+                            return Ok(Self::tuple_access_null(span));
+                        };
+                        table_data_type = fields_type.get(idx).unwrap().clone();
+                        idx + 1
+                    }
+                    MapAccessPath::Wildcard => unreachable!(),
                 };
                 scalar = FunctionCall {
                     span: expr.span(),
@@ -3701,7 +5670,19 @@ impl<'a> TypeChecker<'a> {
                 .into();
                 continue;
             }
-            let box (path_scalar, _) = self.resolve_literal(span, &path_lit)?;
+            let path_scalar = match path {
+                MapAccessPath::Index(idx) if idx >= 0 => {
+                    self.resolve_literal(span, &Literal::UInt64(idx as u64))?.0
+                }
+                MapAccessPath::Index(idx) => ScalarExpr::ConstantExpr(ConstantExpr {
+                    span,
+                    value: Scalar::Number(NumberScalar::Int64(idx)),
+                }),
+                MapAccessPath::Name { name, .. } => {
+                    self.resolve_literal(span, &Literal::String(name))?.0
+                }
+                MapAccessPath::Wildcard => unreachable!(),
+            };
             if let TableDataType::Array(inner_type) = table_data_type {
                 table_data_type = *inner_type;
             }
@@ -3723,7 +5704,8 @@ impl<'a> TypeChecker<'a> {
         span: Span,
         column: ColumnBinding,
         table_data_type: &mut TableDataType,
-        paths: &mut VecDeque<(Span, Literal)>,
+        paths: &mut VecDeque<(Span, MapAccessPath)>,
+        out_of_bounds_as_null: bool,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
         let mut names = Vec::new();
         names.push(column.column_name.clone());
@@ -3736,44 +5718,42 @@ impl<'a> TypeChecker<'a> {
             {
                 let (span, path) = paths.pop_front().unwrap();
                 match path {
-                    Literal::UInt64(idx) => {
-                        if idx == 0 {
-                            return Err(ErrorCode::SemanticError(
-                                "tuple index is starting from 1, but 0 is found".to_string(),
-                            )
-                            .set_span(span));
-                        }
-                        if idx as usize > fields_type.len() {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "tuple index {} is out of bounds for length {}",
-                                idx,
-                                fields_type.len()
-                            ))
-                            .set_span(span));
-                        }
-                        let inner_name = fields_name.get(idx as usize - 1).unwrap();
-                        let inner_type = fields_type.get(idx as usize - 1).unwrap();
+                    MapAccessPath::Index(idx) => {
+                        let Some(idx) =
+                            Self::check_tuple_index(idx, fields_type.len(), out_of_bounds_as_null)
+                                .map_err(|e| e.set_span(span))?
+                        else {
+                            paths.clear();
+                            return Ok(Self::tuple_access_null(span));
+                        };
+                        let inner_name = fields_name.get(idx - 1).unwrap();
+                        let inner_type = fields_type.get(idx - 1).unwrap();
                         names.push(inner_name.clone());
-                        index_with_types.push_back((idx as usize, inner_type.clone()));
+                        index_with_types.push_back((idx, inner_type.clone()));
                         *table_data_type = inner_type.clone();
                     }
-                    Literal::String(name) => match fields_name.iter().position(|k| k == &name) {
-                        Some(idx) => {
-                            let inner_name = fields_name.get(idx).unwrap();
-                            let inner_type = fields_type.get(idx).unwrap();
-                            names.push(inner_name.clone());
-                            index_with_types.push_back((idx + 1, inner_type.clone()));
-                            *table_data_type = inner_type.clone();
-                        }
-                        None => {
-                            return Err(ErrorCode::SemanticError(format!(
-                                "tuple name `{}` does not exist, available names are: {:?}",
-                                name, &fields_name
-                            ))
-                            .set_span(span));
-                        }
-                    },
-                    _ => unreachable!(),
+                    MapAccessPath::Name {
+                        name,
+                        case_sensitive,
+                    } => {
+                        let Some(idx) = Self::resolve_tuple_field_index(
+                            &fields_name,
+                            &name,
+                            case_sensitive,
+                            out_of_bounds_as_null,
+                        )
+                        .map_err(|e| e.set_span(span))?
+                        else {
+                            paths.clear();
+                            return Ok(Self::tuple_access_null(span));
+                        };
+                        let inner_name = fields_name.get(idx).unwrap();
+                        let inner_type = fields_type.get(idx).unwrap();
+                        names.push(inner_name.clone());
+                        index_with_types.push_back((idx + 1, inner_type.clone()));
+                        *table_data_type = inner_type.clone();
+                    }
+                    MapAccessPath::Wildcard => unreachable!(),
                 }
             } else {
                 // other data types use `get` function.
@@ -3818,6 +5798,73 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    /// Check the literal elements of an IN list against the type of the left expression
+    /// up front, so that the error points at the first offending element instead of the
+    /// rewritten OR chain or subquery. `left_type` is the resolved type of `expr`.
+    fn check_inlist_element_types(
+        &mut self,
+        expr: &Expr,
+        left_type: &DataType,
+        list: &[Expr],
+    ) -> Result<()> {
+        let auto_cast_rules = BUILTIN_FUNCTIONS.get_auto_cast_rules("eq");
+        let mut common_type = left_type.clone();
+        for (idx, elem) in list.iter().enumerate() {
+            let Expr::Literal { span, value } = elem else {
+                continue;
+            };
+            let box (scalar, data_type) = self.resolve_literal_scalar(value)?;
+            let incompatible_err = || {
+                ErrorCode::SemanticError(format!(
+                    "IN list element #{} {} of type {} is incompatible with the left expression {} of type {}",
+                    idx + 1,
+                    elem,
+                    data_type,
+                    expr,
+                    left_type
+                ))
+                .set_span(*span)
+            };
+            common_type =
+                type_check::common_super_type(common_type, data_type.clone(), auto_cast_rules)
+                    .ok_or_else(incompatible_err)?;
+
+            // Strings are allowed to be compared with other types, e.g. `col_int = '1'`,
+            // check the string literal can actually be cast to the common type.
+            let dest_type = common_type.remove_nullable();
+            if data_type == DataType::String
+                && !matches!(dest_type, DataType::String | DataType::Variant)
+            {
+                let cast_expr = EExpr::Cast {
+                    span: *span,
+                    is_try: true,
+                    expr: Box::new(EExpr::Constant {
+                        span: *span,
+                        scalar,
+                        data_type,
+                    }),
+                    dest_type: dest_type.wrap_nullable(),
+                };
+                let (cast_expr, _) =
+                    ConstantFolder::fold(&cast_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                if matches!(cast_expr, EExpr::Constant {
+                    scalar: Scalar::Null,
+                    ..
+                }) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "IN list element #{} {} cannot be cast to type {} of the left expression {}",
+                        idx + 1,
+                        elem,
+                        dest_type,
+                        expr
+                    ))
+                    .set_span(*span));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn convert_inlist_to_subquery(
         &mut self,
         expr: &Expr,
@@ -3882,29 +5929,141 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((subquery_expr.into(), data_type)))
     }
 
-    // Rewrite variant map access as `get_by_keypath` function
+    /// Convert the path literal of a map access into an index, `negative` is set
+    /// when the literal is prefixed with a unary minus.
+    fn map_access_index(span: Span, idx: u64, negative: bool) -> Result<i64> {
+        let idx = i64::try_from(idx).map_err(|_| {
+            ErrorCode::SemanticError(format!(
+                "path index is overflow, max allowed value is {}, but got {}",
+                i64::MAX,
+                idx
+            ))
+            .set_span(span)
+        })?;
+        Ok(if negative { -idx } else { idx })
+    }
+
+    /// Check the 1-based tuple index is in the bounds of the tuple fields.
+    /// Returns `None` for an out of bounds index if `out_of_bounds_as_null` is set.
+    fn check_tuple_index(
+        idx: i64,
+        len: usize,
+        out_of_bounds_as_null: bool,
+    ) -> Result<Option<usize>> {
+        if idx == 0 {
+            return Err(ErrorCode::SemanticError(
+                "tuple index is starting from 1, but 0 is found".to_string(),
+            ));
+        }
+        if idx < 0 {
+            return Err(ErrorCode::SemanticError(format!(
+                "tuple index is starting from 1, negative index {} is not supported",
+                idx
+            )));
+        }
+        if idx as usize > len {
+            if out_of_bounds_as_null {
+                return Ok(None);
+            }
+            return Err(ErrorCode::SemanticError(format!(
+                "tuple index {} is out of bounds for length {}",
+                idx, len
+            )));
+        }
+        Ok(Some(idx as usize))
+    }
+
+    /// Find the tuple field by name. Names that are not case-sensitive
+    /// must match exactly one field ignoring case. Returns `None` for a
+    /// non-existent name if `out_of_bounds_as_null` is set.
+    fn resolve_tuple_field_index(
+        fields_name: &[String],
+        name: &str,
+        case_sensitive: bool,
+        out_of_bounds_as_null: bool,
+    ) -> Result<Option<usize>> {
+        let matched = fields_name
+            .iter()
+            .enumerate()
+            .filter(|(_, field_name)| {
+                if case_sensitive {
+                    *field_name == name
+                } else {
+                    field_name.to_lowercase() == name.to_lowercase()
+                }
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+        match matched.as_slice() {
+            [idx] => Ok(Some(*idx)),
+            [] if out_of_bounds_as_null => Ok(None),
+            [] => Err(ErrorCode::SemanticError(format!(
+                "tuple name {} does not exist, available names are: {}",
+                display_ident(name),
+                display_candidates(fields_name)
+            ))),
+            _ => Err(ErrorCode::SemanticError(format!(
+                "tuple name {} is ambiguous, it matches {} ignoring case, please quote the name to access the field exactly",
+                display_ident(name),
+                display_candidates(
+                    &matched
+                        .iter()
+                        .map(|idx| &fields_name[*idx])
+                        .collect::<Vec<_>>()
+                )
+            ))),
+        }
+    }
+
+    /// The result of accessing a non-existent tuple field when
+    /// `tuple_access_out_of_bounds_as_null` is enabled.
+    fn tuple_access_null(span: Span) -> Box<(ScalarExpr, DataType)> {
+        let data_type = DataType::Nullable(Box::new(DataType::Variant));
+        let scalar = ScalarExpr::CastExpr(CastExpr {
+            span,
+            is_try: false,
+            argument: Box::new(ScalarExpr::ConstantExpr(ConstantExpr {
+                span,
+                value: Scalar::Null,
+            })),
+            target_type: Box::new(data_type.clone()),
+        });
+        Box::new((scalar, data_type))
+    }
+
+    // Rewrite variant map access as `get_by_keypath` function,
+    // or `json_path_query_array` function if the paths contain wildcard.
     fn resolve_variant_map_access(
         &mut self,
         scalar: ScalarExpr,
-        paths: &mut VecDeque<(Span, Literal)>,
+        paths: &mut VecDeque<(Span, MapAccessPath)>,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if paths
+            .iter()
+            .any(|(_, path)| matches!(path, MapAccessPath::Wildcard))
+        {
+            return self.resolve_variant_wildcard_access(scalar, paths);
+        }
         let mut key_paths = Vec::with_capacity(paths.len());
         for (span, path) in paths.iter() {
             let key_path = match path {
-                Literal::UInt64(idx) => {
+                MapAccessPath::Index(idx) => {
                     if let Ok(i) = i32::try_from(*idx) {
                         KeyPath::Index(i)
                     } else {
                         return Err(ErrorCode::SemanticError(format!(
-                            "path index is overflow, max allowed value is {}, but got {}",
+                            "path index is overflow, allowed range is [{}, {}], but got {}",
+                            i32::MIN,
                             i32::MAX,
                             idx
                         ))
                         .set_span(*span));
                     }
                 }
-                Literal::String(field) => KeyPath::QuotedName(std::borrow::Cow::Borrowed(field)),
-                _ => unreachable!(),
+                MapAccessPath::Name { name, .. } => {
+                    KeyPath::QuotedName(std::borrow::Cow::Borrowed(name))
+                }
+                MapAccessPath::Wildcard => unreachable!(),
             };
             key_paths.push(key_path);
         }
@@ -3928,6 +6087,46 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    // Rewrite variant map access with wildcard as `json_path_query_array` function,
+    // which returns an array of all the matched values.
+    fn resolve_variant_wildcard_access(
+        &mut self,
+        scalar: ScalarExpr,
+        paths: &mut VecDeque<(Span, MapAccessPath)>,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let mut json_path = "$".to_string();
+        for (_, path) in paths.iter() {
+            match path {
+                MapAccessPath::Index(idx) if *idx >= 0 => {
+                    json_path.push_str(&format!("[{idx}]"));
+                }
+                MapAccessPath::Index(-1) => json_path.push_str("[last]"),
+                MapAccessPath::Index(idx) => {
+                    json_path.push_str(&format!("[last - {}]", idx.unsigned_abs() - 1));
+                }
+                MapAccessPath::Name { name, .. } => {
+                    let name = name.replace('\\', "\\\\").replace('"', "\\\"");
+                    json_path.push_str(&format!("[\"{name}\"]"));
+                }
+                MapAccessPath::Wildcard => json_path.push_str("[*]"),
+            }
+        }
+        let path_scalar = ScalarExpr::ConstantExpr(ConstantExpr {
+            span: None,
+            value: Scalar::String(json_path),
+        });
+
+        Ok(Box::new((
+            ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "json_path_query_array".to_string(),
+                params: vec![],
+                arguments: vec![scalar, path_scalar],
+            }),
+            DataType::Nullable(Box::new(DataType::Variant)),
+        )))
+    }
+
     #[allow(clippy::only_used_in_recursion)]
     fn clone_expr_with_replacement<F>(
         &self,
@@ -4008,6 +6207,7 @@ impl<'a> TypeChecker<'a> {
                     expr,
                     target_type,
                     pg_style,
+                    format,
                 } => Ok(Expr::Cast {
                     span: *span,
                     expr: Box::new(
@@ -4015,17 +6215,20 @@ impl<'a> TypeChecker<'a> {
                     ),
                     target_type: target_type.clone(),
                     pg_style: *pg_style,
+                    format: format.clone(),
                 }),
                 Expr::TryCast {
                     span,
                     expr,
                     target_type,
+                    format,
                 } => Ok(Expr::TryCast {
                     span: *span,
                     expr: Box::new(
                         self.clone_expr_with_replacement(expr.as_ref(), replacement_fn)?,
                     ),
                     target_type: target_type.clone(),
+                    format: format.clone(),
                 }),
                 Expr::Extract { span, kind, expr } => Ok(Expr::Extract {
                     span: *span,
@@ -4045,6 +6248,7 @@ impl<'a> TypeChecker<'a> {
                     span,
                     substr_expr,
                     str_expr,
+                    position_from,
                 } => Ok(Expr::Position {
                     span: *span,
                     substr_expr: Box::new(
@@ -4053,6 +6257,14 @@ impl<'a> TypeChecker<'a> {
                     str_expr: Box::new(
                         self.clone_expr_with_replacement(str_expr.as_ref(), replacement_fn)?,
                     ),
+                    position_from: if let Some(position_from_expr) = position_from {
+                        Some(Box::new(self.clone_expr_with_replacement(
+                            position_from_expr.as_ref(),
+                            replacement_fn,
+                        )?))
+                    } else {
+                        None
+                    },
                 }),
                 Expr::Substring {
                     span,
@@ -4116,6 +6328,8 @@ impl<'a> TypeChecker<'a> {
                             params,
                             window,
                             lambda,
+                            order_by,
+                            filter,
                         },
                 } => Ok(Expr::FunctionCall {
                     span: *span,
@@ -4129,6 +6343,8 @@ impl<'a> TypeChecker<'a> {
                         params: params.clone(),
                         window: window.clone(),
                         lambda: lambda.clone(),
+                        order_by: order_by.clone(),
+                        filter: filter.clone(),
                     },
                 }),
                 Expr::Case {
@@ -4209,28 +6425,54 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
-    fn try_fold_constant<Index: ColumnIndex>(
+    fn try_fold_constant(
         &self,
-        expr: &databend_common_expression::Expr<Index>,
+        expr: &EExpr<ColumnBinding>,
     ) -> Option<Box<(ScalarExpr, DataType)>> {
-        if expr.is_deterministic(&BUILTIN_FUNCTIONS) {
-            if let (databend_common_expression::Expr::Constant { scalar, .. }, _) =
-                ConstantFolder::fold(expr, &self.func_ctx, &BUILTIN_FUNCTIONS)
-            {
-                let scalar = shrink_scalar(scalar);
-                let ty = scalar.as_ref().infer_data_type();
-                return Some(Box::new((
-                    ConstantExpr {
-                        span: expr.span(),
-                        value: scalar,
+        // Non-deterministic expressions are never folded, hence never cached.
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return None;
+        }
+
+        let scalar = match expr {
+            EExpr::Constant { scalar, .. } => Some(scalar.clone()),
+            _ => {
+                let cached = self.constant_fold_cache.borrow().get(expr).cloned();
+                match cached {
+                    Some(scalar) => scalar,
+                    None => {
+                        self.constant_fold_count
+                            .set(self.constant_fold_count.get() + 1);
+                        let scalar =
+                            match ConstantFolder::fold(expr, &self.func_ctx, &BUILTIN_FUNCTIONS) {
+                                (EExpr::Constant { scalar, .. }, _) => Some(scalar),
+                                _ => None,
+                            };
+                        self.constant_fold_cache
+                            .borrow_mut()
+                            .insert(expr.clone(), scalar.clone());
+                        scalar
                     }
-                    .into(),
-                    ty,
-                )));
+                }
             }
-        }
+        }?;
+
+        let scalar = shrink_scalar(scalar);
+        let ty = scalar.as_ref().infer_data_type();
+        Some(Box::new((
+            ConstantExpr {
+                span: expr.span(),
+                value: scalar,
+            }
+            .into(),
+            ty,
+        )))
+    }
 
-        None
+    /// Returns how many times the constant folder has been run by this type checker,
+    /// sub-expressions served from the folding cache are not counted.
+    pub fn constant_fold_count(&self) -> usize {
+        self.constant_fold_count.get()
     }
 }
 
@@ -4246,6 +6488,22 @@ pub fn resolve_type_name_by_str(name: &str, not_null: bool) -> Result<TableDataT
     resolve_type_name(&ast, not_null)
 }
 
+/// Only the types which can be hashed and compared are allowed to be the key of a map.
+pub fn check_map_key_type(key_type: &TableDataType) -> Result<()> {
+    match key_type {
+        TableDataType::Boolean
+        | TableDataType::String
+        | TableDataType::Number(_)
+        | TableDataType::Decimal(_)
+        | TableDataType::Timestamp
+        | TableDataType::Date => Ok(()),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Invalid Map key type \'{:?}\'",
+            key_type
+        ))),
+    }
+}
+
 pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDataType> {
     let data_type = match type_name {
         TypeName::Boolean => TableDataType::Boolean,
@@ -4274,27 +6532,13 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         }
         TypeName::Map { key_type, val_type } => {
             let key_type = resolve_type_name(key_type, true)?;
-            match key_type {
-                TableDataType::Boolean
-                | TableDataType::String
-                | TableDataType::Number(_)
-                | TableDataType::Decimal(_)
-                | TableDataType::Timestamp
-                | TableDataType::Date => {
-                    let val_type = resolve_type_name(val_type, not_null)?;
-                    let inner_type = TableDataType::Tuple {
-                        fields_name: vec!["key".to_string(), "value".to_string()],
-                        fields_type: vec![key_type, val_type],
-                    };
-                    TableDataType::Map(Box::new(inner_type))
-                }
-                _ => {
-                    return Err(ErrorCode::BadArguments(format!(
-                        "Invalid Map key type \'{:?}\'",
-                        key_type
-                    )));
-                }
-            }
+            check_map_key_type(&key_type)?;
+            let val_type = resolve_type_name(val_type, not_null)?;
+            let inner_type = TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![key_type, val_type],
+            };
+            TableDataType::Map(Box::new(inner_type))
         }
         TypeName::Bitmap => TableDataType::Bitmap,
         TypeName::Tuple {
@@ -4359,6 +6603,71 @@ pub fn validate_function_arg(
     }
 }
 
+/// Convert a SQL style datetime format such as `YYYY-MM-DD HH24:MI:SS` used by
+/// `CAST ... FORMAT` into the strftime format understood by the datetime functions.
+/// Text enclosed in double quotes is kept as is.
+fn cast_format_to_strftime(format: &str) -> Result<String> {
+    const ELEMENTS: &[(&str, &str)] = &[
+        ("YYYY", "%Y"),
+        ("YY", "%y"),
+        ("MONTH", "%B"),
+        ("MON", "%b"),
+        ("MM", "%m"),
+        ("MI", "%M"),
+        ("DAY", "%A"),
+        ("DD", "%d"),
+        ("DY", "%a"),
+        ("HH24", "%H"),
+        ("HH12", "%I"),
+        ("HH", "%I"),
+        ("SS", "%S"),
+        ("FF", "%f"),
+        ("AM", "%p"),
+        ("PM", "%p"),
+    ];
+
+    if format.is_empty() {
+        return Err(ErrorCode::SemanticError(
+            "malformed format in CAST: format cannot be empty".to_string(),
+        ));
+    }
+
+    let mut output = String::with_capacity(format.len() * 2);
+    let mut rest = format;
+    while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            let end = rest[1..].find('"').ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "malformed format '{format}' in CAST: unterminated double quote"
+                ))
+            })?;
+            output.push_str(&rest[1..end + 1].replace('%', "%%"));
+            rest = &rest[end + 2..];
+        } else if c.is_ascii_alphabetic() {
+            let (element, spec) = ELEMENTS
+                .iter()
+                .find(|(element, _)| {
+                    rest.get(..element.len())
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(element))
+                })
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "malformed format '{format}' in CAST: unknown format element at '{rest}'"
+                    ))
+                })?;
+            output.push_str(spec);
+            rest = &rest[element.len()..];
+        } else {
+            if c == '%' {
+                output.push('%');
+            }
+            output.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    Ok(output)
+}
+
 // Some check functions for like expression
 fn check_const(like_str: &str) -> bool {
     for char in like_str.chars() {
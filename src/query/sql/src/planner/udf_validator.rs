@@ -18,9 +18,13 @@ use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FunctionCall;
 use databend_common_ast::ast::Lambda;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::udf_client::udf_server_addresses;
+use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_functions::is_builtin_function;
+use databend_common_settings::Settings;
 use derive_visitor::Drive;
 use derive_visitor::Visitor;
 
@@ -52,6 +56,34 @@ impl UDFValidator {
             .extend(lambda.params.iter().map(|v| v.name.clone()));
     }
 
+    /// The default value of a parameter is evaluated at the call site, so it can't reference
+    /// any parameters or columns.
+    pub fn verify_default_expr(&mut self, parameter: &str, default_expr: &Expr) -> Result<()> {
+        let lambda_parameters = std::mem::take(&mut self.lambda_parameters);
+        self.expr_params.clear();
+
+        default_expr.drive(self);
+
+        let default_lambda_parameters =
+            std::mem::replace(&mut self.lambda_parameters, lambda_parameters);
+        if self.has_recursive {
+            return Err(ErrorCode::SyntaxException("Recursive UDF is not supported"));
+        }
+        let mut references = self
+            .expr_params
+            .iter()
+            .filter(|name| !default_lambda_parameters.contains(name))
+            .collect::<Vec<_>>();
+        if references.is_empty() {
+            return Ok(());
+        }
+        references.sort();
+        Err(ErrorCode::SyntaxException(format!(
+            "Default value of parameter {parameter} can not reference parameters or columns: {:?}",
+            references
+        )))
+    }
+
     pub fn verify_definition_expr(&mut self, definition_expr: &Expr) -> Result<()> {
         self.expr_params.clear();
 
@@ -90,3 +122,50 @@ impl UDFValidator {
         )))
     }
 }
+
+/// Check the comma-separated addresses of a UDF server are all in `udf_server_allow_list`,
+/// returns the addresses in order.
+pub fn check_udf_server_addresses(address: &str) -> Result<Vec<&str>> {
+    let addresses = udf_server_addresses(address);
+    if addresses.is_empty() {
+        return Err(ErrorCode::InvalidArgument(
+            "UDF server address can not be empty",
+        ));
+    }
+
+    let udf_server_allow_list = &GlobalConfig::instance().query.udf_server_allow_list;
+    for address in &addresses {
+        if udf_server_allow_list
+            .iter()
+            .all(|addr| addr.trim_end_matches('/') != *address)
+        {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Unallowed UDF server address, '{address}' is not in udf_server_allow_list"
+            )));
+        }
+    }
+    Ok(addresses)
+}
+
+/// Connect to the first reachable address of a UDF server.
+pub async fn connect_udf_server(
+    settings: &Settings,
+    addresses: &[&str],
+) -> Result<UDFFlightClient> {
+    let mut connect_result = Err(ErrorCode::UDFServerConnectError(
+        "No reachable UDF server address",
+    ));
+    for addr in addresses {
+        connect_result = UDFFlightClient::connect(
+            addr,
+            settings.get_external_server_connect_timeout_secs()?,
+            settings.get_external_server_request_timeout_secs()?,
+            settings.get_external_server_request_batch_rows()?,
+        )
+        .await;
+        if connect_result.is_ok() {
+            break;
+        }
+    }
+    connect_result
+}
@@ -80,6 +80,8 @@ async fn test_parse_uri_location() -> Result<()> {
                     ("endpoint_url", "https://oss-cn-litang.example.com"),
                     ("access_key_id", "dzin"),
                     ("access_key_secret", "p=ear1"),
+                    ("server_side_encryption", "KMS"),
+                    ("server_side_encryption_key_id", "key_id"),
                 ]
                 .into_iter()
                 .map(|(k, v)| (k.to_string(), v.to_string()))
@@ -90,11 +92,11 @@ async fn test_parse_uri_location() -> Result<()> {
                     endpoint_url: "https://oss-cn-litang.example.com".to_string(),
                     presign_endpoint_url: "".to_string(),
                     root: "/highest/".to_string(),
-                    server_side_encryption: "".to_string(),
+                    server_side_encryption: "KMS".to_string(),
                     bucket: "zhen".to_string(),
                     access_key_id: "dzin".to_string(),
                     access_key_secret: "p=ear1".to_string(),
-                    server_side_encryption_key_id: "".to_string(),
+                    server_side_encryption_key_id: "key_id".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -188,6 +190,8 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -223,6 +227,8 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -258,6 +264,8 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "".to_string(),
                     external_id: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
                 }),
                 "/".to_string(),
             ),
@@ -288,6 +296,45 @@ async fn test_parse_uri_location() -> Result<()> {
                     enable_virtual_host_style: false,
                     role_arn: "aws::iam::xxxx".to_string(),
                     external_id: "".to_string(),
+                    server_side_encryption: "".to_string(),
+                    server_side_encryption_aws_kms_key_id: "".to_string(),
+                }),
+                "/".to_string(),
+            ),
+        ),
+        (
+            "s3_with_server_side_encryption",
+            UriLocation::new(
+                "s3".to_string(),
+                "test".to_string(),
+                "/tmp/".to_string(),
+                "".to_string(),
+                [
+                    ("role_arn", "aws::iam::xxxx"),
+                    ("region", "us-east-2"),
+                    ("server_side_encryption", "aws:kms"),
+                    ("server_side_encryption_aws_kms_key_id", "kms_key_id"),
+                ]
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<String, String>>(),
+            ),
+            (
+                StorageParams::S3(StorageS3Config {
+                    endpoint_url: STORAGE_S3_DEFAULT_ENDPOINT.to_string(),
+                    region: "us-east-2".to_string(),
+                    bucket: "test".to_string(),
+                    access_key_id: "".to_string(),
+                    secret_access_key: "".to_string(),
+                    security_token: "".to_string(),
+                    master_key: "".to_string(),
+                    root: "/tmp/".to_string(),
+                    disable_credential_loader: false,
+                    enable_virtual_host_style: false,
+                    role_arn: "aws::iam::xxxx".to_string(),
+                    external_id: "".to_string(),
+                    server_side_encryption: "aws:kms".to_string(),
+                    server_side_encryption_aws_kms_key_id: "kms_key_id".to_string(),
                 }),
                 "/".to_string(),
             ),
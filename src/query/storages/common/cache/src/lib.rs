@@ -21,6 +21,8 @@ mod read;
 pub use cache::CacheAccessor;
 pub use cache::Named;
 pub use cache::NamedCache;
+pub use providers::CacheItemOwner;
+pub use providers::CacheUsage;
 pub use providers::DiskCacheError;
 pub use providers::DiskCacheKey;
 pub use providers::DiskCacheResult;
@@ -35,11 +35,15 @@ use parking_lot::RwLock;
 use rayon::prelude::*;
 use rayon::ThreadPoolBuilder;
 
+use crate::providers::disk_cache::disk_cache_usage::CacheUsageTracker;
+use crate::CacheItemOwner;
+use crate::CacheUsage;
 use crate::DiskCacheKey;
 
 pub struct DiskCache<C> {
     cache: C,
     root: PathBuf,
+    usage_tracker: CacheUsageTracker,
 }
 
 impl<C> DiskCache<C>
@@ -65,6 +69,8 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
         DiskCache {
             cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
             root: PathBuf::from(path),
+            // at most half of the cache could be pinned
+            usage_tracker: CacheUsageTracker::new(size / 2),
         }
         .init(disk_cache_key_reload_policy)
     }
@@ -279,19 +285,28 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
             return Err(Error::FileTooLarge);
         }
 
-        // check eviction
-        while self.cache.size() + bytes_len > self.cache.capacity() {
-            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
-                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
-                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
-                    error!(
-                        "Error removing file from cache: `{:?}`: {}",
-                        cached_item_path, e
-                    )
-                });
+        // check eviction, the pinned items are skipped and put back afterwards
+        let mut pinned_items = vec![];
+        let mut pinned_size = 0;
+        while self.cache.size() + pinned_size + bytes_len > self.cache.capacity() {
+            match self.cache.pop_by_policy() {
+                Some((rel_path, size)) if self.usage_tracker.is_pinned(&rel_path) => {
+                    pinned_size += size;
+                    pinned_items.push((rel_path, size));
+                }
+                Some((rel_path, _)) => {
+                    self.remove_file(&DiskCacheKey(rel_path));
+                }
+                None => break,
             }
         }
-        debug_assert!(self.cache.size() <= self.cache.capacity());
+        for (rel_path, size) in pinned_items {
+            self.cache.put(rel_path, size);
+        }
+        // all the rest of the items are pinned
+        if self.cache.size() + bytes_len > self.cache.capacity() {
+            return Err(Error::FileTooLarge);
+        }
 
         let cache_key = self.cache_key(key.as_ref());
         let path = self.abs_path_of_cache_key(&cache_key);
@@ -304,10 +319,22 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
             bufs.push(IoSlice::new(slick));
         }
         f.write_all_vectored(&mut bufs)?;
+        self.usage_tracker.insert(&cache_key.0, key, bytes_len);
         self.cache.put(cache_key.0, bytes_len);
         Ok(())
     }
 
+    fn remove_file(&mut self, cache_key: &DiskCacheKey) {
+        self.usage_tracker.remove(&cache_key.0);
+        let cached_item_path = self.abs_path_of_cache_key(cache_key);
+        fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+            error!(
+                "Error removing file from cache: `{:?}`: {}",
+                cached_item_path, e
+            )
+        });
+    }
+
     /// Return `true` if a file with path `key` is in the cache.
     pub fn contains_key(&self, key: &str) -> bool {
         let cache_key = self.cache_key(key);
@@ -316,10 +343,9 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
 
     pub fn get_cache_path(&mut self, key: &str) -> Option<PathBuf> {
         let cache_key = self.cache_key(key);
-        self.cache
-            .get(&cache_key.0)
-            .map(|_| ()) // release the &mut self
-            .map(|_| self.abs_path_of_cache_key(&cache_key))
+        let hit = self.cache.get(&cache_key.0).is_some();
+        self.usage_tracker.record_access(key, hit);
+        hit.then(|| self.abs_path_of_cache_key(&cache_key))
     }
 
     /// Remove the given key from the cache.
@@ -327,6 +353,7 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
         let cache_key = self.cache_key(key);
         match self.cache.pop(&cache_key.0) {
             Some(_) => {
+                self.usage_tracker.remove(&cache_key.0);
                 let path = self.abs_path_of_cache_key(&cache_key);
                 fs::remove_file(&path).map_err(|e| {
                     error!("Error removing file from cache: `{:?}`: {}", path, e);
@@ -336,6 +363,63 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize> + Send + Sync + 'stati
             None => Ok(()),
         }
     }
+
+    /// Set the max size of the items of the table that are exempt from eviction,
+    /// 0 means no item of the table is pinned.
+    pub fn set_pin_budget(&mut self, table_prefix: &str, budget: u64) {
+        self.usage_tracker.set_pin_budget(table_prefix, budget);
+    }
+
+    /// Return the max size of the items of the table that are exempt from eviction.
+    pub fn pin_budget(&self, table_prefix: &str) -> u64 {
+        self.usage_tracker.pin_budget(table_prefix)
+    }
+
+    /// Remove the items of the table, or all the items if `table_prefix` is `None`.
+    /// Returns the number of removed items.
+    pub fn remove_items(&mut self, table_prefix: Option<&str>) -> usize {
+        match table_prefix {
+            Some(table_prefix) => {
+                let cache_keys = self.usage_tracker.remove_table(table_prefix);
+                let mut removed = 0;
+                for cache_key in cache_keys {
+                    if self.cache.pop(&cache_key).is_some() {
+                        self.remove_file(&DiskCacheKey(cache_key));
+                        removed += 1;
+                    }
+                }
+                removed
+            }
+            None => {
+                let mut removed = 0;
+                while let Some((rel_path, _)) = self.cache.pop_by_policy() {
+                    self.remove_file(&DiskCacheKey(rel_path));
+                    removed += 1;
+                }
+                self.usage_tracker.clear();
+                removed
+            }
+        }
+    }
+
+    /// Return the usages of the cached items by owner, the items whose owners are unknown
+    /// are grouped under `None`.
+    pub fn usages(&self) -> Vec<(Option<CacheItemOwner>, CacheUsage)> {
+        let (usages, num_owned_items, owned_size) = self.usage_tracker.usages();
+        let mut usages = usages
+            .into_iter()
+            .map(|(owner, usage)| (Some(owner), usage))
+            .collect::<Vec<_>>();
+        let num_items = (self.cache.len() as u64).saturating_sub(num_owned_items);
+        if num_items > 0 {
+            usages.push((None, CacheUsage {
+                num_items,
+                size: self.cache.size().saturating_sub(owned_size),
+                ..Default::default()
+            }));
+        }
+        usages
+    }
 }
 
 fn recovery_cache_key_from_path(relative_path: &Path) -> String {
@@ -0,0 +1,265 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+/// The table and column that a cached item of table data belongs to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CacheItemOwner {
+    /// The storage prefix of the table, e.g. `{database_id}/{table_id}`.
+    pub table_prefix: String,
+    pub column_id: u32,
+}
+
+impl CacheItemOwner {
+    /// Parse the owner from the key of a table data cache item, which is formatted as
+    /// `{table_prefix}/_b/{block_name}-{column_id}-{offset}-{len}`.
+    pub fn from_cache_key(key: &str) -> Option<Self> {
+        let mut parts = key.rsplitn(4, '-');
+        let _len = parts.next()?.parse::<u64>().ok()?;
+        let _offset = parts.next()?.parse::<u64>().ok()?;
+        let column_id = parts.next()?.parse::<u32>().ok()?;
+        let block_path = parts.next()?;
+        let (block_dir, _) = block_path.rsplit_once('/')?;
+        let (table_prefix, _) = block_dir.rsplit_once('/')?;
+        Some(CacheItemOwner {
+            table_prefix: table_prefix.to_string(),
+            column_id,
+        })
+    }
+}
+
+/// The usage of the cached items that belong to the same owner.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheUsage {
+    pub num_items: u64,
+    pub size: u64,
+    /// The size of the items that are exempt from eviction.
+    pub pinned_size: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+struct OwnedItem {
+    owner: CacheItemOwner,
+    size: u64,
+    pinned: bool,
+}
+
+/// Track the owners of the cached items, and pin a bounded amount of the items of each table.
+///
+/// Items reloaded from the cache directory on restart only have the hashed keys, their owners
+/// are unknown.
+pub(crate) struct CacheUsageTracker {
+    /// The owned items, keyed by the disk cache key.
+    items: HashMap<String, OwnedItem>,
+    usages: HashMap<CacheItemOwner, CacheUsage>,
+    /// The max size of the items of each table that are exempt from eviction.
+    pin_budgets: HashMap<String, u64>,
+    /// The size of the pinned items of each table.
+    table_pinned_sizes: HashMap<String, u64>,
+    pinned_size: u64,
+    /// The max size of the pinned items of all the tables, the rest of the cache is always
+    /// available for eviction.
+    max_pinned_size: u64,
+}
+
+impl CacheUsageTracker {
+    pub fn new(max_pinned_size: u64) -> Self {
+        CacheUsageTracker {
+            items: HashMap::new(),
+            usages: HashMap::new(),
+            pin_budgets: HashMap::new(),
+            table_pinned_sizes: HashMap::new(),
+            pinned_size: 0,
+            max_pinned_size,
+        }
+    }
+
+    pub fn record_access(&mut self, key: &str, hit: bool) {
+        if let Some(owner) = CacheItemOwner::from_cache_key(key) {
+            let usage = self.usages.entry(owner).or_default();
+            if hit {
+                usage.hits += 1;
+            } else {
+                usage.misses += 1;
+            }
+        }
+    }
+
+    pub fn insert(&mut self, cache_key: &str, key: &str, size: u64) {
+        self.remove(cache_key);
+        let Some(owner) = CacheItemOwner::from_cache_key(key) else {
+            return;
+        };
+        let pinned = self.can_pin(&owner.table_prefix, size);
+        if pinned {
+            self.pin(&owner.table_prefix, size);
+        }
+        let usage = self.usages.entry(owner.clone()).or_default();
+        usage.num_items += 1;
+        usage.size += size;
+        if pinned {
+            usage.pinned_size += size;
+        }
+        self.items.insert(cache_key.to_string(), OwnedItem {
+            owner,
+            size,
+            pinned,
+        });
+    }
+
+    pub fn remove(&mut self, cache_key: &str) {
+        let Some(item) = self.items.remove(cache_key) else {
+            return;
+        };
+        if let Some(usage) = self.usages.get_mut(&item.owner) {
+            usage.num_items -= 1;
+            usage.size -= item.size;
+            if item.pinned {
+                usage.pinned_size -= item.size;
+            }
+        }
+        if item.pinned {
+            self.unpin(&item.owner.table_prefix, item.size);
+        }
+    }
+
+    fn pin(&mut self, table_prefix: &str, size: u64) {
+        *self
+            .table_pinned_sizes
+            .entry(table_prefix.to_string())
+            .or_default() += size;
+        self.pinned_size += size;
+    }
+
+    fn unpin(&mut self, table_prefix: &str, size: u64) {
+        if let Some(table_pinned_size) = self.table_pinned_sizes.get_mut(table_prefix) {
+            *table_pinned_size -= size;
+            if *table_pinned_size == 0 {
+                self.table_pinned_sizes.remove(table_prefix);
+            }
+        }
+        self.pinned_size -= size;
+    }
+
+    pub fn is_pinned(&self, cache_key: &str) -> bool {
+        self.items.get(cache_key).is_some_and(|item| item.pinned)
+    }
+
+    fn table_pinned_size(&self, table_prefix: &str) -> u64 {
+        self.table_pinned_sizes
+            .get(table_prefix)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn pin_budget(&self, table_prefix: &str) -> u64 {
+        self.pin_budgets.get(table_prefix).copied().unwrap_or(0)
+    }
+
+    fn can_pin(&self, table_prefix: &str, size: u64) -> bool {
+        let Some(budget) = self.pin_budgets.get(table_prefix) else {
+            return false;
+        };
+        self.table_pinned_size(table_prefix) + size <= *budget
+            && self.pinned_size + size <= self.max_pinned_size
+    }
+
+    /// Set the pin budget of the table, the cached items of the table are pinned or unpinned
+    /// to fit the new budget.
+    pub fn set_pin_budget(&mut self, table_prefix: &str, budget: u64) {
+        if self.pin_budget(table_prefix) == budget {
+            return;
+        }
+        if budget == 0 {
+            self.pin_budgets.remove(table_prefix);
+        } else {
+            self.pin_budgets.insert(table_prefix.to_string(), budget);
+        }
+
+        let mut table_pinned_size = self.table_pinned_size(table_prefix);
+        // the size of the pinned items of the other tables
+        let other_pinned_size = self.pinned_size - table_pinned_size;
+        for item in self.items.values_mut() {
+            if item.owner.table_prefix != table_prefix {
+                continue;
+            }
+            if item.pinned && table_pinned_size > budget {
+                item.pinned = false;
+                table_pinned_size -= item.size;
+            } else if !item.pinned
+                && table_pinned_size + item.size <= budget
+                && other_pinned_size + table_pinned_size + item.size <= self.max_pinned_size
+            {
+                item.pinned = true;
+                table_pinned_size += item.size;
+            } else {
+                continue;
+            }
+
+            let usage = self.usages.entry(item.owner.clone()).or_default();
+            if item.pinned {
+                usage.pinned_size += item.size;
+            } else {
+                usage.pinned_size -= item.size;
+            }
+        }
+
+        self.pinned_size = other_pinned_size + table_pinned_size;
+        if table_pinned_size == 0 {
+            self.table_pinned_sizes.remove(table_prefix);
+        } else {
+            self.table_pinned_sizes
+                .insert(table_prefix.to_string(), table_pinned_size);
+        }
+    }
+
+    /// Forget the items and usages of the table, returns the cache keys of the items.
+    pub fn remove_table(&mut self, table_prefix: &str) -> Vec<String> {
+        let cache_keys = self
+            .items
+            .iter()
+            .filter(|(_, item)| item.owner.table_prefix == table_prefix)
+            .map(|(cache_key, _)| cache_key.clone())
+            .collect::<Vec<_>>();
+        for cache_key in cache_keys.iter() {
+            self.remove(cache_key);
+        }
+        self.usages
+            .retain(|owner, _| owner.table_prefix != table_prefix);
+        cache_keys
+    }
+
+    /// Forget all the items and usages, the pin budgets are kept.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.usages.clear();
+        self.table_pinned_sizes.clear();
+        self.pinned_size = 0;
+    }
+
+    /// Returns the usages of the owned items, and the number and size of the owned items.
+    pub fn usages(&self) -> (Vec<(CacheItemOwner, CacheUsage)>, u64, u64) {
+        let mut usages = self
+            .usages
+            .iter()
+            .map(|(owner, usage)| (owner.clone(), usage.clone()))
+            .collect::<Vec<_>>();
+        usages.sort_by(|a, b| a.0.cmp(&b.0));
+        let num_items = self.items.len() as u64;
+        let size = self.items.values().map(|item| item.size).sum();
+        (usages, num_items, size)
+    }
+}
@@ -16,7 +16,10 @@
 mod disk_cache;
 mod disk_cache_key;
 mod disk_cache_lru;
+mod disk_cache_usage;
 
 pub use disk_cache::*;
 pub use disk_cache_key::DiskCacheKey;
 pub use disk_cache_lru::*;
+pub use disk_cache_usage::CacheItemOwner;
+pub use disk_cache_usage::CacheUsage;
@@ -18,6 +18,8 @@ mod table_data_cache;
 
 pub use disk_cache::io_result::Error as DiskCacheError;
 pub use disk_cache::io_result::Result as DiskCacheResult;
+pub use disk_cache::CacheItemOwner;
+pub use disk_cache::CacheUsage;
 pub use disk_cache::DiskCacheKey;
 pub use disk_cache::LruDiskCache;
 pub use disk_cache::LruDiskCacheBuilder;
@@ -31,6 +31,8 @@ use log::info;
 
 use crate::providers::LruDiskCacheHolder;
 use crate::CacheAccessor;
+use crate::CacheItemOwner;
+use crate::CacheUsage;
 use crate::LruDiskCacheBuilder;
 
 struct CacheItem {
@@ -96,6 +98,29 @@ impl TableDataCacheBuilder {
     }
 }
 
+impl TableDataCache {
+    /// Set the max size of the cached data of the table that is exempt from eviction.
+    pub fn set_pin_budget(&self, table_prefix: &str, budget: u64) {
+        // it's called on every read of the table, the budget rarely changes
+        if self.external_cache.read().pin_budget(table_prefix) == budget {
+            return;
+        }
+        self.external_cache
+            .write()
+            .set_pin_budget(table_prefix, budget);
+    }
+
+    /// Remove the cached data of the table, or all the cached data if `table_prefix` is `None`.
+    pub fn remove_items(&self, table_prefix: Option<&str>) -> usize {
+        self.external_cache.write().remove_items(table_prefix)
+    }
+
+    /// Return the usages of the cached data by table and column.
+    pub fn usages(&self) -> Vec<(Option<CacheItemOwner>, CacheUsage)> {
+        self.external_cache.read().usages()
+    }
+}
+
 impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<Bytes>> {
         metrics_inc_cache_access_count(1, TABLE_DATA_CACHE_NAME);
@@ -21,6 +21,8 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use databend_common_config::DiskCacheKeyReloadPolicy;
+use databend_storages_common_cache::CacheItemOwner;
+use databend_storages_common_cache::CacheUsage;
 use databend_storages_common_cache::DiskCacheError;
 use databend_storages_common_cache::DiskCacheKey;
 use databend_storages_common_cache::DiskCacheResult;
@@ -162,6 +164,132 @@ fn test_evict_until_enough_space() {
     assert!(c.contains_key("file3"));
 }
 
+fn table_data_key(table_prefix: &str, block: usize, column_id: u32) -> String {
+    format!("{table_prefix}/_b/block{block}_v4.parquet-{column_id}-0-10")
+}
+
+#[test]
+fn test_cache_item_owner() {
+    let owner = CacheItemOwner::from_cache_key("1/2/_b/abc_v4.parquet-3-100-20").unwrap();
+    assert_eq!(owner, CacheItemOwner {
+        table_prefix: "1/2".to_string(),
+        column_id: 3,
+    });
+    assert!(CacheItemOwner::from_cache_key("file1").is_none());
+    assert!(CacheItemOwner::from_cache_key("a/b/c").is_none());
+}
+
+#[test]
+fn test_pinned_items_survive_eviction() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new(f.tmp(), 100, DiskCacheKeyReloadPolicy::Reset).unwrap();
+    c.set_pin_budget("1/2", 20);
+    for block in 0..3 {
+        c.insert_single_slice(&table_data_key("1/2", block, 0), &[0; 10])
+            .unwrap();
+    }
+
+    // put the cache under eviction pressure with the items of another table
+    for block in 0..20 {
+        c.insert_single_slice(&table_data_key("1/3", block, 0), &[0; 10])
+            .unwrap();
+    }
+    assert_eq!(c.size(), 100);
+    // the first two items are pinned, the third one exceeds the budget
+    assert!(c.contains_key(&table_data_key("1/2", 0, 0)));
+    assert!(c.contains_key(&table_data_key("1/2", 1, 0)));
+    assert!(!c.contains_key(&table_data_key("1/2", 2, 0)));
+
+    // unpinned items are evicted again
+    c.set_pin_budget("1/2", 0);
+    for block in 20..30 {
+        c.insert_single_slice(&table_data_key("1/3", block, 0), &[0; 10])
+            .unwrap();
+    }
+    assert!(!c.contains_key(&table_data_key("1/2", 0, 0)));
+    assert!(!c.contains_key(&table_data_key("1/2", 1, 0)));
+}
+
+#[test]
+fn test_pinned_size_is_bounded() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new(f.tmp(), 40, DiskCacheKeyReloadPolicy::Reset).unwrap();
+    c.set_pin_budget("1/2", 100);
+    for block in 0..4 {
+        c.insert_single_slice(&table_data_key("1/2", block, 0), &[0; 10])
+            .unwrap();
+    }
+    // at most half of the cache is pinned
+    let usages = c.usages();
+    assert_eq!(usages.len(), 1);
+    assert_eq!(usages[0].1.pinned_size, 20);
+
+    c.insert_single_slice("file1", &[0; 20]).unwrap();
+    assert_eq!(c.size(), 40);
+    // the new item can't fit without evicting the pinned items
+    match c.insert_single_slice("file2", &[0; 30]) {
+        Err(DiskCacheError::FileTooLarge) => {}
+        x => panic!("Unexpected result: {x:?}"),
+    }
+    assert!(c.contains_key(&table_data_key("1/2", 0, 0)));
+    assert!(c.contains_key(&table_data_key("1/2", 1, 0)));
+}
+
+#[test]
+fn test_cache_usages() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new(f.tmp(), 100, DiskCacheKeyReloadPolicy::Reset).unwrap();
+    c.insert_single_slice(&table_data_key("1/2", 0, 0), &[0; 10])
+        .unwrap();
+    c.insert_single_slice(&table_data_key("1/2", 0, 1), &[0; 10])
+        .unwrap();
+    c.insert_single_slice(&table_data_key("1/3", 0, 0), &[0; 10])
+        .unwrap();
+    c.insert_single_slice("file1", &[0; 5]).unwrap();
+    assert!(c.get_cache_path(&table_data_key("1/2", 0, 0)).is_some());
+    assert!(c.get_cache_path(&table_data_key("1/2", 1, 0)).is_none());
+
+    let owner = |table_prefix: &str, column_id| {
+        Some(CacheItemOwner {
+            table_prefix: table_prefix.to_string(),
+            column_id,
+        })
+    };
+    assert_eq!(c.usages(), vec![
+        (owner("1/2", 0), CacheUsage {
+            num_items: 1,
+            size: 10,
+            pinned_size: 0,
+            hits: 1,
+            misses: 1,
+        }),
+        (owner("1/2", 1), CacheUsage {
+            num_items: 1,
+            size: 10,
+            ..Default::default()
+        }),
+        (owner("1/3", 0), CacheUsage {
+            num_items: 1,
+            size: 10,
+            ..Default::default()
+        }),
+        (None, CacheUsage {
+            num_items: 1,
+            size: 5,
+            ..Default::default()
+        }),
+    ]);
+
+    assert_eq!(c.remove_items(Some("1/2")), 2);
+    assert!(!c.contains_key(&table_data_key("1/2", 0, 0)));
+    assert!(c.contains_key(&table_data_key("1/3", 0, 0)));
+    assert_eq!(c.size(), 15);
+
+    assert_eq!(c.remove_items(None), 2);
+    assert_eq!(c.size(), 0);
+    assert!(c.usages().is_empty());
+}
+
 #[test]
 fn test_fuzzy_restart_parallelism() {
     let f = TestFixture::new();
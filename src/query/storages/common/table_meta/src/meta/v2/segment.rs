@@ -82,6 +82,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// id of the key that encrypts the data block, if the block is encrypted
+    #[serde(default)]
+    pub encryption_key_id: Option<String>,
 }
 
 impl BlockMeta {
@@ -99,6 +103,7 @@ impl BlockMeta {
         inverted_index_size: Option<u64>,
         compression: Compression,
         create_on: Option<DateTime<Utc>>,
+        encryption_key_id: Option<String>,
     ) -> Self {
         Self {
             row_count,
@@ -113,6 +118,7 @@ impl BlockMeta {
             inverted_index_size,
             compression,
             create_on,
+            encryption_key_id,
         }
     }
 
@@ -260,6 +266,7 @@ impl BlockMeta {
             compression: Compression::Lz4,
             inverted_index_size: None,
             create_on: None,
+            encryption_key_id: None,
         }
     }
 
@@ -293,6 +300,7 @@ impl BlockMeta {
             compression: s.compression,
             inverted_index_size: None,
             create_on: None,
+            encryption_key_id: None,
         }
     }
 }
@@ -87,6 +87,11 @@ pub struct TableSnapshot {
     /// The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// id of the query which committed this snapshot, reported when a concurrent
+    /// transaction conflicts with it
+    #[serde(default)]
+    pub query_id: Option<String>,
 }
 
 impl TableSnapshot {
@@ -120,6 +125,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            query_id: None,
         }
     }
 
@@ -234,6 +240,7 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            query_id: None,
         }
     }
 }
@@ -256,6 +263,7 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            query_id: None,
         }
     }
 }
@@ -17,6 +17,10 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+// The max bytes of the table data in disk cache that are exempt from eviction.
+pub const FUSE_OPT_KEY_CACHE_PIN: &str = "cache_pin";
+// The id of the key that encrypts new data blocks, resolved by the key provider.
+pub const FUSE_OPT_KEY_ENCRYPTION_KEY_ID: &str = "encryption_key_id";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use aes::cipher::BlockEncrypt;
+use aes::cipher::KeyInit;
+use aes::Aes256;
+use aes::Block;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parking_lot::RwLock;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Resolves the AES-256 keys used to encrypt the data blocks of fuse tables.
+pub trait EncryptionKeyProvider: Send + Sync {
+    /// Returns the key of `key_id`, or an error if the key is unknown.
+    fn get_key(&self, key_id: &str) -> Result<[u8; 32]>;
+}
+
+/// Key provider backed by the `data_encryption_keys` of the query config.
+///
+/// Each entry is `<key_id>:<hex encoded 32 bytes key>`.
+pub struct StaticKeyProvider {
+    keys: HashMap<String, [u8; 32]>,
+}
+
+impl StaticKeyProvider {
+    pub fn try_create(entries: &[String]) -> Result<Self> {
+        let mut keys = HashMap::with_capacity(entries.len());
+        for entry in entries {
+            let (key_id, hex_key) = entry.split_once(':').ok_or_else(|| {
+                ErrorCode::InvalidConfig("invalid data encryption key, expect '<key_id>:<hex key>'")
+            })?;
+            let key = hex::decode(hex_key.trim())
+                .ok()
+                .and_then(|v| <[u8; 32]>::try_from(v).ok())
+                .ok_or_else(|| {
+                    ErrorCode::InvalidConfig(format!(
+                        "invalid data encryption key '{}', expect 32 bytes in hex",
+                        key_id
+                    ))
+                })?;
+            keys.insert(key_id.trim().to_string(), key);
+        }
+        Ok(Self { keys })
+    }
+}
+
+impl EncryptionKeyProvider for StaticKeyProvider {
+    fn get_key(&self, key_id: &str) -> Result<[u8; 32]> {
+        self.keys.get(key_id).copied().ok_or_else(|| {
+            ErrorCode::StorageOther(format!(
+                "encryption key '{}' is not provided by the key provider",
+                key_id
+            ))
+        })
+    }
+}
+
+pub struct DataEncryption {
+    provider: RwLock<Arc<dyn EncryptionKeyProvider>>,
+}
+
+impl DataEncryption {
+    pub fn init(entries: &[String]) -> Result<()> {
+        let provider = StaticKeyProvider::try_create(entries)?;
+        GlobalInstance::set(Arc::new(DataEncryption {
+            provider: RwLock::new(Arc::new(provider)),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<DataEncryption> {
+        GlobalInstance::get()
+    }
+
+    /// Replaces the key provider, e.g. with a KMS backed one.
+    pub fn set_provider(&self, provider: Arc<dyn EncryptionKeyProvider>) {
+        *self.provider.write() = provider;
+    }
+
+    pub fn cipher(&self, key_id: &str) -> Result<BlockCipher> {
+        let provider = self.provider.read().clone();
+        let key = provider.get_key(key_id)?;
+        Ok(BlockCipher {
+            cipher: Aes256::new(&key.into()),
+        })
+    }
+
+    /// Resolves the cipher of an (optional) block encryption key id.
+    pub fn try_get_cipher(key_id: &Option<String>) -> Result<Option<BlockCipher>> {
+        match key_id {
+            None => Ok(None),
+            Some(key_id) => Ok(Some(Self::instance().cipher(key_id)?)),
+        }
+    }
+}
+
+/// AES-256 in CTR mode.
+///
+/// CTR keeps the size of the payload, so the column offsets recorded in the
+/// block meta stay valid and any byte range of a block can be decrypted on
+/// its own. The initial counter is derived from the block location, which is
+/// never reused for another payload.
+pub struct BlockCipher {
+    cipher: Aes256,
+}
+
+impl BlockCipher {
+    /// Encrypts or decrypts `data`, which starts at `offset` of the block file.
+    pub fn apply_keystream(&self, location: &str, offset: u64, data: &mut [u8]) {
+        let digest = Sha256::digest(location.as_bytes());
+        let mut iv = [0u8; 16];
+        iv.copy_from_slice(&digest[..16]);
+        let iv = u128::from_be_bytes(iv);
+
+        let mut counter = offset / 16;
+        let mut skip = (offset % 16) as usize;
+        let mut pos = 0;
+        while pos < data.len() {
+            let mut keystream = Block::from(iv.wrapping_add(counter as u128).to_be_bytes());
+            self.cipher.encrypt_block(&mut keystream);
+
+            // only the first keystream block may start in the middle
+            let len = (16 - skip).min(data.len() - pos);
+            for (byte, key) in data[pos..pos + len].iter_mut().zip(&keystream[skip..]) {
+                *byte ^= key;
+            }
+            pos += len;
+            skip = 0;
+            counter += 1;
+        }
+    }
+}
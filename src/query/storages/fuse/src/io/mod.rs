@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod encryption;
 mod locations;
 pub mod read;
 mod segments;
 mod snapshots;
 mod write;
 
+pub use encryption::BlockCipher;
+pub use encryption::DataEncryption;
+pub use encryption::EncryptionKeyProvider;
+pub use encryption::StaticKeyProvider;
 pub use locations::TableMetaLocationGenerator;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
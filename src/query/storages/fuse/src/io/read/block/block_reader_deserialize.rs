@@ -96,7 +96,13 @@ impl BlockReader {
     ) -> Result<DataBlock> {
         // Get the merged IO read result.
         let merge_io_read_result = self
-            .read_columns_data_by_merge_io(settings, &meta.location.0, &meta.col_metas, &None)
+            .read_columns_data_by_merge_io(
+                settings,
+                &meta.location.0,
+                &meta.col_metas,
+                &None,
+                &meta.encryption_key_id,
+            )
             .await?;
 
         self.deserialize_chunks_with_meta(meta, storage_format, merge_io_read_result)
@@ -33,6 +33,7 @@ use opendal::Operator;
 use crate::io::read::block::block_reader_merge_io::OwnerMemory;
 use crate::io::read::ReadSettings;
 use crate::io::BlockReader;
+use crate::io::DataEncryption;
 use crate::MergeIOReadResult;
 
 impl BlockReader {
@@ -48,6 +49,7 @@ impl BlockReader {
         location: &str,
         raw_ranges: &[(ColumnId, Range<u64>)],
         put_cache: bool,
+        encryption_key_id: &Option<String>,
     ) -> Result<MergeIOReadResult> {
         let table_data_cache = if put_cache {
             CacheManager::instance().get_table_data_cache()
@@ -66,6 +68,9 @@ impl BlockReader {
             return Ok(read_res);
         }
 
+        // Resolve the key before any IO, a missing key fails the read early.
+        let cipher = DataEncryption::try_get_cipher(encryption_key_id)?;
+
         // Build merged read ranges.
         let ranges = raw_ranges
             .iter()
@@ -97,7 +102,13 @@ impl BlockReader {
         }
 
         let start = Instant::now();
-        let owner_memory = OwnerMemory::create(try_join_all(read_handlers).await?);
+        let mut chunks = try_join_all(read_handlers).await?;
+        if let Some(cipher) = &cipher {
+            for (idx, chunk) in chunks.iter_mut() {
+                cipher.apply_keystream(location, merged_ranges[*idx].start, chunk);
+            }
+        }
+        let owner_memory = OwnerMemory::create(chunks);
         let mut read_res = MergeIOReadResult::create(
             owner_memory,
             raw_ranges.len(),
@@ -136,6 +147,7 @@ impl BlockReader {
         location: &str,
         columns_meta: &HashMap<ColumnId, ColumnMeta>,
         ignore_column_ids: &Option<HashSet<ColumnId>>,
+        encryption_key_id: &Option<String>,
     ) -> Result<MergeIOReadResult> {
         // Perf
         {
@@ -189,6 +201,7 @@ impl BlockReader {
             location,
             &ranges,
             self.put_cache,
+            encryption_key_id,
         )
         .await?;
 
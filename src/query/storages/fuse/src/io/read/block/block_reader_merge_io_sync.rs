@@ -32,6 +32,7 @@ use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::read::block::block_reader_merge_io::OwnerMemory;
 use crate::io::read::ReadSettings;
 use crate::io::BlockReader;
+use crate::io::DataEncryption;
 use crate::MergeIOReadResult;
 
 impl BlockReader {
@@ -40,8 +41,10 @@ impl BlockReader {
         op: Operator,
         location: &str,
         raw_ranges: &[(ColumnId, Range<u64>)],
+        encryption_key_id: &Option<String>,
     ) -> Result<MergeIOReadResult> {
         let path = location.to_string();
+        let cipher = DataEncryption::try_get_cipher(encryption_key_id)?;
 
         // Build merged read ranges.
         let ranges = raw_ranges
@@ -66,6 +69,11 @@ impl BlockReader {
                 range.end,
             )?);
         }
+        if let Some(cipher) = &cipher {
+            for (idx, chunk) in io_res.iter_mut() {
+                cipher.apply_keystream(location, merged_ranges[*idx].start, chunk);
+            }
+        }
 
         let owner_memory = OwnerMemory::create(io_res);
 
@@ -128,8 +136,13 @@ impl BlockReader {
             }
         }
 
-        let mut merge_io_result =
-            Self::sync_merge_io_read(settings, self.operator.clone(), &part.location, &ranges)?;
+        let mut merge_io_result = Self::sync_merge_io_read(
+            settings,
+            self.operator.clone(),
+            &part.location,
+            &ranges,
+            &part.encryption_key_id,
+        )?;
         merge_io_result.cached_column_array = cached_column_array;
 
         self.report_cache_metrics(&merge_io_result, ranges.iter().map(|(_, r)| r));
@@ -71,6 +71,7 @@ impl BlockReader {
                 &part.location,
                 &part.columns_meta,
                 ignore_column_ids,
+                &part.encryption_key_id,
             )
             .await?;
 
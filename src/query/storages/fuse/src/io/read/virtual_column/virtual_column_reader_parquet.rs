@@ -99,11 +99,17 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
-            let merge_io_result =
-                BlockReader::sync_merge_io_read(read_settings, self.dal.clone(), loc, &ranges)
-                    .ok()?;
+            let merge_io_result = BlockReader::sync_merge_io_read(
+                read_settings,
+                self.dal.clone(),
+                loc,
+                &ranges,
+                &None,
+            )
+            .ok()?;
 
             Some(VirtualMergeIOReadResult::create(
                 part,
@@ -144,6 +150,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
@@ -152,6 +159,7 @@ impl VirtualColumnReader {
                 loc,
                 &ranges,
                 self.reader.put_cache,
+                &None,
             )
             .await
             .ok()?;
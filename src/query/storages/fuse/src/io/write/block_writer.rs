@@ -53,6 +53,7 @@ use opendal::Operator;
 
 use crate::io::write::WriteSettings;
 use crate::io::BlockReader;
+use crate::io::DataEncryption;
 use crate::io::InvertedIndexWriter;
 use crate::io::ReadSettings;
 use crate::io::TableMetaLocationGenerator;
@@ -381,6 +382,11 @@ impl BlockBuilder {
             &mut buffer,
         )?;
         let file_size = buffer.len() as u64;
+        if let Some(cipher) =
+            DataEncryption::try_get_cipher(&self.write_settings.encryption_key_id)?
+        {
+            cipher.apply_keystream(&block_location.0, 0, &mut buffer);
+        }
         let inverted_index_size = if !inverted_index_states.is_empty() {
             let size = inverted_index_states.iter().map(|v| v.size).sum();
             Some(size)
@@ -403,6 +409,7 @@ impl BlockBuilder {
             compression: self.write_settings.table_compression.into(),
             inverted_index_size,
             create_on: Some(Utc::now()),
+            encryption_key_id: self.write_settings.encryption_key_id.clone(),
         };
 
         let serialized = BlockSerialization {
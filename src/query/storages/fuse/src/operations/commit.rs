@@ -16,8 +16,8 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-use backoff::backoff::Backoff;
 use chrono::Utc;
+use databend_common_base::fail_point;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
@@ -61,6 +61,7 @@ use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
 use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
+use crate::operations::next_commit_backoff;
 use crate::operations::set_backoff;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
@@ -230,6 +231,7 @@ impl FuseTable {
         };
 
         // 3. let's roll
+        fail_point!("fuse_commit_snapshot");
         catalog.update_table_meta(table_info, req).await?;
 
         // update_table_meta succeed, populate the snapshot cache item and try keeping a hit file of last snapshot
@@ -282,6 +284,7 @@ impl FuseTable {
     ) -> Result<()> {
         let mut retries = 0;
         let mut backoff = set_backoff(None, None, max_retry_elapsed);
+        let max_retries = ctx.get_settings().get_max_commit_retry()?;
 
         let mut latest_snapshot = base_snapshot.clone();
         let mut latest_table_info = &self.table_info;
@@ -301,6 +304,7 @@ impl FuseTable {
                 latest_snapshot.as_ref(),
                 Some(latest_table_info.ident.seq),
             );
+            snapshot_tobe_committed.query_id = Some(ctx.get_id());
 
             let schema = self.schema();
             let (segments_tobe_committed, statistics_tobe_committed) = Self::merge_with_base(
@@ -328,7 +332,7 @@ impl FuseTable {
             .await
             {
                 Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
-                    match backoff.next_backoff() {
+                    match next_commit_backoff(&mut backoff, retries, max_retries) {
                         Some(d) => {
                             let name = self.table_info.name.clone();
                             debug!(
@@ -119,9 +119,16 @@ impl SnapshotGenerator for MutationGenerator {
                     Ok(new_snapshot)
                 } else {
                     metrics_inc_commit_mutation_unresolvable_conflict();
-                    Err(ErrorCode::UnresolvableConflict(format!(
-                        "conflict resolve context:{:?}",
+                    info!(
+                        "unresolvable conflicts detected, conflict resolve context:{:?}",
                         self.conflict_resolve_ctx
+                    );
+                    Err(ErrorCode::UnresolvableConflict(format!(
+                        "{} conflicts with a concurrent transaction which modified the same segments (query id: {}, latest snapshot: {}, base snapshot: {}), please retry",
+                        self.mutation_kind,
+                        previous.query_id.as_deref().unwrap_or("unknown"),
+                        previous.snapshot_id,
+                        self.base_snapshot.snapshot_id
                     )))
                 }
             }
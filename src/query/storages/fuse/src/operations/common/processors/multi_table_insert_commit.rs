@@ -18,7 +18,6 @@ use std::time::Instant;
 
 use async_trait::async_trait;
 use async_trait::unboxed_simple;
-use backoff::backoff::Backoff;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
@@ -38,6 +37,7 @@ use log::debug;
 use log::error;
 use log::info;
 
+use crate::operations::next_commit_backoff;
 use crate::operations::set_backoff;
 use crate::operations::AppendGenerator;
 use crate::operations::CommitMeta;
@@ -90,8 +90,10 @@ impl AsyncSink for CommitMultiTableInsert {
             let mut snapshot_generator = AppendGenerator::new(self.ctx.clone(), self.overwrite);
             snapshot_generator.set_conflict_resolve_context(commit_meta.conflict_resolve_context);
             let table = self.tables.get(&table_id).unwrap();
-            update_table_meta_reqs
-                .push(build_update_table_meta_req(table.as_ref(), &snapshot_generator).await?);
+            update_table_meta_reqs.push(
+                build_update_table_meta_req(self.ctx.as_ref(), table.as_ref(), &snapshot_generator)
+                    .await?,
+            );
             snapshot_generators.insert(table_id, snapshot_generator);
             table_infos.push(table.get_table_info());
         }
@@ -118,6 +120,7 @@ impl AsyncSink for CommitMultiTableInsert {
                 // auto commit
                 let mut backoff = set_backoff(None, None, None);
                 let mut retries = 0;
+                let max_retries = self.ctx.get_settings().get_max_commit_retry()?;
 
                 loop {
                     let update_multi_table_meta_req = UpdateMultiTableMetaReq {
@@ -171,7 +174,7 @@ impl AsyncSink for CommitMultiTableInsert {
                             (&tbl_info.desc, (tid, seq), &meta.engine)
                         })
                         .collect();
-                    match backoff.next_backoff() {
+                    match next_commit_backoff(&mut backoff, retries, max_retries) {
                         Some(duration) => {
                             retries += 1;
 
@@ -190,6 +193,7 @@ impl AsyncSink for CommitMultiTableInsert {
                                 for req in update_table_meta_reqs.iter_mut() {
                                     if req.table_id == tid {
                                         *req = build_update_table_meta_req(
+                                            self.ctx.as_ref(),
                                             table.as_ref(),
                                             snapshot_generators.get(&tid).unwrap(),
                                         )
@@ -247,17 +251,19 @@ impl AsyncSink for CommitMultiTableInsert {
 }
 
 async fn build_update_table_meta_req(
+    ctx: &dyn TableContext,
     table: &dyn Table,
     snapshot_generator: &AppendGenerator,
 ) -> Result<UpdateTableMetaReq> {
     let fuse_table = FuseTable::try_from_table(table)?;
     let previous = fuse_table.read_table_snapshot().await?;
-    let snapshot = snapshot_generator.generate_new_snapshot(
+    let mut snapshot = snapshot_generator.generate_new_snapshot(
         table.schema().as_ref().clone(),
         fuse_table.cluster_key_meta.clone(),
         previous,
         Some(fuse_table.table_info.ident.seq),
     )?;
+    snapshot.query_id = Some(ctx.get_id());
 
     // write snapshot
     let dal = fuse_table.get_operator();
@@ -17,7 +17,6 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
-use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
@@ -46,6 +45,7 @@ use log::warn;
 use opendal::Operator;
 
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::next_commit_backoff;
 use crate::operations::set_backoff;
 use crate::operations::AppendGenerator;
 use crate::operations::CommitMeta;
@@ -87,6 +87,7 @@ pub struct CommitSink<F: SnapshotGenerator> {
     snapshot_gen: F,
     purge: bool,
     retries: u64,
+    max_retries: u64,
     max_retry_elapsed: Option<Duration>,
     backoff: ExponentialBackoff,
 
@@ -115,6 +116,7 @@ where F: SnapshotGenerator + Send + 'static
         deduplicated_label: Option<String>,
     ) -> Result<ProcessorPtr> {
         let purge = Self::do_purge(table, &snapshot_gen);
+        let max_retries = ctx.get_settings().get_max_commit_retry()?;
         Ok(ProcessorPtr::create(Box::new(CommitSink {
             state: State::None,
             ctx,
@@ -126,6 +128,7 @@ where F: SnapshotGenerator + Send + 'static
             purge,
             backoff: ExponentialBackoff::default(),
             retries: 0,
+            max_retries,
             max_retry_elapsed,
             input,
             new_segment_locs: vec![],
@@ -287,7 +290,8 @@ where F: SnapshotGenerator + Send + 'static
                     previous,
                     Some(table_info.ident.seq),
                 ) {
-                    Ok(snapshot) => {
+                    Ok(mut snapshot) => {
+                        snapshot.query_id = Some(self.ctx.get_id());
                         self.state = State::TryCommit {
                             data: snapshot.to_bytes()?,
                             snapshot,
@@ -302,11 +306,17 @@ where F: SnapshotGenerator + Send + 'static
             State::Abort(e) => {
                 let duration = self.start_time.elapsed();
                 metrics_inc_commit_aborts();
+                if e.code() == ErrorCode::UNRESOLVABLE_CONFLICT {
+                    metrics_inc_commit_table_conflicts("unresolvable");
+                }
                 metrics_inc_commit_milliseconds(duration.as_millis());
+                let table_info = self.table.get_table_info();
                 error!(
-                    "transaction aborted after {} retries, which took {} ms, cause: {:?}",
+                    "transaction aborted after {} retries, which took {} ms. table name {}, identity {}, cause: {:?}",
                     self.retries,
                     duration.as_millis(),
+                    table_info.name.as_str(),
+                    table_info.ident,
                     e
                 );
                 return Err(e);
@@ -449,7 +459,21 @@ where F: SnapshotGenerator + Send + 'static
                     }
                     Err(e) if self.is_error_recoverable(&e) => {
                         let table_info = self.table.get_table_info();
-                        match self.backoff.next_backoff() {
+                        let conflict_type = if self.is_append_only_txn() {
+                            "append"
+                        } else {
+                            "mutation"
+                        };
+                        metrics_inc_commit_table_conflicts(conflict_type);
+                        warn!(
+                            "{} commit conflicts with a concurrent transaction. table name {}, identity {}, cause: {}",
+                            conflict_type,
+                            table_info.name.as_str(),
+                            table_info.ident,
+                            e.message()
+                        );
+                        match next_commit_backoff(&mut self.backoff, self.retries, self.max_retries)
+                        {
                             Some(d) => {
                                 let name = table_info.name.clone();
                                 debug!(
@@ -460,6 +484,7 @@ where F: SnapshotGenerator + Send + 'static
                                 );
                                 databend_common_base::base::tokio::time::sleep(d).await;
                                 self.retries += 1;
+                                metrics_inc_commit_table_retries();
                                 self.state = State::RefreshTable;
                             }
                             None => {
@@ -49,5 +49,6 @@ pub use read::need_reserve_block_info;
 pub use replace_into::*;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
+pub use util::next_commit_backoff;
 pub use util::read_block;
 pub use util::set_backoff;
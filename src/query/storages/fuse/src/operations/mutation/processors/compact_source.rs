@@ -211,6 +211,7 @@ impl Processor for CompactSource {
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
+                                        &block.encryption_key_id,
                                     )
                                     .await
                             });
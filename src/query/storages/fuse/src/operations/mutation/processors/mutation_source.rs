@@ -421,6 +421,7 @@ impl Processor for MutationSource {
                                     &fuse_part.location,
                                     &fuse_part.columns_meta,
                                     &None,
+                                    &fuse_part.encryption_key_id,
                                 )
                                 .await?;
                             self.state = State::FilterData(inner_part, read_res);
@@ -443,6 +444,7 @@ impl Processor for MutationSource {
                             &fuse_part.location,
                             &fuse_part.columns_meta,
                             &None,
+                            &fuse_part.encryption_key_id,
                         )
                         .await?;
                     self.state = State::MergeRemain {
@@ -302,6 +302,7 @@ impl Processor for ReadParquetDataSource<false> {
                                 &part.location,
                                 &part.columns_meta,
                                 ignore_column_ids,
+                                &part.encryption_key_id,
                             )
                             .await?;
 
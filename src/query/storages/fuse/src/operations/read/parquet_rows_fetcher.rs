@@ -238,6 +238,7 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
                         &part.location,
                         &part.columns_meta,
                         &None,
+                        &part.encryption_key_id,
                     )
                     .await?;
                 chunks.push(chunk);
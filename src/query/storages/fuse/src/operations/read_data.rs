@@ -28,6 +28,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_storages_common_cache_manager::CacheManager;
 
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
@@ -38,6 +39,7 @@ use crate::pruning::SegmentLocation;
 use crate::FuseLazyPartInfo;
 use crate::FuseStorageFormat;
 use crate::FuseTable;
+use crate::FUSE_OPT_KEY_CACHE_PIN;
 
 impl FuseTable {
     pub fn create_block_reader(
@@ -186,6 +188,13 @@ impl FuseTable {
             });
         }
 
+        if put_cache {
+            if let Some(table_data_cache) = CacheManager::instance().get_table_data_cache() {
+                let cache_pin = self.get_option(FUSE_OPT_KEY_CACHE_PIN, 0);
+                table_data_cache.set_pin_budget(self.meta_location_generator.prefix(), cache_pin);
+            }
+        }
+
         let block_reader = self.build_block_reader(ctx.clone(), plan, put_cache)?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
 
@@ -500,6 +500,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.encryption_key_id.clone(),
         )
     }
 
@@ -548,6 +549,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.encryption_key_id.clone(),
         )
     }
 }
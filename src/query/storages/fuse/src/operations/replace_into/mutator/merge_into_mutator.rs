@@ -631,6 +631,7 @@ impl AggregationContext {
                 &block_meta.location.0,
                 &block_meta.col_metas,
                 &None,
+                &block_meta.encryption_key_id,
             )
             .await?;
 
@@ -18,6 +18,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::OneBlockSource;
 
@@ -36,6 +37,7 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         pipeline: &mut Pipeline,
         mode: TruncateMode,
+        update_stream_meta: Vec<UpdateStreamMetaReq>,
     ) -> Result<()> {
         if let Some(prev_snapshot) = self.read_table_snapshot().await? {
             // Delete operation commit can retry multi-times if table version mismatched.
@@ -63,7 +65,7 @@ impl FuseTable {
                     self,
                     ctx.clone(),
                     None,
-                    vec![],
+                    update_stream_meta.clone(),
                     snapshot_gen.clone(),
                     input,
                     None,
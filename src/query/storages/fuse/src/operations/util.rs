@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
 use backoff::ExponentialBackoffBuilder;
 use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
@@ -68,6 +69,22 @@ pub fn set_backoff(
         .build()
 }
 
+/// Get the delay before retrying a conflicting commit, `None` if the commit should be aborted.
+///
+/// The retries are bounded by both `max_retries` and the max elapsed time of the backoff,
+/// `max_retries` of 0 means only the elapsed time applies.
+pub fn next_commit_backoff(
+    backoff: &mut ExponentialBackoff,
+    retries: u64,
+    max_retries: u64,
+) -> Option<Duration> {
+    if max_retries > 0 && retries >= max_retries {
+        None
+    } else {
+        backoff.next_backoff()
+    }
+}
+
 pub fn column_parquet_metas(
     file_meta: &parquet_rs::format::FileMetaData,
     schema: &TableSchemaRef,
@@ -143,6 +160,7 @@ pub async fn read_block(
             &block_meta.location.0,
             &block_meta.col_metas,
             &None,
+            &block_meta.encryption_key_id,
         )
         .await?;
 
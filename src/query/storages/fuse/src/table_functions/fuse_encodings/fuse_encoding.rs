@@ -119,6 +119,7 @@ impl<'a> FuseEncoding<'a> {
                                 &block.location.0,
                                 &ranges,
                                 true,
+                                &block.encryption_key_id,
                             )
                             .await?;
                             let column_chunks = merge_io_read_res.columns_chunks()?;
@@ -15,6 +15,8 @@
 mod columns_table;
 mod key_column_usage_table;
 mod keywords_table;
+mod parameters_table;
+mod routines_table;
 mod schemata_table;
 mod statistics_table;
 mod tables_table;
@@ -23,6 +25,8 @@ mod views_table;
 pub use columns_table::ColumnsTable;
 pub use key_column_usage_table::KeyColumnUsageTable;
 pub use keywords_table::KeywordsTable;
+pub use parameters_table::ParametersTable;
+pub use routines_table::RoutinesTable;
 pub use schemata_table::SchemataTable;
 pub use statistics_table::StatisticsTable;
 pub use tables_table::TablesTable;
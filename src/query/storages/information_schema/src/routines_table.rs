@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_view::view_table::ViewTable;
+use databend_common_storages_view::view_table::QUERY;
+
+pub struct RoutinesTable {}
+
+impl RoutinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        // UDFs don't belong to any database, and only the time they are last created or
+        // altered is recorded.
+        let query = "SELECT
+            'default' AS specific_catalog,
+            NULL AS specific_schema,
+            f.name AS specific_name,
+            'default' AS routine_catalog,
+            NULL AS routine_schema,
+            f.name AS routine_name,
+            'FUNCTION' AS routine_type,
+            p.data_type AS data_type,
+            IF(f.language = 'SQL', 'SQL', 'EXTERNAL') AS routine_body,
+            f.definition AS routine_definition,
+            IF(f.language = 'SQL', NULL, f.language) AS external_language,
+            f.created_on AS created,
+            f.created_on AS last_altered,
+            f.description AS routine_comment
+        FROM system.user_functions AS f
+        LEFT JOIN system.user_function_parameters AS p
+            ON f.name = p.function_name AND p.ordinal_position = 0";
+
+        let mut options = BTreeMap::new();
+        options.insert(QUERY.to_string(), query.to_string());
+        let table_info = TableInfo {
+            desc: "'information_schema'.'routines'".to_string(),
+            name: "routines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                options,
+                engine: "VIEW".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        ViewTable::create(table_info)
+    }
+}
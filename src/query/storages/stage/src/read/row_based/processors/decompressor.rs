@@ -21,6 +21,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_pipeline_sources::input_formats::InputContext;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
 
 use crate::read::load_context::LoadContext;
@@ -29,28 +31,57 @@ use crate::read::row_based::batch::BytesBatch;
 pub struct Decompressor {
     #[allow(dead_code)]
     ctx: Arc<LoadContext>,
-    algo: Option<CompressAlgorithm>,
+    option: StageFileCompression,
     decompressor: Option<(DecompressDecoder, usize)>,
     path: Option<String>,
 }
 
 impl Decompressor {
-    pub fn try_create(ctx: Arc<LoadContext>, algo: Option<CompressAlgorithm>) -> Result<Self> {
+    pub fn try_create(ctx: Arc<LoadContext>, option: StageFileCompression) -> Result<Self> {
+        // Reject the unimplemented algorithms before reading any file.
+        InputContext::get_compression_alg_copy(option, "")?;
         Ok(Decompressor {
             ctx,
-            algo,
+            option,
             path: None,
             decompressor: None,
         })
     }
 
-    fn new_file(&mut self, path: String) {
+    /// Detect the compression of a file by its extension first, then by the magic bytes
+    /// of its first batch.
+    ///
+    /// `NONE` is the default option of file formats, so it is treated the same as `AUTO`.
+    /// An explicit algorithm is used as is, unless the detected one contradicts it.
+    fn detect_compression(
+        option: StageFileCompression,
+        path: &str,
+        data: &[u8],
+    ) -> Result<Option<CompressAlgorithm>> {
+        let detected = CompressAlgorithm::from_path(path)
+            .or_else(|| CompressAlgorithm::from_magic_bytes(data));
+        match option {
+            StageFileCompression::Auto | StageFileCompression::None => Ok(detected),
+            _ => {
+                let algo = InputContext::get_compression_alg_copy(option, path)?;
+                match (algo, detected) {
+                    (Some(algo), Some(detected)) if algo != detected => {
+                        Err(ErrorCode::InvalidCompressionData(format!(
+                            "file {} is detected as {:?} compressed, which contradicts the option COMPRESSION = {}",
+                            path,
+                            detected,
+                            option.to_string()
+                        )))
+                    }
+                    _ => Ok(algo),
+                }
+            }
+        }
+    }
+
+    fn new_file(&mut self, path: String, data: &[u8]) -> Result<()> {
         assert!(self.decompressor.is_none());
-        let algo = if let Some(algo) = &self.algo {
-            Some(algo.to_owned())
-        } else {
-            CompressAlgorithm::from_path(&path)
-        };
+        let algo = Self::detect_compression(self.option, &path, data)?;
         self.path = Some(path);
 
         if let Some(algo) = algo {
@@ -59,6 +90,7 @@ impl Decompressor {
         } else {
             self.decompressor = None;
         }
+        Ok(())
     }
 }
 
@@ -71,10 +103,10 @@ impl AccumulatingTransform for Decompressor {
             .and_then(BytesBatch::downcast_from)
             .unwrap();
         match &self.path {
-            None => self.new_file(batch.path.clone()),
+            None => self.new_file(batch.path.clone(), &batch.data)?,
             Some(path) => {
                 if path != &batch.path {
-                    self.new_file(batch.path.clone())
+                    self.new_file(batch.path.clone(), &batch.data)?
                 }
             }
         }
@@ -21,10 +21,8 @@ use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
-use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
-use databend_common_pipeline_sources::input_formats::InputContext;
 use databend_common_pipeline_sources::EmptySource;
 use databend_common_pipeline_sources::PrefetchAsyncSourcer;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
@@ -65,7 +63,7 @@ impl RowBasedReadPipelineBuilder<'_> {
 
     // processors:
     // 1. BytesReader
-    // 2. (optional) Decompressor
+    // 2. Decompressor: pass through the files that are not compressed
     // 3. Separator: cut file into RowBatches(bytes with row/field ends)
     // 4. (resize to threads): so row batches can be processed in parallel, regardless of the file it from.
     // 5. BlockBuilder: the slow part most of the time
@@ -108,25 +106,21 @@ impl RowBasedReadPipelineBuilder<'_> {
             self.compact_threshold,
         )?);
 
-        match self
+        // Compressed files are not splittable, each of them is decompressed by a single pipe,
+        // while different files are still processed in parallel.
+        let compression = self
             .stage_table_info
             .stage_info
             .file_format_params
-            .compression()
-        {
-            StageFileCompression::None => {}
-            compression => {
-                let algo = InputContext::get_compression_alg_copy(compression, "")?;
-                pipeline.add_transform(|input, output| {
-                    let transformer = Decompressor::try_create(load_ctx.clone(), algo)?;
-                    Ok(ProcessorPtr::create(AccumulatingTransformer::create(
-                        input,
-                        output,
-                        transformer,
-                    )))
-                })?;
-            }
-        }
+            .compression();
+        pipeline.add_transform(|input, output| {
+            let transformer = Decompressor::try_create(load_ctx.clone(), compression)?;
+            Ok(ProcessorPtr::create(AccumulatingTransformer::create(
+                input,
+                output,
+                transformer,
+            )))
+        })?;
 
         pipeline.add_transform(|input, output| {
             let transformer = Separator::try_create(load_ctx.clone(), format.clone())?;
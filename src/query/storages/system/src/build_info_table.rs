@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::QUERY_BUILD_TIMESTAMP;
+use databend_common_config::QUERY_GIT_SEMVER;
+use databend_common_config::QUERY_GIT_SHA;
+use databend_common_config::QUERY_RUSTC_SEMVER;
+use databend_common_config::QUERY_TARGET_TRIPLE;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// Describes the binary serving the query: version, commit and build environment.
+pub struct BuildInfoTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for BuildInfoTable {
+    const NAME: &'static str = "system.build_info";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let cargo_features = option_env!("VERGEN_CARGO_FEATURES")
+            .map(|features| {
+                features
+                    .split_terminator(',')
+                    .map(|x| x.trim())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .unwrap_or_else(|| "not available".to_string());
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![QUERY_GIT_SEMVER.as_str()]),
+            StringType::from_data(vec![QUERY_GIT_SHA.as_str()]),
+            StringType::from_data(vec![QUERY_BUILD_TIMESTAMP.as_str()]),
+            StringType::from_data(vec![QUERY_RUSTC_SEMVER.as_str()]),
+            StringType::from_data(vec![cargo_features]),
+            StringType::from_data(vec![QUERY_TARGET_TRIPLE.as_str()]),
+        ]))
+    }
+}
+
+impl BuildInfoTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("version", TableDataType::String),
+            TableField::new("git_sha", TableDataType::String),
+            TableField::new("build_timestamp", TableDataType::String),
+            TableField::new("rustc_version", TableDataType::String),
+            TableField::new("cargo_features", TableDataType::String),
+            TableField::new("target_triple", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'build_info'".to_string(),
+            name: "build_info".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemBuildInfo".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(BuildInfoTable { table_info })
+    }
+}
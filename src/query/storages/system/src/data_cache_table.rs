@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::TableContext;
+use databend_storages_common_cache::CacheItemOwner;
+use databend_storages_common_cache_manager::CacheManager;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The usage of the disk cache of table data by table and column.
+pub struct DataCacheTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for DataCacheTable {
+    const NAME: &'static str = "system.data_cache";
+
+    // Allow distributed query.
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let usages = match CacheManager::instance().get_table_data_cache() {
+            Some(table_data_cache) => table_data_cache.usages(),
+            None => vec![],
+        };
+
+        // Resolve the ids to names, the names of the dropped tables are unknown.
+        let mut db_ids = vec![];
+        let mut table_ids = vec![];
+        for (owner, _) in usages.iter() {
+            if let Some((db_id, table_id)) = owner.as_ref().and_then(parse_table_prefix) {
+                db_ids.push(db_id);
+                table_ids.push(table_id);
+            }
+        }
+        db_ids.sort();
+        db_ids.dedup();
+        table_ids.sort();
+        table_ids.dedup();
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_default_catalog()?;
+        let db_names = db_ids
+            .iter()
+            .copied()
+            .zip(catalog.mget_database_names_by_ids(&tenant, &db_ids).await?)
+            .collect::<HashMap<_, _>>();
+        let table_names = table_ids
+            .iter()
+            .copied()
+            .zip(catalog.mget_table_names_by_ids(&tenant, &table_ids).await?)
+            .collect::<HashMap<_, _>>();
+
+        let local_node = ctx.get_cluster().local_id.clone();
+        let mut nodes = Vec::new();
+        let mut databases = Vec::new();
+        let mut tables = Vec::new();
+        let mut column_ids = Vec::new();
+        let mut num_items = Vec::new();
+        let mut sizes = Vec::new();
+        let mut pinned_sizes = Vec::new();
+        let mut hits = Vec::new();
+        let mut misses = Vec::new();
+        let mut hit_rates = Vec::new();
+
+        for (owner, usage) in usages {
+            let (database, table) = owner
+                .as_ref()
+                .and_then(parse_table_prefix)
+                .map(|(db_id, table_id)| {
+                    (
+                        db_names.get(&db_id).cloned().flatten(),
+                        table_names.get(&table_id).cloned().flatten(),
+                    )
+                })
+                .unwrap_or_default();
+            nodes.push(local_node.clone());
+            databases.push(database);
+            tables.push(table);
+            column_ids.push(owner.as_ref().map(|owner| owner.column_id));
+            num_items.push(usage.num_items);
+            sizes.push(usage.size);
+            pinned_sizes.push(usage.pinned_size);
+            hits.push(usage.hits);
+            misses.push(usage.misses);
+            let accesses = usage.hits + usage.misses;
+            hit_rates.push(if accesses == 0 {
+                0.0
+            } else {
+                usage.hits as f64 / accesses as f64
+            });
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(nodes),
+            StringType::from_opt_data(databases),
+            StringType::from_opt_data(tables),
+            UInt32Type::from_opt_data(column_ids),
+            UInt64Type::from_data(num_items),
+            UInt64Type::from_data(sizes),
+            UInt64Type::from_data(pinned_sizes),
+            UInt64Type::from_data(hits),
+            UInt64Type::from_data(misses),
+            Float64Type::from_data(hit_rates),
+        ]))
+    }
+}
+
+/// Parse the database id and table id from the table prefix `{database_id}/{table_id}`.
+fn parse_table_prefix(owner: &CacheItemOwner) -> Option<(u64, u64)> {
+    let (db_prefix, table_id) = owner.table_prefix.rsplit_once('/')?;
+    let db_id = db_prefix.rsplit('/').next()?.parse::<u64>().ok()?;
+    Some((db_id, table_id.parse::<u64>().ok()?))
+}
+
+impl DataCacheTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new(
+                "database",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "table",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "column_id",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+            TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("pinned_size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hits", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("misses", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hit_rate", TableDataType::Number(NumberDataType::Float64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'data_cache'".to_string(),
+            name: "data_cache".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemDataCache".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::parse_computed_expr;
+use databend_common_sql::persisted_definition::parse_persisted_expr;
+use databend_common_sql::persisted_definition::parse_view_definition;
+use databend_common_sql::persisted_definition::PersistedObjectKind;
+use databend_common_sql::Planner;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::QUERY_CHECKSUM;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+struct InvalidObject {
+    database: String,
+    name: String,
+    kind: PersistedObjectKind,
+    definition: String,
+    error: ErrorCode,
+}
+
+/// Lists the views, computed columns and lambda udfs whose persisted
+/// definition can no longer be parsed or bound.
+pub struct InvalidObjectsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for InvalidObjectsTable {
+    const NAME: &'static str = "system.invalid_objects";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut objects = Self::check_tables(&ctx, push_downs).await?;
+        objects.extend(Self::check_udfs(&ctx).await?);
+
+        let mut databases = Vec::with_capacity(objects.len());
+        let mut names = Vec::with_capacity(objects.len());
+        let mut types = Vec::with_capacity(objects.len());
+        let mut definitions = Vec::with_capacity(objects.len());
+        let mut errors = Vec::with_capacity(objects.len());
+        for object in objects {
+            databases.push(object.database);
+            names.push(object.name);
+            types.push(object.kind.to_string());
+            definitions.push(object.definition);
+            errors.push(object.error.message());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_data(definitions),
+            StringType::from_data(errors),
+        ]))
+    }
+}
+
+impl InvalidObjectsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("type", TableDataType::String),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("error", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'invalid_objects'".to_string(),
+            name: "invalid_objects".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemInvalidObjects".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(InvalidObjectsTable { table_info })
+    }
+
+    #[async_backtrace::framed]
+    async fn check_tables(
+        ctx: &Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<Vec<InvalidObject>> {
+        let dialect = ctx.get_settings().get_sql_dialect()?;
+        let mut objects = vec![];
+        for (database, tables) in dump_tables(ctx, push_downs).await? {
+            for table in tables {
+                if table.engine() == VIEW_ENGINE {
+                    let name = table.name().to_string();
+                    let definition = table.options().get(QUERY).cloned().unwrap_or_default();
+                    let checksum = table
+                        .options()
+                        .get(QUERY_CHECKSUM)
+                        .and_then(|checksum| checksum.parse().ok());
+                    // Bind the query qualified with the database of the view, the same
+                    // as it's bound when the view is referenced.
+                    let error = match parse_view_definition(
+                        &database,
+                        &name,
+                        &definition,
+                        checksum,
+                        dialect,
+                    ) {
+                        Ok(query) => {
+                            let mut planner = Planner::new(ctx.clone());
+                            planner.plan_sql(&query.to_string()).await.err()
+                        }
+                        Err(e) => Some(e),
+                    };
+                    if let Some(error) = error {
+                        objects.push(InvalidObject {
+                            database: database.clone(),
+                            name,
+                            kind: PersistedObjectKind::View,
+                            definition,
+                            error,
+                        });
+                    }
+                    continue;
+                }
+
+                let schema = Arc::new(DataSchema::from(table.schema()));
+                for field in table.schema().fields() {
+                    let Some(computed_expr) = field.computed_expr() else {
+                        continue;
+                    };
+                    let name = format!("{}.{}", table.name(), field.name());
+                    let column_name = format!("{database}.{name}");
+                    let definition = computed_expr.expr().clone();
+                    let error = match parse_persisted_expr(
+                        PersistedObjectKind::ComputedColumn,
+                        &column_name,
+                        &definition,
+                        field.computed_expr_checksum(),
+                        dialect,
+                    ) {
+                        Ok(_) => {
+                            parse_computed_expr(ctx.clone(), schema.clone(), &definition).err()
+                        }
+                        Err(e) => Some(e),
+                    };
+                    if let Some(error) = error {
+                        objects.push(InvalidObject {
+                            database: database.clone(),
+                            name,
+                            kind: PersistedObjectKind::ComputedColumn,
+                            definition,
+                            error,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(objects)
+    }
+
+    #[async_backtrace::framed]
+    async fn check_udfs(ctx: &Arc<dyn TableContext>) -> Result<Vec<InvalidObject>> {
+        let dialect = ctx.get_settings().get_sql_dialect()?;
+        let tenant = ctx.get_tenant();
+        let udfs = UserApiProvider::instance().list_udf(&tenant).await?;
+
+        let mut objects = vec![];
+        for udf in udfs {
+            let UDFDefinition::LambdaUDF(lambda_udf) = &udf.definition else {
+                continue;
+            };
+            let definitions =
+                std::iter::once((&lambda_udf.definition, lambda_udf.definition_checksum))
+                    .chain(lambda_udf.defaults.values().map(|default| (default, None)));
+            for (definition, checksum) in definitions {
+                if let Err(error) = parse_persisted_expr(
+                    PersistedObjectKind::Udf,
+                    &udf.name,
+                    definition,
+                    checksum,
+                    dialect,
+                ) {
+                    objects.push(InvalidObject {
+                        database: "".to_string(),
+                        name: udf.name.clone(),
+                        kind: PersistedObjectKind::Udf,
+                        definition: definition.clone(),
+                        error,
+                    });
+                    break;
+                }
+            }
+        }
+        Ok(objects)
+    }
+}
@@ -23,6 +23,7 @@ extern crate core;
 mod background_jobs_table;
 mod background_tasks_table;
 mod backtrace_table;
+mod build_info_table;
 mod build_options_table;
 mod caches_table;
 mod catalogs_table;
@@ -32,10 +33,12 @@ mod columns_table;
 mod configs_table;
 mod contributors_table;
 mod credits_table;
+mod data_cache_table;
 mod databases_table;
 mod engines_table;
 mod functions_table;
 mod indexes_table;
+mod invalid_objects_table;
 mod locks_table;
 mod log_queue;
 mod malloc_stats_table;
@@ -59,6 +62,7 @@ mod tables_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
+mod user_function_parameters_table;
 mod user_functions_table;
 mod users_table;
 mod util;
@@ -67,6 +71,7 @@ mod virtual_columns_table;
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
 pub use backtrace_table::BacktraceTable;
+pub use build_info_table::BuildInfoTable;
 pub use build_options_table::BuildOptionsTable;
 pub use caches_table::CachesTable;
 pub use catalogs_table::CatalogsTable;
@@ -78,10 +83,12 @@ pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
+pub use data_cache_table::DataCacheTable;
 pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
+pub use invalid_objects_table::InvalidObjectsTable;
 pub use locks_table::LocksTable;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
@@ -119,6 +126,7 @@ pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
+pub use user_function_parameters_table::UserFunctionParametersTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
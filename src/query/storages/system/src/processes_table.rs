@@ -74,6 +74,7 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_created_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
+        let mut processes_result_buffered_bytes = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             let data_metrics = &process_info.data_metrics;
@@ -115,6 +116,7 @@ impl SyncSystemTable for ProcessesTable {
 
             // Status info.
             processes_status.push(process_info.status_info.clone().unwrap_or("".to_owned()));
+            processes_result_buffered_bytes.push(process_info.result_buffered_bytes);
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -135,6 +137,7 @@ impl SyncSystemTable for ProcessesTable {
             UInt64Type::from_data(processes_time),
             TimestampType::from_data(processes_created_time),
             StringType::from_data(processes_status),
+            UInt64Type::from_data(processes_result_buffered_bytes),
         ]))
     }
 }
@@ -177,6 +180,10 @@ impl ProcessesTable {
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("created_time", TableDataType::Timestamp),
             TableField::new("status", TableDataType::String),
+            TableField::new(
+                "result_buffered_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ]);
 
         let table_info = TableInfo {
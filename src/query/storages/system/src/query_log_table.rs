@@ -150,6 +150,9 @@ pub struct QueryLogElement {
     pub bytes_from_remote_disk: u64,
     pub bytes_from_local_disk: u64,
     pub bytes_from_memory: u64,
+    pub meta_call_count: u64,
+    pub meta_call_retries: u64,
+    pub meta_call_duration_ms: u64,
 
     // Client.
     pub client_info: String,
@@ -163,6 +166,7 @@ pub struct QueryLogElement {
 
     // Server.
     pub server_version: String,
+    pub server_git_sha: String,
 
     // Session settings
     #[serde(skip_serializing)]
@@ -298,6 +302,18 @@ impl SystemLogElement for QueryLogElement {
                 "bytes_from_memory",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new(
+                "meta_call_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "meta_call_retries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "meta_call_duration_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
             // Client.
             TableField::new("client_info", TableDataType::String),
             TableField::new("client_address", TableDataType::String),
@@ -311,6 +327,7 @@ impl SystemLogElement for QueryLogElement {
             TableField::new("stack_trace", TableDataType::String),
             // Server.
             TableField::new("server_version", TableDataType::String),
+            TableField::new("server_git_sha", TableDataType::String),
             // Session settings
             TableField::new("session_settings", TableDataType::String),
             // Extra.
@@ -517,6 +534,19 @@ impl SystemLogElement for QueryLogElement {
             .unwrap()
             .push(Scalar::Number(NumberScalar::UInt64(self.bytes_from_memory)).as_ref());
 
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.meta_call_count)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.meta_call_retries)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.meta_call_duration_ms)).as_ref());
+
         // Client.
         columns
             .next()
@@ -548,6 +578,10 @@ impl SystemLogElement for QueryLogElement {
             .next()
             .unwrap()
             .push(Scalar::String(self.server_version.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.server_git_sha.clone()).as_ref());
         // Session settings
         columns
             .next()
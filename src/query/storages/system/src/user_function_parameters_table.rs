@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::UserFunctionsTable;
+
+/// The parameters of the udfs, one row for each argument and one row with ordinal position 0
+/// for the return value if its type is declared.
+pub struct UserFunctionParametersTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for UserFunctionParametersTable {
+    const NAME: &'static str = "system.user_function_parameters";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let udfs = UserFunctionsTable::get_udfs(ctx).await?;
+
+        let mut function_names = Vec::new();
+        let mut ordinal_positions = Vec::new();
+        let mut parameter_modes = Vec::new();
+        let mut parameter_names = Vec::new();
+        let mut data_types = Vec::new();
+        for udf in udfs.iter() {
+            let (parameters, return_type) = match &udf.definition {
+                UDFDefinition::LambdaUDF(x) => (
+                    x.parameters
+                        .iter()
+                        .map(|name| (Some(name.clone()), None))
+                        .collect::<Vec<_>>(),
+                    None,
+                ),
+                UDFDefinition::UDFServer(x) => (
+                    x.arg_types
+                        .iter()
+                        .map(|ty| (None, Some(ty.to_string())))
+                        .collect(),
+                    Some(x.return_type.to_string()),
+                ),
+                UDFDefinition::UDFScript(x) => (
+                    x.arg_types
+                        .iter()
+                        .map(|ty| (None, Some(ty.to_string())))
+                        .collect(),
+                    Some(x.return_type.to_string()),
+                ),
+            };
+
+            if let Some(return_type) = return_type {
+                function_names.push(udf.name.clone());
+                ordinal_positions.push(0);
+                parameter_modes.push(None);
+                parameter_names.push(None);
+                data_types.push(Some(return_type));
+            }
+            for (i, (name, data_type)) in parameters.into_iter().enumerate() {
+                function_names.push(udf.name.clone());
+                ordinal_positions.push(i as u64 + 1);
+                parameter_modes.push(Some("IN".to_string()));
+                parameter_names.push(name);
+                data_types.push(data_type);
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(function_names),
+            UInt64Type::from_data(ordinal_positions),
+            StringType::from_opt_data(parameter_modes),
+            StringType::from_opt_data(parameter_names),
+            StringType::from_opt_data(data_types),
+        ]))
+    }
+}
+
+impl UserFunctionParametersTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("function_name", TableDataType::String),
+            TableField::new(
+                "ordinal_position",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "parameter_mode",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "parameter_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "data_type",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'user_function_parameters'".to_string(),
+            name: "user_function_parameters".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemUserFunctionParameters".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(UserFunctionParametersTable { table_info })
+    }
+}
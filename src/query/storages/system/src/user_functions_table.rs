@@ -78,17 +78,7 @@ impl AsyncSystemTable for UserFunctionsTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
-        let enable_experimental_rbac_check =
-            ctx.get_settings().get_enable_experimental_rbac_check()?;
-        let udfs = if enable_experimental_rbac_check {
-            let visibility_checker = ctx.get_visibility_checker().await?;
-            let udfs = UserFunctionsTable::get_udfs(ctx).await?;
-            udfs.into_iter()
-                .filter(|udf| visibility_checker.check_udf_visibility(&udf.name))
-                .collect::<Vec<_>>()
-        } else {
-            UserFunctionsTable::get_udfs(ctx).await?
-        };
+        let udfs = UserFunctionsTable::get_udfs(ctx).await?;
 
         let names: Vec<&str> = udfs
             .iter()
@@ -122,7 +112,7 @@ impl AsyncSystemTable for UserFunctionsTable {
         let definitions = (0..names.len())
             .map(|i| {
                 udfs.get(i)
-                    .map_or("".to_string(), |udf| udf.definition.to_string())
+                    .map_or("".to_string(), |udf| udf.definition.masked().to_string())
             })
             .collect();
 
@@ -178,9 +168,18 @@ impl UserFunctionsTable {
         AsyncOneBlockSystemTable::create(UserFunctionsTable { table_info })
     }
 
+    /// Returns the udfs that are visible to the current user.
     #[async_backtrace::framed]
-    async fn get_udfs(ctx: Arc<dyn TableContext>) -> Result<Vec<UserDefinedFunction>> {
+    pub(crate) async fn get_udfs(ctx: Arc<dyn TableContext>) -> Result<Vec<UserDefinedFunction>> {
         let tenant = ctx.get_tenant();
-        UserApiProvider::instance().list_udf(&tenant).await
+        let udfs = UserApiProvider::instance().list_udf(&tenant).await?;
+        if !ctx.get_settings().get_enable_experimental_rbac_check()? {
+            return Ok(udfs);
+        }
+        let visibility_checker = ctx.get_visibility_checker().await?;
+        Ok(udfs
+            .into_iter()
+            .filter(|udf| visibility_checker.check_udf_visibility(&udf.name))
+            .collect())
     }
 }
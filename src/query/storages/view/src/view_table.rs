@@ -28,6 +28,8 @@ pub struct ViewTable {
 
 pub const VIEW_ENGINE: &str = "VIEW";
 pub const QUERY: &str = "query";
+/// Checksum of the query, to detect a corrupted definition when the view is loaded.
+pub const QUERY_CHECKSUM: &str = "query_checksum";
 
 impl ViewTable {
     pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
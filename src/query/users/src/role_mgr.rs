@@ -225,6 +225,30 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role privileges)"))
     }
 
+    // Get the roles granted privileges on renamed objects, with the grants moved to the new
+    // objects. The roles are not updated.
+    #[async_backtrace::framed]
+    pub async fn rename_grant_objects_of_roles(
+        &self,
+        tenant: &Tenant,
+        rename: &(dyn Fn(&GrantObject) -> Option<GrantObject> + Send + Sync),
+    ) -> Result<Vec<(RoleInfo, RoleInfo)>> {
+        let seq_roles = self
+            .role_api(tenant)
+            .get_meta_roles()
+            .await
+            .map_err(|e| e.add_message_back("(while get roles)."))?;
+        let mut renamed = vec![];
+        for role_info in seq_roles.into_iter().map(|r| r.data) {
+            let mut new_role_info = role_info.clone();
+            if new_role_info.grants.rename_objects(rename) {
+                new_role_info.update_role_time();
+                renamed.push((role_info, new_role_info));
+            }
+        }
+        Ok(renamed)
+    }
+
     // the grant_role can not have cycle with target_role.
     #[async_backtrace::framed]
     pub async fn grant_role_to_role(
@@ -215,6 +215,25 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke user privileges)"))
     }
 
+    // Get the users granted privileges on renamed objects, with the grants moved to the new
+    // objects. The users are not updated.
+    #[async_backtrace::framed]
+    pub async fn rename_grant_objects_of_users(
+        &self,
+        tenant: &Tenant,
+        rename: &(dyn Fn(&GrantObject) -> Option<GrantObject> + Send + Sync),
+    ) -> Result<Vec<(UserInfo, UserInfo)>> {
+        let mut renamed = vec![];
+        for user_info in self.get_users(tenant).await? {
+            let mut new_user_info = user_info.clone();
+            if new_user_info.grants.rename_objects(rename) {
+                new_user_info.update_user_time();
+                renamed.push((user_info, new_user_info));
+            }
+        }
+        Ok(renamed)
+    }
+
     #[async_backtrace::framed]
     pub async fn grant_role_to_user(
         &self,
@@ -73,7 +73,7 @@ const KNOWN_ERRORS: &[&str] = &[
     "start must be greater than or equal to end when step is negative",
     "Expected Number, Date or Timestamp type, but got",
     "Unsupported data type for generate_series",
-    "Having clause can't contain window functions",
+    "is not allowed in HAVING clause",
 ];
 
 pub struct Runner {
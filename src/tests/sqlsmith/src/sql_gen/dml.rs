@@ -88,6 +88,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             source,
             // TODO
             overwrite: false,
+            on_error: None,
         }
     }
 
@@ -490,6 +491,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 columns,
                 source,
                 overwrite: false,
+                on_error: None,
             })
         } else {
             None
@@ -154,6 +154,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -171,6 +173,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -228,6 +232,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -246,6 +252,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -263,6 +271,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -282,6 +292,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -504,11 +516,13 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         span: None,
                         substr_expr: Box::new(substr_expr),
                         str_expr: Box::new(str_expr),
+                        position_from: None,
                     }
                 }
                 2 => Expr::CountAll {
                     span: None,
                     window: None,
+                    filter: None,
                 },
                 3 => {
                     let expr_ty = self.gen_all_number_data_type();
@@ -611,6 +625,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -649,6 +665,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         params: vec![],
                         window: None,
                         lambda: None,
+                        order_by: vec![],
+                        filter: None,
                     },
                 }
             }
@@ -750,12 +768,14 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 expr: Box::new(source_expr),
                 target_type,
                 pg_style: self.rng.gen_bool(0.5),
+                format: None,
             }
         } else {
             Expr::TryCast {
                 span: None,
                 expr: Box::new(source_expr),
                 target_type,
+                format: None,
             }
         }
     }
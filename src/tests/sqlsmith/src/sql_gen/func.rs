@@ -774,6 +774,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 params,
                 window,
                 lambda,
+                order_by: vec![],
+                filter: None,
             },
         }
     }
@@ -236,6 +236,12 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                             orders.push(order_by_expr);
                         }
                     }
+                    GroupBy::Combined(group_bys) => {
+                        return self.gen_order_by(group_bys.into_iter().next());
+                    }
+                    GroupBy::WithTotals(group_by) => {
+                        return self.gen_order_by(Some(*group_by));
+                    }
                 }
             } else {
                 for _ in 0..order_nums {
@@ -409,6 +415,12 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     alias: None,
                 }));
             }
+            Some(GroupBy::Combined(group_bys)) => {
+                return self.gen_select_list(&group_bys.first().cloned());
+            }
+            Some(GroupBy::WithTotals(group_by)) => {
+                return self.gen_select_list(&Some(group_by.as_ref().clone()));
+            }
             None => {
                 let select_num = self.rng.gen_range(1..=7);
                 for _ in 0..select_num {
@@ -543,6 +555,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             })
                         }
@@ -560,6 +574,8 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                     params: vec![],
                                     window: None,
                                     lambda: None,
+                                    order_by: vec![],
+                                    filter: None,
                                 },
                             })
                         }